@@ -22,6 +22,7 @@ use substrate_subxt::{
 use sunshine_bounty_client::{
     bank::Bank,
     bounty::Bounty,
+    court::Court,
     donate::Donate,
     org::Org,
     vote::Vote,
@@ -88,6 +89,10 @@ impl Vote for Runtime {
     type VoteJustification = TextBlock;
 }
 
+impl Court for Runtime {
+    type DisputeId = u64;
+}
+
 impl Donate for Runtime {}
 
 impl Bank for Runtime {
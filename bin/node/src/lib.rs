@@ -24,6 +24,7 @@ use test_runtime::{
     Signature,
     SystemConfig,
     TreasuryConfig,
+    VoteConfig,
     WASM_BINARY,
 };
 
@@ -213,5 +214,6 @@ pub fn testnet_genesis(
             minting_interval: treasury_mint_rate.0,
             mint_amount: treasury_mint_rate.1,
         }),
+        vote: Some(VoteConfig {}),
     }
 }
@@ -303,17 +303,101 @@ impl pallet_transaction_payment::Trait for Runtime {
     type WeightToFee = IdentityFee<Balance>;
     type FeeMultiplierUpdate = ();
 }
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight =
+        Perbill::from_percent(80) * MaximumBlockWeight::get();
+    pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Trait for Runtime {
+    type Event = Event;
+    type Origin = Origin;
+    type PalletsOrigin = OriginCaller;
+    type Call = Call;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = MaxScheduledPerBlock;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const SnapshotRetentionPeriod: BlockNumber = 7 * DAYS;
+}
 impl org::Trait for Runtime {
     type Event = Event;
     type Cid = sunshine_codec::Cid; // Serialize and Deserialize
     type OrgId = u64;
     type Shares = u64;
+    // votes among shareholders, e.g. on issuance cap increases, run in vote-direct
+    type ShareTransferVote = VoteDirect;
+    type ShareTransferRequiresVote = ();
+    type ProxyVotingActive = ();
+    type SnapshotRetentionPeriod = SnapshotRetentionPeriod;
+}
+parameter_types! {
+    pub const IndexJustifications: bool = true;
+    pub const ConvictionLockPeriod: BlockNumber = DAYS;
+    pub const DecayedVotersPerBlock: u32 = 100;
+    pub const MaxVotesPerPool: u32 = 16;
+    pub const VoteCreationDeposit: Balance = 1000;
+    pub const FullRefundTurnout: Permill = Permill::from_percent(20);
+    pub const VoteCreationFee: Balance = 100;
+    pub const VoteRewardPool: ModuleId = ModuleId(*b"vote/rwd");
+}
+/// The treasury module's account, which receives forfeited vote deposits
+pub struct TreasuryAccount;
+impl frame_support::traits::Get<AccountId> for TreasuryAccount {
+    fn get() -> AccountId {
+        treasury::Module::<Runtime>::account_id()
+    }
 }
 impl vote::Trait for Runtime {
     type Event = Event;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type IndexJustifications = IndexJustifications;
+    type MaxOpenVotesPerOrg = ();
+    type PruneParticipationIndex = ();
+    type MaxDelegatorsPerVote = ();
+    type MaxVotesPerPool = MaxVotesPerPool;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type DecayedVotersPerBlock = DecayedVotersPerBlock;
+    type Currency = Balances;
+    type VoteCreationDeposit = VoteCreationDeposit;
+    type FullRefundTurnout = FullRefundTurnout;
+    type Treasury = TreasuryAccount;
+    type VoteCreationFee = VoteCreationFee;
+    type VoteRewardPool = VoteRewardPool;
+    // recipients of a bank spend cannot vote on it
+    type ConflictCheck = Bank;
+}
+parameter_types! {
+    pub const MaxVotersPerVote: u32 = 1000;
+    pub const MaxSignalPerVote: u64 = 1_000_000_000;
+    pub const MaxVotesPerAccount: u32 = 100;
+    pub const ShutdownFinalizationsPerBlock: u32 = 10;
+}
+impl vote_direct::Trait for Runtime {
+    type Event = Event;
+    type Cid = sunshine_codec::Cid;
+    type VoteId = u64;
+    type Signal = u64;
+    type MaxVotersPerVote = MaxVotersPerVote;
+    type MaxSignalPerVote = MaxSignalPerVote;
+    type MaxVotesPerAccount = MaxVotesPerAccount;
+    type SybilResistance = ();
+    type IdentityProvider = ();
+    type Currency = Balances;
+    type BondSlashRecipient = TreasuryAccount;
+    type VoteWeight = util::vote::LinearWeight;
+    type OrgId = u64;
+    type OrgAdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type ShutdownFinalizationsPerBlock = ShutdownFinalizationsPerBlock;
+    type ScheduledCall = Call;
+    type PalletsOrigin = OriginCaller;
+    type Scheduler = Scheduler;
+    type WeightInfo = ();
 }
 impl drip::Trait for Runtime {
     type Event = Event;
@@ -336,6 +420,7 @@ parameter_types! {
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
     pub const MaxTreasuryPerOrg: u32 = 50;
     pub const MinimumDeposit: u128 = 20;
+    pub const ProportionalSpendLifetime: BlockNumber = 7 * DAYS;
 }
 impl bank::Trait for Runtime {
     type Event = Event;
@@ -345,6 +430,25 @@ impl bank::Trait for Runtime {
     type SpendId = u64;
     type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
     type MinDeposit = MinimumDeposit;
+    type ProportionalSpendLifetime = ProportionalSpendLifetime;
+}
+parameter_types! {
+    pub const MinimumDisputeAmount: Balance = 10;
+    pub const MaxDisputeVoteReruns: u32 = 1;
+    pub const DisputeRetentionPeriod: BlockNumber = 7 * DAYS;
+    pub const OracleTimeout: BlockNumber = 3 * DAYS;
+    pub const MaxDisputeExpirationsPerBlock: u32 = 64;
+}
+impl insurance::Trait for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type DisputeId = u64;
+    type MinimumDisputeAmount = MinimumDisputeAmount;
+    type Treasury = TreasuryAccount;
+    type MaxDisputeVoteReruns = MaxDisputeVoteReruns;
+    type DisputeRetentionPeriod = DisputeRetentionPeriod;
+    type OracleTimeout = OracleTimeout;
+    type MaxDisputeExpirationsPerBlock = MaxDisputeExpirationsPerBlock;
 }
 parameter_types! {
     pub const Foundation: ModuleId = ModuleId(*b"fundacon");
@@ -389,13 +493,16 @@ construct_runtime!(
         Grandpa: pallet_grandpa::{Module, Call, Storage, Config, Event},
         Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Module, Storage},
+        Scheduler: pallet_scheduler::{Module, Call, Storage, Event<T>},
         // sunshine-bounty modules
         Org: org::{Module, Call, Config<T>, Storage, Event<T>},
-        Vote: vote::{Module, Call, Storage, Event<T>},
+        Vote: vote::{Module, Call, Config, Storage, Event<T>},
+        VoteDirect: vote_direct::{Module, Call, Storage, Event<T>},
         Drip: drip::{Module, Call, Storage, Event<T>},
         Treasury: treasury::{Module, Call, Config<T>, Storage, Event<T>},
         Donate: donate::{Module, Call, Event<T>},
         Bank: bank::{Module, Call, Storage, Event<T>},
+        Court: insurance::{Module, Call, Storage, Event<T>},
         Bounty: bounty::{Module, Call, Storage, Event<T>},
         Grant: grant::{Module, Call, Config<T>, Storage, Event<T>},
     }
@@ -506,6 +613,15 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
+        fn query_info(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
+            TransactionPayment::query_info(uxt, len)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             opaque::SessionKeys::generate(seed)
@@ -542,4 +658,140 @@ impl_runtime_apis! {
             None
         }
     }
+
+    impl vote::runtime_api::VoteApi<Block, AccountId, u64, u64, sunshine_codec::Cid> for Runtime {
+        fn get_votes_for_vote_id(
+            vote_id: u64,
+        ) -> Vec<(AccountId, util::vote::VoterView, Option<u64>)> {
+            Vote::get_votes_for_vote_id(vote_id)
+        }
+
+        fn get_votes_page(
+            vote_id: u64,
+            last_key: Option<AccountId>,
+            limit: u32,
+        ) -> (Vec<(AccountId, util::vote::VoterView, Option<u64>)>, Option<AccountId>) {
+            Vote::get_votes_page(vote_id, last_key, limit)
+        }
+
+        fn get_vote_creator(vote_id: u64) -> Option<AccountId> {
+            Vote::vote_creator(vote_id)
+        }
+
+        fn compute_pool_outcome(pool_id: u32) -> Option<util::vote::VoteOutcome> {
+            Vote::compute_pool_outcome(pool_id).ok()
+        }
+
+        fn project_outcome(
+            vote_id: u64,
+            account: AccountId,
+            direction: util::vote::VoterView,
+        ) -> Option<util::vote::VoteOutcome> {
+            Vote::project_outcome(vote_id, &account, direction).ok()
+        }
+
+        fn get_votes_participated_in(account: AccountId) -> Vec<u64> {
+            Vote::get_votes_participated_in(account)
+        }
+
+        fn get_vote_comments(vote_id: u64) -> Vec<(AccountId, sunshine_codec::Cid)> {
+            Vote::get_vote_comments(vote_id)
+        }
+
+        fn can_vote(vote_id: u64, account: AccountId) -> bool {
+            Vote::can_vote(vote_id, &account)
+        }
+
+        fn get_voters_by_justification(
+            vote_id: u64,
+            justification: sunshine_codec::Cid,
+        ) -> Vec<AccountId> {
+            Vote::get_voters_by_justification(vote_id, justification)
+        }
+    }
+
+    impl vote::runtime_api::VoteRegistry<Block, u64, u64> for Runtime {
+        fn get_votes_for_org(
+            org_id: u64,
+            status: Option<util::vote::VoteStatus>,
+        ) -> Vec<u64> {
+            Vote::get_votes_for_org(org_id, status)
+        }
+
+        fn count_open_votes() -> u32 {
+            Vote::count_open_votes()
+        }
+    }
+
+    impl org::runtime_api::OrgApi<Block, u64, AccountId, u64> for Runtime {
+        fn list_org_members_page(
+            org_id: u64,
+            cursor: Option<AccountId>,
+            limit: u32,
+        ) -> (Vec<(AccountId, u64)>, Option<AccountId>) {
+            Org::list_org_members_page(org_id, cursor, limit)
+        }
+    }
+
+    impl bank::runtime_api::BankApi<Block, u64, u64> for Runtime {
+        fn get_org_banks(org_id: u64) -> Vec<u64> {
+            Bank::get_org_banks(org_id)
+        }
+    }
+
+    impl bank::runtime_api::BankSummaryApi<Block, u64, Balance> for Runtime {
+        fn get_bank_balances(
+            bank_id: u64,
+        ) -> Option<util::bank::BankBalanceSummary<Balance>> {
+            Bank::get_bank_balances(bank_id)
+        }
+    }
+
+    impl vote_direct::runtime_api::VoteDirectApi<Block, AccountId, u64> for Runtime {
+        fn votes_of(account: AccountId) -> Vec<u64> {
+            VoteDirect::votes_of(account)
+        }
+    }
+
+    impl vote_direct::runtime_api::VoteMetricsApi<Block> for Runtime {
+        fn get_vote_metrics() -> util::vote::VoteMetrics {
+            VoteDirect::get_vote_metrics()
+        }
+    }
+
+    impl insurance::runtime_api::DisputeApi<Block, u64, BlockNumber, u64, Balance, AccountId, u64> for Runtime {
+        fn get_dispute_history(
+            dispute_id: u64,
+        ) -> Vec<(BlockNumber, util::insurance::DisputeState<u64>)> {
+            Court::get_dispute_history(dispute_id)
+        }
+
+        fn minimum_dispute_amount() -> Balance {
+            Court::minimum_dispute_amount()
+        }
+
+        fn get_dispute_details(
+            dispute_id: u64,
+        ) -> Option<util::insurance::DisputeDetails<u64, AccountId, Balance, BlockNumber, u64, u64>> {
+            Court::get_dispute_details(dispute_id)
+        }
+
+        fn get_dispute_for_vote(vote_id: u64) -> Option<u64> {
+            Court::get_dispute_for_vote(vote_id)
+        }
+
+        fn get_account_dispute_history(
+            account: AccountId,
+        ) -> Vec<(u64, util::insurance::DisputeRole, util::insurance::DisputeState<u64>)> {
+            Court::get_account_dispute_history(account)
+        }
+    }
+
+    impl insurance::runtime_api::GovernanceApi<Block, u64, u64, u64> for Runtime {
+        fn org_governance_summary(
+            org: u64,
+        ) -> util::governance::GovernanceSummary<u64, u64> {
+            Court::org_governance_summary(org)
+        }
+    }
 }
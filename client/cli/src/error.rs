@@ -4,6 +4,10 @@ use thiserror::Error;
 #[error("Vote percent threshold input must be 0 < x < 100")]
 pub struct VotePercentThresholdInputBoundError;
 
+#[derive(Debug, Error)]
+#[error("Vote weight fraction input must be 0 < x <= 100")]
+pub struct VoteWeightFractionInputBoundError;
+
 #[derive(Debug, Error)]
 #[error("Input error for posting bounty.")]
 pub struct PostBountyInputError;
@@ -1,4 +1,7 @@
-use crate::error::VotePercentThresholdInputBoundError;
+use crate::error::{
+    VotePercentThresholdInputBoundError,
+    VoteWeightFractionInputBoundError,
+};
 use clap::Clap;
 use core::fmt::{
     Debug,
@@ -202,6 +205,10 @@ pub struct VoteSubmitCommand {
     pub vote_id: u64,
     pub direction: u8,
     pub justification: Option<String>,
+    /// Percent of the voter's signal committed to the direction
+    pub weight_fraction: Option<u8>,
+    /// Conviction level from 0 to 2, multiplying signal for a longer lock
+    pub conviction: Option<u8>,
 }
 
 impl VoteSubmitCommand {
@@ -235,8 +242,23 @@ impl VoteSubmitCommand {
             } else {
                 None
             };
+        let weight_fraction = if let Some(w) = self.weight_fraction {
+            if w > 0u8 && w <= 100u8 {
+                Some(Permill::from_percent(w.into()))
+            } else {
+                return Err(VoteWeightFractionInputBoundError.into())
+            }
+        } else {
+            None
+        };
         let event = client
-            .submit_vote(self.vote_id.into(), voter_view, justification)
+            .submit_vote(
+                self.vote_id.into(),
+                voter_view,
+                justification,
+                weight_fraction,
+                self.conviction.unwrap_or_default(),
+            )
             .await?;
         println!(
             "Account {} voted with view {:?} in VoteId {}",
@@ -0,0 +1,101 @@
+mod subxt;
+
+pub use subxt::*;
+
+use crate::{
+    error::Error,
+    vote::{
+        Vote,
+        VoteStateStore,
+    },
+};
+use sunshine_client_utils::{
+    async_trait,
+    Client,
+    Node,
+    Result,
+};
+
+#[async_trait]
+pub trait CourtClient<N: Node>: Client<N>
+where
+    N::Runtime: Court,
+{
+    /// The dispute the vote was dispatched for, `None` for votes opened by
+    /// governance
+    async fn fetch_dispute_for_vote(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+    ) -> Result<Option<<N::Runtime as Court>::DisputeId>>;
+}
+
+#[async_trait]
+impl<N, C> CourtClient<N> for C
+where
+    N: Node,
+    N::Runtime: Court,
+    C: Client<N>,
+{
+    async fn fetch_dispute_for_vote(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+    ) -> Result<Option<<N::Runtime as Court>::DisputeId>> {
+        // unknown votes fail rather than read as opened by governance
+        self.chain_client()
+            .fetch(&VoteStateStore::<N::Runtime> { vote: vote_id }, None)
+            .await?
+            .ok_or(Error::VoteStateNotFound)?;
+        Ok(self
+            .chain_client()
+            .fetch(&VoteToDisputeStore::<N::Runtime> { vote: vote_id }, None)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_client::{
+        client::{
+            AccountKeyring,
+            Client as _,
+            Node as _,
+        },
+        court::CourtClient,
+        org::OrgClient,
+        utils::{
+            organization::OrgRep,
+            vote::Threshold,
+        },
+        vote::VoteClient,
+        Client,
+        Node,
+        TextBlock,
+    };
+
+    #[async_std::test]
+    async fn governance_votes_have_no_dispute() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let raw_const = TextBlock {
+            text: "votes without disputes".to_string(),
+        };
+        let org = client
+            .new_flat_org(Some(alice.clone()), None, raw_const, &[alice])
+            .await
+            .unwrap()
+            .new_id;
+        let vote = client
+            .create_signal_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(1, None),
+                None,
+            )
+            .await
+            .unwrap()
+            .new_vote_id;
+        assert_eq!(client.fetch_dispute_for_vote(vote).await.unwrap(), None);
+        assert!(client.fetch_dispute_for_vote(vote + 1).await.is_err());
+    }
+}
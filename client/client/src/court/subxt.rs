@@ -0,0 +1,52 @@
+use crate::{
+    org::{
+        Org,
+        OrgEventsDecoder,
+    },
+    vote::{
+        Vote,
+        VoteEventsDecoder,
+    },
+};
+use frame_support::Parameter;
+use parity_scale_codec::{
+    Codec,
+    Encode,
+};
+use sp_runtime::traits::{
+    AtLeast32Bit,
+    MaybeSerializeDeserialize,
+    Member,
+};
+use std::fmt::Debug;
+use substrate_subxt::{
+    module,
+    sp_runtime,
+    system::{
+        System,
+        SystemEventsDecoder,
+    },
+    Store,
+};
+
+/// The subset of the `insurance::Trait` that a client must implement.
+#[module]
+pub trait Court: System + Org + Vote {
+    /// The identifier for each dispute
+    type DisputeId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+}
+
+// ~~ Maps ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct VoteToDisputeStore<T: Court> {
+    #[store(returns = T::DisputeId)]
+    pub vote: <T as Vote>::VoteId,
+}
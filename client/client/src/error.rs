@@ -6,4 +6,10 @@ pub enum Error {
     EventNotFound,
     #[error("Number cannot be parsed from string")]
     ParseIntError,
+    #[error("vote state not found")]
+    VoteStateNotFound,
+    #[error("block hash not found")]
+    BlockHashNotFound,
+    #[error("vote outcome not reached before the timeout")]
+    VoteOutcomeTimeout,
 }
@@ -0,0 +1,43 @@
+//! Fee estimates for extrinsics that have not been submitted yet
+use crate::runtime_api::state_call;
+use frame_support::weights::DispatchClass;
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
+use substrate_subxt::{
+    Call,
+    UncheckedExtrinsic,
+};
+use sunshine_client_utils::{
+    Client,
+    Node,
+    Result,
+};
+
+/// What the runtime's `TransactionPaymentApi` expects an extrinsic to cost
+#[derive(Clone, Copy, Debug, Decode, Eq, PartialEq)]
+pub struct FeeEstimate {
+    /// The call's dispatch weight
+    pub weight: u64,
+    /// The call's dispatch class
+    pub class: DispatchClass,
+    /// Base, length and weight fee, excluding any tip
+    pub partial_fee: u128,
+}
+
+/// Estimates the fee for `call` from an unsigned extrinsic so no key is used
+/// -> slightly below the fee actually charged, the signature's bytes are not
+/// part of the estimated length
+pub async fn estimate_fee<N, C, K>(client: &C, call: K) -> Result<FeeEstimate>
+where
+    N: Node,
+    C: Client<N> + ?Sized,
+    K: Call<N::Runtime> + Send + Sync,
+{
+    let encoded = client.chain_client().encode(call)?;
+    let extrinsic = UncheckedExtrinsic::<N::Runtime>::new_unsigned(encoded);
+    let len = extrinsic.encoded_size() as u32;
+    state_call(client, "TransactionPaymentApi_query_info", (extrinsic, len))
+        .await
+}
@@ -4,8 +4,11 @@ mod error;
 pub use error::Error;
 pub mod bank;
 pub mod bounty;
+pub mod court;
 pub mod donate;
+pub mod fee;
 pub mod org;
+mod runtime_api;
 pub mod vote;
 pub use sunshine_bounty_utils as utils;
 
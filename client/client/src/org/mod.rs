@@ -9,11 +9,17 @@ use libipld::{
     cache::Cache,
     cbor::DagCborCodec,
 };
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
 use substrate_subxt::{
+    sp_core::storage::StorageKey,
     system::System,
     Runtime,
     SignedExtension,
     SignedExtra,
+    Store,
 };
 use sunshine_client_utils::{
     async_trait,
@@ -23,6 +29,9 @@ use sunshine_client_utils::{
     Result,
 };
 
+/// A member of an org and the shares it holds
+pub type MemberShares<T> = (<T as System>::AccountId, <T as Org>::Shares);
+
 #[async_trait]
 pub trait OrgClient<N: Node>: Client<N>
 where
@@ -94,6 +103,19 @@ where
     ) -> Result<
         Option<Vec<(<N::Runtime as System>::AccountId, Prof<N::Runtime>)>>,
     >;
+    async fn list_all_org_members(
+        &self,
+        org: <N::Runtime as Org>::OrgId,
+    ) -> Result<Vec<MemberShares<N::Runtime>>>;
+    async fn list_org_members_page(
+        &self,
+        org: <N::Runtime as Org>::OrgId,
+        cursor: Option<<N::Runtime as System>::AccountId>,
+        limit: u32,
+    ) -> Result<(
+        Vec<MemberShares<N::Runtime>>,
+        Option<<N::Runtime as System>::AccountId>,
+    )>;
     async fn share_profiles(
         &self,
         account: <N::Runtime as System>::AccountId,
@@ -260,6 +282,75 @@ where
             Ok(Some(members_for_org))
         }
     }
+    async fn list_all_org_members(
+        &self,
+        org: <N::Runtime as Org>::OrgId,
+    ) -> Result<Vec<MemberShares<N::Runtime>>> {
+        let mut members = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) =
+                self.list_org_members_page(org, cursor, 100).await?;
+            members.extend(page);
+            if next.is_none() {
+                return Ok(members)
+            }
+            cursor = next;
+        }
+    }
+    /// Pages through the org's `Members` keys like the `list_org_members_page`
+    /// runtime API, fetching at most `limit` profiles per call.
+    ///
+    /// The account is decoded from the end of each `blake2_128_concat` key, so
+    /// keys past the org's prefix are never fetched.
+    async fn list_org_members_page(
+        &self,
+        org: <N::Runtime as Org>::OrgId,
+        cursor: Option<<N::Runtime as System>::AccountId>,
+        limit: u32,
+    ) -> Result<(
+        Vec<MemberShares<N::Runtime>>,
+        Option<<N::Runtime as System>::AccountId>,
+    )> {
+        let probe = cursor.clone().unwrap_or_default();
+        let probe_key = MembersStore::<N::Runtime> { org, who: &probe }
+            .key(self.chain_client().metadata())?;
+        // strip the hashed account to get the org's prefix
+        let prefix_len = probe_key.0.len() - 16 - probe.encode().len();
+        let prefix = probe_key.0[..prefix_len].to_vec();
+        let start = if cursor.is_some() {
+            probe_key
+        } else {
+            StorageKey(prefix.clone())
+        };
+        let keys = self
+            .chain_client()
+            .fetch_keys::<MembersStore<N::Runtime>>(limit + 1, Some(start), None)
+            .await?;
+        let mut members = Vec::new();
+        for key in keys.iter().take_while(|k| k.0.starts_with(&prefix)) {
+            members.push(<N::Runtime as System>::AccountId::decode(
+                &mut &key.0[prefix_len + 16..],
+            )?);
+        }
+        let next = if members.len() > limit as usize {
+            members.truncate(limit as usize);
+            members.last().cloned()
+        } else {
+            None
+        };
+        let mut page = Vec::new();
+        for who in members {
+            if let Some(profile) = self
+                .chain_client()
+                .fetch(&MembersStore::<N::Runtime> { org, who: &who }, None)
+                .await?
+            {
+                page.push((who, profile.total()));
+            }
+        }
+        Ok((page, next))
+    }
     async fn share_profiles(
         &self,
         account: <N::Runtime as System>::AccountId,
@@ -362,4 +453,47 @@ mod tests {
         };
         assert_eq!(event, expected_event);
     }
+
+    #[async_std::test]
+    async fn list_all_org_members_test() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let raw_const = TextBlock {
+            text: "count everyone".to_string(),
+        };
+        let mut members = vec![
+            alice.clone(),
+            random_account_id(),
+            random_account_id(),
+            random_account_id(),
+            random_account_id(),
+        ];
+        let org = client
+            .new_flat_org(Some(alice), None, raw_const, &members)
+            .await
+            .unwrap()
+            .new_id;
+        let mut listed: Vec<_> = client
+            .list_all_org_members(org)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(who, shares)| {
+                assert_eq!(shares, 1);
+                who
+            })
+            .collect();
+        listed.sort();
+        members.sort();
+        assert_eq!(listed, members);
+        // pages cover every member exactly once
+        let (first, cursor) =
+            client.list_org_members_page(org, None, 3).await.unwrap();
+        assert_eq!(first.len(), 3);
+        let (rest, end) =
+            client.list_org_members_page(org, cursor, 3).await.unwrap();
+        assert_eq!(rest.len(), 2);
+        assert!(end.is_none());
+    }
 }
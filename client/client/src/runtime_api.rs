@@ -0,0 +1,40 @@
+//! Calls into the runtime APIs through the node's `state_call` RPC
+use jsonrpsee::common::{
+    to_value as to_json_value,
+    Params,
+};
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
+use substrate_subxt::sp_core::Bytes;
+use sunshine_client_utils::{
+    Client,
+    Node,
+    Result,
+};
+
+/// Calls `method`, named `<Api>_<function>`, with the SCALE encoded `args`
+/// at the best block and decodes what it returns
+pub async fn state_call<N, C, A, R>(
+    client: &C,
+    method: &str,
+    args: A,
+) -> Result<R>
+where
+    N: Node,
+    C: Client<N> + ?Sized,
+    A: Encode,
+    R: Decode,
+{
+    let params = Params::Array(vec![
+        to_json_value(method)?,
+        to_json_value(Bytes(args.encode()))?,
+    ]);
+    let bytes: Bytes = client
+        .chain_client()
+        .rpc_client()
+        .request("state_call", params)
+        .await?;
+    Ok(R::decode(&mut &bytes[..])?)
+}
@@ -4,21 +4,52 @@ pub use subxt::*;
 
 use crate::{
     error::Error,
+    fee::{
+        estimate_fee,
+        FeeEstimate,
+    },
     org::Org,
+    runtime_api::state_call,
 };
 use libipld::{
     cache::Cache,
     cbor::DagCborCodec,
 };
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
+use std::collections::BTreeMap;
 use substrate_subxt::{
+    sp_core::{
+        storage::StorageKey,
+        twox_128,
+    },
+    sp_runtime::{
+        traits::{
+            Header,
+            SaturatedConversion,
+            Zero,
+        },
+        Permill,
+    },
     system::System,
+    EventsDecoder,
+    Raw,
     Runtime,
     SignedExtension,
     SignedExtra,
+    Store,
 };
 use sunshine_bounty_utils::{
     organization::OrgRep,
-    vote::Threshold,
+    traits::VoteVector,
+    vote::{
+        Threshold,
+        VoteOutcome,
+        VoteStatus,
+        VoterView,
+    },
 };
 use sunshine_client_utils::{
     async_trait,
@@ -28,6 +59,36 @@ use sunshine_client_utils::{
     Result,
 };
 
+/// A voter's view and the signal committed to it, `None` if it has not voted
+pub type VoterEntry<T> = (
+    <T as System>::AccountId,
+    VoterView,
+    Option<<T as Vote>::Signal>,
+);
+
+/// Tally replayed from a vote's events, independent of its on-chain state
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReconstructedVote<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub in_favor: T::Signal,
+    pub against: T::Signal,
+    pub turnout: T::Signal,
+    /// Number of `Voted` events replayed
+    pub votes_replayed: u32,
+    /// The vote's `VoteGenesisSet` event, `None` for votes opened before it
+    /// was emitted
+    pub genesis: Option<VoteGenesisSetEvent<T>>,
+}
+
+impl<T: Vote> ReconstructedVote<T> {
+    /// True iff the replayed tally equals the tally stored on-chain
+    pub fn matches(&self, state: &VoteSt<T>) -> bool {
+        self.in_favor == state.in_favor()
+            && self.against == state.against()
+            && self.turnout == state.turnout()
+    }
+}
+
 #[async_trait]
 pub trait VoteClient<N: Node>: Client<N>
 where
@@ -52,18 +113,84 @@ where
         vote_id: <N::Runtime as Vote>::VoteId,
         direction: <N::Runtime as Vote>::VoterView,
         justification: Option<<N::Runtime as Vote>::VoteJustification>,
+        weight_fraction: Option<Permill>,
+        conviction: u8,
     ) -> Result<VotedEvent<N::Runtime>>;
     async fn vote_threshold(
         &self,
         threshold_id: <N::Runtime as Vote>::ThresholdId,
     ) -> Result<ThreshConfig<N::Runtime>>;
+    async fn list_org_votes(
+        &self,
+        org: <N::Runtime as Org>::OrgId,
+        status: Option<VoteStatus>,
+    ) -> Result<Vec<<N::Runtime as Vote>::VoteId>>;
+    async fn count_open_votes(&self) -> Result<u32>;
+    async fn account_ownership_percent(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+        account: <N::Runtime as System>::AccountId,
+    ) -> Result<Permill>;
+    async fn reconstruct_vote(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+    ) -> Result<ReconstructedVote<N::Runtime>>;
+    async fn list_votes_for_vote(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+    ) -> Result<Vec<VoterEntry<N::Runtime>>>;
+    async fn list_votes_page(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+        last_key: Option<<N::Runtime as System>::AccountId>,
+        limit: u32,
+    ) -> Result<(
+        Vec<VoterEntry<N::Runtime>>,
+        Option<<N::Runtime as System>::AccountId>,
+    )>;
+    async fn await_outcome(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+        target: VoteOutcome,
+        timeout_blocks: <N::Runtime as System>::BlockNumber,
+    ) -> Result<VoteOutcome>;
+    async fn list_account_votes(
+        &self,
+        account: <N::Runtime as System>::AccountId,
+    ) -> Result<Vec<<N::Runtime as Vote>::VoteId>>;
+    async fn remaining_signal_needed(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+    ) -> Result<Option<<N::Runtime as Vote>::Signal>>;
+    async fn estimate_create_signal_vote_fee(
+        &self,
+        topic: Option<<N::Runtime as Vote>::VoteTopic>,
+        organization: OrgRep<<N::Runtime as Org>::OrgId>,
+        threshold: Threshold<<N::Runtime as Vote>::Signal>,
+        duration: Option<<N::Runtime as System>::BlockNumber>,
+    ) -> Result<FeeEstimate>;
+    async fn estimate_create_percent_vote_fee(
+        &self,
+        topic: Option<<N::Runtime as Vote>::VoteTopic>,
+        organization: OrgRep<<N::Runtime as Org>::OrgId>,
+        threshold: Threshold<<N::Runtime as Vote>::Percent>,
+        duration: Option<<N::Runtime as System>::BlockNumber>,
+    ) -> Result<FeeEstimate>;
+    async fn estimate_submit_vote_fee(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+        direction: <N::Runtime as Vote>::VoterView,
+        justification: Option<<N::Runtime as Vote>::VoteJustification>,
+        weight_fraction: Option<Permill>,
+        conviction: u8,
+    ) -> Result<FeeEstimate>;
 }
 
 #[async_trait]
 impl<N, C> VoteClient<N> for C
 where
     N: Node,
-    N::Runtime: Vote,
+    N::Runtime: Vote<VoterView = VoterView>,
     <<<N::Runtime as Runtime>::Extra as SignedExtra<N::Runtime>>::Extra as SignedExtension>::AdditionalSigned:
         Send + Sync,
     <N::Runtime as Org>::Cid: From<libipld::cid::Cid>,
@@ -133,6 +260,8 @@ where
         vote_id: <N::Runtime as Vote>::VoteId,
         direction: <N::Runtime as Vote>::VoterView,
         justification: Option<<N::Runtime as Vote>::VoteJustification>,
+        weight_fraction: Option<Permill>,
+        conviction: u8,
     ) -> Result<VotedEvent<N::Runtime>> {
         let signer = self.chain_signer()?;
         let justification = if let Some(j) = justification {
@@ -141,7 +270,14 @@ where
             None
         };
         self.chain_client()
-            .submit_vote_and_watch(&signer, vote_id, direction, justification)
+            .submit_vote_and_watch(
+                &signer,
+                vote_id,
+                direction,
+                justification,
+                weight_fraction,
+                conviction,
+            )
             .await?
             .voted()?
             .ok_or_else(|| Error::EventNotFound.into())
@@ -155,4 +291,735 @@ where
             .vote_thresholds(threshold_id, None)
             .await?)
     }
+    async fn list_org_votes(
+        &self,
+        org: <N::Runtime as Org>::OrgId,
+        status: Option<VoteStatus>,
+    ) -> Result<Vec<<N::Runtime as Vote>::VoteId>> {
+        state_call(self, "VoteRegistry_get_votes_for_org", (org, status)).await
+    }
+    async fn count_open_votes(&self) -> Result<u32> {
+        state_call(self, "VoteRegistry_count_open_votes", ()).await
+    }
+    async fn account_ownership_percent(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+        account: <N::Runtime as System>::AccountId,
+    ) -> Result<Permill> {
+        let magnitude = if let Some(v) = self
+            .chain_client()
+            .fetch(&VoteLoggerStore::<N::Runtime> { vote: vote_id, who: account }, None)
+            .await?
+        {
+            v.magnitude()
+        } else {
+            return Ok(Permill::zero())
+        };
+        let total = if let Some(state) = self
+            .chain_client()
+            .fetch(&VoteStateStore::<N::Runtime> { vote: vote_id }, None)
+            .await?
+        {
+            state.all_possible_turnout()
+        } else {
+            return Ok(Permill::zero())
+        };
+        let total: u128 = total.saturated_into();
+        if total == 0 {
+            return Ok(Permill::zero())
+        }
+        Ok(Permill::from_rational_approximation(
+            magnitude.saturated_into::<u128>(),
+            total,
+        ))
+    }
+    /// Replays the vote's `Voted` events to rebuild its tally.
+    ///
+    /// Only blocks from the vote's opening block up to its end (or the chain head
+    /// for votes without an end) are scanned. The scan is a single
+    /// `state_queryStorage` request for the `System::Events` key over that range,
+    /// so the node must keep state for every block in it (i.e. an archive node).
+    /// Cost grows linearly with the vote's duration and the number of events in
+    /// each block, not with the number of votes on chain.
+    async fn reconstruct_vote(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+    ) -> Result<ReconstructedVote<N::Runtime>> {
+        let state = self
+            .chain_client()
+            .fetch(&VoteStateStore::<N::Runtime> { vote: vote_id }, None)
+            .await?
+            .ok_or(Error::VoteStateNotFound)?;
+        let from = self
+            .chain_client()
+            .block_hash(Some(state.initialized().saturated_into::<u32>().into()))
+            .await?
+            .ok_or(Error::BlockHashNotFound)?;
+        let to = if let Some(ends) = state.ends() {
+            self.chain_client()
+                .block_hash(Some(ends.saturated_into::<u32>().into()))
+                .await?
+        } else {
+            None
+        };
+        let mut events_key = twox_128(b"System").to_vec();
+        events_key.extend(&twox_128(b"Events"));
+        let change_sets = self
+            .chain_client()
+            .query_storage(vec![StorageKey(events_key)], from, to)
+            .await?;
+        let mut decoder = EventsDecoder::<N::Runtime>::new(
+            self.chain_client().metadata().clone(),
+        );
+        decoder.with_vote();
+        // only the latest view of each voter counts towards the tally
+        let mut views = BTreeMap::new();
+        let mut votes_replayed = 0u32;
+        let mut genesis = None;
+        for change_set in change_sets {
+            for (_, data) in change_set.changes {
+                let data = if let Some(d) = data {
+                    d
+                } else {
+                    continue
+                };
+                for (_, raw) in decoder.decode_events(&mut &data.0[..])? {
+                    if let Raw::Event(event) = raw {
+                        if event.module != "Vote" {
+                            continue
+                        }
+                        if event.variant == "VoteGenesisSet" {
+                            let set = VoteGenesisSetEvent::<N::Runtime>::decode(
+                                &mut &event.data[..],
+                            )?;
+                            if set.vote_id == vote_id {
+                                genesis = Some(set);
+                            }
+                            continue
+                        }
+                        if event.variant != "Voted" {
+                            continue
+                        }
+                        let voted =
+                            VotedEvent::<N::Runtime>::decode(&mut &event.data[..])?;
+                        if voted.vote_id == vote_id {
+                            views.insert(voted.voter, (voted.view, voted.magnitude));
+                            votes_replayed += 1;
+                        }
+                    }
+                }
+            }
+        }
+        let mut reconstructed = ReconstructedVote {
+            vote_id,
+            in_favor: Zero::zero(),
+            against: Zero::zero(),
+            turnout: Zero::zero(),
+            votes_replayed,
+            genesis,
+        };
+        for (view, magnitude) in views.values() {
+            match view {
+                VoterView::InFavor => reconstructed.in_favor += *magnitude,
+                VoterView::Against => reconstructed.against += *magnitude,
+                _ => (),
+            }
+            if view.is_cast() {
+                reconstructed.turnout += *magnitude;
+            }
+        }
+        Ok(reconstructed)
+    }
+    async fn list_votes_for_vote(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+    ) -> Result<Vec<VoterEntry<N::Runtime>>> {
+        let mut votes = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) =
+                self.list_votes_page(vote_id, cursor, 100).await?;
+            votes.extend(page);
+            if next.is_none() {
+                return Ok(votes)
+            }
+            cursor = next;
+        }
+    }
+    /// Pages through `VoteLogger` keys so only `limit` voters are fetched per call.
+    ///
+    /// Keys are hashed with `blake2_128_concat`, so every voter's key starts with
+    /// the vote's prefix and ends with the encoded `AccountId`, which is decoded
+    /// instead of fetching each voter's value twice.
+    async fn list_votes_page(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+        last_key: Option<<N::Runtime as System>::AccountId>,
+        limit: u32,
+    ) -> Result<(
+        Vec<VoterEntry<N::Runtime>>,
+        Option<<N::Runtime as System>::AccountId>,
+    )> {
+        let probe = last_key.clone().unwrap_or_default();
+        let probe_key = VoteLoggerStore::<N::Runtime> {
+            vote: vote_id,
+            who: probe.clone(),
+        }
+        .key(self.chain_client().metadata())?;
+        // strip the hashed account to get the vote's prefix
+        let prefix_len = probe_key.0.len() - 16 - probe.encode().len();
+        let prefix = probe_key.0[..prefix_len].to_vec();
+        let start = if last_key.is_some() {
+            probe_key
+        } else {
+            StorageKey(prefix.clone())
+        };
+        let keys = self
+            .chain_client()
+            .fetch_keys::<VoteLoggerStore<N::Runtime>>(limit + 1, Some(start), None)
+            .await?;
+        let mut voters = Vec::new();
+        for key in keys.iter().take_while(|k| k.0.starts_with(&prefix)) {
+            voters.push(<N::Runtime as System>::AccountId::decode(
+                &mut &key.0[prefix_len + 16..],
+            )?);
+        }
+        let next = if voters.len() > limit as usize {
+            voters.truncate(limit as usize);
+            voters.last().cloned()
+        } else {
+            None
+        };
+        let mut page = Vec::new();
+        for who in voters {
+            let vote = if let Some(v) = self
+                .chain_client()
+                .fetch(&VoteLoggerStore::<N::Runtime> { vote: vote_id, who: who.clone() }, None)
+                .await?
+            {
+                v
+            } else {
+                continue
+            };
+            let signal = match vote.direction() {
+                VoterView::Uninitialized | VoterView::Delegated => None,
+                _ => Some(
+                    self.chain_client()
+                        .fetch(&CommittedSignalStore::<N::Runtime> { vote: vote_id, who: who.clone() }, None)
+                        .await?
+                        .unwrap_or_else(|| vote.magnitude()),
+                ),
+            };
+            page.push((who, vote.direction(), signal));
+        }
+        Ok((page, next))
+    }
+    /// Waits for the vote to reach `target`, re-checking its outcome at every
+    /// finalized block.
+    ///
+    /// Votes keep changing until they expire, so an outcome other than `target`
+    /// is returned only once the vote has expired at a finalized block, with an
+    /// undecided vote reported as `ExpiredInconclusive`. Otherwise this fails
+    /// with `VoteOutcomeTimeout` once `timeout_blocks` blocks are finalized,
+    /// counted from the first finalized block after the call.
+    async fn await_outcome(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+        target: VoteOutcome,
+        timeout_blocks: <N::Runtime as System>::BlockNumber,
+    ) -> Result<VoteOutcome> {
+        // subscriptions only see new blocks so the current state is checked first
+        let outcome = self
+            .chain_client()
+            .fetch(&VoteStateStore::<N::Runtime> { vote: vote_id }, None)
+            .await?
+            .ok_or(Error::VoteStateNotFound)?
+            .outcome();
+        if outcome == target {
+            return Ok(outcome)
+        }
+        let mut finalized =
+            self.chain_client().subscribe_finalized_blocks().await?;
+        let mut deadline = None;
+        loop {
+            let header = finalized.next().await;
+            let number = *header.number();
+            let deadline = *deadline.get_or_insert(number + timeout_blocks);
+            let state = self
+                .chain_client()
+                .fetch(
+                    &VoteStateStore::<N::Runtime> { vote: vote_id },
+                    Some(header.hash()),
+                )
+                .await?
+                .ok_or(Error::VoteStateNotFound)?;
+            // no votes are accepted in the blocks after the vote ends
+            let expired =
+                state.ends().map(|ends| ends <= number).unwrap_or(false);
+            let outcome = match state.outcome() {
+                VoteOutcome::NotStarted | VoteOutcome::Voting if expired => {
+                    VoteOutcome::ExpiredInconclusive
+                }
+                outcome => outcome,
+            };
+            if outcome == target || expired {
+                return Ok(outcome)
+            }
+            if number >= deadline {
+                return Err(Error::VoteOutcomeTimeout.into())
+            }
+        }
+    }
+    async fn list_account_votes(
+        &self,
+        account: <N::Runtime as System>::AccountId,
+    ) -> Result<Vec<<N::Runtime as Vote>::VoteId>> {
+        state_call(self, "VoteApi_get_votes_participated_in", (account,)).await
+    }
+    /// In favor signal the vote still needs to meet its threshold, `None` once met
+    async fn remaining_signal_needed(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+    ) -> Result<Option<<N::Runtime as Vote>::Signal>> {
+        Ok(self
+            .chain_client()
+            .fetch(&VoteStateStore::<N::Runtime> { vote: vote_id }, None)
+            .await?
+            .ok_or(Error::VoteStateNotFound)?
+            .remaining_signal_needed())
+    }
+    /// Expected fee of `create_signal_vote` with the same arguments
+    /// -> the topic is stored offchain, as submitting the vote would store it
+    async fn estimate_create_signal_vote_fee(
+        &self,
+        topic: Option<<N::Runtime as Vote>::VoteTopic>,
+        organization: OrgRep<<N::Runtime as Org>::OrgId>,
+        threshold: Threshold<<N::Runtime as Vote>::Signal>,
+        duration: Option<<N::Runtime as System>::BlockNumber>,
+    ) -> Result<FeeEstimate> {
+        let topic = if let Some(t) = topic {
+            Some(self.offchain_client().insert(t).await?.into())
+        } else {
+            None
+        };
+        let call = CreateSignalVoteCall::<N::Runtime> {
+            topic,
+            organization,
+            threshold,
+            duration,
+        };
+        estimate_fee(self, call).await
+    }
+    /// Expected fee of `create_percent_vote` with the same arguments
+    async fn estimate_create_percent_vote_fee(
+        &self,
+        topic: Option<<N::Runtime as Vote>::VoteTopic>,
+        organization: OrgRep<<N::Runtime as Org>::OrgId>,
+        threshold: Threshold<<N::Runtime as Vote>::Percent>,
+        duration: Option<<N::Runtime as System>::BlockNumber>,
+    ) -> Result<FeeEstimate> {
+        let topic = if let Some(t) = topic {
+            Some(self.offchain_client().insert(t).await?.into())
+        } else {
+            None
+        };
+        let call = CreatePercentVoteCall::<N::Runtime> {
+            topic,
+            organization,
+            threshold,
+            duration,
+        };
+        estimate_fee(self, call).await
+    }
+    /// Expected fee of `submit_vote` with the same arguments
+    async fn estimate_submit_vote_fee(
+        &self,
+        vote_id: <N::Runtime as Vote>::VoteId,
+        direction: <N::Runtime as Vote>::VoterView,
+        justification: Option<<N::Runtime as Vote>::VoteJustification>,
+        weight_fraction: Option<Permill>,
+        conviction: u8,
+    ) -> Result<FeeEstimate> {
+        let justification = if let Some(j) = justification {
+            Some(self.offchain_client().insert(j).await?.into())
+        } else {
+            None
+        };
+        let call = SubmitVoteCall::<N::Runtime> {
+            vote_id,
+            direction,
+            justification,
+            weight_fraction,
+            conviction,
+        };
+        estimate_fee(self, call).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use substrate_subxt::sp_runtime::Permill;
+    use test_client::{
+        client::{
+            AccountKeyring,
+            Client as _,
+            Node as _,
+        },
+        org::OrgClient,
+        utils::{
+            organization::OrgRep,
+            vote::{
+                Threshold,
+                VoteOutcome,
+                VoterView,
+            },
+        },
+        vote::{
+            VoteClient,
+            VoteStateStore,
+        },
+        Client,
+        Node,
+        TextBlock,
+    };
+
+    #[async_std::test]
+    async fn account_ownership_percent_test() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let bob = AccountKeyring::Bob.to_account_id();
+        let charlie = AccountKeyring::Charlie.to_account_id();
+        let raw_const = TextBlock {
+            text: "one member one vote".to_string(),
+        };
+        let org = client
+            .new_flat_org(
+                Some(alice.clone()),
+                None,
+                raw_const,
+                &[alice.clone(), bob],
+            )
+            .await
+            .unwrap()
+            .new_id;
+        let vote = client
+            .create_signal_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(2, None),
+                None,
+            )
+            .await
+            .unwrap()
+            .new_vote_id;
+        assert_eq!(
+            client.account_ownership_percent(vote, alice).await.unwrap(),
+            Permill::from_percent(50)
+        );
+        // non-voters and unknown votes own nothing
+        assert_eq!(
+            client
+                .account_ownership_percent(vote, charlie.clone())
+                .await
+                .unwrap(),
+            Permill::zero()
+        );
+        assert_eq!(
+            client
+                .account_ownership_percent(vote + 1, charlie)
+                .await
+                .unwrap(),
+            Permill::zero()
+        );
+    }
+
+    #[async_std::test]
+    async fn reconstruct_vote_test() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let bob = AccountKeyring::Bob.to_account_id();
+        let raw_const = TextBlock {
+            text: "replay everything".to_string(),
+        };
+        let org = client
+            .new_flat_org(Some(alice.clone()), None, raw_const, &[alice, bob])
+            .await
+            .unwrap()
+            .new_id;
+        let vote = client
+            .create_signal_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(2, None),
+                None,
+            )
+            .await
+            .unwrap()
+            .new_vote_id;
+        client
+            .submit_vote(vote, VoterView::Against, None, None, 0)
+            .await
+            .unwrap();
+        client
+            .submit_vote(vote, VoterView::InFavor, None, None, 0)
+            .await
+            .unwrap();
+        let reconstructed = client.reconstruct_vote(vote).await.unwrap();
+        assert_eq!(reconstructed.votes_replayed, 2);
+        let genesis = reconstructed.genesis.clone().unwrap();
+        assert_eq!((genesis.participants, genesis.total_signal), (2, 2));
+        assert_eq!(reconstructed.in_favor, 1);
+        assert_eq!(reconstructed.against, 0);
+        let state = client
+            .chain_client()
+            .fetch(&VoteStateStore { vote }, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(reconstructed.matches(&state));
+    }
+
+    #[async_std::test]
+    async fn list_votes_for_vote_test() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let bob = AccountKeyring::Bob.to_account_id();
+        let charlie = AccountKeyring::Charlie.to_account_id();
+        let raw_const = TextBlock {
+            text: "show your hand".to_string(),
+        };
+        let org = client
+            .new_flat_org(
+                Some(alice.clone()),
+                None,
+                raw_const,
+                &[alice.clone(), bob.clone(), charlie.clone()],
+            )
+            .await
+            .unwrap()
+            .new_id;
+        let vote = client
+            .create_signal_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(3, None),
+                None,
+            )
+            .await
+            .unwrap()
+            .new_vote_id;
+        client
+            .submit_vote(vote, VoterView::InFavor, None, None, 0)
+            .await
+            .unwrap();
+        let mut votes = client.list_votes_for_vote(vote).await.unwrap();
+        votes.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut expected = vec![
+            (alice, VoterView::InFavor, Some(1)),
+            (bob, VoterView::Uninitialized, None),
+            (charlie, VoterView::Uninitialized, None),
+        ];
+        expected.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(votes, expected);
+        // pages cover every voter exactly once
+        let (first, cursor) =
+            client.list_votes_page(vote, None, 2).await.unwrap();
+        assert_eq!(first.len(), 2);
+        let (rest, end) =
+            client.list_votes_page(vote, cursor, 2).await.unwrap();
+        assert_eq!(rest.len(), 1);
+        assert!(end.is_none());
+        let mut paged: Vec<_> = first.into_iter().chain(rest).collect();
+        paged.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(paged, expected);
+    }
+
+    #[async_std::test]
+    async fn await_outcome_test() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let raw_const = TextBlock {
+            text: "decide quickly".to_string(),
+        };
+        let org = client
+            .new_flat_org(Some(alice.clone()), None, raw_const, &[alice])
+            .await
+            .unwrap()
+            .new_id;
+        let approved = client
+            .create_signal_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(1, None),
+                None,
+            )
+            .await
+            .unwrap()
+            .new_vote_id;
+        let rejected = client
+            .create_signal_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(1, Some(1)),
+                None,
+            )
+            .await
+            .unwrap()
+            .new_vote_id;
+        client
+            .submit_vote(approved, VoterView::InFavor, None, None, 0)
+            .await
+            .unwrap();
+        client
+            .submit_vote(rejected, VoterView::Against, None, None, 0)
+            .await
+            .unwrap();
+        assert_eq!(
+            client
+                .await_outcome(approved, VoteOutcome::Approved, 10)
+                .await
+                .unwrap(),
+            VoteOutcome::Approved
+        );
+        // a final outcome other than the target resolves without waiting
+        assert_eq!(
+            client
+                .await_outcome(rejected, VoteOutcome::Approved, 10)
+                .await
+                .unwrap(),
+            VoteOutcome::Rejected
+        );
+        assert!(client
+            .await_outcome(rejected + 1, VoteOutcome::Approved, 10)
+            .await
+            .is_err());
+    }
+
+    #[async_std::test]
+    async fn list_account_votes_test() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let bob = AccountKeyring::Bob.to_account_id();
+        let raw_const = TextBlock {
+            text: "keep a record".to_string(),
+        };
+        let org = client
+            .new_flat_org(
+                Some(alice.clone()),
+                None,
+                raw_const,
+                &[alice.clone(), bob.clone()],
+            )
+            .await
+            .unwrap()
+            .new_id;
+        let mut votes = Vec::new();
+        for _ in 0..2 {
+            votes.push(
+                client
+                    .create_signal_vote(
+                        None,
+                        OrgRep::Equal(org),
+                        Threshold::new(2, None),
+                        None,
+                    )
+                    .await
+                    .unwrap()
+                    .new_vote_id,
+            );
+        }
+        for vote in votes.iter() {
+            client
+                .submit_vote(*vote, VoterView::InFavor, None, None, 0)
+                .await
+                .unwrap();
+        }
+        let mut listed = client.list_account_votes(alice).await.unwrap();
+        listed.sort();
+        assert_eq!(listed, votes);
+        assert!(client.list_account_votes(bob).await.unwrap().is_empty());
+    }
+
+    #[async_std::test]
+    async fn remaining_signal_needed_test() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let bob = AccountKeyring::Bob.to_account_id();
+        let raw_const = TextBlock {
+            text: "count what is left".to_string(),
+        };
+        let org = client
+            .new_flat_org(Some(alice.clone()), None, raw_const, &[alice, bob])
+            .await
+            .unwrap()
+            .new_id;
+        let vote = client
+            .create_signal_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(1, None),
+                None,
+            )
+            .await
+            .unwrap()
+            .new_vote_id;
+        assert_eq!(
+            client.remaining_signal_needed(vote).await.unwrap(),
+            Some(1)
+        );
+        client
+            .submit_vote(vote, VoterView::InFavor, None, None, 0)
+            .await
+            .unwrap();
+        assert_eq!(client.remaining_signal_needed(vote).await.unwrap(), None);
+        assert!(client.remaining_signal_needed(vote + 1).await.is_err());
+    }
+
+    #[async_std::test]
+    async fn estimate_fee_test() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice = AccountKeyring::Alice.to_account_id();
+        let raw_const = TextBlock {
+            text: "price it first".to_string(),
+        };
+        let org = client
+            .new_flat_org(Some(alice.clone()), None, raw_const, &[alice])
+            .await
+            .unwrap()
+            .new_id;
+        let estimate = client
+            .estimate_create_signal_vote_fee(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(1, None),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(estimate.partial_fee > 0);
+        // estimating submits nothing
+        assert_eq!(client.count_open_votes().await.unwrap(), 0);
+        let vote = client
+            .create_signal_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(1, None),
+                None,
+            )
+            .await
+            .unwrap()
+            .new_vote_id;
+        let estimate = client
+            .estimate_submit_vote_fee(vote, VoterView::InFavor, None, None, 0)
+            .await
+            .unwrap();
+        assert!(estimate.partial_fee > 0);
+    }
 }
@@ -53,6 +53,9 @@ pub type ThreshConfig<T> = ThresholdConfig<
     XorThreshold<<T as Vote>::Signal, <T as Vote>::Percent>,
 >;
 
+pub type VoteSt<T> =
+    VoteState<<T as Vote>::Signal, <T as System>::BlockNumber, <T as Org>::Cid>;
+
 /// The subset of the `vote::Trait` that a client must implement.
 #[module]
 pub trait Vote: System + Org {
@@ -139,7 +142,7 @@ pub struct OpenVoteCounterStore {
 
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct VoteStateStore<T: Vote> {
-    #[store(returns = VoteState<T::Signal, <T as System>::BlockNumber, <T as Org>::Cid>)]
+    #[store(returns = VoteSt<T>)]
     pub vote: T::VoteId,
 }
 
@@ -150,6 +153,20 @@ pub struct VoteLoggerStore<T: Vote> {
     pub who: <T as System>::AccountId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct CommittedSignalStore<T: Vote> {
+    #[store(returns = T::Signal)]
+    pub vote: T::VoteId,
+    pub who: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct VotesByOrgStore<T: Vote> {
+    #[store(returns = ())]
+    pub org: T::OrgId,
+    pub vote: T::VoteId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct VoteThresholdsStore<T: Vote> {
     #[store(returns = ThreshConfig<T>)]
@@ -179,6 +196,8 @@ pub struct SubmitVoteCall<T: Vote> {
     pub vote_id: T::VoteId,
     pub direction: <T as Vote>::VoterView,
     pub justification: Option<<T as Org>::Cid>,
+    pub weight_fraction: Option<sp_runtime::Permill>,
+    pub conviction: u8,
 }
 
 // ~~ Events ~~
@@ -195,4 +214,12 @@ pub struct VotedEvent<T: Vote> {
     pub vote_id: T::VoteId,
     pub voter: <T as System>::AccountId,
     pub view: <T as Vote>::VoterView,
+    pub magnitude: T::Signal,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VoteGenesisSetEvent<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub participants: u32,
+    pub total_signal: T::Signal,
 }
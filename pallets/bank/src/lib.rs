@@ -10,6 +10,47 @@
 //!
 //! This pallet allows orgs to govern a pool of capital.
 //!
+//! ## Spend Approval Votes
+//!
+//! The org's supervisor sets a bank's `SpendVoteMinimums` with
+//! `set_spend_vote_minimum`. A spend of at least that amount requires a vote
+//! and cannot be approved by the bank's controller alone. The controller's
+//! first `sudo_approve` opens a vote of the bank's org with the bank's threshold
+//! and records it in `SpendApprovalVotes`. Later calls fail with
+//! `AwaitingVoteApproval` while the vote is undecided. They execute the spend
+//! once the vote approves it and move it to the terminal `Rejected` state once
+//! the vote rejects it.
+//!
+//! ## Conflicts Of Interest
+//!
+//! Every vote a spend proposal opens, by `trigger_vote` or `sudo_approve`, is
+//! indexed in `SpendsByVote`. The module implements `ConflictOfInterestChecker`
+//! with that index: the spend's recipient is conflicted in the vote on it. Set it
+//! as `vote`'s `ConflictCheck` to bar recipients from voting on their spends.
+//!
+//! ## Proportional Spends
+//!
+//! `create_proportional_spend` records each member's shares so every member can
+//! approve their portion of the spend. Creating or rejecting a spend takes the
+//! org's member count, which weighs the call. A spend not finalized within
+//! `ProportionalSpendLifetime` expires, and anyone can then remove it with
+//! `remove_expired_proportional_spend` to release the portions approved from
+//! the bank.
+//!
+//! ## Balance Summaries
+//!
+//! `get_bank_balances` splits a bank account's balance into free and reserved
+//! funds. Reserved funds are further split into funds committed by approvals of
+//! the bank's pending proportional spends and uncommitted funds reserved by other
+//! modules, such as court dispute locks. `BankSummaryApi` exposes the summary so
+//! clients need not derive these from raw balances.
+//!
+//! ## Migrations
+//!
+//! `StorageVersion` records whether banks are indexed by org in `OrgBankRegistry`.
+//! `on_runtime_upgrade` indexes every stored bank under its org, in bank id order,
+//! when the version predates the registry.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -17,6 +58,8 @@
 #[cfg(test)]
 mod tests;
 
+pub mod runtime_api;
+
 use frame_support::{
     decl_error,
     decl_event,
@@ -28,27 +71,37 @@ use frame_support::{
         IterableStorageMap,
     },
     traits::{
+        BalanceStatus,
         Currency,
         ExistenceRequirement,
         Get,
         ReservableCurrency,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
-use parity_scale_codec::Codec;
+use parity_scale_codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use sp_runtime::{
+    helpers_128bit::multiply_by_rational,
     traits::{
         AccountIdConversion,
         AtLeast32Bit,
         MaybeSerializeDeserialize,
         Member,
+        SaturatedConversion,
+        Saturating,
         Zero,
     },
     DispatchError,
     DispatchResult,
     ModuleId,
     Permill,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
@@ -56,16 +109,29 @@ use sp_std::{
 };
 use util::{
     bank::{
+        AuditEntry,
+        BankAction,
+        BankBalanceSummary,
         BankState,
+        DividendPool,
+        ProportionalSpend,
         SpendProposal,
         SpendState,
     },
+    governance::{
+        GovernanceAction,
+        GovernanceExecutor,
+    },
     organization::OrgRep,
     traits::{
+        AccessGenesis,
         ConfigureThreshold,
+        ConflictOfInterestChecker,
         GetVoteOutcome,
         GroupMembership,
         OpenBankAccount,
+        OrganizationSupervisorPermissions,
+        ShareInformation,
         SpendGovernance,
     },
     vote::{
@@ -96,6 +162,24 @@ type SpendProp<T> = SpendProposal<
     <T as frame_system::Trait>::AccountId,
     SpendState<<T as vote::Trait>::VoteId>,
 >;
+type AuditEntryOf<T> = AuditEntry<
+    <T as frame_system::Trait>::BlockNumber,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as org::Trait>::Cid,
+>;
+type DividendPoolOf<T> = DividendPool<BalanceOf<T>, <T as vote::Trait>::Signal>;
+type PropSpend<T> = ProportionalSpend<
+    <T as Trait>::BankId,
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as org::Trait>::Cid,
+>;
+type GovAction<T> = GovernanceAction<
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as org::Trait>::Cid,
+>;
 
 pub trait Trait:
     frame_system::Trait + org::Trait + donate::Trait + vote::Trait
@@ -140,6 +224,8 @@ pub trait Trait:
     type MaxTreasuryPerOrg: Get<u32>;
     /// Min to open bank account
     type MinDeposit: Get<BalanceOf<Self>>;
+    /// Blocks a proportional spend awaits approval before it expires
+    type ProportionalSpendLifetime: Get<Self::BlockNumber>;
 }
 
 decl_event!(
@@ -158,6 +244,26 @@ decl_event!(
         SudoApproved(AccountId, BankId, SpendId),
         ProposalPolled(BankId, SpendId, SpendState<VoteId>),
         AccountClosed(AccountId, BankId, OrgId),
+        /// Bank id, index in the bank's audit log, action recorded
+        AuditLogEntryCreated(BankId, u64, BankAction),
+        /// Bank id, epoch, new pool total
+        DividendPoolFunded(BankId, u32, Balance),
+        /// Bank id, epoch, amount to be claimed
+        DividendPoolClosed(BankId, u32, Balance),
+        DividendClaimed(AccountId, BankId, u32, Balance),
+        ProportionalSpendCreated(AccountId, BankId, SpendId, Balance, AccountId),
+        /// Approver, spend id, portion reserved, total reserved by approvals
+        ProportionalShareApproved(AccountId, SpendId, Balance, Balance),
+        ProportionalShareRejected(AccountId, SpendId),
+        ProportionalSpendExecuted(SpendId, Balance, AccountId),
+        /// Remover, spend id, approved portions released
+        ProportionalSpendExpired(AccountId, SpendId, Balance),
+        /// Controller, bank id, spend id, vote id
+        SpendApprovalVoteTriggered(AccountId, BankId, SpendId, VoteId),
+        /// Controller, bank id, spend id, rejecting vote id
+        SpendRejectedByVote(AccountId, BankId, SpendId, VoteId),
+        /// Supervisor, bank id, smallest spend that requires a vote
+        SpendVoteMinimumSet(AccountId, BankId, Option<Balance>),
     }
 );
 
@@ -173,6 +279,7 @@ decl_error! {
         NotPermittedToPollSpendProposalForBankAccount,
         CannotSpendIfBankDNE,
         OnlyControllerCanCloseBank,
+        CannotCloseBankWithReservedFunds,
         OnlyControllerCanSudoApproveSpendProposals,
         // spend proposal stuff
         CannotProposeSpendIfBankDNE,
@@ -183,16 +290,68 @@ decl_error! {
         CannotSudoApproveSpendProposalIfBaseBankDNE,
         CannotSudoApproveSpendProposalIfSpendProposalDNE,
         CannotSudoApproveFromCurrentState,
+        AwaitingVoteApproval,
+        CannotSetSpendVoteMinimumIfBankDNE,
+        OnlyOrgSupervisorCanSetSpendVoteMinimum,
         CannotPollSpendProposalIfBaseBankDNE,
         CannotPollSpendProposalIfSpendProposalDNE,
+        GovernanceActionNotSupportedByBank,
+        // dividend pool stuff
+        CannotFundDividendPoolIfBankDNE,
+        OnlyControllerCanFundDividendPool,
+        CannotFundDividendPoolAfterItIsClosed,
+        CannotCloseDividendPoolIfBankDNE,
+        OnlyControllerCanCloseDividendPool,
+        CannotCloseDividendPoolThatDNE,
+        DividendPoolAlreadyClosed,
+        CannotSnapshotSharesIfOrgDNE,
+        CannotClaimDividendIfPoolDNE,
+        CannotClaimDividendBeforePoolIsClosed,
+        DividendAlreadyClaimed,
+        NoSharesInDividendSnapshot,
+        DividendComputationOverflow,
+        InsufficientReservedFundsToPayDividend,
+        // proportional spend stuff
+        CannotCreateProportionalSpendIfBankDNE,
+        NotPermittedToCreateProportionalSpendForBank,
+        ProportionalSpendDNE,
+        BankForProportionalSpendDNE,
+        OnlyMembersWithSharesCanApproveProportionalSpend,
+        ProportionalShareAlreadyApproved,
+        CannotRejectProportionalSpendAfterApprovingIt,
+        NotPermittedToRejectProportionalSpend,
+        ProportionalSpendNotYetApproved,
+        ProportionalShareComputationOverflow,
+        CannotCreateProportionalSpendIfOrgHasNoShares,
+        MemberCountWitnessTooLow,
+        ProportionalSpendExpired,
+        ProportionalSpendNotYetExpired,
         // for getting banks for org
         NoBanksForOrg,
         ThresholdCannotBeSetForOrg,
     }
 }
 
+/// Storage layout versions of this module
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// Banks are not indexed by org
+    V1,
+    /// Banks are indexed by org in `OrgBankRegistry`
+    V2,
+}
+
+impl Default for Releases {
+    fn default() -> Releases {
+        Releases::V1
+    }
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as Bank {
+        /// The storage layout version, set to the latest at genesis
+        StorageVersion get(fn storage_version) build(|_: &GenesisConfig<T>| Releases::V2): Releases;
+
         /// Counter for generating unique bank identifiers
         BankIdNonce get(fn bank_id_nonce): T::BankId;
 
@@ -207,6 +366,10 @@ decl_storage! {
         pub OrgTreasuryCount get(fn org_treasury_count): map
             hasher(blake2_128_concat) T::OrgId => u32;
 
+        /// Open banks for each org, in the order they were opened
+        pub OrgBankRegistry get(fn org_bank_registry): map
+            hasher(blake2_128_concat) T::OrgId => Vec<T::BankId>;
+
         /// The store for organizational bank accounts
         /// -> keyset acts as canonical set for unique BankIds
         pub Banks get(fn banks): map
@@ -218,6 +381,68 @@ decl_storage! {
             hasher(blake2_128_concat) T::SpendId => Option<SpendProp<T>>;
         /// Frequency for which all spend proposals are polled and pushed along
         SpendPollFrequency get(fn spend_poll_frequency) config(): T::BlockNumber;
+
+        /// The smallest spend from each bank that requires an org vote to approve
+        pub SpendVoteMinimums get(fn spend_vote_minimums): map
+            hasher(blake2_128_concat) T::BankId => Option<BalanceOf<T>>;
+
+        /// Spends that the controller may only approve after an org vote approves them
+        pub SpendRequiresVote get(fn spend_requires_vote): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => bool;
+
+        /// The vote opened by the controller's first approval of a spend that requires one
+        pub SpendApprovalVotes get(fn spend_approval_votes): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<T::VoteId>;
+
+        /// The spend proposal each spend vote decides on
+        pub SpendsByVote get(fn spends_by_vote): map
+            hasher(blake2_128_concat) T::VoteId => Option<(T::BankId, T::SpendId)>;
+
+        /// Append only record of every state changing operation on a bank
+        /// -> entries are never modified or removed, even after the bank is closed
+        pub BankAuditLog get(fn bank_audit_log): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) u64 => Option<AuditEntryOf<T>>;
+
+        /// The number of entries in each bank's audit log
+        pub BankAuditCounter get(fn bank_audit_counter): map
+            hasher(blake2_128_concat) T::BankId => u64;
+
+        /// Dividend pools, keyed by bank and then epoch
+        pub DividendPools get(fn dividend_pools): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) u32 => Option<DividendPoolOf<T>>;
+        /// Member shares recorded when each dividend pool was closed
+        pub DividendShares get(fn dividend_shares): double_map
+            hasher(blake2_128_concat) (T::BankId, u32),
+            hasher(blake2_128_concat) T::AccountId => Option<T::Signal>;
+        /// Counter for generating unique proportional spend identifiers
+        ProportionalSpendNonce get(fn proportional_spend_nonce): T::SpendId;
+        /// Spends awaiting approval of each member's portion
+        pub ProportionalSpends get(fn proportional_spends): map
+            hasher(blake2_128_concat) T::SpendId => Option<PropSpend<T>>;
+        /// Member shares recorded when each proportional spend was created
+        pub ProportionalShares get(fn proportional_shares): double_map
+            hasher(blake2_128_concat) T::SpendId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::Shares>;
+        /// Total shares of the org when each proportional spend was created
+        pub ProportionalShareTotals get(fn proportional_share_totals): map
+            hasher(blake2_128_concat) T::SpendId => Option<T::Shares>;
+        /// The block each proportional spend expires after and the number of
+        /// members whose shares were recorded, which weighs its removal
+        pub ProportionalSpendExpiries get(fn proportional_spend_expiries): map
+            hasher(blake2_128_concat) T::SpendId => Option<(T::BlockNumber, u32)>;
+        /// Amount reserved from the bank by each member's approval
+        pub ProportionalApprovals get(fn proportional_approvals): double_map
+            hasher(blake2_128_concat) T::SpendId,
+            hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+
+        /// Tracks which members have claimed from each dividend pool
+        pub DividendClaims get(fn dividend_claims): double_map
+            hasher(blake2_128_concat) (T::BankId, u32),
+            hasher(blake2_128_concat) T::AccountId => bool;
     }
 }
 
@@ -226,6 +451,14 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            if <StorageVersion>::get() == Releases::V1 {
+                Self::index_banks_by_org()
+            } else {
+                0
+            }
+        }
+
         #[weight = 0]
         fn open(
             origin,
@@ -240,6 +473,7 @@ decl_module! {
                 Error::<T>::NotPermittedToOpenBankAccountForOrg
             );
             let bank_id = Self::open_bank_account(opener.clone(), org, deposit, controller.clone(), threshold)?;
+            Self::record_audit_entry(bank_id, opener.clone(), BankAction::Deposit, deposit);
             Self::deposit_event(RawEvent::AccountOpened(opener, bank_id, deposit, org, controller));
             Ok(())
         }
@@ -252,6 +486,10 @@ decl_module! {
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
             let new_spend_id = Self::_propose_spend(&caller, bank_id, amount, dest.clone())?;
+            if Self::spend_vote_minimums(bank_id).map(|min| amount >= min).unwrap_or(false) {
+                <SpendRequiresVote<T>>::insert(bank_id, new_spend_id, true);
+            }
+            Self::record_audit_entry(bank_id, caller.clone(), BankAction::Reserve, amount);
             Self::deposit_event(RawEvent::SpendProposed(caller, bank_id, new_spend_id, amount, dest));
             Ok(())
         }
@@ -263,6 +501,9 @@ decl_module! {
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
             let vote_id = Self::_trigger_vote_on_spend_proposal(&caller, bank_id, spend_id)?;
+            if let Some(spend) = <SpendProposals<T>>::get(bank_id, spend_id) {
+                Self::record_audit_entry(bank_id, caller.clone(), BankAction::Commit, spend.amount());
+            }
             Self::deposit_event(RawEvent::VoteTriggered(caller, bank_id, spend_id, vote_id));
             Ok(())
         }
@@ -273,10 +514,43 @@ decl_module! {
             spend_id: T::SpendId,
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
+            if <SpendRequiresVote<T>>::get(bank_id, spend_id) {
+                if let Some(vote_id) = <SpendApprovalVotes<T>>::get(bank_id, spend_id) {
+                    if !Self::spend_approved_by_vote(vote_id)? {
+                        Self::reject_spend_by_vote(&caller, bank_id, spend_id)?;
+                        Self::deposit_event(RawEvent::SpendRejectedByVote(caller, bank_id, spend_id, vote_id));
+                        return Ok(())
+                    }
+                } else {
+                    let vote_id = Self::open_spend_approval_vote(&caller, bank_id, spend_id)?;
+                    Self::deposit_event(RawEvent::SpendApprovalVoteTriggered(caller, bank_id, spend_id, vote_id));
+                    return Ok(())
+                }
+            }
             Self::_sudo_approve_spend_proposal(&caller, bank_id, spend_id)?;
             Self::deposit_event(RawEvent::SudoApproved(caller, bank_id, spend_id));
             Ok(())
         }
+        #[weight = T::DbWeight::get().reads_writes(2, 1)]
+        fn set_spend_vote_minimum(
+            origin,
+            bank_id: T::BankId,
+            minimum: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotSetSpendVoteMinimumIfBankDNE)?;
+            ensure!(
+                <org::Module<T>>::is_organization_supervisor(bank.org(), &caller),
+                Error::<T>::OnlyOrgSupervisorCanSetSpendVoteMinimum
+            );
+            if let Some(min) = minimum {
+                <SpendVoteMinimums<T>>::insert(bank_id, min);
+            } else {
+                <SpendVoteMinimums<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::SpendVoteMinimumSet(caller, bank_id, minimum));
+            Ok(())
+        }
         #[weight = 0]
         fn close(
             origin,
@@ -290,6 +564,11 @@ decl_module! {
                 Error::<T>::OnlyControllerCanCloseBank
             );
             let bank_account_id = Self::bank_account_id(bank_id);
+            // funds locked by other modules (i.e. court disputes) must be released first
+            ensure!(
+                Self::bank_reserved_balance(bank_id).is_zero(),
+                Error::<T>::CannotCloseBankWithReservedFunds
+            );
             let remaining_funds = <T as donate::Trait>::Currency::total_balance(&bank_account_id);
             // distributes remaining funds equally among members in proportion to ownership (PropDonation)
             let _ = <donate::Module<T>>::donate(
@@ -300,10 +579,102 @@ decl_module! {
             )?;
             <Banks<T>>::remove(bank_id);
             <OrgTreasuryCount<T>>::mutate(bank.org(), |count| *count -= 1);
+            <OrgBankRegistry<T>>::mutate(bank.org(), |banks| banks.retain(|id| *id != bank_id));
             <TotalBankCount>::mutate(|count| *count -= 1);
+            Self::record_audit_entry(bank_id, closer.clone(), BankAction::Withdraw, remaining_funds);
             Self::deposit_event(RawEvent::AccountClosed(closer, bank_id, bank.org()));
             Ok(())
         }
+        #[weight = T::DbWeight::get().reads_writes(4, 4)]
+        fn add_to_dividend_pool(
+            origin,
+            bank_id: T::BankId,
+            amount: BalanceOf<T>,
+            epoch: u32,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotFundDividendPoolIfBankDNE)?;
+            ensure!(
+                bank.is_controller(&caller),
+                Error::<T>::OnlyControllerCanFundDividendPool
+            );
+            let pool = <DividendPools<T>>::get(bank_id, epoch)
+                .unwrap_or_else(|| DividendPool::new(Zero::zero(), Zero::zero(), false));
+            ensure!(
+                !pool.claims_closed(),
+                Error::<T>::CannotFundDividendPoolAfterItIsClosed
+            );
+            // set aside in the bank account so that spends cannot draw on it
+            <T as Trait>::Currency::reserve(&Self::bank_account_id(bank_id), amount)?;
+            let pool = pool.add(amount);
+            <DividendPools<T>>::insert(bank_id, epoch, pool);
+            Self::record_audit_entry(bank_id, caller, BankAction::Reserve, amount);
+            Self::deposit_event(RawEvent::DividendPoolFunded(bank_id, epoch, pool.accumulated()));
+            Ok(())
+        }
+        /// Records every member's shares so each can claim their dividend
+        /// -> `member_count` must be at least the number of the org's members
+        #[weight = T::DbWeight::get().reads_writes(member_count as u64 + 4, member_count as u64 + 4)]
+        fn close_dividend_pool(
+            origin,
+            bank_id: T::BankId,
+            epoch: u32,
+            member_count: u32,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotCloseDividendPoolIfBankDNE)?;
+            ensure!(
+                bank.is_controller(&caller),
+                Error::<T>::OnlyControllerCanCloseDividendPool
+            );
+            let pool = <DividendPools<T>>::get(bank_id, epoch).ok_or(Error::<T>::CannotCloseDividendPoolThatDNE)?;
+            ensure!(
+                !pool.claims_closed(),
+                Error::<T>::DividendPoolAlreadyClosed
+            );
+            let unclaimable = Self::snapshot_dividend_shares(bank_id, epoch, bank.org(), &pool, member_count)?;
+            // rounding dust is returned to the bank's free balance
+            <T as Trait>::Currency::unreserve(&Self::bank_account_id(bank_id), unclaimable);
+            let claimable = pool.accumulated() - unclaimable;
+            Self::record_audit_entry(bank_id, caller, BankAction::Commit, claimable);
+            Self::deposit_event(RawEvent::DividendPoolClosed(bank_id, epoch, claimable));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(6, 5)]
+        fn claim_dividend(
+            origin,
+            bank_id: T::BankId,
+            epoch: u32,
+        ) -> DispatchResult {
+            let claimer = ensure_signed(origin)?;
+            let pool = <DividendPools<T>>::get(bank_id, epoch).ok_or(Error::<T>::CannotClaimDividendIfPoolDNE)?;
+            ensure!(
+                pool.claims_closed(),
+                Error::<T>::CannotClaimDividendBeforePoolIsClosed
+            );
+            ensure!(
+                !<DividendClaims<T>>::get((bank_id, epoch), &claimer),
+                Error::<T>::DividendAlreadyClaimed
+            );
+            let shares = <DividendShares<T>>::get((bank_id, epoch), &claimer)
+                .ok_or(Error::<T>::NoSharesInDividendSnapshot)?;
+            let amount = Self::dividend_for(&pool, shares)?;
+            ensure!(
+                Self::bank_reserved_balance(bank_id) >= amount,
+                Error::<T>::InsufficientReservedFundsToPayDividend
+            );
+            let unpaid = <T as Trait>::Currency::repatriate_reserved(
+                &Self::bank_account_id(bank_id),
+                &claimer,
+                amount,
+                BalanceStatus::Free,
+            )?;
+            ensure!(unpaid.is_zero(), Error::<T>::InsufficientReservedFundsToPayDividend);
+            <DividendClaims<T>>::insert((bank_id, epoch), &claimer, true);
+            Self::record_audit_entry(bank_id, claimer.clone(), BankAction::Transfer, amount);
+            Self::deposit_event(RawEvent::DividendClaimed(claimer, bank_id, epoch, amount));
+            Ok(())
+        }
         fn on_finalize(_n: T::BlockNumber) {
             if <frame_system::Module<T>>::block_number() % Self::spend_poll_frequency() == Zero::zero() {
                 <SpendProposals<T>>::iter().for_each(|(_, _, prop)| {
@@ -314,6 +685,155 @@ decl_module! {
                 });
             }
         }
+        /// Creates a spend each member approves their portion of
+        /// -> `member_count` must be at least the number of the org's members
+        #[weight = T::DbWeight::get().reads_writes(3, member_count as u64 + 4)]
+        fn create_proportional_spend(
+            origin,
+            bank_id: T::BankId,
+            total_amount: BalanceOf<T>,
+            reason: T::Cid,
+            recipient: T::AccountId,
+            member_count: u32,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotCreateProportionalSpendIfBankDNE)?;
+            ensure!(
+                <org::Module<T>>::is_member_of_group(bank.org(), &caller),
+                Error::<T>::NotPermittedToCreateProportionalSpendForBank
+            );
+            let membership = <org::Module<T>>::get_membership_with_shape(bank.org())
+                .ok_or(Error::<T>::CannotSnapshotSharesIfOrgDNE)?;
+            ensure!(
+                !membership.total().is_zero(),
+                Error::<T>::CannotCreateProportionalSpendIfOrgHasNoShares
+            );
+            let members = membership.vec();
+            ensure!(members.len() as u32 <= member_count, Error::<T>::MemberCountWitnessTooLow);
+            let id = Self::generate_proportional_spend_uid();
+            // portions are computed from these shares, so later transfers can't
+            // change how much of the spend is left to approve
+            <ProportionalShareTotals<T>>::insert(id, membership.total());
+            for (member, shares) in members.iter() {
+                <ProportionalShares<T>>::insert(id, member, shares);
+            }
+            let expires = <frame_system::Module<T>>::block_number() + T::ProportionalSpendLifetime::get();
+            <ProportionalSpendExpiries<T>>::insert(id, (expires, members.len() as u32));
+            let spend = ProportionalSpend::new(bank_id, recipient.clone(), reason, Zero::zero(), total_amount);
+            <ProportionalSpends<T>>::insert(id, spend);
+            Self::record_audit_entry(bank_id, caller.clone(), BankAction::Reserve, total_amount);
+            Self::deposit_event(RawEvent::ProportionalSpendCreated(caller, bank_id, id, total_amount, recipient));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(7, 5)]
+        fn approve_proportional_share(
+            origin,
+            spend_id: T::SpendId,
+        ) -> DispatchResult {
+            let approver = ensure_signed(origin)?;
+            let spend = <ProportionalSpends<T>>::get(spend_id).ok_or(Error::<T>::ProportionalSpendDNE)?;
+            ensure!(!Self::proportional_spend_expired(spend_id), Error::<T>::ProportionalSpendExpired);
+            ensure!(
+                <ProportionalApprovals<T>>::get(spend_id, &approver).is_none(),
+                Error::<T>::ProportionalShareAlreadyApproved
+            );
+            let shares = <ProportionalShares<T>>::get(spend_id, &approver)
+                .filter(|s| !s.is_zero())
+                .ok_or(Error::<T>::OnlyMembersWithSharesCanApproveProportionalSpend)?;
+            let portion = Self::proportional_share(
+                spend.required(),
+                shares,
+                <ProportionalShareTotals<T>>::get(spend_id).unwrap_or_else(Zero::zero),
+            )?;
+            <T as Trait>::Currency::reserve(&Self::bank_account_id(spend.bank_id()), portion)?;
+            let spend = spend.add_approval(portion);
+            <ProportionalApprovals<T>>::insert(spend_id, &approver, portion);
+            <ProportionalSpends<T>>::insert(spend_id, spend.clone());
+            Self::record_audit_entry(spend.bank_id(), approver.clone(), BankAction::Reserve, portion);
+            Self::deposit_event(RawEvent::ProportionalShareApproved(approver, spend_id, portion, spend.approvals_received()));
+            Ok(())
+        }
+        /// Fails the spend and releases every approved portion
+        /// -> `member_count` must be at least the number of members whose shares
+        /// were recorded when it was created
+        #[weight = T::DbWeight::get().reads_writes(member_count as u64 + 5, 2 * member_count as u64 + 6)]
+        fn reject_proportional_share(
+            origin,
+            spend_id: T::SpendId,
+            member_count: u32,
+        ) -> DispatchResult {
+            let rejector = ensure_signed(origin)?;
+            let spend = <ProportionalSpends<T>>::get(spend_id).ok_or(Error::<T>::ProportionalSpendDNE)?;
+            if let Some((_, recorded)) = <ProportionalSpendExpiries<T>>::get(spend_id) {
+                ensure!(recorded <= member_count, Error::<T>::MemberCountWitnessTooLow);
+            }
+            ensure!(
+                <ProportionalApprovals<T>>::get(spend_id, &rejector).is_none(),
+                Error::<T>::CannotRejectProportionalSpendAfterApprovingIt
+            );
+            let bank = <Banks<T>>::get(spend.bank_id()).ok_or(Error::<T>::BankForProportionalSpendDNE)?;
+            ensure!(
+                <org::Module<T>>::is_member_of_group(bank.org(), &rejector),
+                Error::<T>::NotPermittedToRejectProportionalSpend
+            );
+            // any rejection fails the entire spend
+            Self::release_proportional_approvals(spend_id, &spend);
+            Self::record_audit_entry(spend.bank_id(), rejector.clone(), BankAction::Unreserve, spend.approvals_received());
+            Self::deposit_event(RawEvent::ProportionalShareRejected(rejector, spend_id));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(5, 9)]
+        fn finalize_proportional_spend(
+            origin,
+            spend_id: T::SpendId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let spend = <ProportionalSpends<T>>::get(spend_id).ok_or(Error::<T>::ProportionalSpendDNE)?;
+            ensure!(
+                spend.approved(),
+                Error::<T>::ProportionalSpendNotYetApproved
+            );
+            ensure!(!Self::proportional_spend_expired(spend_id), Error::<T>::ProportionalSpendExpired);
+            let bank_account = Self::bank_account_id(spend.bank_id());
+            // portions are rounded up so the reserve may exceed the spend
+            let _ = <T as Trait>::Currency::unreserve(&bank_account, spend.approvals_received());
+            if let Err(e) = <T as Trait>::Currency::transfer(
+                &bank_account,
+                &spend.recipient(),
+                spend.required(),
+                ExistenceRequirement::KeepAlive,
+            ) {
+                // restore the approvals so the spend can be finalized later
+                <T as Trait>::Currency::reserve(&bank_account, spend.approvals_received())?;
+                return Err(e)
+            }
+            <ProportionalApprovals<T>>::remove_prefix(spend_id);
+            Self::remove_proportional_shares(spend_id);
+            <ProportionalSpends<T>>::remove(spend_id);
+            Self::record_audit_entry(spend.bank_id(), caller, BankAction::Transfer, spend.required());
+            Self::deposit_event(RawEvent::ProportionalSpendExecuted(spend_id, spend.required(), spend.recipient()));
+            Ok(())
+        }
+        /// Removes a spend which expired before it was finalized and releases the
+        /// portions approved from the bank, by anyone
+        /// -> `member_count` must be at least the number of members whose shares
+        /// were recorded when it was created
+        #[weight = T::DbWeight::get().reads_writes(member_count as u64 + 3, 2 * member_count as u64 + 4)]
+        fn remove_expired_proportional_spend(
+            origin,
+            spend_id: T::SpendId,
+            member_count: u32,
+        ) -> DispatchResult {
+            let remover = ensure_signed(origin)?;
+            let spend = <ProportionalSpends<T>>::get(spend_id).ok_or(Error::<T>::ProportionalSpendDNE)?;
+            let (_, recorded) = <ProportionalSpendExpiries<T>>::get(spend_id).ok_or(Error::<T>::ProportionalSpendNotYetExpired)?;
+            ensure!(Self::proportional_spend_expired(spend_id), Error::<T>::ProportionalSpendNotYetExpired);
+            ensure!(recorded <= member_count, Error::<T>::MemberCountWitnessTooLow);
+            Self::release_proportional_approvals(spend_id, &spend);
+            Self::record_audit_entry(spend.bank_id(), remover.clone(), BankAction::Unreserve, spend.approvals_received());
+            Self::deposit_event(RawEvent::ProportionalSpendExpired(remover, spend_id, spend.approvals_received()));
+            Ok(())
+        }
     }
 }
 
@@ -325,12 +845,252 @@ impl<T: Trait> Module<T> {
     pub fn bank_balance(bank: T::BankId) -> BalanceOf<T> {
         <T as Trait>::Currency::total_balance(&Self::bank_account_id(bank))
     }
+    /// Funds reserved from the bank account by other modules, not spendable
+    pub fn bank_reserved_balance(bank: T::BankId) -> BalanceOf<T> {
+        <T as Trait>::Currency::reserved_balance(&Self::bank_account_id(bank))
+    }
+    /// Indexes every stored bank under its org in `OrgBankRegistry`
+    /// -> banks are appended in id order, which is the order they were opened
+    fn index_banks_by_org() -> Weight {
+        let mut banks = <Banks<T>>::iter()
+            .map(|(id, bank)| (id, bank.org()))
+            .collect::<Vec<_>>();
+        banks.sort_by_key(|(id, _)| *id);
+        for (id, org) in banks.iter() {
+            <OrgBankRegistry<T>>::mutate(org, |ids| {
+                if !ids.contains(id) {
+                    ids.push(*id)
+                }
+            });
+        }
+        <StorageVersion>::put(Releases::V2);
+        let n = banks.len() as u64;
+        T::DbWeight::get().reads_writes(2 * n + 1, n + 1)
+    }
+    /// Funds the bank's spend governance can still spend
+    pub fn total_free(bank: T::BankId) -> BalanceOf<T> {
+        <T as Trait>::Currency::free_balance(&Self::bank_account_id(bank))
+    }
+    /// Funds reserved by member approvals of the bank's pending proportional
+    /// spends
+    /// -> iterates every proportional spend, for queries rather than dispatch
+    pub fn total_committed(bank: T::BankId) -> BalanceOf<T> {
+        <ProportionalSpends<T>>::iter()
+            .filter(|(_, spend)| spend.bank_id() == bank)
+            .fold(Zero::zero(), |acc: BalanceOf<T>, (_, spend)| {
+                acc.saturating_add(spend.approvals_received())
+            })
+    }
+    /// Reserved funds that no pending spend committed, i.e. dispute locks
+    pub fn total_uncommitted(bank: T::BankId) -> BalanceOf<T> {
+        Self::bank_balances(bank).uncommitted()
+    }
+    fn bank_balances(bank: T::BankId) -> BankBalanceSummary<BalanceOf<T>> {
+        BankBalanceSummary::from_balances(
+            Self::total_free(bank),
+            Self::bank_reserved_balance(bank),
+            Self::total_committed(bank),
+        )
+    }
+    /// The bank's balances in one response, `None` if the bank is not open
+    pub fn get_bank_balances(
+        bank: T::BankId,
+    ) -> Option<BankBalanceSummary<BalanceOf<T>>> {
+        if Self::is_bank(bank) {
+            Some(Self::bank_balances(bank))
+        } else {
+            None
+        }
+    }
     pub fn is_bank(id: T::BankId) -> bool {
         <Banks<T>>::get(id).is_some()
     }
     pub fn is_spend(bank: T::BankId, spend: T::SpendId) -> bool {
         <SpendProposals<T>>::get(bank, spend).is_some()
     }
+    /// Opens the org vote that must approve a spend before the controller can
+    fn open_spend_approval_vote(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+    ) -> Result<T::VoteId, DispatchError> {
+        let bank = <Banks<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotSudoApproveSpendProposalIfBaseBankDNE)?;
+        ensure!(
+            bank.is_controller(caller),
+            Error::<T>::OnlyControllerCanSudoApproveSpendProposals
+        );
+        let spend_proposal = <SpendProposals<T>>::get(bank_id, spend_id)
+            .ok_or(
+                Error::<T>::CannotSudoApproveSpendProposalIfSpendProposalDNE,
+            )?;
+        ensure!(
+            spend_proposal.state() == SpendState::WaitingForApproval,
+            Error::<T>::CannotSudoApproveFromCurrentState
+        );
+        let vote_id = <vote::Module<T>>::invoke_threshold(
+            bank.threshold_id(),
+            None,
+            None,
+        )?;
+        <SpendApprovalVotes<T>>::insert(bank_id, spend_id, vote_id);
+        <SpendsByVote<T>>::insert(vote_id, (bank_id, spend_id));
+        Ok(vote_id)
+    }
+    /// Returns whether the vote approved the spend, failing while undecided
+    fn spend_approved_by_vote(
+        vote_id: T::VoteId,
+    ) -> Result<bool, DispatchError> {
+        match <vote::Module<T>>::get_vote_outcome(vote_id)? {
+            VoteOutcome::Approved => Ok(true),
+            VoteOutcome::Rejected | VoteOutcome::ExpiredInconclusive => {
+                Ok(false)
+            }
+            _ => Err(Error::<T>::AwaitingVoteApproval.into()),
+        }
+    }
+    /// Moves a spend whose approval vote failed to the terminal rejected state
+    fn reject_spend_by_vote(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+    ) -> DispatchResult {
+        let bank = <Banks<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotSudoApproveSpendProposalIfBaseBankDNE)?;
+        ensure!(
+            bank.is_controller(caller),
+            Error::<T>::OnlyControllerCanSudoApproveSpendProposals
+        );
+        let spend_proposal = <SpendProposals<T>>::get(bank_id, spend_id)
+            .ok_or(
+                Error::<T>::CannotSudoApproveSpendProposalIfSpendProposalDNE,
+            )?;
+        ensure!(
+            spend_proposal.state() == SpendState::WaitingForApproval,
+            Error::<T>::CannotSudoApproveFromCurrentState
+        );
+        <SpendProposals<T>>::insert(
+            bank_id,
+            spend_id,
+            spend_proposal.set_state(SpendState::Rejected),
+        );
+        Ok(())
+    }
+    /// Appends an entry to the bank's audit log
+    fn record_audit_entry(
+        bank_id: T::BankId,
+        actor: T::AccountId,
+        action: BankAction,
+        amount: BalanceOf<T>,
+    ) {
+        let index = <BankAuditCounter<T>>::get(bank_id);
+        let entry = AuditEntry::new(
+            <frame_system::Module<T>>::block_number(),
+            actor,
+            action,
+            amount,
+            None,
+        );
+        <BankAuditLog<T>>::insert(bank_id, index, entry);
+        <BankAuditCounter<T>>::insert(bank_id, index + 1);
+        Self::deposit_event(RawEvent::AuditLogEntryCreated(
+            bank_id, index, action,
+        ));
+    }
+    /// Member's pro rata portion of a closed dividend pool
+    fn dividend_for(
+        pool: &DividendPoolOf<T>,
+        shares: T::Signal,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let amount = multiply_by_rational(
+            pool.accumulated().saturated_into::<u128>(),
+            shares.saturated_into::<u128>(),
+            pool.snapshot_total_shares().saturated_into::<u128>(),
+        )
+        .map_err(|_| Error::<T>::DividendComputationOverflow)?;
+        Ok(amount.saturated_into::<BalanceOf<T>>())
+    }
+    /// Member's portion of a proportional spend, rounded up so that the
+    /// portions of all members cover `amount`
+    fn proportional_share(
+        amount: BalanceOf<T>,
+        shares: T::Shares,
+        total_shares: T::Shares,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let (amount, shares, total_shares) = (
+            amount.saturated_into::<u128>(),
+            shares.saturated_into::<u128>(),
+            total_shares.saturated_into::<u128>(),
+        );
+        ensure!(
+            !total_shares.is_zero(),
+            Error::<T>::ProportionalShareComputationOverflow
+        );
+        let portion = if let Some(product) = amount.checked_mul(shares) {
+            let rounding = if product % total_shares == 0 { 0 } else { 1 };
+            product / total_shares + rounding
+        } else {
+            multiply_by_rational(amount, shares, total_shares)
+                .map_err(|_| Error::<T>::ProportionalShareComputationOverflow)?
+                .saturating_add(1)
+        };
+        Ok(portion.saturated_into::<BalanceOf<T>>())
+    }
+    /// Unreserves every approved portion and removes the spend
+    fn release_proportional_approvals(
+        spend_id: T::SpendId,
+        spend: &PropSpend<T>,
+    ) {
+        let bank_account = Self::bank_account_id(spend.bank_id());
+        <ProportionalApprovals<T>>::drain_prefix(spend_id).for_each(
+            |(_, portion)| {
+                let _ =
+                    <T as Trait>::Currency::unreserve(&bank_account, portion);
+            },
+        );
+        Self::remove_proportional_shares(spend_id);
+        <ProportionalSpends<T>>::remove(spend_id);
+    }
+    /// Removes the shares recorded when the spend was created
+    fn remove_proportional_shares(spend_id: T::SpendId) {
+        <ProportionalShares<T>>::remove_prefix(spend_id);
+        <ProportionalShareTotals<T>>::remove(spend_id);
+        <ProportionalSpendExpiries<T>>::remove(spend_id);
+    }
+    /// Whether the spend's lifetime ended before the current block
+    /// -> spends created before expiries were recorded never expire
+    fn proportional_spend_expired(spend_id: T::SpendId) -> bool {
+        <ProportionalSpendExpiries<T>>::get(spend_id)
+            .map_or(false, |(expires, _)| {
+                <frame_system::Module<T>>::block_number() > expires
+            })
+    }
+    /// Records every member's shares and closes the pool
+    /// -> fails before writing if the org has more than `member_count` members
+    /// -> returns the rounding remainder that no member can claim
+    fn snapshot_dividend_shares(
+        bank_id: T::BankId,
+        epoch: u32,
+        org: T::OrgId,
+        pool: &DividendPoolOf<T>,
+        member_count: u32,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let membership = <org::Module<T>>::get_membership_with_shape(org)
+            .ok_or(Error::<T>::CannotSnapshotSharesIfOrgDNE)?;
+        ensure!(
+            membership.vec().len() as u32 <= member_count,
+            Error::<T>::MemberCountWitnessTooLow
+        );
+        let pool = pool.close(membership.total().into());
+        let mut claimable: BalanceOf<T> = Zero::zero();
+        for (member, shares) in membership.vec() {
+            let signal: T::Signal = shares.into();
+            claimable += Self::dividend_for(&pool, signal)?;
+            <DividendShares<T>>::insert((bank_id, epoch), member, signal);
+        }
+        <DividendPools<T>>::insert(bank_id, epoch, pool);
+        Ok(pool.accumulated() - claimable)
+    }
     fn generate_bank_uid() -> T::BankId {
         let mut bank_nonce_id = <BankIdNonce<T>>::get() + 1u32.into();
         while Self::is_bank(bank_nonce_id) {
@@ -339,6 +1099,14 @@ impl<T: Trait> Module<T> {
         <BankIdNonce<T>>::put(bank_nonce_id);
         bank_nonce_id
     }
+    fn generate_proportional_spend_uid() -> T::SpendId {
+        let mut id_nonce = <ProportionalSpendNonce<T>>::get() + 1u32.into();
+        while <ProportionalSpends<T>>::get(id_nonce).is_some() {
+            id_nonce += 1u32.into();
+        }
+        <ProportionalSpendNonce<T>>::put(id_nonce);
+        id_nonce
+    }
     fn generate_spend_uid(seed: T::BankId) -> T::SpendId {
         let mut id_nonce = <SpendNonceMap<T>>::get(seed) + 1u32.into();
         while Self::is_spend(seed, id_nonce) {
@@ -347,13 +1115,14 @@ impl<T: Trait> Module<T> {
         <SpendNonceMap<T>>::insert(seed, id_nonce);
         id_nonce
     }
+    /// Banks opened by the org which are still open
+    pub fn get_org_banks(org: T::OrgId) -> Vec<T::BankId> {
+        <OrgBankRegistry<T>>::get(org)
+    }
     pub fn get_banks_for_org(
         org: T::OrgId,
     ) -> Result<Vec<T::BankId>, DispatchError> {
-        let ret_vec = <Banks<T>>::iter()
-            .filter(|(_, bank_state)| bank_state.org() == org)
-            .map(|(bank_id, _)| bank_id)
-            .collect::<Vec<T::BankId>>();
+        let ret_vec = Self::get_org_banks(org);
         if !ret_vec.is_empty() {
             Ok(ret_vec)
         } else {
@@ -409,6 +1178,8 @@ impl<T: Trait>
         <Banks<T>>::insert(id, bank);
         // put new org treasury count
         <OrgTreasuryCount<T>>::insert(org, new_count);
+        // index the bank under its org
+        <OrgBankRegistry<T>>::append(org, id);
         // iterate total bank count
         <TotalBankCount>::mutate(|count| *count += 1u32);
         // return new treasury identifier
@@ -468,6 +1239,7 @@ impl<T: Trait>
                     spend_id,
                     new_spend_proposal,
                 );
+                <SpendsByVote<T>>::insert(new_vote_id, (bank_id, spend_id));
                 Ok(new_vote_id)
             }
             _ => {
@@ -494,13 +1266,19 @@ impl<T: Trait>
         match spend_proposal.state() {
             SpendState::WaitingForApproval => {
                 // TODO: if Voting, remove the current live vote
-                let new_spend_proposal = if let Ok(()) =
-                    <T as Trait>::Currency::transfer(
-                        &Self::bank_account_id(bank_id),
-                        &spend_proposal.dest(),
+                let new_spend_proposal = if let Ok(()) = Self::execute(
+                    bank_id,
+                    GovernanceAction::Transfer {
+                        to: spend_proposal.dest(),
+                        amount: spend_proposal.amount(),
+                    },
+                ) {
+                    Self::record_audit_entry(
+                        bank_id,
+                        caller.clone(),
+                        BankAction::Transfer,
                         spend_proposal.amount(),
-                        ExistenceRequirement::KeepAlive,
-                    ) {
+                    );
                     spend_proposal.set_state(SpendState::ApprovedAndExecuted)
                 } else {
                     spend_proposal.set_state(SpendState::ApprovedButNotExecuted)
@@ -530,13 +1308,20 @@ impl<T: Trait>
                     <vote::Module<T>>::get_vote_outcome(vote_id)?;
                 if vote_outcome == VoteOutcome::Approved {
                     // approved so try to execute and if not, still approve
-                    let new_spend_proposal = if let Ok(()) =
-                        <T as Trait>::Currency::transfer(
-                            &Self::bank_account_id(prop.bank_id()),
-                            &prop.dest(),
+                    let new_spend_proposal = if let Ok(()) = Self::execute(
+                        prop.bank_id(),
+                        GovernanceAction::Transfer {
+                            to: prop.dest(),
+                            amount: prop.amount(),
+                        },
+                    ) {
+                        // executed by the poll so the bank is the actor
+                        Self::record_audit_entry(
+                            prop.bank_id(),
+                            Self::bank_account_id(prop.bank_id()),
+                            BankAction::Transfer,
                             prop.amount(),
-                            ExistenceRequirement::KeepAlive,
-                        ) {
+                        );
                         prop.set_state(SpendState::ApprovedAndExecuted)
                     } else {
                         prop.set_state(SpendState::ApprovedButNotExecuted)
@@ -556,3 +1341,39 @@ impl<T: Trait>
         }
     }
 }
+
+impl<T: Trait> ConflictOfInterestChecker<T::AccountId, T::VoteId>
+    for Module<T>
+{
+    /// The recipient of a spend may not vote on it
+    fn has_conflict_of_interest(
+        voter: &T::AccountId,
+        vote_id: T::VoteId,
+    ) -> bool {
+        <SpendsByVote<T>>::get(vote_id)
+            .and_then(|(bank_id, spend_id)| {
+                <SpendProposals<T>>::get(bank_id, spend_id)
+            })
+            .map(|spend| &spend.dest() == voter)
+            .unwrap_or(false)
+    }
+}
+
+impl<T: Trait> GovernanceExecutor<T::BankId, T::AccountId, BalanceOf<T>, T::Cid>
+    for Module<T>
+{
+    /// Spends are the only action a bank governs
+    fn execute(bank_id: T::BankId, action: GovAction<T>) -> DispatchResult {
+        match action {
+            GovernanceAction::Transfer { to, amount } => {
+                <T as Trait>::Currency::transfer(
+                    &Self::bank_account_id(bank_id),
+                    &to,
+                    amount,
+                    ExistenceRequirement::KeepAlive,
+                )
+            }
+            _ => Err(Error::<T>::GovernanceActionNotSupportedByBank.into()),
+        }
+    }
+}
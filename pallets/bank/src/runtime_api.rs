@@ -0,0 +1,24 @@
+//! Runtime API for looking up the banks held by an org and their balances
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+use util::bank::BankBalanceSummary;
+
+sp_api::decl_runtime_apis! {
+    pub trait BankApi<OrgId, BankId> where
+        OrgId: Codec,
+        BankId: Codec,
+    {
+        /// Open banks for the org, in the order they were opened
+        fn get_org_banks(org_id: OrgId) -> Vec<BankId>;
+    }
+
+    pub trait BankSummaryApi<BankId, Balance> where
+        BankId: Codec,
+        Balance: Codec,
+    {
+        /// Free, reserved, committed, uncommitted and total funds of the bank
+        fn get_bank_balances(
+            bank_id: BankId,
+        ) -> Option<BankBalanceSummary<Balance>>;
+    }
+}
@@ -5,7 +5,10 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
-    traits::OnFinalize,
+    traits::{
+        OnFinalize,
+        OnRuntimeUpgrade,
+    },
     weights::Weight,
 };
 use frame_system::{self as system,};
@@ -106,21 +109,41 @@ impl org::Trait for Test {
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type ShareTransferVote = ();
+    type ShareTransferRequiresVote = ();
+    type ProxyVotingActive = ();
+    type SnapshotRetentionPeriod = ();
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type IndexJustifications = ();
+    type MaxOpenVotesPerOrg = ();
+    type PruneParticipationIndex = ();
+    type MaxDelegatorsPerVote = ();
+    type MaxVotesPerPool = ();
+    type ConvictionLockPeriod = ();
+    type DecayedVotersPerBlock = ();
+    type Currency = Balances;
+    type VoteCreationDeposit = ();
+    type FullRefundTurnout = ();
+    type Treasury = ();
+    type VoteCreationFee = ();
+    type VoteRewardPool = VoteRewardPool;
+    type ConflictCheck = ();
 }
 impl donate::Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
 }
 parameter_types! {
+    pub const VoteRewardPool: ModuleId = ModuleId(*b"vote/rwd");
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
     pub const MaxTreasuryPerOrg: u32 = 50;
     pub const MinDeposit: u64 = 20;
+    pub const ProportionalSpendLifetime: u64 = 10;
 }
 impl Trait for Test {
     type Event = TestEvent;
@@ -130,6 +153,7 @@ impl Trait for Test {
     type SpendId = u64;
     type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
     type MinDeposit = MinDeposit;
+    type ProportionalSpendLifetime = ProportionalSpendLifetime;
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
@@ -239,6 +263,59 @@ fn opening_bank_account_works() {
     });
 }
 
+#[test]
+fn org_bank_registry_tracks_open_banks() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_eq!(Bank::get_org_banks(1), Vec::<u64>::new());
+        assert_noop!(Bank::get_banks_for_org(1), Error::<Test>::NoBanksForOrg);
+        assert_ok!(Bank::open(
+            Origin::signed(1),
+            1,
+            20,
+            Some(1),
+            threshold.clone()
+        ));
+        assert_ok!(Bank::open(Origin::signed(2), 1, 20, None, threshold));
+        assert_eq!(Bank::get_org_banks(1), vec![1, 2]);
+        assert_ok!(Bank::close(Origin::signed(1), 1));
+        assert_eq!(Bank::org_bank_registry(1), vec![2]);
+        assert_eq!(Bank::get_banks_for_org(1), Ok(vec![2]));
+    });
+}
+
+#[test]
+fn runtime_upgrade_indexes_existing_banks_by_org() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        for opener in 1..4 {
+            assert_ok!(Bank::open(
+                Origin::signed(opener),
+                1,
+                20,
+                None,
+                threshold.clone()
+            ));
+        }
+        assert_eq!(Bank::storage_version(), Releases::V2);
+        // banks opened before the registry existed
+        <OrgBankRegistry<Test>>::remove(1);
+        <StorageVersion>::put(Releases::V1);
+        Bank::on_runtime_upgrade();
+        assert_eq!(Bank::storage_version(), Releases::V2);
+        assert_eq!(Bank::org_bank_registry(1), vec![1, 2, 3]);
+        // already migrated so upgrading again is a no-op
+        assert_eq!(Bank::on_runtime_upgrade(), 0);
+        assert_eq!(Bank::org_bank_registry(1), vec![1, 2, 3]);
+    });
+}
+
 #[test]
 fn spend_governance_works() {
     new_test_ext().execute_with(|| {
@@ -260,7 +337,9 @@ fn spend_governance_works() {
                 i_origin,
                 1,
                 VoterView::InFavor,
-                None
+                None,
+                None,
+                0
             ));
         }
         assert_eq!(Balances::total_balance(&3), 200);
@@ -271,5 +350,451 @@ fn spend_governance_works() {
         assert_eq!(Balances::total_balance(&4), 75);
         assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 2));
         assert_eq!(Balances::total_balance(&4), 80);
+        // banks only execute spends
+        assert_noop!(
+            Bank::execute(1, GovernanceAction::AdmitMember(7)),
+            Error::<Test>::GovernanceActionNotSupportedByBank
+        );
+    });
+}
+
+#[test]
+fn spend_recipient_has_conflict_of_interest() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 3));
+        // no vote is open yet
+        assert!(!Bank::has_conflict_of_interest(&3, 1));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1));
+        assert_eq!(Bank::spends_by_vote(1), Some((1, 1)));
+        // the recipient is conflicted, every other voter is clean
+        assert!(Bank::has_conflict_of_interest(&3, 1));
+        assert!(!Bank::has_conflict_of_interest(&2, 1));
+        assert!(!Bank::has_conflict_of_interest(&3, 2));
+    });
+}
+
+#[test]
+fn audit_log_records_bank_operations() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 3));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendProposed(1, 1, 1, 10, 3)
+        );
+        System::set_block_number(9);
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1));
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ));
+        }
+        run_to_block(11);
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 5, 4));
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 2));
+        let bank_account = Bank::bank_account_id(1);
+        let expected = vec![
+            AuditEntry::new(1, 1, BankAction::Deposit, 20, None),
+            AuditEntry::new(1, 1, BankAction::Reserve, 10, None),
+            AuditEntry::new(9, 2, BankAction::Commit, 10, None),
+            AuditEntry::new(10, bank_account, BankAction::Transfer, 10, None),
+            AuditEntry::new(11, 1, BankAction::Reserve, 5, None),
+            AuditEntry::new(11, 1, BankAction::Transfer, 5, None),
+        ];
+        assert_eq!(Bank::bank_audit_counter(1), 6);
+        for (index, entry) in expected.into_iter().enumerate() {
+            assert_eq!(Bank::bank_audit_log(1, index as u64), Some(entry));
+        }
+        assert!(System::events().iter().any(|r| r.event
+            == TestEvent::bank(RawEvent::AuditLogEntryCreated(
+                1,
+                5,
+                BankAction::Transfer
+            ))));
+    });
+}
+
+#[test]
+fn dividend_pool_pays_members_pro_rata() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
+        assert_noop!(
+            Bank::add_to_dividend_pool(Origin::signed(2), 1, 13, 1),
+            Error::<Test>::OnlyControllerCanFundDividendPool
+        );
+        assert_ok!(Bank::add_to_dividend_pool(Origin::signed(1), 1, 13, 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DividendPoolFunded(1, 1, 13)
+        );
+        assert_eq!(Bank::bank_reserved_balance(1), 13);
+        assert_noop!(
+            Bank::claim_dividend(Origin::signed(2), 1, 1),
+            Error::<Test>::CannotClaimDividendBeforePoolIsClosed
+        );
+        assert_noop!(
+            Bank::close_dividend_pool(Origin::signed(1), 1, 1, 5),
+            Error::<Test>::MemberCountWitnessTooLow
+        );
+        // 6 members with 1 share each, the 1 left over is unreserved
+        assert_ok!(Bank::close_dividend_pool(Origin::signed(1), 1, 1, 6));
+        assert_eq!(get_last_event(), RawEvent::DividendPoolClosed(1, 1, 12));
+        assert_eq!(
+            Bank::bank_audit_log(1, 2),
+            Some(AuditEntry::new(1, 1, BankAction::Commit, 12, None))
+        );
+        assert_eq!(Bank::bank_reserved_balance(1), 12);
+        assert_eq!(
+            Bank::dividend_pools(1, 1),
+            Some(DividendPool::new(13, 6, true))
+        );
+        assert_noop!(
+            Bank::add_to_dividend_pool(Origin::signed(1), 1, 5, 1),
+            Error::<Test>::CannotFundDividendPoolAfterItIsClosed
+        );
+        for i in 1u64..7u64 {
+            let before = Balances::free_balance(i);
+            assert_ok!(Bank::claim_dividend(Origin::signed(i), 1, 1));
+            assert_eq!(Balances::free_balance(i), before + 2);
+        }
+        assert_eq!(get_last_event(), RawEvent::DividendClaimed(6, 1, 1, 2));
+        assert_eq!(Bank::bank_reserved_balance(1), 0);
+        assert_eq!(Bank::bank_balance(1), 8);
+        assert_noop!(
+            Bank::claim_dividend(Origin::signed(1), 1, 1),
+            Error::<Test>::DividendAlreadyClaimed
+        );
+        assert_noop!(
+            Bank::claim_dividend(Origin::signed(7), 1, 1),
+            Error::<Test>::NoSharesInDividendSnapshot
+        );
+        assert_noop!(
+            Bank::claim_dividend(Origin::signed(1), 1, 2),
+            Error::<Test>::CannotClaimDividendIfPoolDNE
+        );
+    });
+}
+
+#[test]
+fn dividend_claims_require_the_full_amount_reserved() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
+        assert_ok!(Bank::add_to_dividend_pool(Origin::signed(1), 1, 12, 1));
+        assert_ok!(Bank::close_dividend_pool(Origin::signed(1), 1, 1, 6));
+        // the pool's reserve was released out from under it
+        <Balances as ReservableCurrency<u64>>::unreserve(
+            &Bank::bank_account_id(1),
+            11,
+        );
+        assert_noop!(
+            Bank::claim_dividend(Origin::signed(1), 1, 1),
+            Error::<Test>::InsufficientReservedFundsToPayDividend
+        );
+        assert_eq!(Bank::bank_reserved_balance(1), 1);
+        assert!(!Bank::dividend_claims((1, 1), 1));
+    });
+}
+
+#[test]
+fn proportional_spend_requires_every_share_of_approval() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, None, threshold));
+        assert_noop!(
+            Bank::create_proportional_spend(Origin::signed(7), 1, 10, 1, 7, 6),
+            Error::<Test>::NotPermittedToCreateProportionalSpendForBank
+        );
+        assert_ok!(Bank::create_proportional_spend(
+            Origin::signed(1),
+            1,
+            10,
+            1,
+            7,
+            6
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ProportionalSpendCreated(1, 1, 1, 10, 7)
+        );
+        assert_eq!(
+            Bank::bank_audit_log(1, 1),
+            Some(AuditEntry::new(1, 1, BankAction::Reserve, 10, None))
+        );
+        // shares issued after the spend is created don't change the portions
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 1, 6));
+        assert_eq!(Bank::proportional_share_totals(1), Some(6));
+        // 6 members with 1 share each commit 10 / 6 rounded up
+        for i in 1u64..5u64 {
+            assert_ok!(Bank::approve_proportional_share(Origin::signed(i), 1));
+        }
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ProportionalShareApproved(4, 1, 2, 8)
+        );
+        assert_noop!(
+            Bank::approve_proportional_share(Origin::signed(1), 1),
+            Error::<Test>::ProportionalShareAlreadyApproved
+        );
+        assert_noop!(
+            Bank::finalize_proportional_spend(Origin::signed(1), 1),
+            Error::<Test>::ProportionalSpendNotYetApproved
+        );
+        assert_ok!(Bank::approve_proportional_share(Origin::signed(5), 1));
+        assert_eq!(Bank::bank_reserved_balance(1), 10);
+        assert_ok!(Bank::finalize_proportional_spend(Origin::signed(6), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ProportionalSpendExecuted(1, 10, 7)
+        );
+        assert_eq!(Balances::free_balance(7), 10);
+        assert_eq!(Bank::bank_reserved_balance(1), 0);
+        assert_eq!(Bank::bank_balance(1), 10);
+        assert!(Bank::proportional_spends(1).is_none());
+        assert_eq!(Bank::proportional_shares(1, 1), None);
+        assert_eq!(Bank::proportional_share_totals(1), None);
+        // a single rejection releases every approved portion
+        assert_ok!(Bank::create_proportional_spend(
+            Origin::signed(2),
+            1,
+            6,
+            1,
+            7,
+            6
+        ));
+        assert_ok!(Bank::approve_proportional_share(Origin::signed(1), 2));
+        assert_ok!(Bank::approve_proportional_share(Origin::signed(2), 2));
+        // 1 holds 7 of the 12 shares recorded for this spend
+        assert_eq!(Bank::bank_reserved_balance(1), 5);
+        assert_noop!(
+            Bank::reject_proportional_share(Origin::signed(1), 2, 6),
+            Error::<Test>::CannotRejectProportionalSpendAfterApprovingIt
+        );
+        assert_noop!(
+            Bank::reject_proportional_share(Origin::signed(3), 2, 5),
+            Error::<Test>::MemberCountWitnessTooLow
+        );
+        assert_ok!(Bank::reject_proportional_share(Origin::signed(3), 2, 6));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ProportionalShareRejected(3, 2)
+        );
+        assert_eq!(Bank::bank_reserved_balance(1), 0);
+        assert!(Bank::proportional_spends(2).is_none());
+        assert_eq!(Bank::proportional_approvals(2, 1), None);
+        assert_eq!(Bank::proportional_share_totals(2), None);
+    });
+}
+
+#[test]
+fn expired_proportional_spends_can_be_removed_by_anyone() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, None, threshold));
+        assert_noop!(
+            Bank::create_proportional_spend(Origin::signed(1), 1, 12, 1, 7, 5),
+            Error::<Test>::MemberCountWitnessTooLow
+        );
+        assert_ok!(Bank::create_proportional_spend(
+            Origin::signed(1),
+            1,
+            12,
+            1,
+            7,
+            6
+        ));
+        assert_eq!(Bank::proportional_spend_expiries(1), Some((11, 6)));
+        assert_ok!(Bank::approve_proportional_share(Origin::signed(1), 1));
+        assert_eq!(Bank::bank_reserved_balance(1), 2);
+        System::set_block_number(11);
+        assert_noop!(
+            Bank::remove_expired_proportional_spend(Origin::signed(7), 1, 6),
+            Error::<Test>::ProportionalSpendNotYetExpired
+        );
+        System::set_block_number(12);
+        assert_noop!(
+            Bank::approve_proportional_share(Origin::signed(2), 1),
+            Error::<Test>::ProportionalSpendExpired
+        );
+        assert_noop!(
+            Bank::remove_expired_proportional_spend(Origin::signed(7), 1, 5),
+            Error::<Test>::MemberCountWitnessTooLow
+        );
+        assert_ok!(Bank::remove_expired_proportional_spend(
+            Origin::signed(7),
+            1,
+            6
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ProportionalSpendExpired(7, 1, 2)
+        );
+        assert_eq!(Bank::bank_reserved_balance(1), 0);
+        assert!(Bank::proportional_spends(1).is_none());
+        assert_eq!(Bank::proportional_shares(1, 1), None);
+        assert_eq!(Bank::proportional_approvals(1, 1), None);
+        assert!(Bank::proportional_spend_expiries(1).is_none());
+    });
+}
+
+#[test]
+fn bank_balances_split_committed_and_uncommitted_funds() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, None, threshold));
+        assert_eq!(Bank::get_bank_balances(2), None);
+        assert_ok!(Bank::create_proportional_spend(
+            Origin::signed(1),
+            1,
+            10,
+            1,
+            7,
+            6
+        ));
+        assert_ok!(Bank::approve_proportional_share(Origin::signed(1), 1));
+        assert_ok!(Bank::approve_proportional_share(Origin::signed(2), 1));
+        // reserved by another module, as a court dispute lock would be
+        assert_ok!(<Balances as ReservableCurrency<u64>>::reserve(
+            &Bank::bank_account_id(1),
+            3
+        ));
+        let summary = Bank::get_bank_balances(1).unwrap();
+        assert_eq!(summary, BankBalanceSummary::new(13, 7, 4, 3, 20));
+        assert_eq!(Bank::total_free(1), 13);
+        assert_eq!(Bank::total_committed(1), 4);
+        assert_eq!(Bank::total_uncommitted(1), 3);
+        assert_eq!(summary.total(), Bank::bank_balance(1));
+    });
+}
+
+#[test]
+fn spends_requiring_a_vote_wait_for_org_approval() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
+        assert_noop!(
+            Bank::set_spend_vote_minimum(Origin::signed(2), 1, Some(10)),
+            Error::<Test>::OnlyOrgSupervisorCanSetSpendVoteMinimum
+        );
+        assert_ok!(Bank::set_spend_vote_minimum(
+            Origin::signed(1),
+            1,
+            Some(10)
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendVoteMinimumSet(1, 1, Some(10))
+        );
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 3));
+        assert!(Bank::spend_requires_vote(1, 1));
+        assert_noop!(
+            Bank::sudo_approve(Origin::signed(2), 1, 1),
+            Error::<Test>::OnlyControllerCanSudoApproveSpendProposals
+        );
+        // the controller's first approval only opens the vote
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendApprovalVoteTriggered(1, 1, 1, 1)
+        );
+        assert_eq!(Bank::spend_approval_votes(1, 1), Some(1));
+        assert_eq!(Balances::total_balance(&3), 200);
+        assert_noop!(
+            Bank::sudo_approve(Origin::signed(1), 1, 1),
+            Error::<Test>::AwaitingVoteApproval
+        );
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 1));
+        assert_eq!(Balances::total_balance(&3), 210);
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        // spends below the bank's minimum are approved by the controller alone
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 5, 3));
+        assert!(!Bank::spend_requires_vote(1, 2));
+    });
+}
+
+#[test]
+fn spends_rejected_by_vote_cannot_be_approved() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::one(),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
+        assert_ok!(Bank::set_spend_vote_minimum(Origin::signed(1), 1, Some(1)));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 3));
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 1));
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendRejectedByVote(1, 1, 1, 1)
+        );
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::Rejected
+        );
+        assert_noop!(
+            Bank::sudo_approve(Origin::signed(1), 1, 1),
+            Error::<Test>::CannotSudoApproveFromCurrentState
+        );
+        assert_eq!(Balances::total_balance(&3), 200);
     });
 }
@@ -231,7 +231,7 @@ decl_module! {
         ) -> DispatchResult {
             let depositer = ensure_signed(origin)?;
             ensure!(amount >= T::MinDeposit::get(), Error::<T>::BountyPostMustExceedMinDeposit);
-            let imb = T::Currency::withdraw(
+            let imb = <T as Trait>::Currency::withdraw(
                 &depositer,
                 amount,
                 WithdrawReasons::from(WithdrawReason::Transfer),
@@ -239,7 +239,7 @@ decl_module! {
             )?;
             let bounty = Bounty::<T>::new(info, ChallengeNorms::new(depositer.clone(), veto_threshold, refund_threshold), amount);
             let id = Self::bounty_generate_uid();
-            T::Currency::resolve_creating(&Self::bounty_account_id(id), imb);
+            <T as Trait>::Currency::resolve_creating(&Self::bounty_account_id(id), imb);
             <Bounties<T>>::insert(id, bounty);
             <BountyTips<T>>::insert(id, &depositer, amount);
             Self::deposit_event(RawEvent::BountyPosted(depositer, amount, id, info));
@@ -254,7 +254,7 @@ decl_module! {
             let contributor = ensure_signed(origin)?;
             ensure!(amount >= T::MinContribution::get(), Error::<T>::ContributionMustExceedModuleMin);
             let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
-            T::Currency::transfer(
+            <T as Trait>::Currency::transfer(
                 &contributor,
                 &Self::bounty_account_id(bounty_id),
                 amount,
@@ -424,7 +424,7 @@ impl<T: Trait> Module<T> {
             Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable
         );
         // execute payment
-        T::Currency::transfer(
+        <T as Trait>::Currency::transfer(
             &Self::bounty_account_id(bounty_id),
             &submission.submitter(),
             submission.amount(),
@@ -452,7 +452,7 @@ impl<T: Trait> Module<T> {
         _remainder_recipient: &T::AccountId,
     ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
         let from = Self::bounty_account_id(id);
-        let total = T::Currency::total_balance(&from);
+        let total = <T as Trait>::Currency::total_balance(&from);
         let contributors: WeightedVector<T::AccountId, BalanceOf<T>> =
             <BountyTips<T>>::iter()
                 .filter(|(i, _, _)| i == &id)
@@ -461,7 +461,7 @@ impl<T: Trait> Module<T> {
                 .into();
         let num_of_accounts: u32 = contributors.vec().len() as u32;
         if num_of_accounts == 1 {
-            T::Currency::transfer(
+            <T as Trait>::Currency::transfer(
                 &from,
                 &contributors.vec()[0].0,
                 total,
@@ -477,7 +477,7 @@ impl<T: Trait> Module<T> {
             //     let due_proportion =
             //         Permill::from_rational_approximation(nom, &den);
             //     let due_amount: BalanceOf<T> = due_proportion * total;
-            //     T::Currency::transfer(
+            //     <T as Trait>::Currency::transfer(
             //         &from,
             //         &acc,
             //         due_amount,
@@ -487,7 +487,7 @@ impl<T: Trait> Module<T> {
             //     total_to_contributors += due_amount;
             // }
             // // send remainder
-            // T::Currency::transfer(
+            // <T as Trait>::Currency::transfer(
             //     &from,
             //     remainder_recipient,
             //     total,
@@ -91,6 +91,10 @@ impl org::Trait for Test {
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type ShareTransferVote = ();
+    type ShareTransferRequiresVote = ();
+    type ProxyVotingActive = ();
+    type SnapshotRetentionPeriod = ();
 }
 impl Trait for Test {
     type Event = TestEvent;
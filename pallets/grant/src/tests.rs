@@ -104,18 +104,37 @@ impl org::Trait for Test {
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type ShareTransferVote = ();
+    type ShareTransferRequiresVote = ();
+    type ProxyVotingActive = ();
+    type SnapshotRetentionPeriod = ();
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type IndexJustifications = ();
+    type MaxOpenVotesPerOrg = ();
+    type PruneParticipationIndex = ();
+    type MaxDelegatorsPerVote = ();
+    type MaxVotesPerPool = ();
+    type ConvictionLockPeriod = ();
+    type DecayedVotersPerBlock = ();
+    type Currency = Balances;
+    type VoteCreationDeposit = ();
+    type FullRefundTurnout = ();
+    type Treasury = ();
+    type VoteCreationFee = ();
+    type VoteRewardPool = VoteRewardPool;
+    type ConflictCheck = ();
 }
 impl donate::Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
 }
 parameter_types! {
+    pub const VoteRewardPool: ModuleId = ModuleId(*b"vote/rwd");
     pub const BigFoundation: ModuleId = ModuleId(*b"big/fund");
     pub const MinDeposit: u64 = 20;
     pub const MinContribution: u64 = 10;
@@ -11,25 +11,109 @@
 //! If a dispute arises, the vote is dispatched s.t. its outcome resolves the dispute
 //! and performs the associated transfers.
 //!
+//! Disputes may also lock funds held by a `bank` treasury instead of an individual
+//! account. Only the bank's controller may register these disputes. Banks without
+//! a controller cannot back a dispute because no single signer is authorized to
+//! lock their funds outside of the bank's spend governance. The funds are reserved
+//! in the bank's account so they cannot be spent while the dispute is open. If the
+//! dispute is accepted, they are paid out of the bank account to the dispute raiser.
+//! If it is rejected, they are unreserved and stay in the bank. A bank cannot be
+//! closed while it holds reserved funds.
+//!
+//! A dispute may also be registered to award its locked funds to the configured
+//! `Treasury` account on one specific vote outcome, for cases where the court finds
+//! both parties at fault. Setting `Treasury` to an account without a known private
+//! key burns the award.
+//!
+//! Disputes registered with an `expiry` that are still not raised when that block
+//! begins expire and their funds are unreserved back to the locker. At most
+//! `MaxDisputeExpirationsPerBlock` expire in one block; the rest are carried over to
+//! the next block and can no longer be raised in the meantime.
+//!
+//! The org named in a dispute's resolution metadata must exist when the dispute is
+//! registered, unless it is registered with `register_dispute_for_future_org`. That
+//! call names the account expected to form the org, so pre-arranged agreements can
+//! name an org that is formed later. The dispute can only be raised once the org
+//! exists and was formed by that account, so nobody else can claim the org id and
+//! decide the dispute. Nothing guarantees that org is ever formed. Until it is, the
+//! dispute cannot be raised and the funds stay locked, so lockers should register
+//! such disputes with an `expiry` that returns the funds if the org never appears.
+//!
+//! Terminal disputes stay in storage for `DisputeRetentionPeriod` blocks after the
+//! block they closed in. They are then swept in `on_initialize`: every per-dispute
+//! map is cleared and a `DisputeSummary` is kept in `ArchivedDisputes`. A rejected
+//! dispute can only be re-run until it is swept.
+//!
+//! Every dispute transition, from registration through raising, resolution,
+//! expiry and archival, is logged natively under the `runtime::court` target.
+//!
+//! An org supervisor may store a `DisputeTemplate` with the resolution metadata, a
+//! minimum lock and a default expiry duration shared by the org's recurring
+//! agreements. Any account can then register a dispute from the template, choosing
+//! only the amount, the raiser and optionally the expiry.
+//!
+//! A dispute may instead name an oracle account as its resolution path. Raising it
+//! waits on the oracle rather than dispatching a vote, and the oracle resolves it
+//! with `oracle_resolve`, approving or rejecting with a reference to the evidence it
+//! relied on. Acceptance and rejection move the funds as a vote outcome would. The
+//! evidence reference is kept after the dispute is archived for auditing. The locker
+//! cannot be its own dispute's oracle, and a dispute the oracle has not resolved
+//! within `OracleTimeout` blocks of being raised is rejected.
+//!
+//! Disputes are indexed by the org whose vote resolves them until they are
+//! archived. `org_governance_summary` combines that index with the vote pallet's
+//! per-org index and the org's membership into one bounded response for clients.
+//! `get_dispute_details` similarly flattens a single stored dispute, its resolution
+//! org, its registration block and the outcome of its live vote for dashboards.
+//!
+//! The supervisor of the org that resolves a dispute may require a different bar for
+//! it than the org's ordinary votes, e.g. a supermajority, with `set_dispute_threshold`
+//! before the dispute is raised. The override takes precedence over the threshold in
+//! the dispute's resolution metadata: every vote dispatched for the dispute, re-runs
+//! included, is opened with `open_percent_vote` under the override, even if the
+//! metadata describes a signal vote. The org and duration still come from the
+//! metadata. Clearing the override restores the metadata's threshold. An override may
+//! only raise the bar: it must require more than half the org in favor, and at least
+//! the metadata's threshold if that is a percentage.
+//!
+//! Each vote dispatched for a dispute, including re-runs, is indexed in
+//! `VoteToDispute` so clients reading a vote can tell whether a dispute triggered it.
+//!
+//! Raising a dispute and re-running its vote open a vote among every member of
+//! the court org, so both calls take the org's member count as a witness. Oracle
+//! disputes ignore it.
+//! The index outlives archival for historical lookups.
+//!
+//! Transfers are left to the `Currency` trait, but any sum or difference of
+//! `BalanceOf<T>` computed in this module goes through `checked_*` (or `saturating_*`
+//! where a clamped value is correct) and fails with its own error on overflow. The
+//! check runs before any funds move. Topping up a dispute past the balance type's
+//! maximum fails with `DisputeLockOverflow`, for example.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 
+use bank::Trait as Bank;
 use frame_support::{
+    debug,
     decl_error,
     decl_event,
     decl_module,
     decl_storage,
     ensure,
+    storage::IterableStorageDoubleMap,
     traits::{
         Currency,
         ExistenceRequirement,
         Get,
         ReservableCurrency,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -41,8 +125,11 @@ use parity_scale_codec::Codec;
 use sp_runtime::{
     traits::{
         AtLeast32Bit,
+        CheckedAdd,
         MaybeSerializeDeserialize,
         Member,
+        One,
+        Saturating,
         Zero,
     },
     DispatchError,
@@ -51,43 +138,98 @@ use sp_runtime::{
 };
 use sp_std::{
     fmt::Debug,
+    marker::PhantomData,
     prelude::*,
 };
 use util::{
+    governance::GovernanceSummary,
     insurance::{
         Dispute,
+        DisputeDetails,
+        DisputeRole,
         DisputeState,
+        DisputeSummary,
+        DisputeTemplate,
+        ResolutionPath,
+    },
+    meta::{
+        VoteCall,
+        VoteMetadata,
     },
-    meta::VoteMetadata,
     organization::OrgRep,
     traits::{
         GenerateUniqueID,
         GetVoteOutcome,
         IDIsAvailable,
         OpenVote,
+        OrganizationSupervisorPermissions,
         RegisterDisputeType,
     },
-    vote::VoteOutcome,
+    vote::{
+        BoundedVoteIdSet,
+        Threshold,
+        VoteOutcome,
+        VoteStatus,
+    },
 };
 use vote::Trait as Vote;
 
+/// Target of this module's node-side logs, i.e. `RUST_LOG=runtime::court=debug`
+#[allow(dead_code)]
+const LOG_TARGET: &str = "runtime::court";
+
+/// Entries returned in each list of `org_governance_summary`
+pub const MAX_GOVERNANCE_SUMMARY_ENTRIES: usize = 64;
+
 /// The balances type for this module
 type BalanceOf<T> =
     <<T as Trait>::Currency as Currency<<T as System>::AccountId>>::Balance;
+/// Holds no `Vec`, so its encoding has a fixed upper bound without a length limit
 type GovernanceOf<T> = VoteMetadata<
     OrgRep<<T as Org>::OrgId>,
     <T as Vote>::Signal,
     Permill,
     <T as System>::BlockNumber,
 >;
+type ResolutionOf<T> =
+    ResolutionPath<GovernanceOf<T>, <T as System>::AccountId>;
+/// Bounded like `GovernanceOf`: every field is fixed size or an `Option` of one
 type DisputeOf<T> = Dispute<
     <T as System>::AccountId,
     BalanceOf<T>,
     <T as System>::BlockNumber,
-    GovernanceOf<T>,
+    ResolutionOf<T>,
     DisputeState<<T as Vote>::VoteId>,
 >;
-pub trait Trait: System + Org + Vote {
+type DisputeTemplateOf<T> =
+    DisputeTemplate<GovernanceOf<T>, BalanceOf<T>, <T as System>::BlockNumber>;
+type DisputeSummaryOf<T> = DisputeSummary<
+    <T as System>::AccountId,
+    BalanceOf<T>,
+    <T as System>::BlockNumber,
+    DisputeState<<T as Vote>::VoteId>,
+>;
+type DisputeDetailsOf<T> = DisputeDetails<
+    <T as Trait>::DisputeId,
+    <T as System>::AccountId,
+    BalanceOf<T>,
+    <T as System>::BlockNumber,
+    <T as Org>::OrgId,
+    <T as Vote>::VoteId,
+>;
+type VoteRoundsOf<T> =
+    BoundedVoteIdSet<<T as Vote>::VoteId, MaxDisputeVoteRounds<T>>;
+
+/// Votes a dispute may dispatch: the first round and every permitted re-run
+pub struct MaxDisputeVoteRounds<T>(PhantomData<T>);
+
+impl<T: Trait> Get<u32> for MaxDisputeVoteRounds<T> {
+    fn get() -> u32 {
+        T::MaxDisputeVoteReruns::get().saturating_add(1)
+    }
+}
+
+pub trait Trait: System + Org + Vote + Bank {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as System>::Event>;
 
@@ -110,6 +252,21 @@ pub trait Trait: System + Org + Vote {
 
     /// The minimum amount for any dispute registered in this module
     type MinimumDisputeAmount: Get<BalanceOf<Self>>;
+
+    /// Receives locked funds for disputes resolved with a treasury award
+    type Treasury: Get<Self::AccountId>;
+
+    /// Times a rejected dispute's vote may be re-run against the court org's current membership
+    type MaxDisputeVoteReruns: Get<u32>;
+
+    /// Blocks a terminal dispute stays in storage before it is archived and removed
+    type DisputeRetentionPeriod: Get<Self::BlockNumber>;
+
+    /// Blocks an oracle has to resolve a raised dispute before it is rejected
+    type OracleTimeout: Get<Self::BlockNumber>;
+
+    /// The most unraised disputes expired at the start of one block
+    type MaxDisputeExpirationsPerBlock: Get<u32>;
 }
 
 decl_event!(
@@ -119,13 +276,47 @@ decl_event!(
         <T as Org>::OrgId,
         <T as Vote>::VoteId,
         <T as Trait>::DisputeId,
+        <T as Bank>::BankId,
         Balance = BalanceOf<T>,
-
+        <T as System>::BlockNumber,
+        <T as Org>::Cid,
     {
         RegisteredDisputeWithResolutionPath(DisputeId, AccountId, Balance, AccountId, OrgRep<OrgId>),
+        /// Dispute id, bank id, controller, locked amount, dispute raiser, court org
+        RegisteredBankDisputeWithResolutionPath(DisputeId, BankId, AccountId, Balance, AccountId, OrgRep<OrgId>),
         DisputeRaisedAndVoteTriggered(DisputeId, AccountId, Balance, AccountId, OrgRep<OrgId>, VoteId),
         DisputeAcceptedAndLockedFundsTransferred(DisputeId, AccountId, Balance, AccountId, OrgId, VoteId),
         DisputeRejectedAndLockedFundsUnlocked(DisputeId, AccountId, Balance, AccountId, OrgId, VoteId),
+        /// Dispute id, caller, additional amount locked, new total locked
+        DisputeLockIncreased(DisputeId, AccountId, Balance, Balance),
+        /// Dispute id, party that consented to dissolve the dispute
+        DissolutionConsentGiven(DisputeId, AccountId),
+        /// Dispute id, locker, dispute raiser
+        DisputeDissolvedByConsent(DisputeId, AccountId, AccountId),
+        /// Dispute id, locker, amount awarded, treasury, vote id
+        DisputeAwardedToTreasury(DisputeId, AccountId, Balance, AccountId, VoteId),
+        /// Dispute id, locker, amount unlocked
+        DisputeExpiredWithoutBeingRaised(DisputeId, AccountId, Balance),
+        /// Dispute id, vote id, blocks until the vote ends (`None` if it has no end)
+        DisputeStillPending(DisputeId, VoteId, Option<BlockNumber>),
+        /// Dispute id, new vote id, number of re-runs including this one
+        DisputeVoteRerun(DisputeId, VoteId, u32),
+        /// Dispute id, block in which it closed
+        DisputeArchived(DisputeId, BlockNumber),
+        /// Org, template id
+        DisputeTemplateCreated(OrgId, u32),
+        /// Dispute id, org, template id it was registered from
+        RegisteredDisputeFromTemplate(DisputeId, OrgId, u32),
+        /// Dispute id, locker, locked amount, dispute raiser, oracle
+        RegisteredDisputeWithOracle(DisputeId, AccountId, Balance, AccountId, AccountId),
+        /// Dispute id, locker, locked amount, dispute raiser, oracle
+        DisputeRaisedAwaitingOracle(DisputeId, AccountId, Balance, AccountId, AccountId),
+        /// Dispute id, oracle, approved, evidence
+        DisputeResolvedByOracle(DisputeId, AccountId, bool, Cid),
+        /// Dispute id, locker, amount unlocked
+        DisputeRejectedAfterOracleTimeout(DisputeId, AccountId, Balance),
+        /// Dispute id, court org supervisor, whether an override is now set
+        DisputeThresholdSet(DisputeId, AccountId, bool),
     }
 );
 
@@ -134,11 +325,46 @@ decl_error! {
         /// Must register dispute with resolution path before raising one
         CannotRaiseDisputeIfDisputeStateDNE,
         DisputeMustExceedModuleMinimum,
+        CannotRegisterDisputeForBankThatDNE,
+        OnlyBankControllerCanLockBankFundsForDispute,
+        CannotIncreaseLockIfDisputeStateDNE,
+        OnlyLockerCanIncreaseDisputeLock,
+        CannotIncreaseLockAfterDisputeRaised,
         CannotPollDisputeIfDisputeStateDNE,
         SignerNotAuthorizedToRaiseThisDispute,
+        OutsideDisputeRaiseWindow,
+        CannotDissolveIfDisputeStateDNE,
+        OnlyDisputePartiesCanConsentToDissolve,
+        CannotDissolveAfterDisputeRaised,
+        AlreadyConsentedToDissolve,
+        TreasuryAwardRequiresConclusiveOutcome,
+        DisputeExpiryMustBeInTheFuture,
+        CannotRaiseDisputeAfterExpiry,
         ActiveDisputeCannotBeRaisedFromCurrentState,
         ActiveDisputeCannotBePolledFromCurrentState,
         VoteOutcomeInconclusiveSoPollCannotExecuteOutcome,
+        CannotRerunDisputeVoteIfDisputeStateDNE,
+        OnlyRejectedDisputeVotesCanBeRerun,
+        MaxDisputeVoteRerunsReached,
+        CannotRelockFundsToRerunDisputeVote,
+        OnlyOrgSupervisorCanCreateDisputeTemplate,
+        DisputeTemplateMinimumBelowModuleMinimum,
+        DisputeTemplateDNE,
+        DisputeAmountBelowTemplateMinimum,
+        CannotOracleResolveIfDisputeStateDNE,
+        OnlyDisputeOracleCanResolveDispute,
+        DisputeNotAwaitingOracle,
+        LockerCannotBeDisputeOracle,
+        CannotRaiseDisputeBeforeCourtOrgExists,
+        CannotRegisterDisputeForCourtOrgThatDNE,
+        CourtOrgNotFormedByExpectedCreator,
+        /// The dispute's locked funds would exceed the balance type's maximum
+        DisputeLockOverflow,
+        CannotSetDisputeThresholdIfDisputeStateDNE,
+        DisputeThresholdRequiresVoteResolution,
+        OnlyCourtOrgSupervisorCanSetDisputeThreshold,
+        CannotSetDisputeThresholdAfterDisputeRaised,
+        DisputeThresholdBelowFloor,
     }
 }
 
@@ -153,6 +379,94 @@ decl_storage! {
         /// The state of disputes
         pub DisputeStates get(fn dispute_states): map
             hasher(blake2_128_concat) T::DisputeId => Option<DisputeOf<T>>;
+
+        /// The bank whose funds are locked for the dispute, if any
+        /// -> the dispute's locker is the bank's account
+        pub DisputeBanks get(fn dispute_banks): map
+            hasher(blake2_128_concat) T::DisputeId => Option<T::BankId>;
+
+        /// Every dispute an account was registered in and its role in it
+        /// -> never pruned so it serves as the account's dispute history
+        pub DisputesByAccount get(fn disputes_by_account): double_map
+            hasher(blake2_128_concat) T::AccountId,
+            hasher(blake2_128_concat) T::DisputeId => Option<DisputeRole>;
+
+        /// Parties that consented to dissolve the dispute before it is raised
+        /// -> bank disputes record the bank account when its controller consents
+        pub DissolutionConsents get(fn dissolution_consents): map
+            hasher(blake2_128_concat) T::DisputeId => Vec<T::AccountId>;
+
+        /// The number of disputes an account was registered in
+        pub DisputeCount get(fn dispute_count): map
+            hasher(blake2_128_concat) T::AccountId => u32;
+
+        /// Disputes resolved by each org's vote, until they are archived
+        pub DisputesByOrg get(fn disputes_by_org): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::DisputeId => ();
+
+        /// The vote outcome on which the locked funds go to the treasury
+        /// -> set at registration, otherwise approval pays the raiser and rejection refunds the locker
+        pub TreasuryAwards get(fn treasury_awards): map
+            hasher(blake2_128_concat) T::DisputeId => Option<VoteOutcome>;
+
+        /// Disputes that expire at the start of the block if they have not been raised
+        pub DisputeExpirations get(fn dispute_expirations): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::DisputeId>;
+
+        /// Every vote dispatched for the dispute, in order
+        /// -> more than one if the vote was re-run after a rejection
+        /// -> encoded like the `Vec` previously stored here
+        pub DisputeVoteRounds get(fn dispute_vote_rounds): map
+            hasher(blake2_128_concat) T::DisputeId => VoteRoundsOf<T>;
+
+        /// The dispute each dispatched vote resolves
+        /// -> not removed on archival so the vote can still be traced to its dispute
+        pub VoteToDispute get(fn vote_to_dispute): map
+            hasher(blake2_128_concat) T::VoteId => Option<T::DisputeId>;
+
+        /// Every state the dispute entered, with the block it was entered in
+        /// -> bounded by `max_dispute_transitions`
+        pub DisputeHistory get(fn dispute_history): map
+            hasher(blake2_128_concat) T::DisputeId => Vec<(T::BlockNumber, DisputeState<T::VoteId>)>;
+
+        /// Terminal disputes to archive and remove at the start of the block
+        /// -> a dispute re-run after it was indexed is skipped and indexed again when it closes
+        pub DisputeRemovals get(fn dispute_removals): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::DisputeId>;
+
+        /// Summaries of terminal disputes removed from `DisputeStates`
+        pub ArchivedDisputes get(fn archived_disputes): map
+            hasher(blake2_128_concat) T::DisputeId => Option<DisputeSummaryOf<T>>;
+
+        /// The nonce for dispute template id generation, shared by all orgs
+        DisputeTemplateIdCounter get(fn dispute_template_id_counter): u32;
+
+        /// Dispute configurations orgs register disputes from
+        pub DisputeTemplates get(fn dispute_templates): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) u32 => Option<DisputeTemplateOf<T>>;
+
+        /// The evidence the oracle cited when it resolved the dispute
+        /// -> not removed on archival so the resolution can be audited
+        pub OracleEvidence get(fn oracle_evidence): map
+            hasher(blake2_128_concat) T::DisputeId => Option<T::Cid>;
+
+        /// The account expected to form the court org of a dispute registered before
+        /// the org existed
+        /// -> raising fails unless that account formed the org
+        pub CourtOrgCreators get(fn court_org_creators): map
+            hasher(blake2_128_concat) T::DisputeId => Option<T::AccountId>;
+
+        /// Raised oracle disputes to reject at the start of the block if the oracle
+        /// has not resolved them
+        pub OracleDeadlines get(fn oracle_deadlines): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::DisputeId>;
+
+        /// Replaces the threshold in the dispute's resolution metadata for every vote
+        /// dispatched for it
+        pub DisputeThresholds get(fn dispute_thresholds): map
+            hasher(blake2_128_concat) T::DisputeId => Option<Threshold<Permill>>;
     }
 }
 
@@ -161,15 +475,71 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        /// The minimum amount for any dispute registered in this module
+        const MinimumDisputeAmount: BalanceOf<T> = T::MinimumDisputeAmount::get();
+
+        /// Times a rejected dispute's vote may be re-run
+        const MaxDisputeVoteReruns: u32 = T::MaxDisputeVoteReruns::get();
+
+        /// Blocks a terminal dispute stays in storage before it is archived
+        const DisputeRetentionPeriod: T::BlockNumber = T::DisputeRetentionPeriod::get();
+
+        /// Blocks an oracle has to resolve a raised dispute before it is rejected
+        const OracleTimeout: T::BlockNumber = T::OracleTimeout::get();
+
+        /// The most unraised disputes expired at the start of one block
+        const MaxDisputeExpirationsPerBlock: u32 = T::MaxDisputeExpirationsPerBlock::get();
+
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let removals = <DisputeRemovals<T>>::take(n);
+            let (mut reads, mut writes) = (3u64, 3u64);
+            let mut expirations = <DisputeExpirations<T>>::take(n);
+            let limit = T::MaxDisputeExpirationsPerBlock::get() as usize;
+            if expirations.len() > limit {
+                // carried over ahead of the disputes that expire in the next block
+                let next = n + One::one();
+                let mut carried = expirations.split_off(limit);
+                carried.extend(<DisputeExpirations<T>>::take(next));
+                <DisputeExpirations<T>>::insert(next, carried);
+                reads += 1;
+                writes += 1;
+            }
+            for dispute_id in expirations {
+                reads += 1;
+                if let Some((locker, unlocked)) = Self::expire_dispute(dispute_id) {
+                    writes += 5;
+                    Self::deposit_event(RawEvent::DisputeExpiredWithoutBeingRaised(dispute_id, locker, unlocked));
+                }
+            }
+            for dispute_id in <OracleDeadlines<T>>::take(n) {
+                reads += 1;
+                if let Some((locker, unlocked)) = Self::time_out_oracle_dispute(dispute_id) {
+                    writes += 4;
+                    Self::deposit_event(RawEvent::DisputeRejectedAfterOracleTimeout(dispute_id, locker, unlocked));
+                }
+            }
+            for dispute_id in removals {
+                reads += 2;
+                if let Some(closed) = Self::archive_dispute(dispute_id, n) {
+                    writes += 8;
+                    Self::deposit_event(RawEvent::DisputeArchived(dispute_id, closed));
+                }
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[weight = T::DbWeight::get().reads_writes(9, 12)]
         fn register_dispute_type_with_resolution_path(
             origin,
             amount_to_lock: BalanceOf<T>,
             dispute_raiser: T::AccountId,
             resolution_metadata: GovernanceOf<T>,
             expiry: Option<T::BlockNumber>,
+            raise_window: Option<(T::BlockNumber, T::BlockNumber)>,
+            treasury_award: Option<VoteOutcome>,
         ) -> DispatchResult {
             let locker = ensure_signed(origin)?;
+            Self::ensure_valid_treasury_award(treasury_award)?;
             // get court org before new dispute state consumes resolution metadata
             let court_org = resolution_metadata.org();
             let new_dispute_id = Self::register_dispute_type(
@@ -178,28 +548,199 @@ decl_module! {
                 dispute_raiser.clone(),
                 resolution_metadata,
                 expiry,
+                raise_window,
             )?;
+            if let Some(outcome) = treasury_award {
+                <TreasuryAwards<T>>::insert(new_dispute_id, outcome);
+            }
             // emit the event with the DisputeId
             Self::deposit_event(RawEvent::RegisteredDisputeWithResolutionPath(new_dispute_id, locker, amount_to_lock, dispute_raiser, court_org));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::DbWeight::get().reads_writes(10, 13)]
+        fn register_bank_dispute_type_with_resolution_path(
+            origin,
+            bank_id: T::BankId,
+            amount_to_lock: BalanceOf<T>,
+            dispute_raiser: T::AccountId,
+            resolution_metadata: GovernanceOf<T>,
+            expiry: Option<T::BlockNumber>,
+            raise_window: Option<(T::BlockNumber, T::BlockNumber)>,
+            treasury_award: Option<VoteOutcome>,
+        ) -> DispatchResult {
+            let controller = ensure_signed(origin)?;
+            Self::ensure_valid_treasury_award(treasury_award)?;
+            let court_org = resolution_metadata.org();
+            let new_dispute_id = Self::register_bank_dispute_type(
+                &controller,
+                bank_id,
+                amount_to_lock,
+                dispute_raiser.clone(),
+                resolution_metadata,
+                expiry,
+                raise_window,
+            )?;
+            if let Some(outcome) = treasury_award {
+                <TreasuryAwards<T>>::insert(new_dispute_id, outcome);
+            }
+            Self::deposit_event(RawEvent::RegisteredBankDisputeWithResolutionPath(new_dispute_id, bank_id, controller, amount_to_lock, dispute_raiser, court_org));
+            Ok(())
+        }
+        /// Registers a dispute resolved by a vote of an org `court_org_creator` is
+        /// expected to form later
+        #[weight = T::DbWeight::get().reads_writes(9, 12)]
+        fn register_dispute_for_future_org(
+            origin,
+            amount_to_lock: BalanceOf<T>,
+            dispute_raiser: T::AccountId,
+            resolution_metadata: GovernanceOf<T>,
+            court_org_creator: T::AccountId,
+            expiry: Option<T::BlockNumber>,
+            raise_window: Option<(T::BlockNumber, T::BlockNumber)>,
+        ) -> DispatchResult {
+            let locker = ensure_signed(origin)?;
+            let court_org = resolution_metadata.org();
+            let new_dispute_id = Self::register_dispute(
+                locker.clone(),
+                amount_to_lock,
+                dispute_raiser.clone(),
+                ResolutionPath::Vote(resolution_metadata),
+                expiry,
+                raise_window,
+                Some(court_org_creator),
+            )?;
+            Self::deposit_event(RawEvent::RegisteredDisputeWithResolutionPath(new_dispute_id, locker, amount_to_lock, dispute_raiser, court_org));
+            Ok(())
+        }
+        /// Registers a dispute resolved by the oracle's attestation instead of a vote
+        #[weight = T::DbWeight::get().reads_writes(9, 10)]
+        fn register_dispute_type_with_oracle(
+            origin,
+            amount_to_lock: BalanceOf<T>,
+            dispute_raiser: T::AccountId,
+            oracle: T::AccountId,
+            expiry: Option<T::BlockNumber>,
+            raise_window: Option<(T::BlockNumber, T::BlockNumber)>,
+        ) -> DispatchResult {
+            let locker = ensure_signed(origin)?;
+            ensure!(oracle != locker, Error::<T>::LockerCannotBeDisputeOracle);
+            let new_dispute_id = Self::register_dispute(
+                locker.clone(),
+                amount_to_lock,
+                dispute_raiser.clone(),
+                ResolutionPath::Oracle(oracle.clone()),
+                expiry,
+                raise_window,
+                None,
+            )?;
+            Self::deposit_event(RawEvent::RegisteredDisputeWithOracle(new_dispute_id, locker, amount_to_lock, dispute_raiser, oracle));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(4, 2)]
+        fn increase_dispute_lock(
+            origin,
+            dispute_id: T::DisputeId,
+            additional: BalanceOf<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotIncreaseLockIfDisputeStateDNE)?;
+            // bank disputes are topped up by the bank's controller from the bank account
+            let authorized = if let Some(bank_id) = <DisputeBanks<T>>::get(dispute_id) {
+                <bank::Module<T>>::banks(bank_id).map(|b| b.is_controller(&caller)).unwrap_or(false)
+            } else {
+                dispute.locker() == caller
+            };
+            ensure!(authorized, Error::<T>::OnlyLockerCanIncreaseDisputeLock);
+            ensure!(dispute.state() == DisputeState::DisputeNotRaised, Error::<T>::CannotIncreaseLockAfterDisputeRaised);
+            let new_locked_funds = Self::checked_lock_total(dispute.locked_funds(), additional)?;
+            <T as Trait>::Currency::reserve(&dispute.locker(), additional)?;
+            <DisputeStates<T>>::insert(dispute_id, dispute.set_locked_funds(new_locked_funds));
+            Self::deposit_event(RawEvent::DisputeLockIncreased(dispute_id, caller, additional, new_locked_funds));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(6, 5)]
+        fn consent_to_dissolve(
+            origin,
+            dispute_id: T::DisputeId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotDissolveIfDisputeStateDNE)?;
+            // once the vote is dispatched, dissolving would let a party evade its outcome
+            ensure!(dispute.state() == DisputeState::DisputeNotRaised, Error::<T>::CannotDissolveAfterDisputeRaised);
+            let (locker, raiser) = (dispute.locker(), dispute.dispute_raiser());
+            // the bank's controller consents on behalf of the bank account
+            let consents_for_locker = if let Some(bank_id) = <DisputeBanks<T>>::get(dispute_id) {
+                <bank::Module<T>>::banks(bank_id).map(|b| b.is_controller(&caller)).unwrap_or(false)
+            } else {
+                caller == locker
+            };
+            let party = if consents_for_locker {
+                locker.clone()
+            } else if caller == raiser {
+                raiser.clone()
+            } else {
+                return Err(Error::<T>::OnlyDisputePartiesCanConsentToDissolve.into())
+            };
+            let mut consents = <DissolutionConsents<T>>::get(dispute_id);
+            ensure!(!consents.contains(&party), Error::<T>::AlreadyConsentedToDissolve);
+            consents.push(party.clone());
+            Self::deposit_event(RawEvent::DissolutionConsentGiven(dispute_id, party));
+            if consents.contains(&locker) && consents.contains(&raiser) {
+                let _ = <T as Trait>::Currency::unreserve(&locker, dispute.locked_funds());
+                <DisputeStates<T>>::insert(dispute_id, dispute.set_state(DisputeState::DissolvedByMutualConsent));
+                Self::record_dispute_transition(dispute_id, DisputeState::DissolvedByMutualConsent);
+                <DissolutionConsents<T>>::remove(dispute_id);
+                Self::deposit_event(RawEvent::DisputeDissolvedByConsent(dispute_id, locker, raiser));
+            } else {
+                <DissolutionConsents<T>>::insert(dispute_id, consents);
+            }
+            Ok(())
+        }
+        /// Dispatches the dispute's vote, or waits on its oracle
+        /// -> `member_count` must be at least the number of the court org's members
+        #[weight = T::DbWeight::get().reads_writes(3 * member_count as u64 + 12, 2 * member_count as u64 + 12)]
         fn raise_dispute_to_trigger_vote(
             origin,
             dispute_id: T::DisputeId,
+            member_count: u32,
         ) -> DispatchResult {
             let trigger = ensure_signed(origin)?;
             let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotRaiseDisputeIfDisputeStateDNE)?;
             // ensure that the signer can trigger this dispute
             ensure!(dispute.can_raise_dispute(&trigger), Error::<T>::SignerNotAuthorizedToRaiseThisDispute);
+            // the locker's liability is bounded to the raise window, if any
+            let now = <frame_system::Module<T>>::block_number();
+            ensure!(dispute.within_raise_window(now), Error::<T>::OutsideDisputeRaiseWindow);
+            // also covers disputes carried over past their expiry
+            ensure!(dispute.expiry().map_or(true, |expiry| now < expiry), Error::<T>::CannotRaiseDisputeAfterExpiry);
+            ensure!(dispute.state() == DisputeState::DisputeNotRaised, Error::<T>::ActiveDisputeCannotBeRaisedFromCurrentState);
+            let metadata = match dispute.resolution_metadata() {
+                ResolutionPath::Vote(metadata) => metadata,
+                // nothing to dispatch, the oracle resolves it directly
+                ResolutionPath::Oracle(oracle) => {
+                    let (locker, amt_locked) = (dispute.locker(), dispute.locked_funds());
+                    <DisputeStates<T>>::insert(dispute_id, dispute.set_state(DisputeState::DisputeRaisedAwaitingOracle));
+                    Self::record_dispute_transition(dispute_id, DisputeState::DisputeRaisedAwaitingOracle);
+                    let deadline = now.saturating_add(T::OracleTimeout::get());
+                    <OracleDeadlines<T>>::mutate(deadline, |ids| ids.push(dispute_id));
+                    Self::deposit_event(RawEvent::DisputeRaisedAwaitingOracle(dispute_id, locker, amt_locked, trigger, oracle));
+                    return Ok(())
+                }
+            };
+            // registration does not require the org to exist yet
+            ensure!(<org::Orgs<T>>::contains_key(metadata.org().org()), Error::<T>::CannotRaiseDisputeBeforeCourtOrgExists);
+            if let Some(creator) = <CourtOrgCreators<T>>::get(dispute_id) {
+                ensure!(<org::OrgCreators<T>>::get(metadata.org().org()) == Some(creator), Error::<T>::CourtOrgNotFormedByExpectedCreator);
+            }
+            <org::Module<T>>::ensure_member_count_witness(metadata.org().org(), member_count)?;
             // check that it is in a valid state to trigger the dispute
             let (new_dispute, dispatched_vote_id) = match dispute.state() {
                 DisputeState::DisputeNotRaised => {
-                    // use vote metadata to dispatch vote
-                    let new_vote_id = match dispute.resolution_metadata() {
-                        VoteMetadata::Signal(v) => <vote::Module<T>>::open_vote(None, v.org, v.threshold, v.duration)?,
-                        VoteMetadata::Percentage(v) => <vote::Module<T>>::open_percent_vote(None, v.org, v.threshold, v.duration)?,
-                    };
+                    let new_vote_id = Self::dispatch_dispute_vote(dispute_id, &metadata)?;
+                    <VoteToDispute<T>>::insert(new_vote_id, dispute_id);
+                    let mut rounds = VoteRoundsOf::<T>::new();
+                    rounds.insert(new_vote_id).map_err(|_| Error::<T>::MaxDisputeVoteRerunsReached)?;
+                    <DisputeVoteRounds<T>>::insert(dispute_id, rounds);
                     // update the state of the dispute with the new vote identifier
                     let updated_dispute = dispute.set_state(DisputeState::DisputeRaisedAndVoteDispatched(new_vote_id));
                     // return tuple
@@ -211,15 +752,16 @@ decl_module! {
             let (locker, amt_locked, court_org) = (
                 new_dispute.locker(),
                 new_dispute.locked_funds(),
-                new_dispute.resolution_metadata().org(),
+                metadata.org(),
             );
             // insert new dispute state
             <DisputeStates<T>>::insert(dispute_id, new_dispute);
+            Self::record_dispute_transition(dispute_id, DisputeState::DisputeRaisedAndVoteDispatched(dispatched_vote_id));
             // emit the event with the VoteId
             Self::deposit_event(RawEvent::DisputeRaisedAndVoteTriggered(dispute_id, locker, amt_locked, trigger, court_org, dispatched_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::DbWeight::get().reads_writes(8, 6)]
         fn poll_dispute_to_execute_outcome(
             origin,
             dispute_id: T::DisputeId,
@@ -233,31 +775,591 @@ decl_module! {
                 DisputeState::DisputeRaisedAndVoteDispatched(live_vote_id) => {
                     // check the vote outcome
                     let outcome = <vote::Module<T>>::get_vote_outcome(live_vote_id)?;
-                    match outcome {
-                        VoteOutcome::Approved => {
-                            // unreserve capital from locker
-                            let _ = T::Currency::unreserve(&dispute.locker(), dispute.locked_funds());
-                            // transfer from locker to dispute_raiser
-                            T::Currency::transfer(&dispute.locker(), &dispute.dispute_raiser(), dispute.locked_funds(), ExistenceRequirement::KeepAlive)?;
-                            // update dispute state
-                            dispute.set_state(DisputeState::DisputeRaisedAndAccepted(live_vote_id))
-                        }
-                        VoteOutcome::Rejected => {
-                            // unreserve capital from locker
-                            let _ = T::Currency::unreserve(&dispute.locker(), dispute.locked_funds());
-                            // update dispute state
-                            dispute.set_state(DisputeState::DisputeRaisedAndRejected(live_vote_id))
+                    if <TreasuryAwards<T>>::get(dispute_id) == Some(outcome) {
+                        let (locker, amount, treasury) = (dispute.locker(), dispute.locked_funds(), <T as Trait>::Treasury::get());
+                        let _ = <T as Trait>::Currency::unreserve(&locker, amount);
+                        <T as Trait>::Currency::transfer(&locker, &treasury, amount, ExistenceRequirement::KeepAlive)?;
+                        <DisputeStates<T>>::insert(dispute_id, dispute.set_state(DisputeState::DisputeRaisedAndAwardedToTreasury(live_vote_id)));
+                        Self::record_dispute_transition(dispute_id, DisputeState::DisputeRaisedAndAwardedToTreasury(live_vote_id));
+                        Self::deposit_event(RawEvent::DisputeAwardedToTreasury(dispute_id, locker, amount, treasury, live_vote_id));
+                        return Ok(())
+                    }
+                    let accepted = match outcome {
+                        VoteOutcome::Approved => true,
+                        // the raise is not upheld if the vote ended undecided
+                        VoteOutcome::Rejected | VoteOutcome::ExpiredInconclusive => false,
+                        // `InsufficientParticipation` waits for turnout like `Voting`
+                        _ => {
+                            // observable even though the error is returned
+                            let blocks_remaining = Self::blocks_until_vote_ends(live_vote_id);
+                            Self::deposit_event(RawEvent::DisputeStillPending(dispute_id, live_vote_id, blocks_remaining));
+                            return Err(Error::<T>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome.into())
                         }
-                        _ => return Err(Error::<T>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome.into()),
+                    };
+                    // unreserve capital from locker
+                    let _ = <T as Trait>::Currency::unreserve(&dispute.locker(), dispute.locked_funds());
+                    if accepted {
+                        // transfer from locker to dispute_raiser
+                        <T as Trait>::Currency::transfer(&dispute.locker(), &dispute.dispute_raiser(), dispute.locked_funds(), ExistenceRequirement::KeepAlive)?;
+                        // update dispute state
+                        dispute.set_state(DisputeState::DisputeRaisedAndAccepted(live_vote_id))
+                    } else {
+                        // update dispute state
+                        dispute.set_state(DisputeState::DisputeRaisedAndRejected(live_vote_id))
                     }
                 }
                 _ => return Err(Error::<T>::ActiveDisputeCannotBePolledFromCurrentState.into()),
             };
             // insert new dispute state
+            let outcome_state = new_dispute_state.state();
             <DisputeStates<T>>::insert(dispute_id, new_dispute_state);
+            Self::record_dispute_transition(dispute_id, outcome_state);
             // emit the event with the outcome
             Ok(())
         }
+        /// Resolves a raised dispute with the oracle's attestation
+        /// -> approval pays the locked funds to the raiser, rejection unlocks them
+        #[weight = T::DbWeight::get().reads_writes(5, 6)]
+        fn oracle_resolve(
+            origin,
+            dispute_id: T::DisputeId,
+            approve: bool,
+            evidence: T::Cid,
+        ) -> DispatchResult {
+            let oracle = ensure_signed(origin)?;
+            let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotOracleResolveIfDisputeStateDNE)?;
+            ensure!(dispute.resolution_metadata().is_oracle(&oracle), Error::<T>::OnlyDisputeOracleCanResolveDispute);
+            ensure!(dispute.state() == DisputeState::DisputeRaisedAwaitingOracle, Error::<T>::DisputeNotAwaitingOracle);
+            let (locker, amount) = (dispute.locker(), dispute.locked_funds());
+            let _ = <T as Trait>::Currency::unreserve(&locker, amount);
+            let outcome_state = if approve {
+                <T as Trait>::Currency::transfer(&locker, &dispute.dispute_raiser(), amount, ExistenceRequirement::KeepAlive)?;
+                DisputeState::DisputeAcceptedByOracle
+            } else {
+                DisputeState::DisputeRejectedByOracle
+            };
+            Self::close_oracle_dispute(dispute_id, dispute, outcome_state);
+            <OracleEvidence<T>>::insert(dispute_id, evidence.clone());
+            Self::deposit_event(RawEvent::DisputeResolvedByOracle(dispute_id, oracle, approve, evidence));
+            Ok(())
+        }
+        /// Re-runs a rejected dispute's vote with the same resolution metadata so members
+        /// who joined the court org since the last round can vote
+        /// -> `member_count` must be at least the number of the court org's members
+        #[weight = T::DbWeight::get().reads_writes(3 * member_count as u64 + 12, 2 * member_count as u64 + 13)]
+        fn rerun_dispute_vote(
+            origin,
+            dispute_id: T::DisputeId,
+            member_count: u32,
+        ) -> DispatchResult {
+            let trigger = ensure_signed(origin)?;
+            let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotRerunDisputeVoteIfDisputeStateDNE)?;
+            ensure!(dispute.can_raise_dispute(&trigger), Error::<T>::SignerNotAuthorizedToRaiseThisDispute);
+            let now = <frame_system::Module<T>>::block_number();
+            ensure!(dispute.within_raise_window(now), Error::<T>::OutsideDisputeRaiseWindow);
+            let (rejected_vote_id, metadata) = match (dispute.state(), dispute.resolution_metadata()) {
+                (DisputeState::DisputeRaisedAndRejected(vote_id), ResolutionPath::Vote(metadata)) => (vote_id, metadata),
+                _ => return Err(Error::<T>::OnlyRejectedDisputeVotesCanBeRerun.into()),
+            };
+            let mut rounds = <DisputeVoteRounds<T>>::get(dispute_id);
+            if rounds.is_empty() {
+                // raised before rounds were recorded
+                rounds.insert(rejected_vote_id).map_err(|_| Error::<T>::MaxDisputeVoteRerunsReached)?;
+            }
+            let reruns = rounds.len() as u32;
+            ensure!(reruns <= T::MaxDisputeVoteReruns::get(), Error::<T>::MaxDisputeVoteRerunsReached);
+            // the rejection released the locked funds so they are locked again for the new round
+            // -> only once the vote is dispatched, since a failed dispatch would not release them
+            ensure!(
+                <T as Trait>::Currency::can_reserve(&dispute.locker(), dispute.locked_funds()),
+                Error::<T>::CannotRelockFundsToRerunDisputeVote
+            );
+            <org::Module<T>>::ensure_member_count_witness(metadata.org().org(), member_count)?;
+            let new_vote_id = Self::dispatch_dispute_vote(dispute_id, &metadata)?;
+            <T as Trait>::Currency::reserve(&dispute.locker(), dispute.locked_funds())?;
+            <VoteToDispute<T>>::insert(new_vote_id, dispute_id);
+            rounds.insert(new_vote_id).map_err(|_| Error::<T>::MaxDisputeVoteRerunsReached)?;
+            <DisputeVoteRounds<T>>::insert(dispute_id, rounds);
+            <DisputeStates<T>>::insert(dispute_id, dispute.set_state(DisputeState::DisputeRaisedAndVoteDispatched(new_vote_id)));
+            Self::record_dispute_transition(dispute_id, DisputeState::DisputeRaisedAndVoteDispatched(new_vote_id));
+            Self::deposit_event(RawEvent::DisputeVoteRerun(dispute_id, new_vote_id, reruns));
+            Ok(())
+        }
+        /// Sets or clears the threshold every vote dispatched for the dispute must meet
+        /// -> only the court org's supervisor, and only before the dispute is raised
+        #[weight = T::DbWeight::get().reads_writes(3, 1)]
+        fn set_dispute_threshold(
+            origin,
+            dispute_id: T::DisputeId,
+            threshold: Option<Threshold<Permill>>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotSetDisputeThresholdIfDisputeStateDNE)?;
+            let metadata = match dispute.resolution_metadata() {
+                ResolutionPath::Vote(metadata) => metadata,
+                ResolutionPath::Oracle(_) => return Err(Error::<T>::DisputeThresholdRequiresVoteResolution.into()),
+            };
+            ensure!(
+                <org::Module<T>>::is_organization_supervisor(metadata.org().org(), &caller),
+                Error::<T>::OnlyCourtOrgSupervisorCanSetDisputeThreshold
+            );
+            // the parties know the bar before either of them raises the dispute
+            ensure!(dispute.state() == DisputeState::DisputeNotRaised, Error::<T>::CannotSetDisputeThresholdAfterDisputeRaised);
+            if let Some(t) = threshold.as_ref() {
+                // a signal threshold can't be compared, so only the majority floor applies
+                let metadata_floor = match &metadata {
+                    VoteMetadata::Percentage(v) => v.threshold.in_favor(),
+                    VoteMetadata::Signal(_) => Permill::zero(),
+                };
+                ensure!(
+                    t.in_favor() > Permill::from_percent(50) && t.in_favor() >= metadata_floor,
+                    Error::<T>::DisputeThresholdBelowFloor
+                );
+            }
+            let is_set = threshold.is_some();
+            if let Some(t) = threshold {
+                <DisputeThresholds<T>>::insert(dispute_id, t);
+            } else {
+                <DisputeThresholds<T>>::remove(dispute_id);
+            }
+            Self::deposit_event(RawEvent::DisputeThresholdSet(dispute_id, caller, is_set));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(3, 2)]
+        fn create_dispute_template(
+            origin,
+            org_id: T::OrgId,
+            template: DisputeTemplateOf<T>,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+            ensure!(
+                <org::Module<T>>::is_organization_supervisor(org_id, &creator),
+                Error::<T>::OnlyOrgSupervisorCanCreateDisputeTemplate
+            );
+            ensure!(
+                template.minimum_amount() >= T::MinimumDisputeAmount::get(),
+                Error::<T>::DisputeTemplateMinimumBelowModuleMinimum
+            );
+            let template_id = <DisputeTemplateIdCounter>::get() + 1;
+            <DisputeTemplateIdCounter>::put(template_id);
+            <DisputeTemplates<T>>::insert(org_id, template_id, template);
+            Self::deposit_event(RawEvent::DisputeTemplateCreated(org_id, template_id));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(10, 11)]
+        fn register_dispute_from_template(
+            origin,
+            org_id: T::OrgId,
+            template_id: u32,
+            amount_to_lock: BalanceOf<T>,
+            dispute_raiser: T::AccountId,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let locker = ensure_signed(origin)?;
+            let template = <DisputeTemplates<T>>::get(org_id, template_id)
+                .ok_or(Error::<T>::DisputeTemplateDNE)?;
+            ensure!(
+                amount_to_lock >= template.minimum_amount(),
+                Error::<T>::DisputeAmountBelowTemplateMinimum
+            );
+            // an explicit expiry overrides the template's default duration
+            let expiry = expiry.or_else(|| {
+                template
+                    .expiry_duration()
+                    .map(|d| <frame_system::Module<T>>::block_number() + d)
+            });
+            let new_dispute_id = Self::register_dispute_type(
+                locker,
+                amount_to_lock,
+                dispute_raiser,
+                template.resolution_metadata(),
+                expiry,
+                None,
+            )?;
+            Self::deposit_event(RawEvent::RegisteredDisputeFromTemplate(new_dispute_id, org_id, template_id));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Locks funds from the bank's account for a new dispute
+    /// -> only the bank's controller may lock bank funds
+    pub fn register_bank_dispute_type(
+        controller: &T::AccountId,
+        bank_id: T::BankId,
+        amount_to_lock: BalanceOf<T>,
+        dispute_raiser: T::AccountId,
+        resolution_path: GovernanceOf<T>,
+        expiry: Option<T::BlockNumber>,
+        raise_window: Option<(T::BlockNumber, T::BlockNumber)>,
+    ) -> Result<T::DisputeId, DispatchError> {
+        let bank = <bank::Module<T>>::banks(bank_id)
+            .ok_or(Error::<T>::CannotRegisterDisputeForBankThatDNE)?;
+        ensure!(
+            bank.is_controller(controller),
+            Error::<T>::OnlyBankControllerCanLockBankFundsForDispute
+        );
+        // the bank account is the locker so resolution pays out of the bank
+        let new_dispute_id = Self::register_dispute_type(
+            <bank::Module<T>>::bank_account_id(bank_id),
+            amount_to_lock,
+            dispute_raiser,
+            resolution_path,
+            expiry,
+            raise_window,
+        )?;
+        <DisputeBanks<T>>::insert(new_dispute_id, bank_id);
+        Ok(new_dispute_id)
+    }
+    /// Locks the funds and stores a dispute resolved along `resolution_path`
+    /// -> only disputes resolved by a vote are indexed by their court org
+    /// -> the court org must exist unless `court_org_creator` is to form it
+    fn register_dispute(
+        locker: T::AccountId,
+        amount_to_lock: BalanceOf<T>,
+        dispute_raiser: T::AccountId,
+        resolution_path: ResolutionOf<T>,
+        expiry: Option<T::BlockNumber>,
+        raise_window: Option<(T::BlockNumber, T::BlockNumber)>,
+        court_org_creator: Option<T::AccountId>,
+    ) -> Result<T::DisputeId, DispatchError> {
+        ensure!(
+            amount_to_lock >= T::MinimumDisputeAmount::get(),
+            Error::<T>::DisputeMustExceedModuleMinimum
+        );
+        if let Some(org) = resolution_path.vote().map(|v| v.org().org()) {
+            ensure!(
+                court_org_creator.is_some()
+                    || <org::Orgs<T>>::contains_key(org),
+                Error::<T>::CannotRegisterDisputeForCourtOrgThatDNE
+            );
+        }
+        if let Some(n) = expiry {
+            ensure!(
+                n > <frame_system::Module<T>>::block_number(),
+                Error::<T>::DisputeExpiryMustBeInTheFuture
+            );
+        }
+        // lock the amount in question
+        <T as Trait>::Currency::reserve(&locker, amount_to_lock)?;
+        // form the dispute state
+        let court_org = resolution_path.vote().map(|v| v.org().org());
+        let new_dispute_state = Dispute::new(
+            locker.clone(),
+            amount_to_lock,
+            dispute_raiser.clone(),
+            resolution_path,
+            DisputeState::DisputeNotRaised,
+            expiry,
+            raise_window,
+        );
+        // generate unique dispute identifier
+        let new_dispute_id = Self::generate_unique_id();
+        // insert the dispute state
+        <DisputeStates<T>>::insert(new_dispute_id, new_dispute_state);
+        if let Some(org) = court_org {
+            <DisputesByOrg<T>>::insert(org, new_dispute_id, ());
+        }
+        if let Some(creator) = court_org_creator {
+            <CourtOrgCreators<T>>::insert(new_dispute_id, creator);
+        }
+        Self::record_dispute_transition(
+            new_dispute_id,
+            DisputeState::DisputeNotRaised,
+        );
+        if let Some(n) = expiry {
+            <DisputeExpirations<T>>::mutate(n, |ids| ids.push(new_dispute_id));
+        }
+        // the locker keeps its role if it also raises the dispute
+        Self::log_dispute_role(
+            &dispute_raiser,
+            new_dispute_id,
+            DisputeRole::Raiser,
+        );
+        Self::log_dispute_role(&locker, new_dispute_id, DisputeRole::Locker);
+        Ok(new_dispute_id)
+    }
+    /// Opens the vote described by the dispute's resolution metadata
+    /// -> signal is minted for the court org's membership at the time of the call
+    /// -> a threshold set for the dispute replaces the metadata's
+    fn dispatch_dispute_vote(
+        dispute_id: T::DisputeId,
+        metadata: &GovernanceOf<T>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let metadata = match <DisputeThresholds<T>>::get(dispute_id) {
+            Some(threshold) => {
+                VoteMetadata::Percentage(VoteCall::new(
+                    metadata.org(),
+                    threshold,
+                    metadata.duration(),
+                ))
+            }
+            None => metadata.clone(),
+        };
+        match metadata {
+            VoteMetadata::Signal(v) => {
+                <vote::Module<T>>::open_vote(
+                    None,
+                    v.org,
+                    v.threshold,
+                    v.duration,
+                )
+            }
+            VoteMetadata::Percentage(v) => {
+                <vote::Module<T>>::open_percent_vote(
+                    None,
+                    v.org,
+                    v.threshold,
+                    v.duration,
+                )
+            }
+        }
+    }
+    /// Blocks until the vote stops accepting votes, zero once it has ended
+    /// -> `None` if the vote has no end
+    fn blocks_until_vote_ends(vote_id: T::VoteId) -> Option<T::BlockNumber> {
+        let ends = <vote::Module<T>>::vote_states(vote_id)?.ends()?;
+        let now = <frame_system::Module<T>>::block_number();
+        Some(ends.saturating_sub(now))
+    }
+    /// Unlocks the funds of a dispute that was never raised
+    /// -> returns the locker and the amount unlocked, `None` if the dispute left `DisputeNotRaised`
+    fn expire_dispute(
+        dispute_id: T::DisputeId,
+    ) -> Option<(T::AccountId, BalanceOf<T>)> {
+        let dispute = <DisputeStates<T>>::get(dispute_id)?;
+        if dispute.state() != DisputeState::DisputeNotRaised {
+            return None
+        }
+        let (locker, locked_funds) = (dispute.locker(), dispute.locked_funds());
+        let _ = <T as Trait>::Currency::unreserve(&locker, locked_funds);
+        <DisputeStates<T>>::insert(
+            dispute_id,
+            dispute.set_state(DisputeState::ExpiredWithoutBeingRaised),
+        );
+        Self::record_dispute_transition(
+            dispute_id,
+            DisputeState::ExpiredWithoutBeingRaised,
+        );
+        <DissolutionConsents<T>>::remove(dispute_id);
+        Some((locker, locked_funds))
+    }
+    /// Registration, the raise and the outcome, plus a dispatch and an
+    /// outcome for every re-run the runtime allows
+    pub fn max_dispute_transitions() -> usize {
+        3 + 2 * T::MaxDisputeVoteReruns::get() as usize
+    }
+    pub fn minimum_dispute_amount() -> BalanceOf<T> {
+        T::MinimumDisputeAmount::get()
+    }
+    pub fn get_dispute_history(
+        dispute_id: T::DisputeId,
+    ) -> Vec<(T::BlockNumber, DisputeState<T::VoteId>)> {
+        <DisputeHistory<T>>::get(dispute_id)
+    }
+    /// The stored dispute with its resolution org, registration block and the
+    /// outcome of its live vote, `None` once it is archived
+    pub fn get_dispute_details(
+        dispute_id: T::DisputeId,
+    ) -> Option<DisputeDetailsOf<T>> {
+        let dispute = <DisputeStates<T>>::get(dispute_id)?;
+        let state = dispute.state();
+        let vote_outcome = match state {
+            DisputeState::DisputeRaisedAndVoteDispatched(vote_id) => {
+                <vote::VoteStates<T>>::get(vote_id).map(|v| v.outcome())
+            }
+            _ => None,
+        };
+        let registered_at = <DisputeHistory<T>>::get(dispute_id)
+            .first()
+            .filter(|(_, s)| *s == DisputeState::DisputeNotRaised)
+            .map(|(block, _)| *block);
+        Some(DisputeDetails::new(
+            dispute_id,
+            dispute.locker(),
+            dispute.dispute_raiser(),
+            dispute.locked_funds(),
+            state,
+            dispute.resolution_metadata().vote().map(|v| v.org().org()),
+            state.vote_id(),
+            vote_outcome,
+            dispute.expiry(),
+            registered_at,
+        ))
+    }
+    /// The dispute the vote was dispatched for, `None` for votes opened
+    /// outside this module
+    pub fn get_dispute_for_vote(vote_id: T::VoteId) -> Option<T::DisputeId> {
+        <VoteToDispute<T>>::get(vote_id)
+    }
+    fn record_dispute_transition(
+        dispute_id: T::DisputeId,
+        state: DisputeState<T::VoteId>,
+    ) {
+        let now = <frame_system::Module<T>>::block_number();
+        debug::native::debug!(
+            target: LOG_TARGET,
+            "dispute {:?} entered {:?} at block {:?}",
+            dispute_id,
+            state,
+            now,
+        );
+        <DisputeHistory<T>>::mutate(dispute_id, |history| {
+            // unreachable unless the transitions above change
+            debug_assert!(history.len() < Self::max_dispute_transitions());
+            if history.len() < Self::max_dispute_transitions() {
+                history.push((now, state));
+            }
+        });
+        if state.is_terminal() {
+            let removal = Self::removal_block(now);
+            <DisputeRemovals<T>>::mutate(removal, |ids| ids.push(dispute_id));
+        }
+    }
+    /// The first block in which a dispute that closed in `closed` may be removed
+    fn removal_block(closed: T::BlockNumber) -> T::BlockNumber {
+        closed
+            .saturating_add(T::DisputeRetentionPeriod::get())
+            .saturating_add(One::one())
+    }
+    /// Replaces a terminal dispute with its summary once its retention period ends
+    /// -> returns the block it closed in, `None` if it is not due for removal
+    fn archive_dispute(
+        dispute_id: T::DisputeId,
+        now: T::BlockNumber,
+    ) -> Option<T::BlockNumber> {
+        let dispute = <DisputeStates<T>>::get(dispute_id)?;
+        let final_state = dispute.state();
+        let (closed, _) = <DisputeHistory<T>>::get(dispute_id).pop()?;
+        // re-run since it was indexed, or closed again later
+        if !final_state.is_terminal() || Self::removal_block(closed) > now {
+            return None
+        }
+        let summary = DisputeSummary::new(
+            dispute.locker(),
+            dispute.dispute_raiser(),
+            dispute.locked_funds(),
+            final_state,
+            closed,
+        );
+        <ArchivedDisputes<T>>::insert(dispute_id, summary);
+        if let ResolutionPath::Vote(metadata) = dispute.resolution_metadata() {
+            <DisputesByOrg<T>>::remove(metadata.org().org(), dispute_id);
+        }
+        <DisputeStates<T>>::remove(dispute_id);
+        <DisputeHistory<T>>::remove(dispute_id);
+        <DisputeVoteRounds<T>>::remove(dispute_id);
+        <DisputeBanks<T>>::remove(dispute_id);
+        <DissolutionConsents<T>>::remove(dispute_id);
+        <TreasuryAwards<T>>::remove(dispute_id);
+        <DisputeThresholds<T>>::remove(dispute_id);
+        <CourtOrgCreators<T>>::remove(dispute_id);
+        debug::native::debug!(
+            target: LOG_TARGET,
+            "dispute {:?} archived, closed at block {:?}",
+            dispute_id,
+            closed,
+        );
+        Some(closed)
+    }
+    fn ensure_valid_treasury_award(
+        treasury_award: Option<VoteOutcome>,
+    ) -> DispatchResult {
+        match treasury_award {
+            None
+            | Some(VoteOutcome::Approved)
+            | Some(VoteOutcome::Rejected) => Ok(()),
+            _ => Err(Error::<T>::TreasuryAwardRequiresConclusiveOutcome.into()),
+        }
+    }
+    /// Every dispute the account was registered in with its role and current state
+    /// -> archived disputes report their final state
+    pub fn get_account_dispute_history(
+        account: T::AccountId,
+    ) -> Vec<(T::DisputeId, DisputeRole, DisputeState<T::VoteId>)> {
+        <DisputesByAccount<T>>::iter_prefix(account)
+            .filter_map(|(id, role)| {
+                <DisputeStates<T>>::get(id)
+                    .map(|d| d.state())
+                    .or_else(|| {
+                        <ArchivedDisputes<T>>::get(id).map(|a| a.final_state())
+                    })
+                    .map(|state| (id, role, state))
+            })
+            .collect()
+    }
+    /// The org's open votes, its disputes that are not terminal and its member count
+    /// -> each list holds at most `MAX_GOVERNANCE_SUMMARY_ENTRIES` ids, in storage order
+    /// -> reads every indexed vote and dispute of the org until a list is full, one
+    /// state read per entry, plus one read per member
+    pub fn org_governance_summary(
+        org: T::OrgId,
+    ) -> GovernanceSummary<T::VoteId, T::DisputeId> {
+        let open_votes = <vote::VotesByOrg<T>>::iter_prefix(org)
+            .map(|(vote_id, _)| vote_id)
+            .filter(|vote_id| {
+                <vote::Module<T>>::vote_status(*vote_id) == VoteStatus::Open
+            })
+            .take(MAX_GOVERNANCE_SUMMARY_ENTRIES)
+            .collect();
+        let active_disputes = <DisputesByOrg<T>>::iter_prefix(org)
+            .map(|(dispute_id, _)| dispute_id)
+            .filter(|dispute_id| {
+                <DisputeStates<T>>::get(*dispute_id)
+                    .map(|d| !d.state().is_terminal())
+                    .unwrap_or(false)
+            })
+            .take(MAX_GOVERNANCE_SUMMARY_ENTRIES)
+            .collect();
+        let member_count = <org::Members<T>>::iter_prefix(org).count() as u32;
+        GovernanceSummary::new(open_votes, active_disputes, member_count)
+    }
+    fn log_dispute_role(
+        account: &T::AccountId,
+        dispute_id: T::DisputeId,
+        role: DisputeRole,
+    ) {
+        if !<DisputesByAccount<T>>::contains_key(account, dispute_id) {
+            <DisputeCount<T>>::mutate(account, |c| *c += 1);
+        }
+        <DisputesByAccount<T>>::insert(account, dispute_id, role);
+    }
+    /// Sums a dispute's locked funds and a top-up
+    /// -> called before reserving since the reserve is not rolled back on error
+    fn checked_lock_total(
+        locked: BalanceOf<T>,
+        additional: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        locked
+            .checked_add(&additional)
+            .ok_or_else(|| Error::<T>::DisputeLockOverflow.into())
+    }
+    /// Rejects an oracle dispute its oracle did not resolve in time
+    /// -> returns the locker and the amount unlocked, `None` if it was resolved
+    fn time_out_oracle_dispute(
+        dispute_id: T::DisputeId,
+    ) -> Option<(T::AccountId, BalanceOf<T>)> {
+        let dispute = <DisputeStates<T>>::get(dispute_id)?;
+        if dispute.state() != DisputeState::DisputeRaisedAwaitingOracle {
+            return None
+        }
+        let (locker, amount) = (dispute.locker(), dispute.locked_funds());
+        let _ = <T as Trait>::Currency::unreserve(&locker, amount);
+        Self::close_oracle_dispute(
+            dispute_id,
+            dispute,
+            DisputeState::DisputeRejectedByOracle,
+        );
+        Some((locker, amount))
+    }
+    fn close_oracle_dispute(
+        dispute_id: T::DisputeId,
+        dispute: DisputeOf<T>,
+        outcome: DisputeState<T::VoteId>,
+    ) {
+        <DisputeStates<T>>::insert(dispute_id, dispute.set_state(outcome));
+        Self::record_dispute_transition(dispute_id, outcome);
     }
 }
 
@@ -293,26 +1395,16 @@ impl<T: Trait>
         dispute_raiser: T::AccountId,
         resolution_path: GovernanceOf<T>,
         expiry: Option<T::BlockNumber>,
+        raise_window: Option<(T::BlockNumber, T::BlockNumber)>,
     ) -> Result<Self::DisputeIdentifier, DispatchError> {
-        ensure!(
-            amount_to_lock >= T::MinimumDisputeAmount::get(),
-            Error::<T>::DisputeMustExceedModuleMinimum
-        );
-        // lock the amount in question
-        T::Currency::reserve(&locker, amount_to_lock)?;
-        // form the dispute state
-        let new_dispute_state = Dispute::new(
+        Self::register_dispute(
             locker,
             amount_to_lock,
             dispute_raiser,
-            resolution_path,
-            DisputeState::DisputeNotRaised,
+            ResolutionPath::Vote(resolution_path),
             expiry,
-        );
-        // generate unique dispute identifier
-        let new_dispute_id = Self::generate_unique_id();
-        // insert the dispute state
-        <DisputeStates<T>>::insert(new_dispute_id, new_dispute_state);
-        Ok(new_dispute_id)
+            raise_window,
+            None,
+        )
     }
 }
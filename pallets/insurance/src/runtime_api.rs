@@ -0,0 +1,50 @@
+//! Runtime APIs for reading disputes, the limits on registering them and an
+//! org's governance summary
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+use util::{
+    governance::GovernanceSummary,
+    insurance::{
+        DisputeDetails,
+        DisputeRole,
+        DisputeState,
+    },
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait DisputeApi<DisputeId, BlockNumber, VoteId, Balance, AccountId, OrgId> where
+        DisputeId: Codec,
+        BlockNumber: Codec,
+        VoteId: Codec,
+        Balance: Codec,
+        AccountId: Codec,
+        OrgId: Codec,
+    {
+        /// Every state the dispute entered, with the block it was entered in
+        fn get_dispute_history(dispute_id: DisputeId) -> Vec<(BlockNumber, DisputeState<VoteId>)>;
+        /// The least a dispute may lock, so clients can check before registering
+        fn minimum_dispute_amount() -> Balance;
+        /// The dispute in one flat response, `None` if it does not exist or was archived
+        fn get_dispute_details(
+            dispute_id: DisputeId,
+        ) -> Option<DisputeDetails<DisputeId, AccountId, Balance, BlockNumber, OrgId, VoteId>>;
+        /// The dispute the vote was dispatched for, kept after the dispute is archived
+        fn get_dispute_for_vote(vote_id: VoteId) -> Option<DisputeId>;
+        /// Every dispute the account was registered in with its role and current
+        /// state, archived disputes report their final state
+        fn get_account_dispute_history(
+            account: AccountId,
+        ) -> Vec<(DisputeId, DisputeRole, DisputeState<VoteId>)>;
+    }
+
+    /// Reads the org's votes, disputes and membership for one landing page
+    pub trait GovernanceApi<OrgId, VoteId, DisputeId> where
+        OrgId: Codec,
+        VoteId: Codec,
+        DisputeId: Codec,
+    {
+        /// Open votes, disputes that are not terminal and the member count
+        /// -> each list is truncated to `MAX_GOVERNANCE_SUMMARY_ENTRIES`
+        fn org_governance_summary(org: OrgId) -> GovernanceSummary<VoteId, DisputeId>;
+    }
+}
@@ -1,26 +1,48 @@
 use super::*;
 use frame_support::{
+    assert_err,
     assert_noop,
     assert_ok,
     impl_outer_event,
     impl_outer_origin,
+    metadata::{
+        DecodeDifferent,
+        DefaultByte,
+    },
     parameter_types,
+    storage::IterableStorageDoubleMap,
+    traits::{
+        OnFinalize,
+        OnInitialize,
+    },
     weights::Weight,
 };
 use frame_system::{self as system,};
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
     traits::IdentityLookup,
+    ModuleId,
     Perbill,
 };
 use util::{
     meta::VoteCall,
     organization::Organization,
+    testing::{
+        TestEnvironmentBuilder,
+        TestRuntime,
+    },
     traits::GroupMembership,
     vote::{
+        CapacityExceeded,
         Threshold,
+        ThresholdInput,
         VoterView,
+        XorThreshold,
     },
 };
 
@@ -42,6 +64,8 @@ impl_outer_event! {
         pallet_balances<T>,
         org<T>,
         vote<T>,
+        donate<T>,
+        bank<T>,
         court<T>,
     }
 }
@@ -99,29 +123,78 @@ impl org::Trait for Test {
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type ShareTransferVote = ();
+    type ShareTransferRequiresVote = ();
+    type ProxyVotingActive = ();
+    type SnapshotRetentionPeriod = ();
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type IndexJustifications = ();
+    type MaxOpenVotesPerOrg = ();
+    type PruneParticipationIndex = ();
+    type MaxDelegatorsPerVote = ();
+    type MaxVotesPerPool = ();
+    type ConvictionLockPeriod = ();
+    type DecayedVotersPerBlock = ();
+    type Currency = Balances;
+    type VoteCreationDeposit = ();
+    type FullRefundTurnout = ();
+    type Treasury = ();
+    type VoteCreationFee = ();
+    type VoteRewardPool = VoteRewardPool;
+    type ConflictCheck = ();
+}
+impl donate::Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+}
+parameter_types! {
+    pub const VoteRewardPool: ModuleId = ModuleId(*b"vote/rwd");
+    pub const BigBank: ModuleId = ModuleId(*b"big/bank");
+    pub const MaxTreasuryPerOrg: u32 = 50;
+    pub const MinDeposit: u64 = 20;
+}
+impl bank::Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+    type BigBank = BigBank;
+    type BankId = u64;
+    type SpendId = u64;
+    type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
+    type MinDeposit = MinDeposit;
+    type ProportionalSpendLifetime = ();
 }
 parameter_types! {
     pub const MinimumDisputeAmount: u64 = 10;
+    pub const CourtTreasury: u64 = 100;
+    pub const MaxDisputeVoteReruns: u32 = 1;
+    pub const DisputeRetentionPeriod: u64 = 10;
+    pub const OracleTimeout: u64 = 5;
+    pub const MaxDisputeExpirationsPerBlock: u32 = 1;
 }
 impl Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
     type DisputeId = u64;
     type MinimumDisputeAmount = MinimumDisputeAmount;
+    type Treasury = CourtTreasury;
+    type MaxDisputeVoteReruns = MaxDisputeVoteReruns;
+    type DisputeRetentionPeriod = DisputeRetentionPeriod;
+    type OracleTimeout = OracleTimeout;
+    type MaxDisputeExpirationsPerBlock = MaxDisputeExpirationsPerBlock;
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
 pub type Org = org::Module<Test>;
 pub type Vote = vote::Module<Test>;
+pub type Bank = bank::Module<Test>;
 pub type Court = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64, u64, u64, u32> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -157,6 +230,83 @@ fn new_test_ext() -> sp_io::TestExternalities {
     ext
 }
 
+impl TestRuntime for Test {
+    type AccountId = u64;
+    type Balance = u64;
+    type OrgId = u64;
+    type BankId = u64;
+    type VoteId = u64;
+    type Voters = OrgRep<u64>;
+    type Threshold = Threshold<u64>;
+    type Direction = VoterView;
+    type Outcome = VoteOutcome;
+
+    fn genesis_storage(accounts: Vec<(u64, u64)>) -> sp_runtime::Storage {
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+        pallet_balances::GenesisConfig::<Test> { balances: accounts }
+            .assimilate_storage(&mut t)
+            .unwrap();
+        t
+    }
+    fn register_org(members: Vec<u64>) -> u64 {
+        let sudo = members[0];
+        assert_ok!(Org::new_flat_org(
+            Origin::signed(sudo),
+            Some(sudo),
+            None,
+            1738,
+            members
+        ));
+        Org::org_id_nonce()
+    }
+    fn open_bank(org_id: u64, balance: u64) -> u64 {
+        let members = org::Members::<Test>::iter_prefix(org_id)
+            .map(|(who, _)| who)
+            .collect::<Vec<u64>>();
+        let opener = *members
+            .iter()
+            .find(|who| Balances::free_balance(*who) > balance)
+            .expect("a member can fund the bank");
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(org_id),
+            XorThreshold::Signal(Threshold::new(
+                members.len() as u64 / 2 + 1,
+                None,
+            )),
+        );
+        assert_ok!(Bank::open(
+            Origin::signed(opener),
+            org_id,
+            balance,
+            None,
+            threshold
+        ));
+        *Bank::get_org_banks(org_id).last().unwrap()
+    }
+    fn open_signal_vote(voters: OrgRep<u64>, threshold: Threshold<u64>) -> u64 {
+        Vote::open_vote_with_min_signal(None, voters, threshold, None, None)
+            .unwrap()
+    }
+    fn cast_vote(vote_id: u64, account: u64, direction: VoterView) {
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(account),
+            vote_id,
+            direction,
+            None,
+            None,
+            0
+        ));
+    }
+    fn vote_outcome(vote_id: u64) -> VoteOutcome {
+        Vote::get_vote_outcome(vote_id).unwrap()
+    }
+    fn advance_blocks(n: u32) {
+        System::set_block_number(System::block_number() + n as u64);
+    }
+}
+
 #[test]
 fn genesis_config_works() {
     new_test_ext().execute_with(|| {
@@ -190,6 +340,8 @@ fn dispute_registration_works() {
                 2,
                 new_resolution_metadata.clone(),
                 None,
+                None,
+                None,
             ),
             Error::<Test>::DisputeMustExceedModuleMinimum
         );
@@ -200,6 +352,8 @@ fn dispute_registration_works() {
                 2,
                 new_resolution_metadata.clone(),
                 None,
+                None,
+                None,
             ),
             DispatchError::Module {
                 index: 0,
@@ -213,6 +367,8 @@ fn dispute_registration_works() {
             2,
             new_resolution_metadata,
             None,
+            None,
+            None,
         ));
         assert_eq!(
             get_last_event(),
@@ -239,7 +395,7 @@ fn dispute_raised_works() {
             None,
         ));
         assert_noop!(
-            Court::raise_dispute_to_trigger_vote(two.clone(), 1),
+            Court::raise_dispute_to_trigger_vote(two.clone(), 1, 6),
             Error::<Test>::CannotRaiseDisputeIfDisputeStateDNE
         );
         assert_ok!(Court::register_dispute_type_with_resolution_path(
@@ -248,12 +404,18 @@ fn dispute_raised_works() {
             2,
             new_resolution_metadata,
             None,
+            None,
+            None,
         ));
         assert_noop!(
-            Court::raise_dispute_to_trigger_vote(one, 1),
+            Court::raise_dispute_to_trigger_vote(one, 1, 6),
             Error::<Test>::SignerNotAuthorizedToRaiseThisDispute
         );
-        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1));
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(two.clone(), 1, 5),
+            org::Error::<Test>::MemberCountWitnessTooLow
+        );
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
         assert_eq!(
             get_last_event(),
             RawEvent::DisputeRaisedAndVoteTriggered(
@@ -285,19 +447,1648 @@ fn poll_dispute_to_execute_outcome_works() {
             2,
             new_resolution_metadata,
             None,
+            None,
+            None,
         ));
         assert_noop!(
             Court::poll_dispute_to_execute_outcome(one.clone(), 1),
             Error::<Test>::ActiveDisputeCannotBePolledFromCurrentState
         );
-        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1));
-        assert_noop!(
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
+        assert_eq!(
             Court::poll_dispute_to_execute_outcome(one.clone(), 1),
-            Error::<Test>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome
+            Err(Error::<Test>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome
+                .into())
         );
         // use vote to pass the proposal
-        assert_ok!(Vote::submit_vote(one.clone(), 1, VoterView::InFavor, None));
+        assert_ok!(Vote::submit_vote(one.clone(), 1, VoterView::InFavor, None, None, 0));
         // then poll again to execute
         assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
     });
 }
+
+#[test]
+fn bank_dispute_locks_and_pays_out_bank_funds() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert_noop!(
+            Court::register_bank_dispute_type_with_resolution_path(
+                one.clone(),
+                1,
+                10,
+                2,
+                resolution_metadata.clone(),
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::CannotRegisterDisputeForBankThatDNE
+        );
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Signal(Threshold::new(1, None)),
+        );
+        assert_ok!(Bank::open(one.clone(), 1, 50, Some(1), threshold));
+        assert_noop!(
+            Court::register_bank_dispute_type_with_resolution_path(
+                Origin::signed(3),
+                1,
+                10,
+                2,
+                resolution_metadata.clone(),
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::OnlyBankControllerCanLockBankFundsForDispute
+        );
+        assert_ok!(Court::register_bank_dispute_type_with_resolution_path(
+            one.clone(),
+            1,
+            10,
+            2,
+            resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::RegisteredBankDisputeWithResolutionPath(
+                1,
+                1,
+                1,
+                10,
+                2,
+                OrgRep::Equal(1)
+            )
+        );
+        let bank_account = Bank::bank_account_id(1);
+        assert_eq!(Court::dispute_banks(1), Some(1));
+        assert_eq!(Court::dispute_states(1).unwrap().locker(), bank_account);
+        assert_eq!(Bank::bank_reserved_balance(1), 10);
+        // reserved funds block closing the bank
+        assert_noop!(
+            Bank::close(one.clone(), 1),
+            bank::Error::<Test>::CannotCloseBankWithReservedFunds
+        );
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            1,
+            6
+        ));
+        assert_ok!(Vote::submit_vote(one.clone(), 1, VoterView::InFavor, None, None, 0));
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        // payout flows from the bank account to the dispute raiser
+        assert_eq!(Bank::bank_reserved_balance(1), 0);
+        assert_eq!(Bank::bank_balance(1), 40);
+        assert_eq!(Balances::free_balance(2), 108);
+    });
+}
+
+#[test]
+fn increase_dispute_lock_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert_noop!(
+            Court::increase_dispute_lock(one.clone(), 1, 5),
+            Error::<Test>::CannotIncreaseLockIfDisputeStateDNE
+        );
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_noop!(
+            Court::increase_dispute_lock(two.clone(), 1, 5),
+            Error::<Test>::OnlyLockerCanIncreaseDisputeLock
+        );
+        assert_ok!(Court::increase_dispute_lock(one.clone(), 1, 5));
+        assert_eq!(get_last_event(), RawEvent::DisputeLockIncreased(1, 1, 5, 15));
+        assert_eq!(Court::dispute_states(1).unwrap().locked_funds(), 15);
+        assert_eq!(Balances::reserved_balance(1), 15);
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
+        assert_noop!(
+            Court::increase_dispute_lock(one, 1, 5),
+            Error::<Test>::CannotIncreaseLockAfterDisputeRaised
+        );
+    });
+}
+
+#[test]
+fn dispute_lock_top_up_near_max_balance_fails_without_wrapping() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        // no account could reserve this much so the state is written directly
+        <DisputeStates<Test>>::insert(
+            1,
+            Dispute::new(
+                1,
+                u64::MAX - 2,
+                2,
+                ResolutionPath::Vote(metadata),
+                DisputeState::DisputeNotRaised,
+                None,
+                None,
+            ),
+        );
+        assert_noop!(
+            Court::increase_dispute_lock(one.clone(), 1, 3),
+            Error::<Test>::DisputeLockOverflow
+        );
+        assert_noop!(
+            Court::increase_dispute_lock(one, 1, u64::MAX),
+            Error::<Test>::DisputeLockOverflow
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().locked_funds(),
+            u64::MAX - 2
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn account_dispute_history_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert!(Court::get_account_dispute_history(1).is_empty());
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata.clone(),
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            two.clone(),
+            10,
+            1,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(Court::dispute_count(1), 2);
+        assert_eq!(Court::dispute_count(2), 2);
+        assert_eq!(Court::dispute_count(3), 0);
+        assert_eq!(Court::disputes_by_account(1, 1), Some(DisputeRole::Locker));
+        assert_eq!(Court::disputes_by_account(1, 2), Some(DisputeRole::Raiser));
+        // history keeps resolved disputes with their latest state
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
+        assert_ok!(Vote::submit_vote(one.clone(), 1, VoterView::InFavor, None, None, 0));
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        let mut history = Court::get_account_dispute_history(2);
+        history.sort_by_key(|(id, _, _)| *id);
+        assert_eq!(
+            history,
+            vec![
+                (
+                    1,
+                    DisputeRole::Raiser,
+                    DisputeState::DisputeRaisedAndAccepted(1)
+                ),
+                (2, DisputeRole::Locker, DisputeState::DisputeNotRaised),
+            ]
+        );
+    });
+}
+
+#[test]
+fn dispute_raise_window_is_enforced() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        for _ in 0..2 {
+            assert_ok!(Court::register_dispute_type_with_resolution_path(
+                one.clone(),
+                10,
+                2,
+                new_resolution_metadata.clone(),
+                None,
+                Some((5, 10)),
+                None,
+            ));
+        }
+        // before the window
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(two.clone(), 1, 6),
+            Error::<Test>::OutsideDisputeRaiseWindow
+        );
+        // during the window
+        System::set_block_number(5);
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two.clone(), 1, 6));
+        // after the window
+        System::set_block_number(11);
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(two, 2, 6),
+            Error::<Test>::OutsideDisputeRaiseWindow
+        );
+    });
+}
+
+#[test]
+fn dispute_dissolves_by_mutual_consent() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert_noop!(
+            Court::consent_to_dissolve(one.clone(), 1),
+            Error::<Test>::CannotDissolveIfDisputeStateDNE
+        );
+        for _ in 0..2 {
+            assert_ok!(Court::register_dispute_type_with_resolution_path(
+                one.clone(),
+                10,
+                2,
+                new_resolution_metadata.clone(),
+                None,
+                None,
+                None,
+            ));
+        }
+        assert_eq!(Balances::reserved_balance(1), 20);
+        assert_noop!(
+            Court::consent_to_dissolve(Origin::signed(3), 1),
+            Error::<Test>::OnlyDisputePartiesCanConsentToDissolve
+        );
+        assert_ok!(Court::consent_to_dissolve(one.clone(), 1));
+        assert_eq!(Court::dissolution_consents(1), vec![1]);
+        assert_noop!(
+            Court::consent_to_dissolve(one.clone(), 1),
+            Error::<Test>::AlreadyConsentedToDissolve
+        );
+        assert_ok!(Court::consent_to_dissolve(two.clone(), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DisputeDissolvedByConsent(1, 1, 2)
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DissolvedByMutualConsent
+        );
+        assert_eq!(Balances::reserved_balance(1), 10);
+        assert!(Court::dissolution_consents(1).is_empty());
+        // a dissolved dispute can't be raised
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(two.clone(), 1, 6),
+            Error::<Test>::ActiveDisputeCannotBeRaisedFromCurrentState
+        );
+        // a raised dispute can't be dissolved
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 2, 6));
+        assert_noop!(
+            Court::consent_to_dissolve(one, 2),
+            Error::<Test>::CannotDissolveAfterDisputeRaised
+        );
+    });
+}
+
+#[test]
+fn dispute_without_quorum_stays_pending() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
+        assert_ok!(Vote::set_vote_quorum(1, Some(3)));
+        assert_ok!(Vote::submit_vote(one.clone(), 1, VoterView::InFavor, None, None, 0));
+        assert_eq!(
+            Vote::get_vote_outcome(1).unwrap(),
+            VoteOutcome::InsufficientParticipation
+        );
+        // a met threshold short of quorum is as inconclusive as no votes
+        assert_eq!(
+            Court::poll_dispute_to_execute_outcome(one.clone(), 1),
+            Err(Error::<Test>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome
+                .into())
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndVoteDispatched(1)
+        );
+        for voter in 2..4 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                1,
+                VoterView::Abstain,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndAccepted(1)
+        );
+    });
+}
+
+#[test]
+fn dispute_threshold_override_requires_a_supermajority() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let ordinary = Threshold::new(1, None);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            ordinary.clone(),
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        let supermajority = Threshold::new(Permill::from_percent(80), None);
+        // overrides may not lower the bar to a minority of the org
+        for floor in [0, 50].iter() {
+            assert_noop!(
+                Court::set_dispute_threshold(
+                    one.clone(),
+                    1,
+                    Some(Threshold::new(Permill::from_percent(*floor), None))
+                ),
+                Error::<Test>::DisputeThresholdBelowFloor
+            );
+        }
+        assert_noop!(
+            Court::set_dispute_threshold(
+                two.clone(),
+                1,
+                Some(supermajority.clone())
+            ),
+            Error::<Test>::OnlyCourtOrgSupervisorCanSetDisputeThreshold
+        );
+        assert_ok!(Court::set_dispute_threshold(
+            one.clone(),
+            1,
+            Some(supermajority.clone())
+        ));
+        assert_eq!(Court::dispute_thresholds(1), Some(supermajority.clone()));
+        // one vote in favor passes an ordinary vote of the org
+        assert_ok!(Vote::create_signal_vote(
+            one.clone(),
+            None,
+            OrgRep::Equal(1),
+            ordinary,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(one.clone(), 1, VoterView::InFavor, None, None, 0));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
+        assert_noop!(
+            Court::set_dispute_threshold(one.clone(), 1, None),
+            Error::<Test>::CannotSetDisputeThresholdAfterDisputeRaised
+        );
+        for voter in 1..4 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                2,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ));
+        }
+        // half the org in favor is short of the dispute's bar
+        assert_eq!(
+            Court::poll_dispute_to_execute_outcome(one.clone(), 1),
+            Err(Error::<Test>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome
+                .into())
+        );
+        for voter in 4..6 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                2,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndAccepted(2)
+        );
+    });
+}
+
+#[test]
+fn dispute_awards_locked_funds_to_treasury() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(6, Some(1)),
+            None,
+        ));
+        assert_noop!(
+            Court::register_dispute_type_with_resolution_path(
+                one.clone(),
+                10,
+                2,
+                new_resolution_metadata.clone(),
+                None,
+                None,
+                Some(VoteOutcome::Voting),
+            ),
+            Error::<Test>::TreasuryAwardRequiresConclusiveOutcome
+        );
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            Some(VoteOutcome::Rejected),
+        ));
+        assert_eq!(Court::treasury_awards(1), Some(VoteOutcome::Rejected));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
+        assert_ok!(Vote::submit_vote(
+            one.clone(),
+            1,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DisputeAwardedToTreasury(1, 1, 10, 100, 1)
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndAwardedToTreasury(1)
+        );
+        // nothing stays reserved and neither party receives the funds
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 90);
+        assert_eq!(Balances::free_balance(2), 98);
+        assert_eq!(Balances::free_balance(100), 10);
+    });
+}
+
+#[test]
+fn unraised_dispute_expires_on_initialize() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert_noop!(
+            Court::register_dispute_type_with_resolution_path(
+                one.clone(),
+                10,
+                2,
+                new_resolution_metadata.clone(),
+                Some(1),
+                None,
+                None,
+            ),
+            Error::<Test>::DisputeExpiryMustBeInTheFuture
+        );
+        for _ in 0..2 {
+            assert_ok!(Court::register_dispute_type_with_resolution_path(
+                one.clone(),
+                10,
+                2,
+                new_resolution_metadata.clone(),
+                Some(5),
+                None,
+                None,
+            ));
+        }
+        assert_eq!(Court::dispute_expirations(5), vec![1, 2]);
+        assert_eq!(Balances::reserved_balance(1), 20);
+        // raised disputes are resolved by their vote instead
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two.clone(), 2, 6));
+        Court::on_initialize(4);
+        assert_eq!(Balances::reserved_balance(1), 20);
+        System::set_block_number(5);
+        Court::on_initialize(5);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DisputeExpiredWithoutBeingRaised(1, 1, 10)
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::ExpiredWithoutBeingRaised
+        );
+        assert_eq!(
+            Court::dispute_states(2).unwrap().state(),
+            DisputeState::DisputeRaisedAndVoteDispatched(1)
+        );
+        assert_eq!(Balances::reserved_balance(1), 10);
+        assert!(Court::dispute_expirations(5).is_empty());
+        // expirations over the per block limit are carried to the next block
+        assert_eq!(Court::dispute_expirations(6), vec![2]);
+        System::set_block_number(6);
+        Court::on_initialize(6);
+        assert!(Court::dispute_expirations(6).is_empty());
+        assert_eq!(
+            Court::dispute_states(2).unwrap().state(),
+            DisputeState::DisputeRaisedAndVoteDispatched(1)
+        );
+        // a dispute can't be raised once its expiry block begins
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            Some(7),
+            None,
+            None,
+        ));
+        System::set_block_number(7);
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(two, 3, 6),
+            Error::<Test>::CannotRaiseDisputeAfterExpiry
+        );
+    });
+}
+
+#[test]
+fn polling_open_dispute_vote_emits_pending_event() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            Some(10),
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
+        let ends = Vote::vote_states(1).unwrap().ends().unwrap();
+        System::set_block_number(4);
+        assert_eq!(
+            Court::poll_dispute_to_execute_outcome(one.clone(), 1),
+            Err(Error::<Test>::VoteOutcomeInconclusiveSoPollCannotExecuteOutcome
+                .into())
+        );
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DisputeStillPending(1, 1, Some(ends - 4))
+        );
+        // the dispute is untouched and can still be executed later
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndVoteDispatched(1)
+        );
+        assert_ok!(Vote::submit_vote(one.clone(), 1, VoterView::InFavor, None, None, 0));
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+    });
+}
+
+#[test]
+fn expired_undecided_dispute_vote_rejects_the_dispute() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            Some(10),
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1, 6));
+        assert_eq!(Balances::reserved_balance(1), 10);
+        let ends = Vote::vote_states(1).unwrap().ends().unwrap();
+        System::set_block_number(ends + 1);
+        assert_eq!(
+            Vote::get_vote_outcome(1),
+            Ok(VoteOutcome::ExpiredInconclusive)
+        );
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndRejected(1)
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn rejected_dispute_vote_reruns_with_current_membership() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(4, Some(3)),
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two.clone(), 1, 6));
+        assert_noop!(
+            Court::rerun_dispute_vote(two.clone(), 1, 6),
+            Error::<Test>::OnlyRejectedDisputeVotesCanBeRerun
+        );
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one.clone(), 1));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        // the court org grows after the first round
+        assert_ok!(Org::issue_shares(one.clone(), 1, 7, 1));
+        assert_noop!(
+            Court::rerun_dispute_vote(one.clone(), 1, 7),
+            Error::<Test>::SignerNotAuthorizedToRaiseThisDispute
+        );
+        assert_noop!(
+            Court::rerun_dispute_vote(two.clone(), 1, 6),
+            org::Error::<Test>::MemberCountWitnessTooLow
+        );
+        assert_ok!(Court::rerun_dispute_vote(two.clone(), 1, 7));
+        assert_eq!(get_last_event(), RawEvent::DisputeVoteRerun(1, 2, 1));
+        assert_eq!(Court::get_dispute_for_vote(2), Some(1));
+        assert_eq!(Court::dispute_vote_rounds(1).to_vec(), vec![1, 2]);
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndVoteDispatched(2)
+        );
+        assert_eq!(Balances::reserved_balance(1), 10);
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 6);
+        assert_eq!(Vote::vote_states(2).unwrap().all_possible_turnout(), 7);
+        // the new member votes in the new round
+        for i in 5u64..8u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndRejected(2)
+        );
+        assert_noop!(
+            Court::rerun_dispute_vote(two, 1, 7),
+            Error::<Test>::MaxDisputeVoteRerunsReached
+        );
+    });
+}
+
+#[test]
+fn failed_rerun_dispatch_leaves_funds_unlocked() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(4, Some(3)),
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            1,
+            6
+        ));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one.clone(), 1));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        // the court org shrinks below the threshold so no vote can be opened
+        for i in 4u64..7u64 {
+            assert_ok!(Org::burn_shares(one.clone(), 1, i, 1));
+        }
+        assert_err!(
+            Court::rerun_dispute_vote(Origin::signed(2), 1, 6),
+            vote::Error::<Test>::InputThresholdExceedsBounds
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndRejected(1)
+        );
+    });
+}
+
+#[test]
+fn terminal_disputes_are_archived_after_retention() {
+    new_test_ext().execute_with(|| {
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            Origin::signed(1),
+            10,
+            2,
+            new_resolution_metadata.clone(),
+            Some(5),
+            None,
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            Origin::signed(1),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        System::set_block_number(5);
+        Court::on_initialize(5);
+        // retained through block 15
+        assert_eq!(Court::dispute_removals(16), vec![1]);
+        Court::on_initialize(15);
+        assert!(Court::dispute_states(1).is_some());
+        System::set_block_number(16);
+        Court::on_initialize(16);
+        assert_eq!(get_last_event(), RawEvent::DisputeArchived(1, 5));
+        assert!(Court::dispute_states(1).is_none());
+        assert!(Court::dispute_history(1).is_empty());
+        assert!(Court::dispute_removals(16).is_empty());
+        let summary = Court::archived_disputes(1).unwrap();
+        assert_eq!(summary.locker(), 1);
+        assert_eq!(summary.dispute_raiser(), 2);
+        assert_eq!(summary.locked_funds(), 10);
+        assert_eq!(
+            summary.final_state(),
+            DisputeState::ExpiredWithoutBeingRaised
+        );
+        assert_eq!(summary.closed(), 5);
+        // open disputes are untouched
+        assert_eq!(
+            Court::dispute_states(2).unwrap().state(),
+            DisputeState::DisputeNotRaised
+        );
+        assert!(Court::archived_disputes(2).is_none());
+        let mut history = Court::get_account_dispute_history(1);
+        history.sort_by_key(|(id, _, _)| *id);
+        assert_eq!(
+            history,
+            vec![
+                (
+                    1,
+                    DisputeRole::Locker,
+                    DisputeState::ExpiredWithoutBeingRaised
+                ),
+                (2, DisputeRole::Locker, DisputeState::DisputeNotRaised),
+            ]
+        );
+    });
+}
+
+#[test]
+fn dispute_encodings_have_a_fixed_maximum_length() {
+    // every field takes its largest variant with each `Option` set
+    let governance: GovernanceOf<Test> = VoteMetadata::Signal(VoteCall::new(
+        OrgRep::Weighted(u64::MAX),
+        Threshold::new(u64::MAX, Some(u64::MAX)),
+        Some(u64::MAX),
+    ));
+    // tag + org + in_favor + against + duration
+    let max_governance_len = 1 + 9 + 8 + 9 + 9;
+    assert_eq!(governance.encode().len(), max_governance_len);
+    let percentage: GovernanceOf<Test> =
+        VoteMetadata::Percentage(VoteCall::new(
+            OrgRep::Weighted(u64::MAX),
+            Threshold::new(Permill::one(), Some(Permill::one())),
+            Some(u64::MAX),
+        ));
+    assert!(percentage.encode().len() < max_governance_len);
+    let dispute: DisputeOf<Test> = Dispute::new(
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        ResolutionPath::Vote(governance),
+        DisputeState::DisputeRaisedAndVoteDispatched(u64::MAX),
+        Some(u64::MAX),
+        Some((u64::MAX, u64::MAX)),
+    );
+    // locker + funds + raiser + resolution + state + expiry + raise window
+    let max_dispute_len = 8 + 8 + 8 + (1 + max_governance_len) + 9 + 9 + 17;
+    assert_eq!(dispute.encode().len(), max_dispute_len);
+    let oracle: DisputeOf<Test> = Dispute::new(
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        ResolutionPath::Oracle(u64::MAX),
+        DisputeState::DisputeRaisedAndVoteDispatched(u64::MAX),
+        Some(u64::MAX),
+        Some((u64::MAX, u64::MAX)),
+    );
+    assert!(oracle.encode().len() < max_dispute_len);
+}
+
+#[test]
+fn vote_rounds_are_bounded_by_reruns() {
+    // one re-run allowed in the mock
+    assert_eq!(VoteRoundsOf::<Test>::capacity(), 2);
+    let mut rounds = VoteRoundsOf::<Test>::new();
+    assert_ok!(rounds.insert(4));
+    assert_ok!(rounds.insert(9));
+    // a repeat is not a new round
+    assert_ok!(rounds.insert(4));
+    assert_eq!(rounds.insert(11), Err(CapacityExceeded));
+    assert_eq!(rounds.len(), 2);
+    assert!(rounds.contains(&9));
+    assert!(!rounds.contains(&11));
+    assert_eq!(rounds.iter().copied().collect::<Vec<u64>>(), vec![4, 9]);
+    // same encoding as the `Vec` stored before
+    assert_eq!(rounds.encode(), vec![4u64, 9].encode());
+    let encoded = rounds.encode();
+    assert_eq!(
+        VoteRoundsOf::<Test>::decode(&mut &encoded[..]).unwrap(),
+        rounds
+    );
+    // over capacity fails before the identifiers are read
+    let too_many = vec![1u64, 2, 3].encode();
+    assert!(VoteRoundsOf::<Test>::decode(&mut &too_many[..]).is_err());
+}
+
+#[test]
+fn disputes_register_from_org_templates() {
+    new_test_ext().execute_with(|| {
+        let resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        let template =
+            DisputeTemplate::new(resolution_metadata.clone(), 15, Some(10));
+        assert_noop!(
+            Court::create_dispute_template(
+                Origin::signed(2),
+                1,
+                template.clone()
+            ),
+            Error::<Test>::OnlyOrgSupervisorCanCreateDisputeTemplate
+        );
+        assert_noop!(
+            Court::create_dispute_template(
+                Origin::signed(1),
+                1,
+                DisputeTemplate::new(resolution_metadata.clone(), 9, None)
+            ),
+            Error::<Test>::DisputeTemplateMinimumBelowModuleMinimum
+        );
+        assert_ok!(Court::create_dispute_template(
+            Origin::signed(1),
+            1,
+            template.clone()
+        ));
+        assert_eq!(get_last_event(), RawEvent::DisputeTemplateCreated(1, 1));
+        assert_eq!(Court::dispute_templates(1, 1), Some(template));
+        assert_noop!(
+            Court::register_dispute_from_template(
+                Origin::signed(3),
+                1,
+                1,
+                14,
+                2,
+                None
+            ),
+            Error::<Test>::DisputeAmountBelowTemplateMinimum
+        );
+        assert_noop!(
+            Court::register_dispute_from_template(
+                Origin::signed(3),
+                1,
+                2,
+                15,
+                2,
+                None
+            ),
+            Error::<Test>::DisputeTemplateDNE
+        );
+        // the template's duration sets the expiry by default
+        assert_ok!(Court::register_dispute_from_template(
+            Origin::signed(3),
+            1,
+            1,
+            15,
+            2,
+            None
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::RegisteredDisputeFromTemplate(1, 1, 1)
+        );
+        let dispute = Court::dispute_states(1).unwrap();
+        assert_eq!(dispute.locker(), 3);
+        assert_eq!(dispute.dispute_raiser(), 2);
+        assert_eq!(dispute.locked_funds(), 15);
+        assert_eq!(dispute.resolution_metadata(), resolution_metadata);
+        assert_eq!(dispute.expiry(), Some(11));
+        assert_eq!(Balances::reserved_balance(3), 15);
+        // an explicit expiry overrides the template
+        assert_ok!(Court::register_dispute_from_template(
+            Origin::signed(4),
+            1,
+            1,
+            20,
+            5,
+            Some(30)
+        ));
+        assert_eq!(Court::dispute_states(2).unwrap().expiry(), Some(30));
+    });
+}
+
+#[test]
+fn dispute_history_records_every_transition() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(4, Some(3)),
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(
+            Court::dispute_history(1),
+            vec![(1, DisputeState::DisputeNotRaised)]
+        );
+        System::set_block_number(2);
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two.clone(), 1, 6));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        System::set_block_number(3);
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one.clone(), 1));
+        System::set_block_number(4);
+        assert_ok!(Court::rerun_dispute_vote(two, 1, 6));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        System::set_block_number(5);
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        let history = Court::get_dispute_history(1);
+        assert_eq!(
+            history,
+            vec![
+                (1, DisputeState::DisputeNotRaised),
+                (2, DisputeState::DisputeRaisedAndVoteDispatched(1)),
+                (3, DisputeState::DisputeRaisedAndRejected(1)),
+                (4, DisputeState::DisputeRaisedAndVoteDispatched(2)),
+                (5, DisputeState::DisputeRaisedAndRejected(2)),
+            ]
+        );
+        // one re-run is allowed so the history is full
+        assert_eq!(history.len(), Court::max_dispute_transitions());
+    });
+}
+
+#[test]
+fn test_environment_seeds_orgs_banks_and_votes() {
+    let mut env = TestEnvironmentBuilder::<Test>::new()
+        .with_accounts(vec![(1, 100), (2, 10), (3, 10), (7, 10)])
+        .with_org(1, vec![1, 2, 3])
+        .with_bank(1, 1, 50)
+        .build();
+    env.execute_with(|| {
+        assert_eq!(System::block_number(), 1);
+        assert!(Org::is_member_of_group(1, &3));
+        assert!(!Org::is_member_of_group(1, &7));
+        assert_eq!(Bank::get_org_banks(1), vec![1]);
+        assert_eq!(Balances::free_balance(1), 50);
+    });
+    let vote_id =
+        env.add_signal_vote(OrgRep::Equal(1), Threshold::new(2, None));
+    env.cast_vote(vote_id, 1, VoterView::InFavor);
+    env.assert_vote_outcome(vote_id, VoteOutcome::Voting);
+    env.cast_vote(vote_id, 2, VoterView::InFavor);
+    env.assert_vote_outcome(vote_id, VoteOutcome::Approved);
+    env.advance_blocks(9);
+    env.execute_with(|| assert_eq!(System::block_number(), 10));
+}
+
+#[test]
+fn dispute_constants_surface_in_metadata() {
+    new_test_ext().execute_with(|| {
+        let constant_value = |name: &'static str| {
+            let constant = Court::module_constants_metadata()
+                .iter()
+                .find(|c| c.name == DecodeDifferent::Encode(name))
+                .unwrap();
+            match &constant.value {
+                DecodeDifferent::Encode(getter) => getter.0.default_byte(),
+                DecodeDifferent::Decoded(value) => value.clone(),
+            }
+        };
+        assert_eq!(constant_value("MinimumDisputeAmount"), 10u64.encode());
+        assert_eq!(constant_value("MaxDisputeVoteReruns"), 1u32.encode());
+        assert_eq!(constant_value("DisputeRetentionPeriod"), 10u64.encode());
+        assert_eq!(constant_value("OracleTimeout"), 5u64.encode());
+        assert_eq!(
+            constant_value("MaxDisputeExpirationsPerBlock"),
+            1u32.encode()
+        );
+        assert_eq!(Court::minimum_dispute_amount(), 10);
+    });
+}
+
+#[test]
+fn org_governance_summary_combines_votes_disputes_and_members() {
+    new_test_ext().execute_with(|| {
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        let summary = Court::org_governance_summary(1);
+        assert!(summary.open_votes().is_empty());
+        assert!(summary.active_disputes().is_empty());
+        assert_eq!(summary.member_count(), 6);
+        // expires unraised at block 5
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            Origin::signed(1),
+            10,
+            2,
+            new_resolution_metadata.clone(),
+            Some(5),
+            None,
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            Origin::signed(1),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        // dispatches vote 1 for the court org
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            2,
+            6
+        ));
+        let vote_id = Vote::open_vote(
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        )
+        .unwrap();
+        assert_eq!(vote_id, 2);
+        assert!(<DisputesByOrg<Test>>::contains_key(1, 1));
+        System::set_block_number(5);
+        Court::on_initialize(5);
+        let summary = Court::org_governance_summary(1);
+        let mut open_votes = summary.open_votes();
+        open_votes.sort();
+        assert_eq!(open_votes, vec![1, 2]);
+        assert_eq!(summary.active_disputes(), vec![2]);
+        assert_eq!(summary.member_count(), 6);
+        // other orgs see none of it
+        let summary = Court::org_governance_summary(2);
+        assert!(summary.open_votes().is_empty());
+        assert!(summary.active_disputes().is_empty());
+        assert_eq!(summary.member_count(), 0);
+        // archiving drops the expired dispute from the index
+        System::set_block_number(16);
+        Court::on_initialize(16);
+        assert!(!<DisputesByOrg<Test>>::contains_key(1, 1));
+        assert!(<DisputesByOrg<Test>>::contains_key(1, 2));
+    });
+}
+
+#[test]
+fn oracle_resolves_disputes_naming_it() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let oracle = Origin::signed(3);
+        assert_ok!(Court::register_dispute_type_with_oracle(
+            one.clone(),
+            10,
+            2,
+            3,
+            None,
+            None,
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::RegisteredDisputeWithOracle(1, 1, 10, 2, 3)
+        );
+        // no court org votes on it
+        assert!(Court::org_governance_summary(1).active_disputes().is_empty());
+        assert_noop!(
+            Court::oracle_resolve(oracle.clone(), 1, true, 7),
+            Error::<Test>::DisputeNotAwaitingOracle
+        );
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two.clone(), 1, 0));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DisputeRaisedAwaitingOracle(1, 1, 10, 2, 3)
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAwaitingOracle
+        );
+        // no vote was dispatched
+        assert!(Vote::vote_states(1).is_none());
+        assert_ok!(Court::oracle_resolve(oracle.clone(), 1, true, 7));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DisputeResolvedByOracle(1, 3, true, 7)
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeAcceptedByOracle
+        );
+        assert_eq!(Court::oracle_evidence(1), Some(7));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 90);
+        assert_eq!(Balances::free_balance(2), 108);
+        // already resolved
+        assert_noop!(
+            Court::oracle_resolve(oracle.clone(), 1, false, 8),
+            Error::<Test>::DisputeNotAwaitingOracle
+        );
+        // rejection unlocks the funds and cannot be re-run as a vote
+        assert_ok!(Court::register_dispute_type_with_oracle(
+            one, 10, 2, 3, None, None
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two.clone(), 2, 0));
+        assert_ok!(Court::oracle_resolve(oracle, 2, false, 8));
+        assert_eq!(
+            Court::dispute_states(2).unwrap().state(),
+            DisputeState::DisputeRejectedByOracle
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 90);
+        assert_noop!(
+            Court::rerun_dispute_vote(two, 2, 0),
+            Error::<Test>::OnlyRejectedDisputeVotesCanBeRerun
+        );
+        // the evidence outlives the archived dispute
+        System::set_block_number(12);
+        Court::on_initialize(12);
+        assert!(Court::dispute_states(1).is_none());
+        assert_eq!(
+            Court::archived_disputes(1).unwrap().final_state(),
+            DisputeState::DisputeAcceptedByOracle
+        );
+        assert_eq!(Court::oracle_evidence(1), Some(7));
+    });
+}
+
+#[test]
+fn only_the_named_oracle_can_resolve_a_dispute() {
+    new_test_ext().execute_with(|| {
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_oracle(
+            Origin::signed(1),
+            10,
+            2,
+            3,
+            None,
+            None,
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            1,
+            0
+        ));
+        // neither party nor any other account may attest
+        for who in [1u64, 2, 4].iter() {
+            assert_noop!(
+                Court::oracle_resolve(Origin::signed(*who), 1, true, 7),
+                Error::<Test>::OnlyDisputeOracleCanResolveDispute
+            );
+        }
+        assert_noop!(
+            Court::oracle_resolve(Origin::signed(3), 2, true, 7),
+            Error::<Test>::CannotOracleResolveIfDisputeStateDNE
+        );
+        // disputes resolved by a vote have no oracle
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            Origin::signed(1),
+            10,
+            2,
+            new_resolution_metadata,
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            2,
+            6
+        ));
+        assert_noop!(
+            Court::oracle_resolve(Origin::signed(3), 2, true, 7),
+            Error::<Test>::OnlyDisputeOracleCanResolveDispute
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAwaitingOracle
+        );
+        assert_eq!(Balances::reserved_balance(1), 20);
+        // the locker cannot attest to its own dispute
+        assert_noop!(
+            Court::register_dispute_type_with_oracle(
+                Origin::signed(1),
+                10,
+                2,
+                1,
+                None,
+                None,
+            ),
+            Error::<Test>::LockerCannotBeDisputeOracle
+        );
+    });
+}
+
+#[test]
+fn unresolved_oracle_disputes_are_rejected_after_the_timeout() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Court::register_dispute_type_with_oracle(
+            Origin::signed(1),
+            10,
+            2,
+            3,
+            None,
+            None,
+        ));
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            1,
+            0
+        ));
+        assert_eq!(Court::oracle_deadlines(6), vec![1]);
+        System::set_block_number(5);
+        Court::on_initialize(5);
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAwaitingOracle
+        );
+        System::set_block_number(6);
+        Court::on_initialize(6);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DisputeRejectedAfterOracleTimeout(1, 1, 10)
+        );
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRejectedByOracle
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+        assert!(Court::oracle_evidence(1).is_none());
+        assert_noop!(
+            Court::oracle_resolve(Origin::signed(3), 1, true, 7),
+            Error::<Test>::DisputeNotAwaitingOracle
+        );
+    });
+}
+
+#[test]
+fn registered_disputes_start_in_the_default_state() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            VoteMetadata::Signal(VoteCall::new(
+                OrgRep::Equal(1),
+                Threshold::new(1, None),
+                None,
+            )),
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_oracle(
+            one, 10, 2, 3, None, None
+        ));
+        for id in 1..3 {
+            assert_eq!(
+                Court::dispute_states(id).unwrap().state(),
+                DisputeState::default()
+            );
+        }
+        assert_eq!(
+            DisputeState::<u64>::default(),
+            DisputeState::DisputeNotRaised
+        );
+    });
+}
+
+#[test]
+fn dispute_details_flatten_the_stored_dispute() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert!(Court::get_dispute_details(1).is_none());
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            VoteMetadata::Signal(VoteCall::new(
+                OrgRep::Equal(1),
+                Threshold::new(1, None),
+                None,
+            )),
+            Some(20),
+            None,
+            None,
+        ));
+        assert_eq!(
+            Court::get_dispute_details(1),
+            Some(DisputeDetails::new(
+                1,
+                1,
+                2,
+                10,
+                DisputeState::DisputeNotRaised,
+                Some(1),
+                None,
+                None,
+                Some(20),
+                Some(1)
+            ))
+        );
+        System::set_block_number(3);
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            1,
+            6
+        ));
+        let details = Court::get_dispute_details(1).unwrap();
+        assert_eq!(details.vote_id(), Some(1));
+        assert_eq!(details.vote_outcome(), Some(VoteOutcome::Voting));
+        assert_eq!(details.registered_at(), Some(1));
+        assert_ok!(Vote::submit_vote(
+            one.clone(),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(
+            Court::get_dispute_details(1).unwrap().vote_outcome(),
+            Some(VoteOutcome::Approved)
+        );
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one.clone(), 1));
+        let details = Court::get_dispute_details(1).unwrap();
+        assert_eq!(details.state(), DisputeState::DisputeRaisedAndAccepted(1));
+        assert_eq!(details.vote_id(), Some(1));
+        // the vote is no longer live
+        assert_eq!(details.vote_outcome(), None);
+        // oracle disputes have no resolution org
+        assert_ok!(Court::register_dispute_type_with_oracle(
+            one, 10, 2, 3, None, None
+        ));
+        let details = Court::get_dispute_details(2).unwrap();
+        assert_eq!(details.resolution_org(), None);
+        assert_eq!(details.registered_at(), Some(3));
+    });
+}
+
+#[test]
+fn disputes_may_name_an_org_formed_after_registration() {
+    new_test_ext().execute_with(|| {
+        let future_org = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(2),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert!(Org::orgs(2).is_none());
+        // an unknown org must be bound to the account expected to form it
+        assert_noop!(
+            Court::register_dispute_type_with_resolution_path(
+                Origin::signed(1),
+                10,
+                2,
+                future_org.clone(),
+                Some(20),
+                None,
+                None,
+            ),
+            Error::<Test>::CannotRegisterDisputeForCourtOrgThatDNE
+        );
+        assert_ok!(Court::register_dispute_for_future_org(
+            Origin::signed(1),
+            10,
+            2,
+            future_org.clone(),
+            3,
+            Some(20),
+            None,
+        ));
+        assert_eq!(Court::court_org_creators(1), Some(3));
+        assert_ok!(Court::register_dispute_for_future_org(
+            Origin::signed(1),
+            10,
+            2,
+            future_org,
+            4,
+            Some(20),
+            None,
+        ));
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(Origin::signed(2), 1, 3),
+            Error::<Test>::CannotRaiseDisputeBeforeCourtOrgExists
+        );
+        // 3 forms the org
+        assert_eq!(Test::register_org(vec![3, 4, 5]), 2);
+        assert_eq!(Org::org_creators(2), Some(3));
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(Origin::signed(2), 2, 3),
+            Error::<Test>::CourtOrgNotFormedByExpectedCreator
+        );
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            1,
+            3
+        ));
+        assert_eq!(
+            Court::dispute_states(1).unwrap().state(),
+            DisputeState::DisputeRaisedAndVoteDispatched(1)
+        );
+        assert!(Vote::vote_logger(1, 3).is_some());
+        assert!(Vote::vote_logger(1, 1).is_none());
+    });
+}
+
+#[test]
+fn dispute_votes_are_traced_back_to_their_dispute() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            VoteMetadata::Signal(VoteCall::new(
+                OrgRep::Equal(1),
+                Threshold::new(1, None),
+                None,
+            )),
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(Court::get_dispute_for_vote(1), None);
+        assert_ok!(Court::raise_dispute_to_trigger_vote(
+            Origin::signed(2),
+            1,
+            6
+        ));
+        assert_eq!(Court::get_dispute_for_vote(1), Some(1));
+        assert_ok!(Vote::submit_vote(
+            one.clone(),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_ok!(Court::poll_dispute_to_execute_outcome(one, 1));
+        // kept after the dispute is archived
+        System::set_block_number(12);
+        Court::on_initialize(12);
+        assert!(Court::dispute_states(1).is_none());
+        assert_eq!(Court::get_dispute_for_vote(1), Some(1));
+        assert_eq!(Court::get_dispute_for_vote(2), None);
+    });
+}
@@ -110,18 +110,37 @@ impl org::Trait for Test {
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type ShareTransferVote = ();
+    type ShareTransferRequiresVote = ();
+    type ProxyVotingActive = ();
+    type SnapshotRetentionPeriod = ();
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type IndexJustifications = ();
+    type MaxOpenVotesPerOrg = ();
+    type PruneParticipationIndex = ();
+    type MaxDelegatorsPerVote = ();
+    type MaxVotesPerPool = ();
+    type ConvictionLockPeriod = ();
+    type DecayedVotersPerBlock = ();
+    type Currency = Balances;
+    type VoteCreationDeposit = ();
+    type FullRefundTurnout = ();
+    type Treasury = ();
+    type VoteCreationFee = ();
+    type VoteRewardPool = VoteRewardPool;
+    type ConflictCheck = ();
 }
 impl donate::Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
 }
 parameter_types! {
+    pub const VoteRewardPool: ModuleId = ModuleId(*b"vote/rwd");
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
     pub const MinDeposit: u64 = 20;
 }
@@ -381,7 +400,9 @@ fn spend_governance_works() {
                 i_origin,
                 1,
                 VoterView::InFavor,
-                None
+                None,
+                None,
+                0
             ));
         }
         assert_eq!(Balances::total_balance(&7), 87);
@@ -407,7 +428,9 @@ fn member_governance_works() {
                 i_origin,
                 1,
                 VoterView::InFavor,
-                None
+                None,
+                None,
+                0
             ));
         }
         assert_eq!(Balances::total_balance(&7), 77);
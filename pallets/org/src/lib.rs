@@ -11,6 +11,51 @@
 //! member (`AccountId`) in an org has some quantity of `Shares` in proportion
 //! to their relative ownership.
 //!
+//! ## Member Count Witnesses
+//!
+//! Calls which open a vote among the org's members or snapshot them take the
+//! org's member count, which weighs the call. They fail with
+//! `MemberCountWitnessTooLow` before reading the membership if the org has more
+//! members.
+//!
+//! ## Voting Proxies
+//!
+//! If `ProxyVotingActive` is set, a share holder can name a proxy with
+//! `set_voting_proxy`. Share weighted votes opened for the org credit the holder's
+//! signal to the proxy and leave the holder with none. Delegation is one hop: the
+//! signal a proxy receives stays with it even if the proxy names a proxy of its own.
+//!
+//! ## Seniority
+//!
+//! The block in which each account became a member is kept in `MemberSince` until
+//! it leaves the org. The org's sudo may store a `SeniorityConfig` with
+//! `set_seniority_config`, which `vote` uses to raise the signal of long standing
+//! members in seniority weighted votes. Members admitted before `MemberSince` was
+//! tracked have no entry and receive no bonus.
+//!
+//! ## Snapshots
+//!
+//! `take_org_snapshot` stores the org's share weighted membership under a new
+//! snapshot id. The org's supervisor takes one with `snapshot_org`. A vote opened
+//! from a snapshot is unaffected by shares issued, burned or transferred after the
+//! snapshot was taken. Snapshots older than
+//! `SnapshotRetentionPeriod` blocks can be removed by any account with
+//! `prune_old_snapshots`, which takes the org's snapshot count as a witness.
+//!
+//! ## Paginated Membership
+//!
+//! `list_org_members_page` returns up to `limit` of an org's members with their
+//! shares, after the member given as a cursor, along with the cursor for the
+//! next page. Clients of large orgs call it repeatedly through `OrgApi` rather than
+//! reading every member at once.
+//!
+//! ## Share Locks
+//!
+//! `lock_shares_until` keeps an account from burning or transferring its shares
+//! below the locked amount until the lock's block, i.e. while `vote` counts them
+//! with conviction. A new lock on the same account keeps the larger amount and
+//! the later block of the two.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -18,6 +63,8 @@
 #[cfg(test)]
 mod tests;
 
+pub mod runtime_api;
+
 use frame_support::{
     decl_error,
     decl_event,
@@ -25,6 +72,7 @@ use frame_support::{
     decl_storage,
     ensure,
     storage::IterableStorageDoubleMap,
+    traits::Get,
     Parameter,
 };
 use frame_system::{
@@ -41,6 +89,7 @@ use sp_runtime::{
         CheckedSub,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
@@ -52,19 +101,28 @@ use sp_std::{
     prelude::*,
 };
 use util::{
+    governance::{
+        GovernanceAction,
+        GovernanceExecutor,
+        GovernanceProposal,
+    },
     organization::{
+        OrgSnapshot,
         Organization,
         OrganizationSource,
         Relation,
+        SeniorityConfig,
     },
     share::{
         ProfileState,
         SharePortion,
         ShareProfile,
+        ShareTransferProposal,
         WeightedVector,
     },
     traits::{
         AccessGenesis,
+        DirectVote,
         GenerateUniqueID,
         GetGroup,
         GroupMembership,
@@ -76,6 +134,11 @@ use util::{
         ShareInformation,
         ShareIssuance,
         VerifyShape,
+        VoteOutcomeHook,
+    },
+    vote::{
+        Threshold,
+        VoteOutcome,
     },
 };
 
@@ -90,6 +153,25 @@ type Profile<T> = ShareProfile<
     <T as Trait>::Shares,
     ProfileState,
 >;
+type TransferProp<T> = ShareTransferProposal<
+    <T as Trait>::OrgId,
+    <T as System>::AccountId,
+    <T as Trait>::Shares,
+>;
+type TransferVoteId<T> = <<T as Trait>::ShareTransferVote as DirectVote<
+    <T as System>::AccountId,
+    <T as Trait>::Shares,
+>>::VoteId;
+type GovAction<T> = GovernanceAction<
+    <T as System>::AccountId,
+    <T as Trait>::Shares,
+    <T as Trait>::Cid,
+>;
+type GovProp<T> = GovernanceProposal<GovAction<T>, TransferVoteId<T>>;
+type Snapshot<T> = OrgSnapshot<
+    WeightedVector<<T as System>::AccountId, <T as Trait>::Shares>,
+    <T as System>::BlockNumber,
+>;
 
 pub trait Trait: System {
     /// Overarching event type
@@ -124,6 +206,19 @@ pub trait Trait: System {
         + CheckedSub
         + Zero
         + AtLeast32BitUnsigned;
+
+    /// Votes among shareholders to approve share transfers and issuance cap increases
+    type ShareTransferVote: DirectVote<Self::AccountId, Self::Shares>;
+
+    /// If false, shares transfer without a vote (i.e. open membership orgs)
+    type ShareTransferRequiresVote: Get<bool>;
+
+    /// If false, voting proxies can't be set and votes mint signal to every
+    /// share holder directly
+    type ProxyVotingActive: Get<bool>;
+
+    /// Blocks a membership snapshot is kept before it may be pruned
+    type SnapshotRetentionPeriod: Get<Self::BlockNumber>;
 }
 
 decl_event!(
@@ -133,6 +228,7 @@ decl_event!(
         OrgId = <T as Trait>::OrgId,
         <T as Trait>::Shares,
         <T as Trait>::Cid,
+        <T as System>::BlockNumber,
     {
         /// No shares issued but an organization was registered with flat membership with the last `u32` as the number of members
         NewFlatOrg(AccountId, OrgId, Cid, u32),
@@ -156,6 +252,32 @@ decl_event!(
         SharesBatchBurned(OrgId, Shares),
         /// Organization ID Removed
         OrgRemoved(OrgId),
+        /// Proposal ID, Organization ID, Sender, Recipient, Amount
+        ShareTransferProposed(u32, OrgId, AccountId, AccountId, Shares),
+        /// Organization ID, Sender, Recipient, Amount
+        SharesTransferred(OrgId, AccountId, AccountId, Shares),
+        /// Proposal ID, Organization ID
+        ShareTransferRejected(u32, OrgId),
+        /// Organization ID, Issuance Cap
+        IssuanceCapSet(OrgId, Shares),
+        /// Organization ID, Proposed Issuance Cap
+        IssuanceCapIncreaseProposed(OrgId, Shares),
+        /// Organization ID, Rejected Issuance Cap
+        IssuanceCapIncreaseRejected(OrgId, Shares),
+        /// Proposal ID, Organization ID, Proposer
+        GovernanceActionProposed(u32, OrgId, AccountId),
+        /// Proposal ID, Organization ID
+        GovernanceActionExecuted(u32, OrgId),
+        /// Proposal ID, Organization ID
+        GovernanceActionRejected(u32, OrgId),
+        /// Organization ID, Share Holder, Proxy (None if cleared)
+        VotingProxySet(OrgId, AccountId, Option<AccountId>),
+        /// Organization ID, Seniority Config (None if cleared)
+        SeniorityConfigSet(OrgId, Option<SeniorityConfig<BlockNumber>>),
+        /// Organization ID, Snapshot ID
+        OrgSnapshotTaken(OrgId, u32),
+        /// Organization ID, Number of Snapshots Pruned
+        OrgSnapshotsPruned(OrgId, u32),
     }
 );
 
@@ -163,6 +285,7 @@ decl_error! {
     pub enum Error for Module<T: Trait> {
         OrgDNE,
         ProfileDNE,
+        CannotSnapshotOrgThatDNE,
         NotAuthorizedForAccount,
         CannotBurnMoreThanTotalShares,
         NotEnoughSharesToSatisfyBurnRequest,
@@ -174,6 +297,25 @@ decl_error! {
         CannotUnLockIfAlreadyUnLocked,
         OrganizationCannotBeRemovedIfInputIdIsAvailable,
         AccountHasNoOwnershipInOrg,
+        NotEnoughSharesToProposeTransfer,
+        ShareTransferProposalDNE,
+        ShareTransferVoteInconclusive,
+        ShareIssuanceCapExceeded,
+        IssuanceCapBelowOutstandingShares,
+        IssuanceCapAlreadySetSoIncreaseRequiresVote,
+        IssuanceCapCanOnlyIncrease,
+        IssuanceCapIncreaseAlreadyPending,
+        NoPendingIssuanceCapIncrease,
+        IssuanceCapVoteInconclusive,
+        GovernanceActionNotSupportedByOrg,
+        CannotAdmitExistingMember,
+        GovernanceProposalDNE,
+        GovernanceVoteInconclusive,
+        ProxyVotingNotActive,
+        CannotSetSelfAsVotingProxy,
+        SharesLockedUntilVoteEnds,
+        MemberCountWitnessTooLow,
+        SnapshotCountWitnessTooLow,
     }
 }
 
@@ -189,6 +331,11 @@ decl_storage! {
         pub Orgs get(fn orgs): map
             hasher(blake2_128_concat) T::OrgId => Option<Org<T>>;
 
+        /// The account which formed each org with a create extrinsic
+        /// -> orgs formed by other modules have no creator
+        pub OrgCreators get(fn org_creators): map
+            hasher(blake2_128_concat) T::OrgId => Option<T::AccountId>;
+
         /// Hierarchical relationships between orgs
         pub OrgTree get(fn org_tree): double_map
             hasher(blake2_128_concat) T::OrgId,
@@ -198,6 +345,62 @@ decl_storage! {
         pub Members get(fn members): double_map
             hasher(blake2_128_concat) T::OrgId,
             hasher(blake2_128_concat) T::AccountId => Option<Profile<T>>;
+
+        /// Nonce for share transfer proposal identifiers
+        ShareTransferNonce get(fn share_transfer_nonce): u32;
+
+        /// Share transfers pending approval by the org's shareholders
+        pub ShareTransferProposals get(fn share_transfer_proposals): map
+            hasher(blake2_128_concat) u32 => Option<TransferProp<T>>;
+
+        /// The vote dispatched for each pending share transfer
+        pub TransferProposalVotes get(fn transfer_proposal_votes): map
+            hasher(blake2_128_concat) u32 => Option<TransferVoteId<T>>;
+
+        /// Upper bound on total shares for the org, no bound if None
+        pub ShareIssuanceCap get(fn share_issuance_cap): map
+            hasher(blake2_128_concat) T::OrgId => Option<T::Shares>;
+
+        /// Pending increase of the org's issuance cap and the vote to approve it
+        pub IssuanceCapVotes get(fn issuance_cap_votes): map
+            hasher(blake2_128_concat) T::OrgId => Option<(T::Shares, TransferVoteId<T>)>;
+
+        /// Nonce for governance proposal identifiers
+        GovernanceProposalNonce get(fn governance_proposal_nonce): u32;
+
+        /// Membership and configuration changes pending approval by the org's shareholders
+        pub GovernanceProposals get(fn governance_proposals): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) u32 => Option<GovProp<T>>;
+
+        /// The account credited with a share holder's signal in the org's share
+        /// weighted votes
+        pub VotingProxies get(fn voting_proxies): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::AccountId>;
+
+        /// The block in which the account last became a member of the org
+        pub MemberSince get(fn member_since): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
+
+        /// How membership duration weighs signal in the org's seniority weighted votes
+        pub SeniorityConfigs get(fn seniority_configs): map
+            hasher(blake2_128_concat) T::OrgId => Option<SeniorityConfig<T::BlockNumber>>;
+
+        /// The nonce for each org's snapshot ids
+        SnapshotIdNonce get(fn snapshot_id_nonce): map
+            hasher(blake2_128_concat) T::OrgId => u32;
+
+        /// Point in time captures of the org's share weighted membership
+        pub OrgSnapshots get(fn org_snapshots): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) u32 => Option<Snapshot<T>>;
+
+        /// Shares the account cannot burn or transfer before the block
+        pub ShareLocks get(fn share_locks): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<(T::Shares, T::BlockNumber)>;
     }
     add_extra_genesis {
         config(sudo): T::AccountId;
@@ -238,6 +441,7 @@ decl_module! {
             } else {
                 Self::register_organization(OrganizationSource::Accounts(m), sudo, constitution.clone())?
             };
+            <OrgCreators<T>>::insert(new_id, &caller);
             Self::deposit_event(RawEvent::NewFlatOrg(caller, new_id, constitution, total));
             Ok(())
         }
@@ -257,6 +461,7 @@ decl_module! {
             } else {
                 Self::register_organization(OrganizationSource::AccountsWeighted(weighted_members), sudo, constitution.clone())?
             };
+            <OrgCreators<T>>::insert(new_id, &caller);
             Self::deposit_event(RawEvent::NewWeightedOrg(caller, new_id, constitution, wm_cpy.total()));
             Ok(())
         }
@@ -343,10 +548,299 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesUnlocked(organization, who));
             Ok(())
         }
+        /// -> `member_count` must be at least the number of the org's members
+        #[weight = T::DbWeight::get().reads_writes(3 * member_count as u64 + 6, 2 * member_count as u64 + 8)]
+        fn propose_share_transfer(origin, organization: T::OrgId, to: T::AccountId, amount: T::Shares, member_count: u32) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            let profile = <Members<T>>::get(organization, &from).ok_or(Error::<T>::AccountHasNoOwnershipInOrg)?;
+            ensure!(profile.total() >= amount, Error::<T>::NotEnoughSharesToProposeTransfer);
+            if T::ShareTransferRequiresVote::get() {
+                Self::ensure_member_count_witness(organization, member_count)?;
+                let proposal_id = Self::propose_transfer(organization, from.clone(), to.clone(), amount)?;
+                Self::deposit_event(RawEvent::ShareTransferProposed(proposal_id, organization, from, to, amount));
+            } else {
+                Self::transfer_shares(organization, from.clone(), to.clone(), amount)?;
+                Self::deposit_event(RawEvent::SharesTransferred(organization, from, to, amount));
+            }
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(8, 8)]
+        fn poll_share_transfer(origin, proposal_id: u32) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let proposal = <ShareTransferProposals<T>>::get(proposal_id).ok_or(Error::<T>::ShareTransferProposalDNE)?;
+            if Self::on_vote_outcome(proposal_id)? == VoteOutcome::Approved {
+                Self::deposit_event(RawEvent::SharesTransferred(proposal.org(), proposal.from(), proposal.to(), proposal.amount()));
+            } else {
+                Self::deposit_event(RawEvent::ShareTransferRejected(proposal_id, proposal.org()));
+            }
+            Ok(())
+        }
+        /// Sets the first issuance cap for the org, increases require a vote
+        #[weight = T::DbWeight::get().reads_writes(2, 1)]
+        fn set_share_issuance_cap(origin, organization: T::OrgId, cap: T::Shares) -> DispatchResult {
+            let setter = ensure_signed(origin)?;
+            let org = <Orgs<T>>::get(organization).ok_or(Error::<T>::OrgDNE)?;
+            ensure!(org.is_sudo(&setter), Error::<T>::NotAuthorizedForAccount);
+            ensure!(<ShareIssuanceCap<T>>::get(organization).is_none(), Error::<T>::IssuanceCapAlreadySetSoIncreaseRequiresVote);
+            ensure!(cap >= org.total_shares(), Error::<T>::IssuanceCapBelowOutstandingShares);
+            <ShareIssuanceCap<T>>::insert(organization, cap);
+            Self::deposit_event(RawEvent::IssuanceCapSet(organization, cap));
+            Ok(())
+        }
+        /// Dispatches a supermajority vote among shareholders to raise the cap
+        /// -> `member_count` must be at least the number of the org's members
+        #[weight = T::DbWeight::get().reads_writes(3 * member_count as u64 + 6, 2 * member_count as u64 + 6)]
+        fn propose_issuance_cap_increase(origin, organization: T::OrgId, new_cap: T::Shares, member_count: u32) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &proposer), Error::<T>::NotAuthorizedForAccount);
+            // the cap is never decreased so existing holders cannot be locked out of issuance
+            let cap = <ShareIssuanceCap<T>>::get(organization).ok_or(Error::<T>::IssuanceCapCanOnlyIncrease)?;
+            ensure!(new_cap > cap, Error::<T>::IssuanceCapCanOnlyIncrease);
+            ensure!(<IssuanceCapVotes<T>>::get(organization).is_none(), Error::<T>::IssuanceCapIncreaseAlreadyPending);
+            Self::ensure_member_count_witness(organization, member_count)?;
+            let shareholders = Self::get_membership_with_shape(organization).ok_or(Error::<T>::OrgDNE)?;
+            // at least 2/3 of outstanding shares to approve, more than 1/3 to block
+            let total = shareholders.total();
+            let third = total / 3u32.into();
+            let vote_id = T::ShareTransferVote::open_direct_vote(
+                shareholders,
+                Threshold::new(total - third, Some(third + 1u32.into())),
+            )?;
+            <IssuanceCapVotes<T>>::insert(organization, (new_cap, vote_id));
+            Self::deposit_event(RawEvent::IssuanceCapIncreaseProposed(organization, new_cap));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(3, 2)]
+        fn poll_issuance_cap_increase(origin, organization: T::OrgId) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let (new_cap, vote_id) = <IssuanceCapVotes<T>>::get(organization).ok_or(Error::<T>::NoPendingIssuanceCapIncrease)?;
+            match T::ShareTransferVote::direct_vote_outcome(vote_id)? {
+                VoteOutcome::Approved => {
+                    <ShareIssuanceCap<T>>::insert(organization, new_cap);
+                    Self::deposit_event(RawEvent::IssuanceCapSet(organization, new_cap));
+                }
+                VoteOutcome::Rejected => {
+                    Self::deposit_event(RawEvent::IssuanceCapIncreaseRejected(organization, new_cap));
+                }
+                _ => return Err(Error::<T>::IssuanceCapVoteInconclusive.into()),
+            }
+            <IssuanceCapVotes<T>>::remove(organization);
+            Ok(())
+        }
+        /// Dispatches a majority vote among shareholders to approve the action
+        /// -> `member_count` must be at least the number of the org's members
+        #[weight = T::DbWeight::get().reads_writes(3 * member_count as u64 + 5, 2 * member_count as u64 + 7)]
+        fn propose_governance_action(origin, organization: T::OrgId, action: GovAction<T>, member_count: u32) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &proposer), Error::<T>::NotAuthorizedForAccount);
+            // orgs do not hold capital so spends are proposed to a bank instead
+            if let GovernanceAction::Transfer { .. } = action {
+                return Err(Error::<T>::GovernanceActionNotSupportedByOrg.into())
+            }
+            Self::ensure_member_count_witness(organization, member_count)?;
+            let vote_id = Self::open_majority_vote(organization)?;
+            let proposal_id = <GovernanceProposalNonce>::get() + 1u32;
+            <GovernanceProposalNonce>::put(proposal_id);
+            <GovernanceProposals<T>>::insert(organization, proposal_id, GovernanceProposal::new(action, vote_id));
+            Self::deposit_event(RawEvent::GovernanceActionProposed(proposal_id, organization, proposer));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(8, 7)]
+        fn poll_governance_proposal(origin, organization: T::OrgId, proposal_id: u32) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let proposal = <GovernanceProposals<T>>::get(organization, proposal_id).ok_or(Error::<T>::GovernanceProposalDNE)?;
+            match T::ShareTransferVote::direct_vote_outcome(proposal.vote_id())? {
+                VoteOutcome::Approved => {
+                    Self::execute(organization, proposal.action())?;
+                    Self::deposit_event(RawEvent::GovernanceActionExecuted(proposal_id, organization));
+                }
+                VoteOutcome::Rejected => {
+                    Self::deposit_event(RawEvent::GovernanceActionRejected(proposal_id, organization));
+                }
+                _ => return Err(Error::<T>::GovernanceVoteInconclusive.into()),
+            }
+            <GovernanceProposals<T>>::remove(organization, proposal_id);
+            Ok(())
+        }
+        /// Sets or clears the account that votes with the caller's shares
+        #[weight = T::DbWeight::get().reads_writes(1, 1)]
+        fn set_voting_proxy(origin, organization: T::OrgId, proxy: Option<T::AccountId>) -> DispatchResult {
+            let holder = ensure_signed(origin)?;
+            ensure!(T::ProxyVotingActive::get(), Error::<T>::ProxyVotingNotActive);
+            ensure!(<Members<T>>::contains_key(organization, &holder), Error::<T>::AccountHasNoOwnershipInOrg);
+            if let Some(p) = &proxy {
+                ensure!(*p != holder, Error::<T>::CannotSetSelfAsVotingProxy);
+                <VotingProxies<T>>::insert(organization, &holder, p);
+            } else {
+                <VotingProxies<T>>::remove(organization, &holder);
+            }
+            Self::deposit_event(RawEvent::VotingProxySet(organization, holder, proxy));
+            Ok(())
+        }
+        /// Sets or clears the org's seniority config, only the org sudo may call this
+        #[weight = T::DbWeight::get().reads_writes(1, 1)]
+        fn set_seniority_config(origin, organization: T::OrgId, config: Option<SeniorityConfig<T::BlockNumber>>) -> DispatchResult {
+            let setter = ensure_signed(origin)?;
+            let org = <Orgs<T>>::get(organization).ok_or(Error::<T>::OrgDNE)?;
+            ensure!(org.is_sudo(&setter), Error::<T>::NotAuthorizedForAccount);
+            if let Some(c) = config {
+                <SeniorityConfigs<T>>::insert(organization, c);
+            } else {
+                <SeniorityConfigs<T>>::remove(organization);
+            }
+            Self::deposit_event(RawEvent::SeniorityConfigSet(organization, config));
+            Ok(())
+        }
+        /// Snapshots the org's current share weighted membership, only the org's
+        /// supervisor may call this
+        /// -> `member_count` must be at least the number of the org's members
+        #[weight = T::DbWeight::get().reads_writes(2 * member_count as u64 + 4, 2)]
+        fn snapshot_org(origin, organization: T::OrgId, member_count: u32) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrgDNE);
+            ensure!(Self::is_organization_supervisor(organization, &caller), Error::<T>::NotAuthorizedForAccount);
+            Self::ensure_member_count_witness(organization, member_count)?;
+            Self::take_org_snapshot(organization)?;
+            Ok(())
+        }
+        /// Removes the org's snapshots older than `SnapshotRetentionPeriod`, callable by anyone
+        /// -> `snapshot_count` must be at least the number of the org's snapshots
+        #[weight = T::DbWeight::get().reads_writes(2 * snapshot_count as u64 + 2, snapshot_count as u64)]
+        fn prune_old_snapshots(origin, organization: T::OrgId, snapshot_count: u32) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            ensure!(
+                <OrgSnapshots<T>>::iter_prefix_values(organization)
+                    .take(snapshot_count as usize + 1)
+                    .count()
+                    <= snapshot_count as usize,
+                Error::<T>::SnapshotCountWitnessTooLow
+            );
+            let pruned = Self::prune_snapshots(organization);
+            Self::deposit_event(RawEvent::OrgSnapshotsPruned(organization, pruned));
+            Ok(())
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Fails if the org has more than `member_count` members
+    /// -> reads at most one member entry past the witness
+    pub fn ensure_member_count_witness(
+        organization: T::OrgId,
+        member_count: u32,
+    ) -> DispatchResult {
+        ensure!(
+            <Members<T>>::iter_prefix_values(organization)
+                .take(member_count as usize + 1)
+                .count()
+                <= member_count as usize,
+            Error::<T>::MemberCountWitnessTooLow
+        );
+        Ok(())
+    }
+    /// The proxy credited with the holder's signal, `None` while proxy voting
+    /// is not active
+    pub fn voting_proxy_of(
+        organization: T::OrgId,
+        holder: &T::AccountId,
+    ) -> Option<T::AccountId> {
+        if T::ProxyVotingActive::get() {
+            <VotingProxies<T>>::get(organization, holder)
+        } else {
+            None
+        }
+    }
+    /// Up to `limit` members and their shares after the `cursor` member
+    /// -> returns the next page's cursor, `None` once every member is returned
+    /// -> members are in storage order so a page is O(members before the cursor)
+    pub fn list_org_members_page(
+        organization: T::OrgId,
+        cursor: Option<T::AccountId>,
+        limit: u32,
+    ) -> (Vec<(T::AccountId, T::Shares)>, Option<T::AccountId>) {
+        let mut members = <Members<T>>::iter_prefix(organization);
+        if let Some(last) = cursor {
+            // unknown cursors yield an empty page
+            if members.find(|(member, _)| member == &last).is_none() {
+                return (Vec::new(), None)
+            }
+        }
+        let page: Vec<(T::AccountId, T::Shares)> = members
+            .by_ref()
+            .take(limit as usize)
+            .map(|(member, profile)| (member, profile.total()))
+            .collect();
+        let next = if members.next().is_some() {
+            page.last().map(|(member, _)| member.clone())
+        } else {
+            None
+        };
+        (page, next)
+    }
+    /// Stores the org's current share weighted membership under a new snapshot id
+    pub fn take_org_snapshot(
+        organization: T::OrgId,
+    ) -> Result<u32, DispatchError> {
+        let genesis = Self::get_membership_with_shape(organization)
+            .ok_or(Error::<T>::CannotSnapshotOrgThatDNE)?;
+        let snapshot_id = <SnapshotIdNonce<T>>::get(organization) + 1;
+        let now = <frame_system::Module<T>>::block_number();
+        <OrgSnapshots<T>>::insert(
+            organization,
+            snapshot_id,
+            OrgSnapshot::new(genesis, now),
+        );
+        <SnapshotIdNonce<T>>::insert(organization, snapshot_id);
+        Self::deposit_event(RawEvent::OrgSnapshotTaken(
+            organization,
+            snapshot_id,
+        ));
+        Ok(snapshot_id)
+    }
+    /// Removes snapshots taken more than `SnapshotRetentionPeriod` blocks ago
+    /// -> returns the number of snapshots removed
+    fn prune_snapshots(organization: T::OrgId) -> u32 {
+        let now = <frame_system::Module<T>>::block_number();
+        let expired = <OrgSnapshots<T>>::iter_prefix(organization)
+            .filter(|(_, snapshot)| {
+                snapshot
+                    .taken()
+                    .saturating_add(T::SnapshotRetentionPeriod::get())
+                    < now
+            })
+            .map(|(id, _)| id)
+            .collect::<Vec<u32>>();
+        expired
+            .iter()
+            .for_each(|id| <OrgSnapshots<T>>::remove(organization, id));
+        expired.len() as u32
+    }
+    /// Locks `shares` of the account's shares in the org until the block
+    /// -> an existing lock keeps the larger amount and the later block
+    pub fn lock_shares_until(
+        organization: T::OrgId,
+        who: &T::AccountId,
+        shares: T::Shares,
+        until: T::BlockNumber,
+    ) {
+        <ShareLocks<T>>::mutate(organization, who, |lock| {
+            *lock = Some(match lock.take() {
+                Some((s, u)) => (s.max(shares), u.max(until)),
+                None => (shares, until),
+            });
+        });
+    }
+    /// Blocks since the member joined the org, zero if no join block is recorded
+    pub fn tenure(
+        organization: T::OrgId,
+        who: &T::AccountId,
+    ) -> T::BlockNumber {
+        <MemberSince<T>>::get(organization, who)
+            .map(|joined| {
+                <frame_system::Module<T>>::block_number().saturating_sub(joined)
+            })
+            .unwrap_or_else(Zero::zero)
+    }
     pub fn is_immediate_child(parent: T::OrgId, child: T::OrgId) -> bool {
         <OrgTree<T>>::get(parent, child).is_some()
     }
@@ -360,6 +854,117 @@ impl<T: Trait> Module<T> {
             Some(ret)
         }
     }
+    /// Dispatches a vote among the current shareholders
+    /// -> a majority of outstanding shares approves or blocks it
+    fn open_majority_vote(
+        organization: T::OrgId,
+    ) -> Result<TransferVoteId<T>, DispatchError> {
+        let shareholders = Self::get_membership_with_shape(organization)
+            .ok_or(Error::<T>::OrgDNE)?;
+        let majority = shareholders.total() / 2u32.into() + 1u32.into();
+        T::ShareTransferVote::open_direct_vote(
+            shareholders,
+            Threshold::new(majority, Some(majority)),
+        )
+    }
+    /// Dispatches a vote among the current shareholders to approve the transfer
+    fn propose_transfer(
+        organization: T::OrgId,
+        from: T::AccountId,
+        to: T::AccountId,
+        amount: T::Shares,
+    ) -> Result<u32, DispatchError> {
+        let vote_id = Self::open_majority_vote(organization)?;
+        let proposal_id = <ShareTransferNonce>::get() + 1u32;
+        <ShareTransferNonce>::put(proposal_id);
+        <ShareTransferProposals<T>>::insert(
+            proposal_id,
+            ShareTransferProposal::new(organization, from, to, amount),
+        );
+        <TransferProposalVotes<T>>::insert(proposal_id, vote_id);
+        Ok(proposal_id)
+    }
+    fn ensure_within_issuance_cap(
+        organization: T::OrgId,
+        new_total: T::Shares,
+    ) -> DispatchResult {
+        if let Some(cap) = <ShareIssuanceCap<T>>::get(organization) {
+            ensure!(new_total <= cap, Error::<T>::ShareIssuanceCapExceeded);
+        }
+        Ok(())
+    }
+    /// Moves shares between members, total issuance for the org is unchanged
+    fn transfer_shares(
+        organization: T::OrgId,
+        from: T::AccountId,
+        to: T::AccountId,
+        amount: T::Shares,
+    ) -> DispatchResult {
+        // batch flag skips updating org total shares
+        Self::burn(organization, from, Some(amount), true)?;
+        Self::issue(organization, to, amount, true)
+    }
+}
+
+impl<T: Trait> VoteOutcomeHook<u32> for Module<T> {
+    type Outcome = VoteOutcome;
+    /// Executes the transfer if approved and cleans up the proposal once the
+    /// vote has an outcome
+    fn on_vote_outcome(proposal_id: u32) -> Result<VoteOutcome, DispatchError> {
+        let proposal = <ShareTransferProposals<T>>::get(proposal_id)
+            .ok_or(Error::<T>::ShareTransferProposalDNE)?;
+        let vote_id = <TransferProposalVotes<T>>::get(proposal_id)
+            .ok_or(Error::<T>::ShareTransferProposalDNE)?;
+        let outcome = T::ShareTransferVote::direct_vote_outcome(vote_id)?;
+        match outcome {
+            VoteOutcome::Approved => {
+                Self::transfer_shares(
+                    proposal.org(),
+                    proposal.from(),
+                    proposal.to(),
+                    proposal.amount(),
+                )?;
+            }
+            VoteOutcome::Rejected => (),
+            _ => return Err(Error::<T>::ShareTransferVoteInconclusive.into()),
+        }
+        <ShareTransferProposals<T>>::remove(proposal_id);
+        <TransferProposalVotes<T>>::remove(proposal_id);
+        Ok(outcome)
+    }
+}
+
+impl<T: Trait> GovernanceExecutor<T::OrgId, T::AccountId, T::Shares, T::Cid>
+    for Module<T>
+{
+    /// Admitted members receive a single share, removed members lose all of theirs
+    fn execute(organization: T::OrgId, action: GovAction<T>) -> DispatchResult {
+        match action {
+            GovernanceAction::UpdateConfig(constitution) => {
+                let org =
+                    <Orgs<T>>::get(organization).ok_or(Error::<T>::OrgDNE)?;
+                <Orgs<T>>::insert(
+                    organization,
+                    org.set_constitution(constitution),
+                );
+                Ok(())
+            }
+            GovernanceAction::AdmitMember(who) => {
+                ensure!(
+                    !Self::is_member_of_group(organization, &who),
+                    Error::<T>::CannotAdmitExistingMember
+                );
+                Self::issue(organization, who, 1u32.into(), false)
+            }
+            GovernanceAction::RemoveMember(who) => {
+                Self::burn(organization, who, None, false)?;
+                Ok(())
+            }
+            GovernanceAction::Transfer { .. } => {
+                Err(Error::<T>::GovernanceActionNotSupportedByOrg.into())
+            }
+        }
+    }
 }
 
 impl<T: Trait> GroupMembership<T::OrgId, T::AccountId> for Module<T> {
@@ -565,9 +1170,8 @@ impl<T: Trait> ShareInformation<T::OrgId, T::AccountId, T::Shares>
     ) -> Option<Self::Genesis> {
         if !Self::id_is_available(organization) {
             Some(
-                <Members<T>>::iter()
-                    .filter(|(org, _, _)| *org == organization)
-                    .map(|(_, account, profile)| (account, profile.total()))
+                <Members<T>>::iter_prefix(organization)
+                    .map(|(account, profile)| (account, profile.total()))
                     .collect::<Vec<(T::AccountId, T::Shares)>>()
                     .into(),
             )
@@ -595,10 +1199,19 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         };
         if !batch {
             let org = <Orgs<T>>::get(organization).ok_or(Error::<T>::OrgDNE)?;
+            Self::ensure_within_issuance_cap(
+                organization,
+                org.total_shares() + amount,
+            )?;
             <Orgs<T>>::insert(organization, org.add_shares(amount));
         }
         <Members<T>>::insert(organization, new_owner.clone(), new_profile);
         if new_member {
+            <MemberSince<T>>::insert(
+                organization,
+                &new_owner,
+                <frame_system::Module<T>>::block_number(),
+            );
             Self::deposit_event(RawEvent::AddedOrgMember(
                 organization,
                 new_owner,
@@ -633,6 +1246,18 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
             org.total_shares() >= amt_to_burn,
             Error::<T>::CannotBurnMoreThanTotalShares
         );
+        if let Some((locked, until)) =
+            <ShareLocks<T>>::get(organization, &old_owner)
+        {
+            if <frame_system::Module<T>>::block_number() < until {
+                ensure!(
+                    old_profile.total().saturating_sub(amt_to_burn) >= locked,
+                    Error::<T>::SharesLockedUntilVoteEnds
+                );
+            } else {
+                <ShareLocks<T>>::remove(organization, &old_owner);
+            }
+        }
         if !batch {
             <Orgs<T>>::insert(organization, org.subtract_shares(amt_to_burn));
         }
@@ -640,6 +1265,7 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         if new_profile.is_zero() {
             // leave the group
             <Members<T>>::remove(organization, old_owner.clone());
+            <MemberSince<T>>::remove(organization, &old_owner);
             Self::deposit_event(RawEvent::RemovedOrgMember(
                 organization,
                 old_owner,
@@ -663,6 +1289,7 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         let new_issuance = total_shares
             .checked_add(&genesis.total())
             .ok_or(Error::<T>::IssuanceWouldOverflowShares)?;
+        Self::ensure_within_issuance_cap(organization, new_issuance)?;
         genesis.vec().into_iter().for_each(|(member, shares)| {
             if let Ok(()) =
                 Self::issue(organization, member.clone(), shares, true)
@@ -0,0 +1,19 @@
+//! Runtime API for reading the membership of large orgs in pages
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+    pub trait OrgApi<OrgId, AccountId, Shares> where
+        OrgId: Codec,
+        AccountId: Codec,
+        Shares: Codec,
+    {
+        /// Up to `limit` members after `cursor` with their shares and the
+        /// cursor for the next page
+        fn list_org_members_page(
+            org_id: OrgId,
+            cursor: Option<AccountId>,
+            limit: u32,
+        ) -> (Vec<(AccountId, Shares)>, Option<AccountId>);
+    }
+}
@@ -2,18 +2,30 @@
 
 use super::*;
 use frame_support::{
+    assert_noop,
     assert_ok,
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::schedule::{
+        Anon as ScheduleAnon,
+        DispatchTime,
+        Period,
+        Priority,
+    },
     weights::Weight,
 };
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
     traits::IdentityLookup,
+    DispatchError,
     Perbill,
 };
+use util::vote::{
+    LinearWeight,
+    VoterView,
+};
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
@@ -29,7 +41,9 @@ mod org {
 impl_outer_event! {
     pub enum TestEvent for TestRuntime {
         frame_system<T>,
+        pallet_balances<T>,
         org<T>,
+        vote<T>,
     }
 }
 
@@ -68,16 +82,97 @@ impl frame_system::Trait for TestRuntime {
     type BaseCallFilter = ();
     type SystemWeightInfo = ();
 }
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+    pub const MaxLocks: u32 = 50;
+}
+impl pallet_balances::Trait for TestRuntime {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type MaxLocks = MaxLocks;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const BondSlashRecipient: u64 = 0;
+    pub const ShutdownFinalizationsPerBlock: u32 = 2;
+    pub const MaxVotesPerAccount: u32 = 10;
+    pub const MaxVotersPerVote: u32 = 100;
+    pub const MaxSignalPerVote: u64 = 1_000_000;
+}
+impl vote::Trait for TestRuntime {
+    type Event = TestEvent;
+    type Cid = u32;
+    type VoteId = u64;
+    type Signal = u64;
+    type MaxVotersPerVote = MaxVotersPerVote;
+    type MaxSignalPerVote = MaxSignalPerVote;
+    type MaxVotesPerAccount = MaxVotesPerAccount;
+    type SybilResistance = ();
+    type IdentityProvider = ();
+    type Currency = Balances;
+    type BondSlashRecipient = BondSlashRecipient;
+    type OrgId = u64;
+    type OrgAdminOrigin = frame_system::EnsureRoot<u64>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type ShutdownFinalizationsPerBlock = ShutdownFinalizationsPerBlock;
+    type VoteWeight = LinearWeight;
+    type ScheduledCall = vote::Call<TestRuntime>;
+    type PalletsOrigin = frame_system::RawOrigin<u64>;
+    type Scheduler = TestScheduler;
+    type WeightInfo = ();
+}
+/// Org tests never run a vote past its end so scheduled calls are dropped
+pub struct TestScheduler;
+impl ScheduleAnon<u64, vote::Call<TestRuntime>, frame_system::RawOrigin<u64>>
+    for TestScheduler
+{
+    type Address = ();
+    fn schedule(
+        _when: DispatchTime<u64>,
+        _maybe_periodic: Option<Period<u64>>,
+        _priority: Priority,
+        _origin: frame_system::RawOrigin<u64>,
+        _call: vote::Call<TestRuntime>,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+    fn cancel(_address: ()) -> Result<(), ()> {
+        Ok(())
+    }
+    fn reschedule(
+        _address: (),
+        _when: DispatchTime<u64>,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+    fn next_dispatch_time(_address: ()) -> Result<u64, ()> {
+        Err(())
+    }
+}
+parameter_types! {
+    pub const ShareTransferRequiresVote: bool = true;
+    pub const ProxyVotingActive: bool = true;
+    pub const SnapshotRetentionPeriod: u64 = 10;
+}
 impl Trait for TestRuntime {
     type Event = TestEvent;
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type ShareTransferVote = vote::Module<TestRuntime>;
+    type ShareTransferRequiresVote = ShareTransferRequiresVote;
+    type ProxyVotingActive = ProxyVotingActive;
+    type SnapshotRetentionPeriod = SnapshotRetentionPeriod;
 }
 pub type System = frame_system::Module<TestRuntime>;
+pub type Balances = pallet_balances::Module<TestRuntime>;
 pub type Org = Module<TestRuntime>;
+pub type Vote = vote::Module<TestRuntime>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u32> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u32, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -233,3 +328,356 @@ fn share_burn() {
         assert_eq!(post_shares, 6);
     });
 }
+
+#[test]
+fn share_transfer_proposal_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::propose_share_transfer(Origin::signed(7), 1, 8, 1, 6),
+            Error::<TestRuntime>::AccountHasNoOwnershipInOrg
+        );
+        assert_noop!(
+            Org::propose_share_transfer(Origin::signed(1), 1, 7, 2, 6),
+            Error::<TestRuntime>::NotEnoughSharesToProposeTransfer
+        );
+        assert_noop!(
+            Org::propose_share_transfer(Origin::signed(1), 1, 7, 1, 5),
+            Error::<TestRuntime>::MemberCountWitnessTooLow
+        );
+        assert_ok!(Org::propose_share_transfer(Origin::signed(1), 1, 7, 1, 6));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ShareTransferProposed(1, 1, 1, 7, 1)
+        );
+        assert_eq!(Org::transfer_proposal_votes(1), Some(1));
+        assert_noop!(
+            Org::poll_share_transfer(Origin::signed(1), 1),
+            Error::<TestRuntime>::ShareTransferVoteInconclusive
+        );
+        // 4 of 6 shares is a majority
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_ok!(Org::poll_share_transfer(Origin::signed(1), 1));
+        assert_eq!(get_last_event(), RawEvent::SharesTransferred(1, 1, 7, 1));
+        assert!(!Org::is_member_of_group(1, &1));
+        assert_eq!(Org::members(1, 7).unwrap().total(), 1);
+        assert_eq!(Org::orgs(1).unwrap().total_shares(), 6);
+        assert!(Org::share_transfer_proposals(1).is_none());
+        // members can block transfers
+        assert_ok!(Org::propose_share_transfer(Origin::signed(2), 1, 8, 1, 6));
+        for i in 3u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None
+            ));
+        }
+        assert_ok!(Org::poll_share_transfer(Origin::signed(1), 2));
+        assert_eq!(get_last_event(), RawEvent::ShareTransferRejected(2, 1));
+        assert!(Org::is_member_of_group(1, &2));
+        assert!(!Org::is_member_of_group(1, &8));
+        assert!(Org::transfer_proposal_votes(2).is_none());
+    });
+}
+
+#[test]
+fn share_issuance_cap_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::set_share_issuance_cap(Origin::signed(2), 1, 10),
+            Error::<TestRuntime>::NotAuthorizedForAccount
+        );
+        assert_noop!(
+            Org::set_share_issuance_cap(Origin::signed(1), 1, 5),
+            Error::<TestRuntime>::IssuanceCapBelowOutstandingShares
+        );
+        assert_ok!(Org::set_share_issuance_cap(Origin::signed(1), 1, 9));
+        assert_eq!(get_last_event(), RawEvent::IssuanceCapSet(1, 9));
+        assert_noop!(
+            Org::set_share_issuance_cap(Origin::signed(1), 1, 20),
+            Error::<TestRuntime>::IssuanceCapAlreadySetSoIncreaseRequiresVote
+        );
+        // minting up to exactly the cap is allowed
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 1, 3));
+        assert_eq!(Org::orgs(1).unwrap().total_shares(), 9);
+        assert_noop!(
+            Org::issue_shares(Origin::signed(1), 1, 2, 1),
+            Error::<TestRuntime>::ShareIssuanceCapExceeded
+        );
+        assert_noop!(
+            Org::batch_issue_shares(Origin::signed(1), 1, vec![(7, 1)]),
+            Error::<TestRuntime>::ShareIssuanceCapExceeded
+        );
+        assert_noop!(
+            Org::propose_issuance_cap_increase(Origin::signed(1), 1, 9, 6),
+            Error::<TestRuntime>::IssuanceCapCanOnlyIncrease
+        );
+        assert_noop!(
+            Org::propose_issuance_cap_increase(Origin::signed(1), 1, 12, 5),
+            Error::<TestRuntime>::MemberCountWitnessTooLow
+        );
+        // account 1 holds 4 of 9 shares so 2 more are needed for 2/3
+        assert_ok!(Org::propose_issuance_cap_increase(
+            Origin::signed(1),
+            1,
+            12,
+            6
+        ));
+        assert_noop!(
+            Org::propose_issuance_cap_increase(Origin::signed(2), 1, 15, 6),
+            Error::<TestRuntime>::IssuanceCapIncreaseAlreadyPending
+        );
+        for i in 1u64..3u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_noop!(
+            Org::poll_issuance_cap_increase(Origin::signed(1), 1),
+            Error::<TestRuntime>::IssuanceCapVoteInconclusive
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Org::poll_issuance_cap_increase(Origin::signed(1), 1));
+        assert_eq!(get_last_event(), RawEvent::IssuanceCapSet(1, 12));
+        assert_eq!(Org::share_issuance_cap(1), Some(12));
+        assert!(Org::issuance_cap_votes(1).is_none());
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 2, 1));
+    });
+}
+
+#[test]
+fn governance_proposals_execute_membership_changes() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::propose_governance_action(
+                Origin::signed(7),
+                1,
+                GovernanceAction::AdmitMember(7),
+                6
+            ),
+            Error::<TestRuntime>::NotAuthorizedForAccount
+        );
+        assert_noop!(
+            Org::propose_governance_action(
+                Origin::signed(1),
+                1,
+                GovernanceAction::Transfer { to: 7, amount: 1 },
+                6
+            ),
+            Error::<TestRuntime>::GovernanceActionNotSupportedByOrg
+        );
+        assert_noop!(
+            Org::propose_governance_action(
+                Origin::signed(1),
+                1,
+                GovernanceAction::AdmitMember(7),
+                5
+            ),
+            Error::<TestRuntime>::MemberCountWitnessTooLow
+        );
+        assert_ok!(Org::propose_governance_action(
+            Origin::signed(1),
+            1,
+            GovernanceAction::AdmitMember(7),
+            6
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::GovernanceActionProposed(1, 1, 1)
+        );
+        assert_eq!(Org::governance_proposals(1, 1).unwrap().vote_id(), 1);
+        assert_noop!(
+            Org::poll_governance_proposal(Origin::signed(1), 1, 1),
+            Error::<TestRuntime>::GovernanceVoteInconclusive
+        );
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_ok!(Org::poll_governance_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(get_last_event(), RawEvent::GovernanceActionExecuted(1, 1));
+        assert_eq!(Org::members(1, 7).unwrap().total(), 1);
+        assert_eq!(Org::orgs(1).unwrap().total_shares(), 7);
+        assert!(Org::governance_proposals(1, 1).is_none());
+        // rejected actions are discarded without executing
+        assert_ok!(Org::propose_governance_action(
+            Origin::signed(2),
+            1,
+            GovernanceAction::RemoveMember(2),
+            7
+        ));
+        for i in 3u64..8u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None
+            ));
+        }
+        assert_ok!(Org::poll_governance_proposal(Origin::signed(1), 1, 2));
+        assert_eq!(get_last_event(), RawEvent::GovernanceActionRejected(2, 1));
+        assert!(Org::is_member_of_group(1, &2));
+        // config updates replace the constitution
+        assert_ok!(Org::execute(1, GovernanceAction::UpdateConfig(1739)));
+        assert_eq!(Org::orgs(1).unwrap().constitution(), 1739);
+        assert_noop!(
+            Org::execute(1, GovernanceAction::AdmitMember(7)),
+            Error::<TestRuntime>::CannotAdmitExistingMember
+        );
+    });
+}
+
+#[test]
+fn voting_proxies_are_set_and_cleared() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::set_voting_proxy(Origin::signed(7), 1, Some(2)),
+            Error::<TestRuntime>::AccountHasNoOwnershipInOrg
+        );
+        assert_noop!(
+            Org::set_voting_proxy(Origin::signed(2), 1, Some(2)),
+            Error::<TestRuntime>::CannotSetSelfAsVotingProxy
+        );
+        // proxies need not hold shares themselves
+        assert_ok!(Org::set_voting_proxy(Origin::signed(2), 1, Some(7)));
+        assert_eq!(get_last_event(), RawEvent::VotingProxySet(1, 2, Some(7)));
+        assert_eq!(Org::voting_proxy_of(1, &2), Some(7));
+        assert_ok!(Org::set_voting_proxy(Origin::signed(2), 1, None));
+        assert_eq!(get_last_event(), RawEvent::VotingProxySet(1, 2, None));
+        assert_eq!(Org::voting_proxies(1, 2), None);
+    });
+}
+
+#[test]
+fn member_since_and_seniority_config() {
+    new_test_ext().execute_with(|| {
+        // genesis members joined before block 1
+        assert_eq!(Org::member_since(1, 2), Some(0));
+        System::set_block_number(5);
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 7, 4));
+        assert_eq!(Org::member_since(1, 7), Some(5));
+        // more shares keep the join block
+        System::set_block_number(8);
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 7, 4));
+        assert_eq!(Org::member_since(1, 7), Some(5));
+        assert_eq!(Org::tenure(1, &7), 3);
+        assert_ok!(Org::burn_shares(Origin::signed(1), 1, 7, 8));
+        assert_eq!(Org::member_since(1, 7), None);
+        assert_eq!(Org::tenure(1, &7), 0);
+        let config = SeniorityConfig::new(Permill::from_percent(50), 10);
+        assert_noop!(
+            Org::set_seniority_config(Origin::signed(2), 1, Some(config)),
+            Error::<TestRuntime>::NotAuthorizedForAccount
+        );
+        assert_ok!(Org::set_seniority_config(
+            Origin::signed(1),
+            1,
+            Some(config)
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SeniorityConfigSet(1, Some(config))
+        );
+        assert_eq!(Org::seniority_configs(1), Some(config));
+        // the bonus ramps linearly and stops growing after 10 blocks
+        assert_eq!(config.weigh(100u64, 0), 100);
+        assert_eq!(config.weigh(100u64, 4), 120);
+        assert_eq!(config.weigh(100u64, 10), 150);
+        assert_eq!(config.weigh(100u64, 1000), 150);
+        assert_ok!(Org::set_seniority_config(Origin::signed(1), 1, None));
+        assert_eq!(Org::seniority_configs(1), None);
+    });
+}
+
+#[test]
+fn org_snapshots_are_taken_and_pruned() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::take_org_snapshot(9),
+            Error::<TestRuntime>::CannotSnapshotOrgThatDNE
+        );
+        assert_noop!(
+            Org::snapshot_org(Origin::signed(2), 1, 6),
+            Error::<TestRuntime>::NotAuthorizedForAccount
+        );
+        assert_noop!(
+            Org::snapshot_org(Origin::signed(1), 1, 5),
+            Error::<TestRuntime>::MemberCountWitnessTooLow
+        );
+        assert_ok!(Org::snapshot_org(Origin::signed(1), 1, 6));
+        assert_eq!(get_last_event(), RawEvent::OrgSnapshotTaken(1, 1));
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 7, 4));
+        // the snapshot keeps the membership it was taken with
+        let snapshot = Org::org_snapshots(1, 1).unwrap();
+        assert_eq!(snapshot.taken(), 1);
+        assert_eq!(snapshot.genesis().total(), 6);
+        assert!(snapshot.genesis().vec().iter().all(|(who, _)| *who != 7));
+        System::set_block_number(5);
+        assert_eq!(Org::take_org_snapshot(1), Ok(2));
+        assert_eq!(Org::org_snapshots(1, 2).unwrap().genesis().total(), 10);
+        // the first snapshot is retained through block 11
+        System::set_block_number(11);
+        assert_noop!(
+            Org::prune_old_snapshots(Origin::signed(3), 1, 1),
+            Error::<TestRuntime>::SnapshotCountWitnessTooLow
+        );
+        assert_ok!(Org::prune_old_snapshots(Origin::signed(3), 1, 2));
+        assert_eq!(get_last_event(), RawEvent::OrgSnapshotsPruned(1, 0));
+        System::set_block_number(12);
+        assert_ok!(Org::prune_old_snapshots(Origin::signed(3), 1, 2));
+        assert_eq!(get_last_event(), RawEvent::OrgSnapshotsPruned(1, 1));
+        assert!(Org::org_snapshots(1, 1).is_none());
+        assert!(Org::org_snapshots(1, 2).is_some());
+    });
+}
+
+#[test]
+fn org_members_are_listed_in_pages() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 7, 4));
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) = Org::list_org_members_page(1, cursor, 3);
+            assert!(page.len() <= 3);
+            paged.extend(page);
+            if next.is_none() {
+                break
+            }
+            cursor = next;
+        }
+        paged.sort_by_key(|(member, _)| *member);
+        assert_eq!(
+            paged,
+            vec![(1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 4)]
+        );
+        // a page which ends on the last member has no next cursor
+        let (page, next) = Org::list_org_members_page(1, None, 7);
+        assert_eq!(page.len(), 7);
+        assert_eq!(next, None);
+        assert_eq!(
+            Org::list_org_members_page(1, Some(8), 3),
+            (Vec::new(), None)
+        );
+        assert_eq!(Org::list_org_members_page(9, None, 3), (Vec::new(), None));
+    });
+}
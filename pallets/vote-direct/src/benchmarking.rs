@@ -0,0 +1,115 @@
+//! Benchmarks for opening and finalizing votes as the voter set grows
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_support::traits::OnInitialize;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// `p` accounts with one signal each
+fn voter_set<T: Trait>(p: u32) -> WeightedVector<T::AccountId, T::Signal> {
+    (0..p)
+        .map(|i| (account("voter", i, SEED), T::Signal::one()))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Opens a vote for `p` voters which is sealed two blocks from now
+fn open_expiring_vote<T: Trait>(p: u32) -> T::VoteId {
+    Module::<T>::open_vote(
+        None,
+        voter_set::<T>(p),
+        Threshold::new(T::Signal::one(), None),
+        Some(One::one()),
+    )
+    .expect("voter set is within the configured bounds")
+}
+
+benchmarks! {
+    _ { }
+
+    create_signal_vote {
+        let p in 1 .. T::MaxVotersPerVote::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let threshold = Threshold::new(T::Signal::one(), None);
+    }: _(RawOrigin::Signed(caller), None, voter_set::<T>(p), threshold, Some(10u32.into()))
+    verify {
+        assert_eq!(<OpenVoteCounter>::get(), 1);
+    }
+
+    create_percent_vote {
+        let p in 1 .. T::MaxVotersPerVote::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let threshold = Threshold::new(Permill::one(), None);
+    }: _(RawOrigin::Signed(caller), None, voter_set::<T>(p), threshold, Some(10u32.into()))
+    verify {
+        assert_eq!(<OpenVoteCounter>::get(), 1);
+    }
+
+    // ids taken without moving `VoteIdCounter` are skipped one read at a time
+    create_signal_vote_after_id_collisions {
+        let c in 0 .. 100;
+        let caller: T::AccountId = whitelisted_caller();
+        let first = open_expiring_vote::<T>(1);
+        let state = <VoteStates<T>>::get(first).expect("vote was just opened");
+        for i in 1 ..= c {
+            <VoteStates<T>>::insert(first + i.into(), state.clone());
+        }
+        let threshold = Threshold::new(T::Signal::one(), None);
+    }: create_signal_vote(RawOrigin::Signed(caller), None, voter_set::<T>(1), threshold, None)
+    verify {
+        assert_eq!(<VoteIdCounter<T>>::get(), first + (c + 1).into());
+    }
+
+    finalize_vote {
+        let p in 1 .. T::MaxVotersPerVote::get();
+        let vote_id = open_expiring_vote::<T>(p);
+        let sealed = <frame_system::Module<T>>::block_number() + 2u32.into();
+        <frame_system::Module<T>>::set_block_number(sealed);
+    }: _(RawOrigin::Root, vote_id)
+    verify {
+        assert!(<FinalOutcomes<T>>::get(vote_id).is_some());
+    }
+
+    on_initialize_shutdown {
+        let v in 1 .. T::ShutdownFinalizationsPerBlock::get().max(1);
+        for _ in 0 .. v {
+            open_expiring_vote::<T>(T::MaxVotersPerVote::get());
+        }
+        <ShutdownMode>::put(true);
+        let now = <frame_system::Module<T>>::block_number();
+    }: {
+        Module::<T>::on_initialize(now);
+    }
+    verify {
+        let limit = T::ShutdownFinalizationsPerBlock::get();
+        assert_eq!(<OpenVoteCounter>::get(), v.saturating_sub(limit));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{
+        new_test_ext,
+        Test,
+    };
+    use frame_support::assert_ok;
+
+    #[test]
+    fn benchmarks_run_against_the_mock() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_signal_vote::<Test>());
+            assert_ok!(test_benchmark_create_percent_vote::<Test>());
+            assert_ok!(
+                test_benchmark_create_signal_vote_after_id_collisions::<Test>()
+            );
+            assert_ok!(test_benchmark_finalize_vote::<Test>());
+            assert_ok!(test_benchmark_on_initialize_shutdown::<Test>());
+        });
+    }
+}
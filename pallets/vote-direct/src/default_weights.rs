@@ -0,0 +1,21 @@
+//! Weights for runtimes which have not benchmarked this module
+//! -> zero until the benchmarks are run on reference hardware
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn create_signal_vote(_p: u32) -> Weight {
+        0
+    }
+    fn create_percent_vote(_p: u32) -> Weight {
+        0
+    }
+    fn create_signal_vote_after_id_collisions(_c: u32) -> Weight {
+        0
+    }
+    fn finalize_vote(_p: u32) -> Weight {
+        0
+    }
+    fn on_initialize_shutdown(_v: u32) -> Weight {
+        0
+    }
+}
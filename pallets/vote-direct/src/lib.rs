@@ -13,37 +13,226 @@
 //! voter (`AccountId`) has some quantity of `Signal` in proportion
 //! to their relative voting power.
 //!
+//! ## Storage Hashers
+//!
+//! All maps use `blake2_128_concat` so keys can be recovered and maps iterated by
+//! prefix, matching the other pallets. `VoteId`s are generated sequentially by this
+//! module so `twox_64_concat` would be safe for them alone, but the `AccountId` keys
+//! are chosen by users and need a cryptographic hasher to prevent crafted keys from
+//! unbalancing the trie. One hasher is used for both for consistency; the extra
+//! hashing cost is negligible.
+//!
+//! Earlier versions used `opaque_blake2_256`. Single maps are migrated by
+//! [`migrations::v1_to_v2::LegacyMigration`] in `on_runtime_upgrade` by enumerating
+//! every generated `VoteId`. Account keys can't be recovered from their opaque hash so
+//! double map entries are moved for every account in `frame_system::Account`, and
+//! the entries of any other voter are moved on first read.
+//! `LegacyMigration::pre_upgrade` and `post_upgrade` check that vote states decode as
+//! expected on either side of the upgrade.
+//! [`migrations::v2_to_v3::DistinctVoterMigration`] then adds the distinct voter count
+//! to every vote state.
+//!
+//! ## Bonded Voting
+//!
+//! A vote's creator may call `require_vote_bonds` before anyone votes, after which
+//! `bond_amount` is reserved from each voter on their first vote. `settle_bonds`
+//! waits for the vote to be finalized or sealed, then unreserves the winning side's
+//! bonds and slashes the losing side's bonds to `BondSlashRecipient` if configured.
+//! Abstaining voters and every voter of a vote that ends without an outcome get
+//! their bond back.
+//!
+//! ## Token Weighted Votes
+//!
+//! A vote marked with `set_joinable` can be joined by any account with `join_vote`,
+//! which reserves `lock_amount` from the caller and registers it as a voter with that
+//! much signal, weighted by `VoteWeight` like the rest of the voter set. Locks are kept
+//! in `VoteLocks` and every one is unreserved when the vote is finalized, however it
+//! is closed. Joined voters are registered late, so they can't vote in a vote which
+//! disallows late registration.
+//!
+//! ## Multi Round Votes
+//!
+//! `create_multiround_vote` opens one vote per candidate. Once every vote in the round
+//! is finalized or sealed, `advance_to_next_round` finalizes with the approved candidate
+//! with the most support. If no candidate is approved, the round's top `advancing`
+//! candidates run off in the next round or, after the last round, the leading candidate's
+//! vote is reported as rejected. The caller passes the current round's candidate count,
+//! which weighs opening the next round.
+//!
+//! ## Org Scoped Votes
+//!
+//! `create_signal_vote` lets any account open a vote. Orgs which restrict this keep a
+//! list of `AuthorizedVoteCreators`, managed by `OrgAdminOrigin`, and only those
+//! accounts may open votes for the org with `create_signal_vote_for_org`.
+//!
+//! ## Proportional Thresholds
+//!
+//! A percent vote keeps the `Permill` threshold it was opened with. Once
+//! `set_proportional_threshold` is enabled for the vote, every `register_voter` or
+//! `remove_voter` re-derives the signal threshold from that percentage against the new
+//! total. Otherwise the signal threshold stays fixed as the total changes.
+//!
+//! ## Voter Set Bounds
+//!
+//! Every vote writes one `VoteLogger` entry per voter when it opens, so the voter set
+//! is bounded by `MaxVotersPerVote`. Its total signal is bounded by `MaxSignalPerVote`
+//! so the vote power minted from it, and the tallies built on it, stay well clear of
+//! overflow. Sets over either bound fail with `VoterSetTooLarge` or
+//! `SignalExceedsBound` before anything is written. Voters added later with
+//! `register_voter` or `join_vote` fail with the same errors if they would take
+//! the vote over either bound, with their vote power counted against
+//! `MaxSignalPerVote`.
+//!
+//! ## Votes By Account
+//!
+//! `VotesByAccount` maps each account to the open votes it is a voter in, so the votes
+//! of one account can be listed without scanning `VoteLogger`. An account is indexed
+//! when a vote opens with it in the voter set or when it is added with
+//! `register_voter`, and unindexed when it is removed with `remove_voter` or the vote is
+//! finalized. Votes without an end are never finalized and stay indexed.
+//!
+//! Anyone can open a vote over any voter set, so an account's entry grows with every
+//! vote it is named in. It holds at most `MaxVotesPerAccount` votes; opening a vote or
+//! registering a voter fails with `AccountVoteIndexFull` if a voter's entry is full,
+//! before anything is written. Entries free up as votes are finalized. The `votes_of`
+//! runtime API reads the entry.
+//!
+//! ## Vote Metrics
+//!
+//! Alongside `OpenVoteCounter`, the module keeps the most votes ever open at once in
+//! `OpenVotePeakCounter`, the number of votes ever opened in `TotalVotesEverCreated`
+//! and the number finalized with each outcome in `TotalVotesByOutcome`. The
+//! `VoteMetricsApi` runtime API returns them together.
+//!
+//! ## Cached Outcomes
+//!
+//! Each `VoteStates` entry caches the vote's outcome, recomputed whenever the tally,
+//! turnout or threshold changes, so `get_vote_outcome` never recomputes it on read.
+//! `OutcomeComputedAt` records the block at which each cached outcome was computed.
+//!
+//! ## Vote Finalization
+//!
+//! Opening a vote with a duration schedules `finalize_vote` with `T::Scheduler` for the
+//! first block after the vote ends. It stores the vote's outcome in `FinalOutcomes` and
+//! removes the vote from `OpenVoteCounter`. Extending a vote reschedules the call.
+//! A vote which is still undecided when it is finalized records the terminal
+//! `ExpiredInconclusive` outcome, which `get_vote_outcome` returns from then on so
+//! consumers can tell an undecided vote that ended from one still counting.
+//!
+//! A vote may be given a grace period with `set_grace_period` before it ends. For that
+//! many blocks after the end, voters who voted in favor or against may still retract by
+//! switching to `Abstain`, but no new support is accepted. The outcome is sealed once the
+//! grace period passes, and `finalize_vote` is scheduled for that block. Bonds and multi
+//! round standings wait for the seal rather than the nominal end.
+//!
+//! The creator of a vote opened by extrinsic sets its grace period with
+//! `configure_grace_period` and marks it joinable with `configure_joinable`. Other
+//! accounts fail with `OnlyVoteCreatorCanConfigureVote`.
+//!
+//! Root may close a wedged vote early with `force_finalize_vote`, which sets its
+//! outcome directly. No more votes are accepted once a vote is finalized.
+//! Finalization is logged natively under the `runtime::vote_direct` target.
+//!
+//! ## Graceful Shutdown
+//!
+//! Before a runtime upgrade which changes vote semantics, `ForceOrigin` sets
+//! `ShutdownMode` with `set_shutdown_mode`. No vote may be opened while it is set, by
+//! extrinsic or by another pallet, and advancing a multi round vote fails because it
+//! opens votes. Each block's `on_initialize` checks the next
+//! `ShutdownFinalizationsPerBlock` vote ids, resuming from `ShutdownCursor`, and
+//! finalizes the open ones with their current outcome, reporting those which are
+//! still undecided as `ExpiredInconclusive`. `VotePalletShutdownComplete` is
+//! emitted once `OpenVoteCounter` reaches zero. `ForceOrigin` unsets the mode after
+//! the upgrade to resume normal operation.
+//!
+//! ## Signal Overrides
+//!
+//! Votes opened by extrinsic record their creator in `VoteCreators`. Until the vote
+//! expires, the creator may correct a voter's signal with `override_voter_signal` as
+//! long as that voter hasn't voted. The vote's total signal moves by the difference,
+//! and the override fails with `InputThresholdExceedsBounds` if the threshold would no
+//! longer be reachable, or with `SignalExceedsBound` if the total would exceed
+//! `MaxSignalPerVote`. Joined voters' signal is the balance they locked, so it can't
+//! be overridden. `VoterSignalOverridden` records both vote powers.
+//!
+//! ## Benchmarks
+//!
+//! With the `runtime-benchmarks` feature, `benchmarking.rs` measures opening and
+//! finalizing a vote against the size of its voter set, id generation against the
+//! number of taken ids it skips, and one block of shutdown. Opening a signal or percent
+//! vote is weighed by the size of its voter set, `finalize_vote` by the largest voter
+//! set allowed, and a block of shutdown by the vote ids it checked. `WeightInfo for ()`
+//! is zero until those results are generated on reference hardware.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod default_weights;
+pub mod migrations;
 #[cfg(test)]
 mod tests;
 
+pub mod runtime_api;
+
 use frame_support::{
+    debug,
     decl_error,
     decl_event,
     decl_module,
     decl_storage,
     ensure,
+    storage::{
+        migration::take_storage_value,
+        IterableStorageDoubleMap,
+        IterableStorageMap,
+    },
+    traits::{
+        schedule::{
+            Anon as ScheduleAnon,
+            DispatchTime,
+            LOWEST_PRIORITY,
+        },
+        BalanceStatus,
+        Currency,
+        EnsureOrigin,
+        Get,
+        OnRuntimeUpgrade,
+        ReservableCurrency,
+    },
+    weights::Weight,
+    Blake2_256,
     Parameter,
+    StorageHasher,
 };
 use frame_system::{
+    ensure_root,
     ensure_signed,
     Trait as System,
 };
-use parity_scale_codec::Codec;
+use parity_scale_codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use sp_runtime::{
     traits::{
         AtLeast32BitUnsigned,
+        CheckedAdd,
         CheckedSub,
         MaybeSerializeDeserialize,
         Member,
+        One,
+        SaturatedConversion,
+        Saturating,
         Zero,
     },
     DispatchError,
     DispatchResult,
     Permill,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
@@ -56,30 +245,72 @@ use util::{
         Apply,
         ApplyVote,
         CheckVoteStatus,
+        DirectVote,
         GenerateUniqueID,
         GetVoteOutcome,
         IDIsAvailable,
+        IdentityNameOf,
         OpenVote,
+        SybilCheck,
         UpdateVote,
         VoteOnProposal,
         VoteVector,
+        VoteWeight,
     },
     vote::{
+        BondedVotingConfig,
+        BoundedVoteIdSet,
+        MultiRoundVote,
+        MultipleThreshold,
+        RoundSpec,
         Threshold,
         Vote,
+        VoteMetrics,
         VoteOutcome,
         VoteState,
+        VoteStateV1,
+        VoteStateV2,
         VoterView,
     },
 };
 
+/// Target of this module's node-side logs
+#[allow(dead_code)]
+const LOG_TARGET: &str = "runtime::vote_direct";
+
 // type aliases
 type VoteSt<T> = VoteState<
     <T as Trait>::Signal,
     <T as System>::BlockNumber,
     <T as Trait>::Cid,
 >;
+type VoteStV1<T> = VoteStateV1<
+    <T as Trait>::Signal,
+    <T as System>::BlockNumber,
+    <T as Trait>::Cid,
+>;
+type VoteStV2<T> = VoteStateV2<
+    <T as Trait>::Signal,
+    <T as System>::BlockNumber,
+    <T as Trait>::Cid,
+>;
 type VoteVec<T> = Vote<<T as Trait>::Signal, <T as Trait>::Cid>;
+type MultiRound<T> = MultiRoundVote<
+    <T as Trait>::Cid,
+    <T as Trait>::VoteId,
+    <T as Trait>::Signal,
+    <T as System>::BlockNumber,
+>;
+type AccountVotes<T> =
+    BoundedVoteIdSet<<T as Trait>::VoteId, <T as Trait>::MaxVotesPerAccount>;
+type Round<T> = RoundSpec<<T as Trait>::Signal, <T as System>::BlockNumber>;
+type BalanceOf<T> =
+    <<T as Trait>::Currency as Currency<<T as System>::AccountId>>::Balance;
+type ScheduleAddress<T> = <<T as Trait>::Scheduler as ScheduleAnon<
+    <T as System>::BlockNumber,
+    <T as Trait>::ScheduledCall,
+    <T as Trait>::PalletsOrigin,
+>>::Address;
 
 pub trait Trait: System {
     /// The overarching event type
@@ -113,6 +344,71 @@ pub trait Trait: System {
         + PartialOrd
         + CheckedSub
         + Zero;
+
+    /// The most accounts a vote may be opened with
+    type MaxVotersPerVote: Get<u32>;
+
+    /// The most signal a vote may be opened with, before `VoteWeight` is applied
+    type MaxSignalPerVote: Get<Self::Signal>;
+
+    /// The most open votes kept in `VotesByAccount` for each account
+    type MaxVotesPerAccount: Get<u32>;
+
+    /// Eligibility check run before every vote, `()` allows all accounts
+    type SybilResistance: SybilCheck<Self::AccountId>;
+
+    /// Names voters in `Voted` events, `()` for runtimes without identities
+    type IdentityProvider: IdentityNameOf<Self::AccountId>;
+
+    /// The currency voters bond in bonded votes
+    type Currency: ReservableCurrency<Self::AccountId>;
+
+    /// Receives the bonds slashed from the losing side of bonded votes
+    type BondSlashRecipient: Get<Self::AccountId>;
+
+    /// Maps each voter's signal to vote power when it is minted, `LinearWeight` for 1:1
+    /// -> thresholds are expressed in vote power
+    type VoteWeight: VoteWeight<Self::Signal>;
+
+    /// Identifier for orgs which restrict who may open their votes
+    type OrgId: Parameter + Member + Copy;
+
+    /// Origin permitted to manage each org's vote creators
+    type OrgAdminOrigin: EnsureOrigin<Self::Origin>;
+
+    /// Origin permitted to toggle `ShutdownMode` around runtime upgrades
+    type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+    /// The most open votes `on_initialize` finalizes per block in `ShutdownMode`
+    type ShutdownFinalizationsPerBlock: Get<u32>;
+
+    /// The runtime call `finalize_vote` is scheduled as
+    type ScheduledCall: From<Call<Self>>;
+
+    /// The origin scheduled calls are dispatched with
+    type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+    /// Dispatches `finalize_vote` once each vote with an end expires
+    type Scheduler: ScheduleAnon<
+        Self::BlockNumber,
+        Self::ScheduledCall,
+        Self::PalletsOrigin,
+    >;
+
+    /// Weights measured by this module's benchmarks
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions generated from `benchmarking.rs`
+/// - `p` is the number of voters the vote is opened with
+/// - `c` is the number of taken ids `generate_unique_id` skips
+/// - `v` is the number of vote ids checked in one block of shutdown
+pub trait WeightInfo {
+    fn create_signal_vote(p: u32) -> Weight;
+    fn create_percent_vote(p: u32) -> Weight;
+    fn create_signal_vote_after_id_collisions(c: u32) -> Weight;
+    fn finalize_vote(p: u32) -> Weight;
+    fn on_initialize_shutdown(v: u32) -> Weight;
 }
 
 decl_event!(
@@ -120,9 +416,41 @@ decl_event!(
     where
         <T as System>::AccountId,
         <T as Trait>::VoteId,
+        <T as Trait>::OrgId,
+        <T as Trait>::Signal,
+        Balance = BalanceOf<T>,
     {
         NewVoteStarted(AccountId, VoteId),
-        Voted(VoteId, AccountId, VoterView),
+        /// Creator, org the vote was opened for, vote id
+        NewOrgVoteStarted(AccountId, OrgId, VoteId),
+        VoteCreatorAdded(OrgId, AccountId),
+        VoteCreatorRemoved(OrgId, AccountId),
+        /// Vote id, voter, view, voter's display name if they have one
+        Voted(VoteId, AccountId, VoterView, Option<Vec<u8>>),
+        /// Vote id, bond each voter reserves, whether the losing side is slashed
+        VoteBondsRequired(VoteId, Balance, bool),
+        /// Vote id, creator who changed its configuration
+        VoteConfigured(VoteId, AccountId),
+        /// Vote id, total bonds returned, total bonds slashed
+        BondsSettled(VoteId, Balance, Balance),
+        /// Vote id, account which joined, balance it locked
+        VoteJoined(VoteId, AccountId, Balance),
+        /// Creator, multi round vote id
+        MultiRoundVoteStarted(AccountId, u32),
+        /// Multi round vote id, index of the round just opened
+        MultiRoundVoteAdvanced(u32, u8),
+        /// Multi round vote id, vote of the winning (or leading if none won) candidate in the final round, outcome
+        MultiRoundVoteFinalized(u32, VoteId, VoteOutcome),
+        /// Vote id, outcome when the vote expired
+        VoteFinalized(VoteId, VoteOutcome),
+        /// Vote id, outcome set by root
+        VoteForceFinalized(VoteId, VoteOutcome),
+        /// Whether the pallet is now shutting down
+        ShutdownModeSet(bool),
+        /// Every open vote is finalized while shutting down
+        VotePalletShutdownComplete,
+        /// Vote id, voter, vote power before and after the creator's override
+        VoterSignalOverridden(VoteId, AccountId, Signal, Signal),
     }
 );
 
@@ -137,29 +465,194 @@ decl_error! {
         // i.e. changing from any non-NoVote view to NoVote (some vote changes aren't allowed to simplify assumptions)
         VoteChangeNotSupported,
         InputThresholdExceedsBounds,
+        ThresholdRoundsToZero,
+        CannotRegisterVoterIfVoteStateDNE,
+        CannotRegisterVoterAfterVoteExpired,
+        VoterAlreadyRegisteredForVote,
+        VoterRegisteredAfterSnapshot,
+        SybilCheckFailed,
+        CannotBondVoteIfVoteStateDNE,
+        CannotBondVoteAfterVotingStarted,
+        CannotSettleBondsIfVoteNotBonded,
+        CannotSettleBondsBeforeVoteConcludes,
+        OnlyVoteCreatorCanConfigureVote,
+        VoterSetTotalMustEqualSumOfSignal,
+        VoterSetTooLarge,
+        AccountVoteIndexFull,
+        SignalExceedsBound,
+        PossibleTurnoutOverflow,
+        PossibleTurnoutBelowTally,
+        MultiRoundVoteRequiresCandidates,
+        MultiRoundVoteRequiresAtLeastOneRound,
+        MultiRoundVoteExceedsMaxRounds,
+        MultiRoundVoteDNE,
+        RoundStillInProgress,
+        CandidateCountWitnessTooLow,
+        NotAuthorizedToCreateVoteForOrg,
+        AlreadyAuthorizedToCreateVoteForOrg,
+        NotAVoteCreatorForOrg,
+        CannotRemoveVoterIfVoteStateDNE,
+        CannotRemoveVoterAfterVoteExpired,
+        VoterNotRegisteredForVote,
+        CannotRemoveVoterAfterTheyVoted,
+        RemovingVoterLeavesThresholdUnreachable,
+        OnlyPercentVotesHaveProportionalThresholds,
+        CannotFinalizeVoteIfVoteStateDNE,
+        CannotFinalizeVoteBeforeItExpires,
+        VoteAlreadyFinalized,
+        CannotForceFinalizeVoteWithNonTerminalOutcome,
+        PalletIsShuttingDown,
+        CannotSetGracePeriodAfterVoteExpired,
+        OnlyRetractionAllowedDuringGracePeriod,
+        VoteNotOpenToJoin,
+        CannotJoinVoteWithoutLock,
+        InsufficientBalanceToJoinVote,
+        OnlyVoteCreatorCanOverrideSignal,
+        CannotOverrideSignalIfVoteStateDNE,
+        CannotOverrideSignalAfterVoteExpired,
+        CannotOverrideSignalAfterVoterVoted,
+        CannotOverrideSignalBackedByLock,
+    }
+}
+
+/// Storage layout versions of this module
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// `opaque_blake2_256` hashers and the `VoteStateV1` layout
+    V1OpaqueHashers,
+    /// `blake2_128_concat` hashers and the `VoteStateV2` layout
+    V2ConcatHashers,
+    /// The current `VoteState` layout, which counts distinct voters
+    V3DistinctVoters,
+}
+
+impl Default for Releases {
+    fn default() -> Releases {
+        Releases::V1OpaqueHashers
     }
 }
 
 decl_storage! {
-    trait Store for Module<T: Trait> as Vote {
+    // distinct from the `vote` module's prefix so both fit in one runtime
+    trait Store for Module<T: Trait> as VoteDirect {
         /// The nonce for unique vote id generation
         VoteIdCounter get(fn vote_id_counter): T::VoteId;
 
+        /// The storage layout version, used to gate migrations
+        StorageVersion get(fn storage_version): Releases;
+
         /// The number of open votes
         pub OpenVoteCounter get(fn open_vote_counter): u32;
 
+        /// The most votes that were open at once
+        pub OpenVotePeakCounter get(fn open_vote_peak_counter): u32;
+
+        /// The number of votes ever opened, never decremented
+        pub TotalVotesEverCreated get(fn total_votes_ever_created): u64;
+
+        /// The number of finalized votes with each final outcome
+        pub TotalVotesByOutcome get(fn total_votes_by_outcome): map
+            hasher(blake2_128_concat) VoteOutcome => u64;
+
         /// The state of a vote
         pub VoteStates get(fn vote_states): map
-            hasher(opaque_blake2_256) T::VoteId => Option<VoteSt<T>>;
+            hasher(blake2_128_concat) T::VoteId => Option<VoteSt<T>>;
+
+        /// The block at which each vote's cached outcome was last computed
+        /// -> written with every `VoteStates` change that can move the outcome
+        pub OutcomeComputedAt get(fn outcome_computed_at): map
+            hasher(blake2_128_concat) T::VoteId => Option<T::BlockNumber>;
 
         /// Total signal minted for the vote; sum of all participant signal for the vote
         pub TotalSignalIssuance get(fn total_signal_issuance): map
-            hasher(opaque_blake2_256) T::VoteId => Option<T::Signal>;
+            hasher(blake2_128_concat) T::VoteId => Option<T::Signal>;
 
         /// Tracks all votes and signal for each participating account
         pub VoteLogger get(fn vote_logger): double_map
-            hasher(opaque_blake2_256) T::VoteId,
-            hasher(opaque_blake2_256) T::AccountId  => Option<VoteVec<T>>;
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId  => Option<VoteVec<T>>;
+
+        /// The open votes each account is a voter in, earliest indexed first
+        /// -> holds at most `MaxVotesPerAccount` votes per account
+        pub VotesByAccount get(fn votes_by_account): map
+            hasher(blake2_128_concat) T::AccountId => AccountVotes<T>;
+
+        /// The block at which the voter set was taken from the vote's src
+        pub SnapshotBlock get(fn snapshot_block): map
+            hasher(blake2_128_concat) T::VoteId => Option<T::BlockNumber>;
+
+        /// Voters added after the snapshot and the block they were added
+        pub LateRegistrations get(fn late_registrations): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
+
+        /// The bond every voter reserves to vote, removed once bonds are settled
+        pub BondedVoting get(fn bonded_voting): map
+            hasher(blake2_128_concat) T::VoteId => Option<BondedVotingConfig<BalanceOf<T>>>;
+
+        /// Bonds reserved from voters until the vote's bonds are settled
+        pub VoteBonds get(fn vote_bonds): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+
+        /// Votes which any account may join by locking a balance
+        pub JoinableVotes get(fn joinable_votes): map
+            hasher(blake2_128_concat) T::VoteId => bool;
+
+        /// Balances reserved by the accounts which joined each vote, released when
+        /// the vote is finalized
+        pub VoteLocks get(fn vote_locks): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+
+        /// Nonce for multi round vote identifiers
+        MultiRoundVoteIdCounter get(fn multi_round_vote_id_counter): u32;
+
+        /// Aggregate state of multi round votes, removed once finalized
+        /// -> each round's votes are stored in `VoteStates` like any other vote
+        pub MultiRoundVoteStates get(fn multi_round_vote_states): map
+            hasher(blake2_128_concat) u32 => Option<MultiRound<T>>;
+
+        /// The voter set every round of the multi round vote is opened with
+        pub MultiRoundVoters get(fn multi_round_voters): map
+            hasher(blake2_128_concat) u32 => Option<WeightedVector<T::AccountId, T::Signal>>;
+
+        /// The account which opened each vote by extrinsic
+        /// -> votes opened by other modules have no creator
+        pub VoteCreators get(fn vote_creators): map
+            hasher(blake2_128_concat) T::VoteId => Option<T::AccountId>;
+
+        /// Accounts permitted to open votes scoped to each org
+        pub AuthorizedVoteCreators get(fn authorized_vote_creators): map
+            hasher(blake2_128_concat) T::OrgId => Vec<T::AccountId>;
+
+        /// The percentage threshold a percent vote was opened with
+        pub PercentThresholds get(fn percent_thresholds): map
+            hasher(blake2_128_concat) T::VoteId => Option<Threshold<Permill>>;
+
+        /// Percent votes whose signal threshold follows the total as voters change
+        pub ProportionalThresholds get(fn proportional_thresholds): map
+            hasher(blake2_128_concat) T::VoteId => bool;
+
+        /// The scheduled `finalize_vote` call for votes that have an end
+        pub ScheduledFinalizations get(fn scheduled_finalizations): map
+            hasher(blake2_128_concat) T::VoteId => Option<ScheduleAddress<T>>;
+
+        /// The outcome each vote was finalized with
+        pub FinalOutcomes get(fn final_outcomes): map
+            hasher(blake2_128_concat) T::VoteId => Option<VoteOutcome>;
+
+        /// Blocks after each vote's end during which voters may still retract
+        pub GracePeriods get(fn grace_periods): map
+            hasher(blake2_128_concat) T::VoteId => T::BlockNumber;
+
+        /// Set while a runtime upgrade is pending, no votes may be opened
+        /// -> open votes are finalized by `on_initialize`
+        pub ShutdownMode get(fn shutdown_mode): bool;
+
+        /// The next vote id `on_initialize` checks in `ShutdownMode`
+        /// -> reset whenever `ShutdownMode` is set
+        ShutdownCursor get(fn shutdown_cursor): T::VoteId;
     }
 }
 
@@ -168,7 +661,24 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        fn on_runtime_upgrade() -> Weight {
+            // each step leaves the version the next step expects
+            migrations::v1_to_v2::LegacyMigration::<T>::on_runtime_upgrade()
+                + migrations::v2_to_v3::DistinctVoterMigration::<T>::on_runtime_upgrade()
+        }
+
+        fn on_initialize(_n: T::BlockNumber) -> Weight {
+            if !<ShutdownMode>::get() || <OpenVoteCounter>::get() == 0 {
+                return T::DbWeight::get().reads(2)
+            }
+            let checked = Self::finalize_for_shutdown();
+            if <OpenVoteCounter>::get() == 0 {
+                Self::deposit_event(RawEvent::VotePalletShutdownComplete);
+            }
+            T::WeightInfo::on_initialize_shutdown(checked)
+        }
+
+        #[weight = T::WeightInfo::create_signal_vote(src.vec().len() as u32)]
         pub fn create_signal_vote(
             origin,
             topic: Option<T::Cid>,
@@ -184,11 +694,73 @@ decl_module! {
                 threshold,
                 duration,
             )?;
+            <VoteCreators<T>>::insert(vote_id, &vote_creator);
             // emit event
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        /// Opens a signal vote on behalf of the org, only for its authorized creators
+        #[weight = T::WeightInfo::create_signal_vote(src.vec().len() as u32)]
+        pub fn create_signal_vote_for_org(
+            origin,
+            org_id: T::OrgId,
+            topic: Option<T::Cid>,
+            src: WeightedVector<T::AccountId, T::Signal>,
+            threshold: Threshold<T::Signal>,
+            duration: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let vote_creator = ensure_signed(origin)?;
+            ensure!(
+                <AuthorizedVoteCreators<T>>::get(org_id).contains(&vote_creator),
+                Error::<T>::NotAuthorizedToCreateVoteForOrg
+            );
+            let vote_id = Self::open_vote(
+                topic,
+                src,
+                threshold,
+                duration,
+            )?;
+            <VoteCreators<T>>::insert(vote_id, &vote_creator);
+            Self::deposit_event(RawEvent::NewOrgVoteStarted(vote_creator, org_id, vote_id));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(1, 1)]
+        fn add_vote_creator(
+            origin,
+            org_id: T::OrgId,
+            creator: T::AccountId,
+        ) -> DispatchResult {
+            T::OrgAdminOrigin::ensure_origin(origin)?;
+            <AuthorizedVoteCreators<T>>::try_mutate(org_id, |creators| -> DispatchResult {
+                ensure!(
+                    !creators.contains(&creator),
+                    Error::<T>::AlreadyAuthorizedToCreateVoteForOrg
+                );
+                creators.push(creator.clone());
+                Ok(())
+            })?;
+            Self::deposit_event(RawEvent::VoteCreatorAdded(org_id, creator));
+            Ok(())
+        }
+        #[weight = T::DbWeight::get().reads_writes(1, 1)]
+        fn remove_vote_creator(
+            origin,
+            org_id: T::OrgId,
+            creator: T::AccountId,
+        ) -> DispatchResult {
+            T::OrgAdminOrigin::ensure_origin(origin)?;
+            <AuthorizedVoteCreators<T>>::try_mutate(org_id, |creators| -> DispatchResult {
+                let index = creators
+                    .iter()
+                    .position(|c| c == &creator)
+                    .ok_or(Error::<T>::NotAVoteCreatorForOrg)?;
+                creators.remove(index);
+                Ok(())
+            })?;
+            Self::deposit_event(RawEvent::VoteCreatorRemoved(org_id, creator));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::create_percent_vote(src.vec().len() as u32)]
         pub fn create_percent_vote(
             origin,
             topic: Option<T::Cid>,
@@ -204,10 +776,50 @@ decl_module! {
                 threshold,
                 duration,
             )?;
+            <VoteCreators<T>>::insert(vote_id, &vote_creator);
             // emit event
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, vote_id));
             Ok(())
         }
+        #[weight = T::WeightInfo::create_signal_vote(src.vec().len() as u32)]
+        pub fn create_compound_threshold_vote(
+            origin,
+            topic: Option<T::Cid>,
+            src: WeightedVector<T::AccountId, T::Signal>,
+            threshold: MultipleThreshold<T::Signal>,
+            duration: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let vote_creator = ensure_signed(origin)?;
+            // call helper method
+            let vote_id = Self::open_compound_vote(
+                topic,
+                src,
+                threshold,
+                duration,
+            )?;
+            <VoteCreators<T>>::insert(vote_id, &vote_creator);
+            // emit event
+            Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, vote_id));
+            Ok(())
+        }
+        /// Corrects the signal of a voter who hasn't voted yet, only for the vote's
+        /// creator
+        #[weight = T::DbWeight::get().reads_writes(9, 4)]
+        fn override_voter_signal(
+            origin,
+            vote_id: T::VoteId,
+            voter: T::AccountId,
+            new_signal: T::Signal,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(
+                <VoteCreators<T>>::get(vote_id) == Some(caller),
+                Error::<T>::OnlyVoteCreatorCanOverrideSignal
+            );
+            let (old_power, new_power) = Self::set_voter_signal(vote_id, &voter, new_signal)?;
+            Self::deposit_event(RawEvent::VoterSignalOverridden(vote_id, voter, old_power, new_power));
+            Ok(())
+        }
         #[weight = 0]
         pub fn submit_vote(
             origin,
@@ -217,13 +829,223 @@ decl_module! {
         ) -> DispatchResult {
             let voter = ensure_signed(origin)?;
             Self::vote_on_proposal(vote_id, voter.clone(), direction, justification)?;
-            Self::deposit_event(RawEvent::Voted(vote_id, voter, direction));
+            let display_name = T::IdentityProvider::display_name(&voter);
+            Self::deposit_event(RawEvent::Voted(vote_id, voter, direction, display_name));
+            Ok(())
+        }
+        /// Requires every voter to bond before voting, only for the vote's creator
+        /// before anyone votes
+        #[weight = T::DbWeight::get().reads_writes(2, 1)]
+        fn require_vote_bonds(
+            origin,
+            vote_id: T::VoteId,
+            config: BondedVotingConfig<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(
+                <VoteCreators<T>>::get(vote_id) == Some(caller),
+                Error::<T>::OnlyVoteCreatorCanConfigureVote
+            );
+            Self::set_bonded_voting(vote_id, config)?;
+            Self::deposit_event(RawEvent::VoteBondsRequired(
+                vote_id,
+                config.bond_amount(),
+                config.slash_on_losing_side(),
+            ));
+            Ok(())
+        }
+        /// Sets the blocks voters may retract their votes for after the vote ends,
+        /// only for the vote's creator
+        #[weight = T::DbWeight::get().reads_writes(4, 4)]
+        fn configure_grace_period(
+            origin,
+            vote_id: T::VoteId,
+            grace_period: T::BlockNumber,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(
+                <VoteCreators<T>>::get(vote_id) == Some(caller.clone()),
+                Error::<T>::OnlyVoteCreatorCanConfigureVote
+            );
+            Self::set_grace_period(vote_id, grace_period)?;
+            Self::deposit_event(RawEvent::VoteConfigured(vote_id, caller));
+            Ok(())
+        }
+        /// Toggles whether any account may join the vote, only for the vote's creator
+        #[weight = T::DbWeight::get().reads_writes(2, 1)]
+        fn configure_joinable(
+            origin,
+            vote_id: T::VoteId,
+            joinable: bool,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(
+                <VoteCreators<T>>::get(vote_id) == Some(caller.clone()),
+                Error::<T>::OnlyVoteCreatorCanConfigureVote
+            );
+            Self::set_joinable(vote_id, joinable)?;
+            Self::deposit_event(RawEvent::VoteConfigured(vote_id, caller));
+            Ok(())
+        }
+        /// Returns or slashes the vote's bonds, once for every bonded voter
+        #[weight = T::DbWeight::get().reads_writes(3 + 3 * T::MaxVotersPerVote::get() as u64, 1 + 3 * T::MaxVotersPerVote::get() as u64)]
+        pub fn settle_bonds(
+            origin,
+            vote_id: T::VoteId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let (returned, slashed) = Self::settle_vote_bonds(vote_id)?;
+            Self::deposit_event(RawEvent::BondsSettled(vote_id, returned, slashed));
+            Ok(())
+        }
+        /// Registers the caller in a joinable vote with signal equal to the balance
+        /// it locks, until the vote is finalized
+        #[weight = T::DbWeight::get().reads_writes(T::MaxVotersPerVote::get() as u64 + 9, 8)]
+        pub fn join_vote(
+            origin,
+            vote_id: T::VoteId,
+            lock_amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            Self::join_with_lock(vote_id, voter.clone(), lock_amount)?;
+            Self::deposit_event(RawEvent::VoteJoined(vote_id, voter, lock_amount));
+            Ok(())
+        }
+        /// Opens the first round with one vote per candidate
+        #[weight = T::WeightInfo::create_signal_vote(src.vec().len() as u32)
+            .saturating_mul(candidates.len() as Weight)
+            .saturating_add(T::DbWeight::get().reads_writes(1, 3))]
+        pub fn create_multiround_vote(
+            origin,
+            candidates: Vec<T::Cid>,
+            src: WeightedVector<T::AccountId, T::Signal>,
+            rounds: Vec<Round<T>>,
+        ) -> DispatchResult {
+            let vote_creator = ensure_signed(origin)?;
+            let id = Self::open_multiround_vote(candidates, src, rounds)?;
+            Self::deposit_event(RawEvent::MultiRoundVoteStarted(vote_creator, id));
+            Ok(())
+        }
+        /// Finalizes the multi round vote or opens its next round
+        /// -> `candidate_count` must be at least the number of candidates in the
+        /// current round
+        #[weight = T::WeightInfo::create_signal_vote(T::MaxVotersPerVote::get())
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_mul(candidate_count as Weight)
+            .saturating_add(T::DbWeight::get().reads_writes(2, 3))]
+        pub fn advance_to_next_round(
+            origin,
+            multi_round_vote_id: u32,
+            candidate_count: u32,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let multi_round = <MultiRoundVoteStates<T>>::get(multi_round_vote_id)
+                .ok_or(Error::<T>::MultiRoundVoteDNE)?;
+            ensure!(
+                multi_round.current_votes().len() as u32 <= candidate_count,
+                Error::<T>::CandidateCountWitnessTooLow
+            );
+            Self::advance_multiround_vote(multi_round_vote_id)?;
+            Ok(())
+        }
+        /// Records the outcome of an expired vote and closes it
+        /// -> dispatched by the scheduler in the first block after the vote ends
+        #[weight = T::WeightInfo::finalize_vote(T::MaxVotersPerVote::get())]
+        fn finalize_vote(
+            origin,
+            vote_id: T::VoteId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let vote_state = <VoteStates<T>>::get(vote_id)
+                .ok_or(Error::<T>::CannotFinalizeVoteIfVoteStateDNE)?;
+            ensure!(<FinalOutcomes<T>>::get(vote_id).is_none(), Error::<T>::VoteAlreadyFinalized);
+            ensure!(Self::check_vote_sealed(vote_id, &vote_state), Error::<T>::CannotFinalizeVoteBeforeItExpires);
+            let outcome = match vote_state.outcome() {
+                VoteOutcome::NotStarted | VoteOutcome::Voting => VoteOutcome::ExpiredInconclusive,
+                outcome => outcome,
+            };
+            Self::close_vote(vote_id, outcome);
+            Self::deposit_event(RawEvent::VoteFinalized(vote_id, outcome));
+            Ok(())
+        }
+        /// Closes a vote with the given outcome, whether or not it has expired
+        /// -> escape hatch for votes wedged by a bug, so the outcome must be terminal
+        #[weight = T::WeightInfo::finalize_vote(T::MaxVotersPerVote::get())]
+        fn force_finalize_vote(
+            origin,
+            vote_id: T::VoteId,
+            outcome: VoteOutcome,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                !matches!(outcome, VoteOutcome::NotStarted | VoteOutcome::Voting),
+                Error::<T>::CannotForceFinalizeVoteWithNonTerminalOutcome
+            );
+            ensure!(<VoteStates<T>>::get(vote_id).is_some(), Error::<T>::CannotFinalizeVoteIfVoteStateDNE);
+            ensure!(<FinalOutcomes<T>>::get(vote_id).is_none(), Error::<T>::VoteAlreadyFinalized);
+            Self::close_vote(vote_id, outcome);
+            Self::deposit_event(RawEvent::VoteForceFinalized(vote_id, outcome));
+            Ok(())
+        }
+        /// Stops new votes from opening and finalizes the open ones, or resumes
+        /// normal operation once the upgrade is done
+        #[weight = T::DbWeight::get().reads_writes(1, 2)]
+        fn set_shutdown_mode(
+            origin,
+            shutting_down: bool,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            <ShutdownMode>::put(shutting_down);
+            <ShutdownCursor<T>>::kill();
+            Self::deposit_event(RawEvent::ShutdownModeSet(shutting_down));
+            if shutting_down && <OpenVoteCounter>::get() == 0 {
+                Self::deposit_event(RawEvent::VotePalletShutdownComplete);
+            }
             Ok(())
         }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Takes a double map entry stored under the legacy opaque hasher
+    fn take_legacy_double_map_entry<V: Decode + Sized>(
+        item: &[u8],
+        vote_id: T::VoteId,
+        who: &T::AccountId,
+    ) -> Option<V> {
+        let mut hash = Blake2_256::hash(&vote_id.encode()).to_vec();
+        hash.extend_from_slice(&Blake2_256::hash(&who.encode()));
+        take_storage_value(b"VoteDirect", item, &hash)
+    }
+    /// Reads the voter's entry, moving it from the legacy hasher if necessary
+    fn vote_logger_entry(
+        vote_id: T::VoteId,
+        who: &T::AccountId,
+    ) -> Option<VoteVec<T>> {
+        <VoteLogger<T>>::get(vote_id, who).or_else(|| {
+            let entry = Self::take_legacy_double_map_entry::<VoteVec<T>>(
+                b"VoteLogger",
+                vote_id,
+                who,
+            )?;
+            <VoteLogger<T>>::insert(vote_id, who, entry.clone());
+            Some(entry)
+        })
+    }
+    fn late_registration_entry(
+        vote_id: T::VoteId,
+        who: &T::AccountId,
+    ) -> Option<T::BlockNumber> {
+        <LateRegistrations<T>>::get(vote_id, who).or_else(|| {
+            let registered = Self::take_legacy_double_map_entry(
+                b"LateRegistrations",
+                vote_id,
+                who,
+            )?;
+            <LateRegistrations<T>>::insert(vote_id, who, registered);
+            Some(registered)
+        })
+    }
     fn valid_signal_threshold(
         threshold: &Threshold<T::Signal>,
         all_possible_turnout: T::Signal,
@@ -249,6 +1071,746 @@ impl<T: Trait> Module<T> {
         };
         Threshold::new(in_favor_t, against_t)
     }
+    /// Rejects a non-zero percentage that converted to a zero signal threshold
+    /// -> only possible for a voter set without signal
+    fn ensure_threshold_not_rounded_to_zero(
+        percent: &Threshold<Permill>,
+        signal: &Threshold<T::Signal>,
+    ) -> DispatchResult {
+        let rounded_to_zero = |p: Permill, s: T::Signal| {
+            p != Permill::from_parts(0) && s.is_zero()
+        };
+        let against_rounded = match (percent.against(), signal.against()) {
+            (Some(p), Some(s)) => rounded_to_zero(p, s),
+            _ => false,
+        };
+        ensure!(
+            !rounded_to_zero(percent.in_favor(), signal.in_favor())
+                && !against_rounded,
+            Error::<T>::ThresholdRoundsToZero
+        );
+        Ok(())
+    }
+    /// Adds a voter to an open vote after its snapshot was taken
+    pub fn register_voter(
+        vote_id: T::VoteId,
+        who: T::AccountId,
+        signal: T::Signal,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotRegisterVoterIfVoteStateDNE)?;
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::CannotRegisterVoterAfterVoteExpired
+        );
+        ensure!(
+            Self::vote_logger_entry(vote_id, &who).is_none(),
+            Error::<T>::VoterAlreadyRegisteredForVote
+        );
+        ensure!(
+            (<VoteLogger<T>>::iter_prefix(vote_id).count() as u32)
+                < T::MaxVotersPerVote::get(),
+            Error::<T>::VoterSetTooLarge
+        );
+        let vote_power = T::VoteWeight::transform(signal);
+        ensure!(
+            Self::total_signal_issuance(vote_id)
+                .unwrap_or_else(Zero::zero)
+                .checked_add(&vote_power)
+                .map_or(false, |total| total <= T::MaxSignalPerVote::get()),
+            Error::<T>::SignalExceedsBound
+        );
+        let new_vote_state = vote_state
+            .add_possible_turnout(vote_power)
+            .ok_or(Error::<T>::PossibleTurnoutOverflow)?;
+        Self::index_voter(vote_id, &who)?;
+        let new_vote = Vote::new(vote_power, VoterView::Uninitialized, None);
+        <VoteLogger<T>>::insert(vote_id, &who, new_vote);
+        <TotalSignalIssuance<T>>::mutate(vote_id, |total| {
+            *total = Some(total.unwrap_or_else(Zero::zero) + vote_power)
+        });
+        Self::store_vote_state(
+            vote_id,
+            Self::rederive_threshold(vote_id, new_vote_state),
+        );
+        let now = frame_system::Module::<T>::block_number();
+        <LateRegistrations<T>>::insert(vote_id, who, now);
+        Ok(())
+    }
+    /// Removes a voter from an open vote before they vote
+    pub fn remove_voter(
+        vote_id: T::VoteId,
+        who: T::AccountId,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotRemoveVoterIfVoteStateDNE)?;
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::CannotRemoveVoterAfterVoteExpired
+        );
+        let entry = Self::vote_logger_entry(vote_id, &who)
+            .ok_or(Error::<T>::VoterNotRegisteredForVote)?;
+        ensure!(
+            entry.direction() == VoterView::Uninitialized,
+            Error::<T>::CannotRemoveVoterAfterTheyVoted
+        );
+        let new_vote_state = Self::rederive_threshold(
+            vote_id,
+            vote_state
+                .subtract_possible_turnout(entry.magnitude())
+                .ok_or(Error::<T>::PossibleTurnoutBelowTally)?,
+        );
+        ensure!(
+            Self::valid_signal_threshold(
+                &new_vote_state.threshold(),
+                new_vote_state.all_possible_turnout()
+            ),
+            Error::<T>::RemovingVoterLeavesThresholdUnreachable
+        );
+        <VoteLogger<T>>::remove(vote_id, &who);
+        Self::unindex_voter(vote_id, &who);
+        <LateRegistrations<T>>::remove(vote_id, &who);
+        <TotalSignalIssuance<T>>::mutate(vote_id, |total| {
+            *total = total.map(|t| t.saturating_sub(entry.magnitude()))
+        });
+        Self::store_vote_state(vote_id, new_vote_state);
+        Ok(())
+    }
+    /// Replaces the signal of a voter who hasn't voted yet
+    /// -> returns the voter's vote power before and after
+    pub fn set_voter_signal(
+        vote_id: T::VoteId,
+        who: &T::AccountId,
+        signal: T::Signal,
+    ) -> Result<(T::Signal, T::Signal), DispatchError> {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotOverrideSignalIfVoteStateDNE)?;
+        ensure!(
+            <FinalOutcomes<T>>::get(vote_id).is_none(),
+            Error::<T>::VoteAlreadyFinalized
+        );
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::CannotOverrideSignalAfterVoteExpired
+        );
+        let entry = Self::vote_logger_entry(vote_id, who)
+            .ok_or(Error::<T>::VoterNotRegisteredForVote)?;
+        ensure!(
+            entry.direction() == VoterView::Uninitialized,
+            Error::<T>::CannotOverrideSignalAfterVoterVoted
+        );
+        // a joined voter's signal is the balance it locked
+        ensure!(
+            !<VoteLocks<T>>::contains_key(vote_id, who),
+            Error::<T>::CannotOverrideSignalBackedByLock
+        );
+        let old_power = entry.magnitude();
+        let new_power = T::VoteWeight::transform(signal);
+        let new_total = Self::total_signal_issuance(vote_id)
+            .unwrap_or_else(Zero::zero)
+            .saturating_sub(old_power)
+            .saturating_add(new_power);
+        ensure!(
+            new_total <= T::MaxSignalPerVote::get(),
+            Error::<T>::SignalExceedsBound
+        );
+        let new_vote_state = Self::rederive_threshold(
+            vote_id,
+            vote_state
+                .subtract_possible_turnout(old_power)
+                .ok_or(Error::<T>::PossibleTurnoutBelowTally)?
+                .add_possible_turnout(new_power)
+                .ok_or(Error::<T>::PossibleTurnoutOverflow)?,
+        );
+        ensure!(
+            Self::valid_signal_threshold(
+                &new_vote_state.threshold(),
+                new_vote_state.all_possible_turnout()
+            ),
+            Error::<T>::InputThresholdExceedsBounds
+        );
+        let new_vote = Vote::new(new_power, VoterView::Uninitialized, None);
+        <VoteLogger<T>>::insert(vote_id, who, new_vote);
+        <TotalSignalIssuance<T>>::insert(vote_id, new_total);
+        Self::store_vote_state(vote_id, new_vote_state);
+        Ok((old_power, new_power))
+    }
+    /// Toggles whether the percent vote's signal threshold is re-derived when
+    /// voters are added or removed
+    pub fn set_proportional_threshold(
+        vote_id: T::VoteId,
+        proportional: bool,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotUpdateVoteIfVoteStateDNE)?;
+        ensure!(
+            <PercentThresholds<T>>::get(vote_id).is_some(),
+            Error::<T>::OnlyPercentVotesHaveProportionalThresholds
+        );
+        if proportional {
+            <ProportionalThresholds<T>>::insert(vote_id, true);
+            Self::store_vote_state(
+                vote_id,
+                Self::rederive_threshold(vote_id, vote_state),
+            );
+        } else {
+            <ProportionalThresholds<T>>::remove(vote_id);
+        }
+        Ok(())
+    }
+    /// Lets voters retract their votes for `grace_period` blocks after the vote
+    /// ends, before its outcome is sealed
+    /// -> zero removes the grace period
+    pub fn set_grace_period(
+        vote_id: T::VoteId,
+        grace_period: T::BlockNumber,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotUpdateVoteIfVoteStateDNE)?;
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::CannotSetGracePeriodAfterVoteExpired
+        );
+        if grace_period.is_zero() {
+            <GracePeriods<T>>::remove(vote_id);
+        } else {
+            <GracePeriods<T>>::insert(vote_id, grace_period);
+        }
+        // the vote is finalized once the new grace period passes
+        if let Some(address) = <ScheduledFinalizations<T>>::take(vote_id) {
+            let _ = T::Scheduler::cancel(address);
+            Self::schedule_finalization(vote_id, vote_state.ends())?;
+        }
+        Ok(())
+    }
+    /// Whether the vote's grace period after expiry has passed, after which no
+    /// vote may change
+    /// -> the same as `check_vote_expired` for votes without a grace period
+    fn check_vote_sealed(vote_id: T::VoteId, state: &VoteSt<T>) -> bool {
+        let now = frame_system::Module::<T>::block_number();
+        state
+            .ends()
+            .map(|ends| ends + <GracePeriods<T>>::get(vote_id) < now)
+            .unwrap_or(false)
+    }
+    /// Schedules `finalize_vote` for the first block the vote is sealed in
+    fn schedule_finalization(
+        vote_id: T::VoteId,
+        ends: Option<T::BlockNumber>,
+    ) -> DispatchResult {
+        if let Some(end) = ends {
+            let sealed = end + <GracePeriods<T>>::get(vote_id) + One::one();
+            let address = T::Scheduler::schedule(
+                DispatchTime::At(sealed),
+                None,
+                LOWEST_PRIORITY,
+                frame_system::RawOrigin::<T::AccountId>::Root.into(),
+                Call::<T>::finalize_vote(vote_id).into(),
+            )?;
+            <ScheduledFinalizations<T>>::insert(vote_id, address);
+        }
+        Ok(())
+    }
+    /// Stores a vote state whose cached outcome was just computed
+    fn store_vote_state(vote_id: T::VoteId, state: VoteSt<T>) {
+        <VoteStates<T>>::insert(vote_id, state);
+        <OutcomeComputedAt<T>>::insert(
+            vote_id,
+            frame_system::Module::<T>::block_number(),
+        );
+    }
+    /// The open votes the account is a voter in
+    pub fn votes_of(account: T::AccountId) -> Vec<T::VoteId> {
+        <VotesByAccount<T>>::get(account).to_vec()
+    }
+    /// Adds the vote to the voter's `VotesByAccount` entry
+    /// -> fails if the entry is full
+    fn index_voter(vote_id: T::VoteId, who: &T::AccountId) -> DispatchResult {
+        let mut votes = <VotesByAccount<T>>::get(who);
+        votes
+            .insert(vote_id)
+            .map_err(|_| Error::<T>::AccountVoteIndexFull)?;
+        <VotesByAccount<T>>::insert(who, votes);
+        Ok(())
+    }
+    /// Removes the vote from the voter's `VotesByAccount` entry
+    fn unindex_voter(vote_id: T::VoteId, who: &T::AccountId) {
+        let mut votes = <VotesByAccount<T>>::get(who);
+        votes.remove(&vote_id);
+        if votes.is_empty() {
+            <VotesByAccount<T>>::remove(who);
+        } else {
+            <VotesByAccount<T>>::insert(who, votes);
+        }
+    }
+    /// Finalizes the open votes among the next `ShutdownFinalizationsPerBlock`
+    /// vote ids from `ShutdownCursor` with their current outcome, undecided
+    /// votes as `ExpiredInconclusive`
+    /// -> returns the number of vote ids checked
+    fn finalize_for_shutdown() -> u32 {
+        let limit = T::ShutdownFinalizationsPerBlock::get();
+        let last = <VoteIdCounter<T>>::get();
+        let mut cursor = <ShutdownCursor<T>>::get().max(1u32.into());
+        let mut checked = 0u32;
+        while checked < limit && cursor <= last {
+            let vote_id = cursor;
+            cursor += 1u32.into();
+            checked += 1;
+            if <FinalOutcomes<T>>::contains_key(vote_id) {
+                continue
+            }
+            if let Some(vote_state) = <VoteStates<T>>::get(vote_id) {
+                let outcome = match vote_state.outcome() {
+                    VoteOutcome::NotStarted | VoteOutcome::Voting => {
+                        VoteOutcome::ExpiredInconclusive
+                    }
+                    outcome => outcome,
+                };
+                Self::close_vote(vote_id, outcome);
+                Self::deposit_event(RawEvent::VoteFinalized(vote_id, outcome));
+            }
+        }
+        // every id was checked so start over in case any vote was missed
+        if cursor > last {
+            cursor = Zero::zero();
+        }
+        <ShutdownCursor<T>>::put(cursor);
+        checked
+    }
+    /// Counts a newly opened vote in the open, peak and total counters
+    fn note_vote_opened() {
+        let open = <OpenVoteCounter>::get() + 1u32;
+        <OpenVoteCounter>::put(open);
+        <OpenVotePeakCounter>::mutate(|peak| *peak = open.max(*peak));
+        <TotalVotesEverCreated>::mutate(|total| {
+            *total = total.saturating_add(1)
+        });
+    }
+    /// The open, peak and total vote counts and the finalized votes by outcome
+    pub fn get_vote_metrics() -> VoteMetrics {
+        VoteMetrics::new(
+            <OpenVoteCounter>::get(),
+            <OpenVotePeakCounter>::get(),
+            <TotalVotesEverCreated>::get(),
+            <TotalVotesByOutcome>::iter().collect(),
+        )
+    }
+    /// Stores the final outcome and removes the vote from the open vote count
+    fn close_vote(vote_id: T::VoteId, outcome: VoteOutcome) {
+        debug::native::debug!(
+            target: LOG_TARGET,
+            "vote {:?} finalized with {:?}",
+            vote_id,
+            outcome,
+        );
+        <FinalOutcomes<T>>::insert(vote_id, outcome);
+        <TotalVotesByOutcome>::mutate(outcome, |n| *n = n.saturating_add(1));
+        <VoteLogger<T>>::iter_prefix(vote_id)
+            .for_each(|(who, _)| Self::unindex_voter(vote_id, &who));
+        <JoinableVotes<T>>::remove(vote_id);
+        <VoteLocks<T>>::drain_prefix(vote_id).for_each(|(who, lock)| {
+            let _ = T::Currency::unreserve(&who, lock);
+        });
+        // fails for the call being dispatched, which is no longer scheduled
+        if let Some(address) = <ScheduledFinalizations<T>>::take(vote_id) {
+            let _ = T::Scheduler::cancel(address);
+        }
+        <OpenVoteCounter>::mutate(|count| *count = count.saturating_sub(1));
+    }
+    /// Recomputes the signal threshold from the vote's original percentage
+    /// -> unchanged unless the vote has a proportional threshold
+    fn rederive_threshold(
+        vote_id: T::VoteId,
+        vote_state: VoteSt<T>,
+    ) -> VoteSt<T> {
+        if !<ProportionalThresholds<T>>::get(vote_id) {
+            return vote_state
+        }
+        match <PercentThresholds<T>>::get(vote_id) {
+            Some(percent) => {
+                let threshold = Self::from_permill_to_signal(
+                    &percent,
+                    vote_state.all_possible_turnout(),
+                );
+                vote_state.set_threshold(threshold)
+            }
+            None => vote_state,
+        }
+    }
+    /// Toggles whether voters registered after the snapshot may vote
+    pub fn set_disallow_late_registration(
+        vote_id: T::VoteId,
+        disallow: bool,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotUpdateVoteIfVoteStateDNE)?;
+        Self::store_vote_state(
+            vote_id,
+            vote_state.set_disallow_late_registration(disallow),
+        );
+        Ok(())
+    }
+    /// Toggles whether any account may join the vote with `join_vote`
+    pub fn set_joinable(vote_id: T::VoteId, joinable: bool) -> DispatchResult {
+        ensure!(
+            <VoteStates<T>>::contains_key(vote_id),
+            Error::<T>::CannotUpdateVoteIfVoteStateDNE
+        );
+        if joinable {
+            <JoinableVotes<T>>::insert(vote_id, true);
+        } else {
+            <JoinableVotes<T>>::remove(vote_id);
+        }
+        Ok(())
+    }
+    /// Reserves `lock_amount` from the account and registers it as a voter with
+    /// as much signal
+    fn join_with_lock(
+        vote_id: T::VoteId,
+        who: T::AccountId,
+        lock_amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(
+            <JoinableVotes<T>>::get(vote_id),
+            Error::<T>::VoteNotOpenToJoin
+        );
+        ensure!(
+            !lock_amount.is_zero(),
+            Error::<T>::CannotJoinVoteWithoutLock
+        );
+        ensure!(
+            T::Currency::can_reserve(&who, lock_amount),
+            Error::<T>::InsufficientBalanceToJoinVote
+        );
+        let signal: T::Signal =
+            lock_amount.saturated_into::<u128>().saturated_into();
+        // fails before writing if the vote expired, the account is a voter or
+        // the voter set would exceed its bounds
+        Self::register_voter(vote_id, who.clone(), signal)?;
+        T::Currency::reserve(&who, lock_amount)?;
+        <VoteLocks<T>>::insert(vote_id, who, lock_amount);
+        Ok(())
+    }
+    /// Requires voters to reserve a bond to vote
+    /// -> only set before anyone votes so every voter is bonded
+    pub fn set_bonded_voting(
+        vote_id: T::VoteId,
+        config: BondedVotingConfig<BalanceOf<T>>,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotBondVoteIfVoteStateDNE)?;
+        ensure!(
+            vote_state.turnout() == Zero::zero(),
+            Error::<T>::CannotBondVoteAfterVotingStarted
+        );
+        <BondedVoting<T>>::insert(vote_id, config);
+        Ok(())
+    }
+    /// Returns or slashes every bond once the vote is finalized or sealed
+    /// -> an open vote's outcome may still change, so its bonds stay reserved
+    /// -> returns the totals returned and slashed
+    pub fn settle_vote_bonds(
+        vote_id: T::VoteId,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        let config = <BondedVoting<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotSettleBondsIfVoteNotBonded)?;
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForOutcomeQuery)?;
+        let outcome = match <FinalOutcomes<T>>::get(vote_id) {
+            Some(outcome) => outcome,
+            None if Self::check_vote_sealed(vote_id, &vote_state) => {
+                vote_state.outcome()
+            }
+            None => {
+                return Err(
+                    Error::<T>::CannotSettleBondsBeforeVoteConcludes.into()
+                )
+            }
+        };
+        let losing_side = match outcome {
+            VoteOutcome::Approved => Some(VoterView::Against),
+            VoteOutcome::Rejected => Some(VoterView::InFavor),
+            // inconclusive votes return every bond
+            _ => None,
+        };
+        let recipient = T::BondSlashRecipient::get();
+        let (mut returned, mut slashed) =
+            (BalanceOf::<T>::zero(), BalanceOf::<T>::zero());
+        // collect first because the bonds are removed while settling
+        let bonds: Vec<(T::AccountId, BalanceOf<T>)> =
+            <VoteBonds<T>>::drain_prefix(vote_id).collect();
+        for (voter, bond) in bonds {
+            let lost = config.slash_on_losing_side()
+                && losing_side.is_some()
+                && Self::vote_logger_entry(vote_id, &voter)
+                    .map(|v| Some(v.direction()) == losing_side)
+                    .unwrap_or(false);
+            let repatriated = if lost {
+                T::Currency::repatriate_reserved(
+                    &voter,
+                    &recipient,
+                    bond,
+                    BalanceStatus::Free,
+                )
+                .ok()
+            } else {
+                None
+            };
+            if let Some(missing) = repatriated {
+                slashed += bond - missing;
+            } else {
+                // bonds that can't be slashed are returned rather than stuck
+                let _ = T::Currency::unreserve(&voter, bond);
+                returned += bond;
+            }
+        }
+        <BondedVoting<T>>::remove(vote_id);
+        Ok((returned, slashed))
+    }
+    /// Mints each voter's vote power for the vote
+    /// -> returns the total vote power minted
+    fn mint_signal(
+        vote_id: T::VoteId,
+        src: &WeightedVector<T::AccountId, T::Signal>,
+    ) -> Result<T::Signal, DispatchError> {
+        let mut minted: T::Signal = Zero::zero();
+        src.mint_into(|who, signal| {
+            let vote_power = T::VoteWeight::transform(*signal);
+            minted = minted.saturating_add(vote_power);
+            let new_vote =
+                Vote::new(vote_power, VoterView::Uninitialized, None);
+            <VoteLogger<T>>::insert(vote_id, who, new_vote);
+        })
+        .map_err(|_| Error::<T>::VoterSetTotalMustEqualSumOfSignal)?;
+        // every entry was checked for room before the vote was opened
+        for (who, _) in src.vec() {
+            Self::index_voter(vote_id, &who)?;
+        }
+        Ok(minted)
+    }
+    /// Checks that every voter's `VotesByAccount` entry has room for `votes`
+    /// more votes
+    fn ensure_voters_indexable(
+        src: &WeightedVector<T::AccountId, T::Signal>,
+        votes: usize,
+    ) -> DispatchResult {
+        let capacity = AccountVotes::<T>::capacity();
+        ensure!(
+            src.vec().iter().all(|(who, _)| {
+                <VotesByAccount<T>>::get(who).len() + votes <= capacity
+            }),
+            Error::<T>::AccountVoteIndexFull
+        );
+        Ok(())
+    }
+    /// Checks the voter set against `MaxVotersPerVote` and `MaxSignalPerVote`
+    fn ensure_voter_set_within_bounds(
+        src: &WeightedVector<T::AccountId, T::Signal>,
+    ) -> DispatchResult {
+        ensure!(
+            src.vec().len() as u32 <= T::MaxVotersPerVote::get(),
+            Error::<T>::VoterSetTooLarge
+        );
+        ensure!(
+            src.total() <= T::MaxSignalPerVote::get(),
+            Error::<T>::SignalExceedsBound
+        );
+        Ok(())
+    }
+    /// Total vote power of the voter set, the possible turnout of its votes
+    fn vote_power_total(
+        src: &WeightedVector<T::AccountId, T::Signal>,
+    ) -> T::Signal {
+        src.vec()
+            .into_iter()
+            .fold(Zero::zero(), |total, (_, signal)| {
+                total.saturating_add(T::VoteWeight::transform(signal))
+            })
+    }
+    /// Opens a vote for each candidate with the round's configuration
+    fn open_round(
+        candidates: Vec<T::Cid>,
+        src: &WeightedVector<T::AccountId, T::Signal>,
+        spec: &Round<T>,
+    ) -> Result<Vec<(T::Cid, T::VoteId)>, DispatchError> {
+        // check once so no vote is opened for a round that can't be opened
+        Self::ensure_voter_set_within_bounds(src)?;
+        Self::ensure_voters_indexable(src, candidates.len())?;
+        ensure!(
+            Self::valid_signal_threshold(
+                &spec.threshold(),
+                Self::vote_power_total(src)
+            ),
+            Error::<T>::InputThresholdExceedsBounds
+        );
+        let mut votes = Vec::new();
+        for candidate in candidates {
+            let vote_id = Self::open_vote(
+                Some(candidate),
+                src.clone(),
+                spec.threshold(),
+                spec.duration(),
+            )?;
+            votes.push((candidate, vote_id));
+        }
+        Ok(votes)
+    }
+    /// Opens the first round of a multi round vote
+    pub fn open_multiround_vote(
+        candidates: Vec<T::Cid>,
+        src: WeightedVector<T::AccountId, T::Signal>,
+        rounds: Vec<Round<T>>,
+    ) -> Result<u32, DispatchError> {
+        ensure!(
+            !candidates.is_empty(),
+            Error::<T>::MultiRoundVoteRequiresCandidates
+        );
+        ensure!(
+            !rounds.is_empty(),
+            Error::<T>::MultiRoundVoteRequiresAtLeastOneRound
+        );
+        // `current_round` is a u8 index
+        ensure!(
+            rounds.len() <= u8::MAX as usize + 1,
+            Error::<T>::MultiRoundVoteExceedsMaxRounds
+        );
+        let first_round = Self::open_round(candidates, &src, &rounds[0])?;
+        let id = <MultiRoundVoteIdCounter>::get() + 1u32;
+        <MultiRoundVoteIdCounter>::put(id);
+        <MultiRoundVoteStates<T>>::insert(
+            id,
+            MultiRoundVote::new(rounds, first_round),
+        );
+        <MultiRoundVoters<T>>::insert(id, src);
+        Ok(id)
+    }
+    /// Finalizes the multi round vote if the current round approved a candidate or
+    /// was the last round, otherwise opens the next round for the top candidates
+    pub fn advance_multiround_vote(id: u32) -> DispatchResult {
+        let multi_round = <MultiRoundVoteStates<T>>::get(id)
+            .ok_or(Error::<T>::MultiRoundVoteDNE)?;
+        let mut standings = Vec::new();
+        for (candidate, vote_id) in multi_round.current_votes() {
+            let state = <VoteStates<T>>::get(vote_id)
+                .ok_or(Error::<T>::NoVoteStateForOutcomeQuery)?;
+            // an open vote's outcome can still change
+            let outcome = match <FinalOutcomes<T>>::get(vote_id) {
+                Some(outcome) => outcome,
+                None => {
+                    ensure!(
+                        Self::check_vote_sealed(vote_id, &state),
+                        Error::<T>::RoundStillInProgress
+                    );
+                    state.outcome()
+                }
+            };
+            standings.push((candidate, vote_id, state.in_favor(), outcome));
+        }
+        // stable so ties keep the order candidates were submitted in
+        standings.sort_by(|a, b| b.2.cmp(&a.2));
+        let winner = standings
+            .iter()
+            .find(|(_, _, _, outcome)| *outcome == VoteOutcome::Approved)
+            .map(|(_, vote_id, _, _)| *vote_id);
+        match (winner, multi_round.next_spec()) {
+            (Some(vote_id), _) => {
+                Self::finalize_multiround_vote(
+                    id,
+                    vote_id,
+                    VoteOutcome::Approved,
+                );
+            }
+            (None, Some(next_spec)) => {
+                let advancing = multi_round.current_spec().advancing().max(1);
+                let candidates = standings
+                    .into_iter()
+                    .take(advancing as usize)
+                    .map(|(candidate, _, _, _)| candidate)
+                    .collect();
+                let voters = <MultiRoundVoters<T>>::get(id)
+                    .ok_or(Error::<T>::MultiRoundVoteDNE)?;
+                let next_round =
+                    Self::open_round(candidates, &voters, &next_spec)?;
+                let new_multi_round = multi_round.advance(next_round);
+                let round = new_multi_round.current_round();
+                <MultiRoundVoteStates<T>>::insert(id, new_multi_round);
+                Self::deposit_event(RawEvent::MultiRoundVoteAdvanced(
+                    id, round,
+                ));
+            }
+            (None, None) => {
+                // candidates is non-empty so every round has a leader
+                Self::finalize_multiround_vote(
+                    id,
+                    standings[0].1,
+                    VoteOutcome::Rejected,
+                );
+            }
+        }
+        Ok(())
+    }
+    fn finalize_multiround_vote(
+        id: u32,
+        final_vote_id: T::VoteId,
+        outcome: VoteOutcome,
+    ) {
+        <MultiRoundVoteStates<T>>::remove(id);
+        <MultiRoundVoters<T>>::remove(id);
+        Self::deposit_event(RawEvent::MultiRoundVoteFinalized(
+            id,
+            final_vote_id,
+            outcome,
+        ));
+    }
+    /// Opens a vote which passes once the compound threshold is met
+    pub fn open_compound_vote(
+        topic: Option<T::Cid>,
+        src: WeightedVector<T::AccountId, T::Signal>,
+        threshold: MultipleThreshold<T::Signal>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let possible_turnout = Self::ensure_can_open(&src)?;
+        ensure!(
+            threshold.is_reachable(possible_turnout),
+            Error::<T>::InputThresholdExceedsBounds
+        );
+        Self::open_with_state(&src, duration, |total, now, ends| {
+            VoteState::new_compound(topic, total, threshold, now, ends)
+        })
+    }
+    /// Checks that a vote may be opened for the voter set
+    /// -> returns the vote power the voter set would be minted
+    fn ensure_can_open(
+        src: &WeightedVector<T::AccountId, T::Signal>,
+    ) -> Result<T::Signal, DispatchError> {
+        ensure!(!<ShutdownMode>::get(), Error::<T>::PalletIsShuttingDown);
+        Self::ensure_voter_set_within_bounds(src)?;
+        Self::ensure_voters_indexable(src, 1)?;
+        Ok(Self::vote_power_total(src))
+    }
+    /// Mints the voter set and stores the vote state `build` makes from the
+    /// minted total, the current block and the vote's end
+    /// -> shared by every kind of vote once its threshold is checked
+    fn open_with_state(
+        src: &WeightedVector<T::AccountId, T::Signal>,
+        duration: Option<T::BlockNumber>,
+        build: impl FnOnce(
+            T::Signal,
+            T::BlockNumber,
+            Option<T::BlockNumber>,
+        ) -> VoteSt<T>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let vote_id = Self::generate_unique_id();
+        let total = Self::mint_signal(vote_id, src)?;
+        <TotalSignalIssuance<T>>::insert(vote_id, total);
+        let now = frame_system::Module::<T>::block_number();
+        let ends = duration.map(|time_to_add| now + time_to_add);
+        Self::schedule_finalization(vote_id, ends)?;
+        Self::store_vote_state(vote_id, build(total, now, ends));
+        <SnapshotBlock<T>>::insert(vote_id, now);
+        Self::note_vote_opened();
+        Ok(vote_id)
+    }
 }
 
 impl<T: Trait> IDIsAvailable<T::VoteId> for Module<T> {
@@ -275,7 +1837,9 @@ impl<T: Trait> GetVoteOutcome<T::VoteId> for Module<T> {
     ) -> Result<Self::Outcome, DispatchError> {
         let vote_state = <VoteStates<T>>::get(vote_id)
             .ok_or(Error::<T>::NoVoteStateForOutcomeQuery)?;
-        Ok(vote_state.outcome())
+        // a finalized outcome may have been forced by root
+        Ok(<FinalOutcomes<T>>::get(vote_id)
+            .unwrap_or_else(|| vote_state.outcome()))
     }
 }
 
@@ -295,32 +1859,14 @@ impl<T: Trait>
         threshold: Threshold<T::Signal>,
         duration: Option<T::BlockNumber>,
     ) -> Result<Self::VoteIdentifier, DispatchError> {
+        let possible_turnout = Self::ensure_can_open(&src)?;
         ensure!(
-            Self::valid_signal_threshold(&threshold, src.total()),
+            Self::valid_signal_threshold(&threshold, possible_turnout),
             Error::<T>::InputThresholdExceedsBounds
         );
-        let vote_id = Self::generate_unique_id();
-        // iterate through src and mint the signal
-        src.vec().iter().for_each(|(who, vote_power)| {
-            let new_vote =
-                Vote::new(*vote_power, VoterView::Uninitialized, None);
-            <VoteLogger<T>>::insert(vote_id, who, new_vote);
-        });
-        <TotalSignalIssuance<T>>::insert(vote_id, src.total());
-        let now = frame_system::Module::<T>::block_number();
-        let ends: Option<T::BlockNumber> = if let Some(time_to_add) = duration {
-            Some(now + time_to_add)
-        } else {
-            None
-        };
-        let new_vote_state =
-            VoteState::new(topic, src.total(), threshold, now, ends);
-        // insert the VoteState
-        <VoteStates<T>>::insert(vote_id, new_vote_state);
-        // increment open vote count
-        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
-        <OpenVoteCounter>::put(new_vote_count);
-        Ok(vote_id)
+        Self::open_with_state(&src, duration, |total, now, ends| {
+            VoteState::new(topic, total, threshold, now, ends)
+        })
     }
     fn open_percent_vote(
         topic: Option<T::Cid>,
@@ -328,37 +1874,41 @@ impl<T: Trait>
         threshold: Threshold<Permill>,
         duration: Option<T::BlockNumber>,
     ) -> Result<Self::VoteIdentifier, DispatchError> {
+        let possible_turnout = Self::ensure_can_open(&src)?;
         let signal_threshold =
-            Self::from_permill_to_signal(&threshold, src.total());
+            Self::from_permill_to_signal(&threshold, possible_turnout);
+        Self::ensure_threshold_not_rounded_to_zero(
+            &threshold,
+            &signal_threshold,
+        )?;
         ensure!(
-            Self::valid_signal_threshold(&signal_threshold, src.total()),
+            Self::valid_signal_threshold(&signal_threshold, possible_turnout),
             Error::<T>::InputThresholdExceedsBounds
         );
-        let vote_id = Self::generate_unique_id();
-        // iterate through src and mint the signal
-        src.vec().iter().for_each(|(who, vote_power)| {
-            let new_vote =
-                Vote::new(*vote_power, VoterView::Uninitialized, None);
-            <VoteLogger<T>>::insert(vote_id, who, new_vote);
-        });
-        <TotalSignalIssuance<T>>::insert(vote_id, src.total());
-        let now = frame_system::Module::<T>::block_number();
-        let ends: Option<T::BlockNumber> = if let Some(time_to_add) = duration {
-            Some(now + time_to_add)
-        } else {
-            None
-        };
-        let new_vote_state =
-            VoteState::new(topic, src.total(), signal_threshold, now, ends);
-        // insert the VoteState
-        <VoteStates<T>>::insert(vote_id, new_vote_state);
-        // increment open vote count
-        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
-        <OpenVoteCounter>::put(new_vote_count);
+        let vote_id =
+            Self::open_with_state(&src, duration, |total, now, ends| {
+                VoteState::new(topic, total, signal_threshold, now, ends)
+            })?;
+        <PercentThresholds<T>>::insert(vote_id, threshold);
         Ok(vote_id)
     }
 }
 
+impl<T: Trait> DirectVote<T::AccountId, T::Signal> for Module<T> {
+    type VoteId = T::VoteId;
+    fn open_direct_vote(
+        src: WeightedVector<T::AccountId, T::Signal>,
+        threshold: Threshold<T::Signal>,
+    ) -> Result<T::VoteId, DispatchError> {
+        Self::open_vote(None, src, threshold, None)
+    }
+    fn direct_vote_outcome(
+        vote_id: T::VoteId,
+    ) -> Result<VoteOutcome, DispatchError> {
+        Self::get_vote_outcome(vote_id)
+    }
+}
+
 impl<T: Trait> UpdateVote<T::VoteId, T::Cid, T::BlockNumber> for Module<T> {
     fn update_vote_topic(
         vote_id: T::VoteId,
@@ -372,7 +1922,7 @@ impl<T: Trait> UpdateVote<T::VoteId, T::Cid, T::BlockNumber> for Module<T> {
         } else {
             old_vote_state.update_topic_without_clearing_state(new_topic)
         };
-        <VoteStates<T>>::insert(vote_id, new_vote_state);
+        Self::store_vote_state(vote_id, new_vote_state);
         Ok(())
     }
     fn extend_vote_length(
@@ -385,8 +1935,15 @@ impl<T: Trait> UpdateVote<T::VoteId, T::Cid, T::BlockNumber> for Module<T> {
             .ok_or(Error::<T>::CannotUpdateVoteIfVoteStateDNE)?;
         if let Some(e) = pvs.ends() {
             if e < new_end_time {
+                // the vote is finalized at its new end instead
+                if let Some(address) =
+                    <ScheduledFinalizations<T>>::take(vote_id)
+                {
+                    let _ = T::Scheduler::cancel(address);
+                }
+                Self::schedule_finalization(vote_id, Some(new_end_time))?;
                 let nvs = pvs.set_ends(new_end_time);
-                <VoteStates<T>>::insert(vote_id, nvs);
+                Self::store_vote_state(vote_id, nvs);
             }
         }
         Ok(())
@@ -431,13 +1988,43 @@ impl<T: Trait> VoteOnProposal<T::AccountId, T::VoteId, T::Cid> for Module<T> {
             .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
         // TODO: add permissioned method for adding time to the vote state because of this restriction but this is a legitimate restriction
         // -> every standard vote has a recognized end to establish when the decision must be made based on collected input
+        let in_grace_period = Self::check_vote_expired(&vote_state);
         ensure!(
-            !Self::check_vote_expired(&vote_state),
+            !Self::check_vote_sealed(vote_id, &vote_state),
             Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
         );
+        ensure!(
+            <FinalOutcomes<T>>::get(vote_id).is_none(),
+            Error::<T>::VoteAlreadyFinalized
+        );
+        ensure!(
+            T::SybilResistance::is_eligible(&voter),
+            Error::<T>::SybilCheckFailed
+        );
         // get the organization associated with this vote_state
-        let old_vote = <VoteLogger<T>>::get(vote_id, voter.clone())
+        let old_vote = Self::vote_logger_entry(vote_id, &voter)
             .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        if vote_state.disallow_late_registration() {
+            // only voters in the snapshot may vote
+            if let (Some(snapshot), Some(registered)) = (
+                <SnapshotBlock<T>>::get(vote_id),
+                Self::late_registration_entry(vote_id, &voter),
+            ) {
+                ensure!(
+                    registered <= snapshot,
+                    Error::<T>::VoterRegisteredAfterSnapshot
+                );
+            }
+        }
+        if in_grace_period {
+            // support already given may be withdrawn, but none given anew
+            ensure!(
+                direction == VoterView::Abstain
+                    && (old_vote.direction() == VoterView::InFavor
+                        || old_vote.direction() == VoterView::Against),
+                Error::<T>::OnlyRetractionAllowedDuringGracePeriod
+            );
+        }
         let new_vote = old_vote.set_new_view(direction, justification).ok_or(
             Error::<T>::OldVoteDirectionEqualsNewVoteDirectionSoNoChange,
         )?;
@@ -448,10 +2035,19 @@ impl<T: Trait> VoteOnProposal<T::AccountId, T::VoteId, T::Cid> for Module<T> {
             direction,
         )
         .ok_or(Error::<T>::VoteChangeNotSupported)?;
+        // the bond is reserved once, when the voter first votes
+        if let Some(config) = <BondedVoting<T>>::get(vote_id) {
+            if !<VoteBonds<T>>::contains_key(vote_id, &voter) {
+                T::Currency::reserve(&voter, config.bond_amount())?;
+                <VoteBonds<T>>::insert(vote_id, &voter, config.bond_amount());
+            }
+        }
         // set the new vote for the voter's profile
         <VoteLogger<T>>::insert(vote_id, voter, new_vote);
+        // catch apply_vote bugs in debug builds
+        debug_assert_eq!(new_state.validate(), Ok(()));
         // commit new vote state to storage
-        <VoteStates<T>>::insert(vote_id, new_state);
+        Self::store_vote_state(vote_id, new_state);
         Ok(())
     }
 }
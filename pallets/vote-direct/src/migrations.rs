@@ -0,0 +1,3 @@
+//! Storage migrations for this module, one submodule per `Releases` step
+pub mod v1_to_v2;
+pub mod v2_to_v3;
@@ -0,0 +1,213 @@
+//! Moves storage written under `Releases::V1OpaqueHashers` to the
+//! `V2ConcatHashers` layout
+//! - the vote keyed single maps move from `opaque_blake2_256` to `blake2_128_concat`
+//! - vote states are decoded with the `VoteStateV1` layout, which predates `quorum`,
+//! and written back as `VoteStateV2` with the new fields left at their defaults
+//! - double map entries are moved for every account known to the system module,
+//! whose opaque key hashes are matched against the legacy keys
+//! - entries of voters without a system account can't be recovered from their opaque
+//! hash so they still move when first read (see `take_legacy_double_map_entry`)
+use crate::{
+    LateRegistrations,
+    Releases,
+    SnapshotBlock,
+    StorageVersion,
+    TotalSignalIssuance,
+    Trait,
+    VoteIdCounter,
+    VoteLogger,
+    VoteStV1,
+    VoteStV2,
+    VoteStates,
+    VoteVec,
+};
+use frame_support::{
+    storage::{
+        migration::{
+            put_storage_value,
+            take_storage_value,
+            StorageIterator,
+        },
+        unhashed,
+        IterableStorageMap,
+        StorageDoubleMap,
+        StorageMap,
+        StorageValue,
+    },
+    traits::{
+        Get,
+        OnRuntimeUpgrade,
+    },
+    weights::Weight,
+    Blake2_128Concat,
+    Blake2_256,
+    StorageHasher,
+    Twox128,
+};
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
+use sp_std::{
+    collections::btree_map::BTreeMap,
+    marker::PhantomData,
+    prelude::*,
+};
+
+/// Runs once, while `StorageVersion` is still `V1OpaqueHashers`
+pub struct LegacyMigration<T>(PhantomData<T>);
+
+impl<T: Trait> OnRuntimeUpgrade for LegacyMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if <StorageVersion>::get() == Releases::V1OpaqueHashers {
+            migrate::<T>()
+        } else {
+            T::DbWeight::get().reads(1)
+        }
+    }
+}
+
+impl<T: Trait> LegacyMigration<T> {
+    /// Confirms the first legacy vote state found still decodes as `VoteStateV1`
+    /// -> call before the upgrade, it does not write to storage
+    pub fn pre_upgrade() -> Result<(), &'static str> {
+        if <StorageVersion>::get() != Releases::V1OpaqueHashers {
+            return Err("vote states were already migrated")
+        }
+        let counter = <VoteIdCounter<T>>::get();
+        let mut vote_id: T::VoteId = 1u32.into();
+        while vote_id <= counter {
+            if let Some(raw) = unhashed::get_raw(&legacy_key::<T>(vote_id)) {
+                return VoteStV1::<T>::decode(&mut &raw[..])
+                    .map(|_| ())
+                    .map_err(|_| "legacy vote state does not decode as V1")
+            }
+            vote_id += 1u32.into();
+        }
+        Ok(())
+    }
+    /// Confirms every vote state decodes with the `VoteStateV2` layout and none
+    /// remain under the legacy hasher
+    pub fn post_upgrade() -> Result<(), &'static str> {
+        if <StorageVersion>::get() != Releases::V2ConcatHashers {
+            return Err("storage version was not bumped")
+        }
+        let counter = <VoteIdCounter<T>>::get();
+        let mut vote_id: T::VoteId = 1u32.into();
+        while vote_id <= counter {
+            if unhashed::exists(&legacy_key::<T>(vote_id)) {
+                return Err("vote state left under the legacy hasher")
+            }
+            if let Some(raw) =
+                unhashed::get_raw(&<VoteStates<T>>::hashed_key_for(vote_id))
+            {
+                VoteStV2::<T>::decode(&mut &raw[..])
+                    .map_err(|_| "vote state does not decode as V2")?;
+            }
+            vote_id += 1u32.into();
+        }
+        Ok(())
+    }
+}
+
+/// Key of the vote state stored under the legacy opaque hasher
+fn legacy_key<T: Trait>(vote_id: T::VoteId) -> Vec<u8> {
+    let mut key = Twox128::hash(b"VoteDirect").to_vec();
+    key.extend_from_slice(&Twox128::hash(b"VoteStates"));
+    key.extend_from_slice(&Blake2_256::hash(&vote_id.encode()));
+    key
+}
+
+/// Takes the vote's legacy double map entries for `item` whose account is known
+/// -> returns the number of entries read with the entries taken
+fn take_legacy_entries<T: Trait, V: Decode>(
+    item: &[u8],
+    vote_hash: &[u8],
+    accounts: &BTreeMap<Vec<u8>, T::AccountId>,
+) -> (u64, Vec<(T::AccountId, V)>) {
+    // collected first so no key is removed while iterating
+    let account_hashes =
+        StorageIterator::<V>::with_suffix(b"VoteDirect", item, vote_hash)
+            .map(|(account_hash, _)| account_hash)
+            .collect::<Vec<_>>();
+    let read = account_hashes.len() as u64;
+    let taken = account_hashes
+        .into_iter()
+        .filter_map(|account_hash| {
+            let who = accounts.get(&account_hash)?.clone();
+            let mut hash = vote_hash.to_vec();
+            hash.extend_from_slice(&account_hash);
+            take_storage_value::<V>(b"VoteDirect", item, &hash)
+                .map(|entry| (who, entry))
+        })
+        .collect();
+    (read, taken)
+}
+
+/// Weight is charged per vote id checked, per account hashed and per entry read
+/// or moved
+fn migrate<T: Trait>() -> Weight {
+    let counter = <VoteIdCounter<T>>::get();
+    let accounts = <frame_system::Account<T>>::iter()
+        .map(|(who, _)| (Blake2_256::hash(&who.encode()).to_vec(), who))
+        .collect::<BTreeMap<_, _>>();
+    let (mut reads, mut writes) = (2u64 + accounts.len() as u64, 1u64);
+    let mut vote_id: T::VoteId = 1u32.into();
+    while vote_id <= counter {
+        let hash = Blake2_256::hash(&vote_id.encode());
+        if let Some(state) = take_storage_value::<VoteStV1<T>>(
+            b"VoteDirect",
+            b"VoteStates",
+            &hash,
+        ) {
+            // written as `VoteStateV2` for the next step to translate
+            put_storage_value(
+                b"VoteDirect",
+                b"VoteStates",
+                &Blake2_128Concat::hash(&vote_id.encode()),
+                state.upgrade(),
+            );
+            writes += 2;
+        }
+        if let Some(total) = take_storage_value::<T::Signal>(
+            b"VoteDirect",
+            b"TotalSignalIssuance",
+            &hash,
+        ) {
+            <TotalSignalIssuance<T>>::insert(vote_id, total);
+            writes += 2;
+        }
+        if let Some(snapshot) = take_storage_value::<T::BlockNumber>(
+            b"VoteDirect",
+            b"SnapshotBlock",
+            &hash,
+        ) {
+            <SnapshotBlock<T>>::insert(vote_id, snapshot);
+            writes += 2;
+        }
+        let (read, entries) = take_legacy_entries::<T, VoteVec<T>>(
+            b"VoteLogger",
+            &hash,
+            &accounts,
+        );
+        reads += read;
+        for (who, entry) in entries {
+            <VoteLogger<T>>::insert(vote_id, who, entry);
+            writes += 2;
+        }
+        let (read, registrations) = take_legacy_entries::<T, T::BlockNumber>(
+            b"LateRegistrations",
+            &hash,
+            &accounts,
+        );
+        reads += read;
+        for (who, registered) in registrations {
+            <LateRegistrations<T>>::insert(vote_id, who, registered);
+            writes += 2;
+        }
+        reads += 3;
+        vote_id += 1u32.into();
+    }
+    <StorageVersion>::put(Releases::V2ConcatHashers);
+    T::DbWeight::get().reads_writes(reads, writes)
+}
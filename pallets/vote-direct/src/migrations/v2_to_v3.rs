@@ -0,0 +1,50 @@
+//! Translates vote states from the `VoteStateV2` layout, which predates
+//! `min_distinct_voters` and `distinct_voters`
+//! - counts start at zero because voters who voted before the first upgrade may
+//! still sit under the legacy hasher, where they can't be enumerated
+//! - this module never sets a distinct voter requirement so the count only
+//! informs readers of the vote state
+use crate::{
+    Releases,
+    StorageVersion,
+    Trait,
+    VoteStV2,
+    VoteStates,
+};
+use frame_support::{
+    storage::{
+        IterableStorageMap,
+        StorageValue,
+    },
+    traits::{
+        Get,
+        OnRuntimeUpgrade,
+    },
+    weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// Runs once, while `StorageVersion` is `V2ConcatHashers`
+pub struct DistinctVoterMigration<T>(PhantomData<T>);
+
+impl<T: Trait> OnRuntimeUpgrade for DistinctVoterMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if <StorageVersion>::get() == Releases::V2ConcatHashers {
+            migrate::<T>()
+        } else {
+            T::DbWeight::get().reads(1)
+        }
+    }
+}
+
+fn migrate<T: Trait>() -> Weight {
+    // `translate` only takes `Fn` closures
+    let translated = sp_std::cell::Cell::new(0u64);
+    <VoteStates<T>>::translate::<VoteStV2<T>, _>(|_, old| {
+        translated.set(translated.get() + 1);
+        Some(old.upgrade())
+    });
+    <StorageVersion>::put(Releases::V3DistinctVoters);
+    let n = translated.get();
+    T::DbWeight::get().reads_writes(n + 1, n + 1)
+}
@@ -0,0 +1,21 @@
+//! Runtime APIs for looking up the direct votes of an account and the
+//! module's vote counts
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+use util::vote::VoteMetrics;
+
+sp_api::decl_runtime_apis! {
+    pub trait VoteDirectApi<AccountId, VoteId> where
+        AccountId: Codec,
+        VoteId: Codec,
+    {
+        /// The open votes the account is a voter in, earliest indexed first
+        fn votes_of(account: AccountId) -> Vec<VoteId>;
+    }
+
+    /// Counts of the module's votes, kept in storage so no events are indexed
+    pub trait VoteMetricsApi {
+        /// Open and peak open votes, votes ever opened and finalized votes by outcome
+        fn get_vote_metrics() -> VoteMetrics;
+    }
+}
@@ -5,14 +5,33 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    storage::{
+        migration::put_storage_value,
+        StorageValue,
+    },
+    traits::{
+        schedule::{
+            Period,
+            Priority,
+        },
+        OnInitialize,
+        OnRuntimeUpgrade,
+        UnfilteredDispatchable,
+    },
     weights::Weight,
 };
+use migrations::v1_to_v2::LegacyMigration;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
     traits::IdentityLookup,
     Perbill,
 };
+use std::cell::RefCell;
+use util::vote::{
+    LinearWeight,
+    VoteStateError,
+};
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
@@ -56,11 +75,153 @@ impl frame_system::Trait for Test {
     type BaseCallFilter = ();
     type SystemWeightInfo = ();
 }
+/// Account 13 never passes identity verification
+pub struct UnverifiedThirteen;
+impl SybilCheck<u64> for UnverifiedThirteen {
+    fn is_eligible(voter: &u64) -> bool {
+        *voter != 13
+    }
+}
+/// Only account 3 has a display name
+pub struct NamedThree;
+impl IdentityNameOf<u64> for NamedThree {
+    fn display_name(who: &u64) -> Option<Vec<u8>> {
+        if *who == 3 {
+            Some(b"three".to_vec())
+        } else {
+            None
+        }
+    }
+}
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+    pub const MaxLocks: u32 = 50;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type MaxLocks = MaxLocks;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const BondSlashRecipient: u64 = 100;
+    pub const ShutdownFinalizationsPerBlock: u32 = 2;
+    pub const MaxVotesPerAccount: u32 = 5;
+    pub const MaxVotersPerVote: u32 = 6;
+}
+thread_local! {
+    static MAX_SIGNAL_PER_VOTE: RefCell<u64> = RefCell::new(100);
+}
+/// Tests raise the bound to reach the checks behind it
+pub struct MaxSignalPerVote;
+impl Get<u64> for MaxSignalPerVote {
+    fn get() -> u64 {
+        // otherwise the signal bound rejects the set before it is minted
+        MAX_SIGNAL_PER_VOTE.with(|m| *m.borrow())
+    }
+}
 impl Trait for Test {
     type Event = TestEvent;
     type Cid = u32;
     type VoteId = u64;
     type Signal = u64;
+    type MaxVotersPerVote = MaxVotersPerVote;
+    type MaxSignalPerVote = MaxSignalPerVote;
+    type MaxVotesPerAccount = MaxVotesPerAccount;
+    type SybilResistance = UnverifiedThirteen;
+    type IdentityProvider = NamedThree;
+    type Currency = Balances;
+    type BondSlashRecipient = BondSlashRecipient;
+    type OrgId = u64;
+    type OrgAdminOrigin = frame_system::EnsureRoot<u64>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type ShutdownFinalizationsPerBlock = ShutdownFinalizationsPerBlock;
+    type VoteWeight = LinearWeight;
+    type ScheduledCall = Call<Test>;
+    type PalletsOrigin = frame_system::RawOrigin<u64>;
+    type Scheduler = TestScheduler;
+    type WeightInfo = ();
+}
+
+thread_local! {
+    static SCHEDULED: RefCell<Vec<Option<(u64, Call<Test>)>>> =
+        RefCell::new(Vec::new());
+}
+
+/// Holds scheduled calls until `run_to_block` dispatches them
+pub struct TestScheduler;
+impl ScheduleAnon<u64, Call<Test>, frame_system::RawOrigin<u64>>
+    for TestScheduler
+{
+    type Address = u32;
+    fn schedule(
+        when: DispatchTime<u64>,
+        _maybe_periodic: Option<Period<u64>>,
+        _priority: Priority,
+        _origin: frame_system::RawOrigin<u64>,
+        call: Call<Test>,
+    ) -> Result<u32, DispatchError> {
+        let at = match when {
+            DispatchTime::At(n) => n,
+            DispatchTime::After(n) => System::block_number() + n,
+        };
+        Ok(SCHEDULED.with(|s| {
+            let mut scheduled = s.borrow_mut();
+            scheduled.push(Some((at, call)));
+            scheduled.len() as u32 - 1
+        }))
+    }
+    fn cancel(address: u32) -> Result<(), ()> {
+        SCHEDULED.with(|s| {
+            s.borrow_mut()
+                .get_mut(address as usize)
+                .and_then(Option::take)
+                .map(|_| ())
+                .ok_or(())
+        })
+    }
+    fn reschedule(
+        address: u32,
+        when: DispatchTime<u64>,
+    ) -> Result<u32, DispatchError> {
+        let (_, call) = SCHEDULED
+            .with(|s| {
+                s.borrow_mut()
+                    .get_mut(address as usize)
+                    .and_then(Option::take)
+            })
+            .ok_or(DispatchError::Other("call is not scheduled"))?;
+        Self::schedule(when, None, 0, frame_system::RawOrigin::Root, call)
+    }
+    fn next_dispatch_time(address: u32) -> Result<u64, ()> {
+        SCHEDULED.with(|s| {
+            s.borrow()
+                .get(address as usize)
+                .and_then(|c| c.as_ref().map(|(at, _)| *at))
+                .ok_or(())
+        })
+    }
+}
+
+/// Dispatches the calls scheduled for every block up to `n` as root
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let now = System::block_number() + 1;
+        System::set_block_number(now);
+        let due = SCHEDULED.with(|s| {
+            s.borrow_mut()
+                .iter_mut()
+                .filter(|c| matches!(c, Some((at, _)) if *at == now))
+                .filter_map(Option::take)
+                .collect::<Vec<_>>()
+        });
+        for (_, call) in due {
+            assert_ok!(call.dispatch_bypass_filter(Origin::root()));
+        }
+    }
 }
 
 mod vote {
@@ -70,14 +231,16 @@ mod vote {
 impl_outer_event! {
     pub enum TestEvent for Test {
         frame_system<T>,
+        pallet_balances<T>,
         vote<T>,
     }
 }
 pub type System = frame_system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
 // pub type Organization = org::Module<Test>;
 pub type Vote = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -92,10 +255,15 @@ fn get_last_event() -> RawEvent<u64, u64> {
         .unwrap()
 }
 
-fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::default()
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
         .build_storage::<Test>()
         .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100), (3, 100), (4, 5)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     let mut ext: sp_io::TestExternalities = t.into();
     ext.execute_with(|| System::set_block_number(1));
     ext
@@ -126,6 +294,89 @@ fn vote_creation_works() {
     });
 }
 
+#[test]
+fn minted_vote_power_follows_the_vote_weight() {
+    new_test_ext().execute_with(|| {
+        // the mock weighs linearly so minted magnitudes equal signal
+        let vote_set: WeightedVector<u64, u64> = vec![(1, 10), (2, 20)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(30, None),
+            None
+        ));
+        assert_eq!(Vote::vote_logger(1, 2).unwrap().magnitude(), 20);
+        assert_eq!(Vote::total_signal_issuance(1), Some(30));
+    });
+}
+
+#[test]
+fn only_authorized_creators_open_org_votes() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> = vec![(1, 10), (2, 20)].into();
+        assert_noop!(
+            Vote::add_vote_creator(Origin::signed(1), 7, 1),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Vote::create_signal_vote_for_org(
+                Origin::signed(1),
+                7,
+                None,
+                vote_set.clone(),
+                Threshold::new(10, None),
+                None
+            ),
+            Error::<Test>::NotAuthorizedToCreateVoteForOrg
+        );
+        assert_ok!(Vote::add_vote_creator(Origin::root(), 7, 1));
+        assert_eq!(get_last_event(), RawEvent::VoteCreatorAdded(7, 1));
+        assert_noop!(
+            Vote::add_vote_creator(Origin::root(), 7, 1),
+            Error::<Test>::AlreadyAuthorizedToCreateVoteForOrg
+        );
+        assert_ok!(Vote::create_signal_vote_for_org(
+            Origin::signed(1),
+            7,
+            None,
+            vote_set.clone(),
+            Threshold::new(10, None),
+            None
+        ));
+        assert_eq!(get_last_event(), RawEvent::NewOrgVoteStarted(1, 7, 1));
+        // the whitelist is per org
+        assert_noop!(
+            Vote::create_signal_vote_for_org(
+                Origin::signed(1),
+                8,
+                None,
+                vote_set.clone(),
+                Threshold::new(10, None),
+                None
+            ),
+            Error::<Test>::NotAuthorizedToCreateVoteForOrg
+        );
+        assert_ok!(Vote::remove_vote_creator(Origin::root(), 7, 1));
+        assert_eq!(Vote::authorized_vote_creators(7), Vec::<u64>::new());
+        assert_noop!(
+            Vote::remove_vote_creator(Origin::root(), 7, 1),
+            Error::<Test>::NotAVoteCreatorForOrg
+        );
+        assert_noop!(
+            Vote::create_signal_vote_for_org(
+                Origin::signed(1),
+                7,
+                None,
+                vote_set,
+                Threshold::new(10, None),
+                None
+            ),
+            Error::<Test>::NotAuthorizedToCreateVoteForOrg
+        );
+    });
+}
+
 #[test]
 fn vote_signal_threshold_works() {
     new_test_ext().execute_with(|| {
@@ -156,7 +407,10 @@ fn vote_signal_threshold_works() {
         // check that the vote has passed
         let outcome_has_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_has_passed, VoteOutcome::Approved);
-        assert_eq!(get_last_event(), RawEvent::Voted(1, 6, VoterView::InFavor));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::Voted(1, 6, VoterView::InFavor, None)
+        );
     });
 }
 
@@ -201,7 +455,10 @@ fn vote_pct_threshold_works() {
         // check that the vote has passed
         let outcome_has_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_has_passed, VoteOutcome::Approved);
-        assert_eq!(get_last_event(), RawEvent::Voted(1, 3, VoterView::InFavor));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::Voted(1, 3, VoterView::InFavor, Some(b"three".to_vec()))
+        );
     });
 }
 
@@ -273,3 +530,1425 @@ fn changing_votes_upholds_invariants() {
         assert_eq!(outcome_almost_passed, VoteOutcome::Approved);
     });
 }
+
+#[test]
+fn compound_threshold_vote_works() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1)].into();
+        assert_noop!(
+            Vote::create_compound_threshold_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                MultipleThreshold::and(
+                    MultipleThreshold::Single(Threshold::new(2, None)),
+                    MultipleThreshold::Single(Threshold::new(7, None)),
+                ),
+                None
+            ),
+            Error::<Test>::InputThresholdExceedsBounds
+        );
+        // (>= 2 AND >= 4) OR >= 6 => passes with 4 in favor
+        let tree = MultipleThreshold::or(
+            MultipleThreshold::and(
+                MultipleThreshold::Single(Threshold::new(2, None)),
+                MultipleThreshold::Single(Threshold::new(4, None)),
+            ),
+            MultipleThreshold::Single(Threshold::new(6, None)),
+        );
+        assert_ok!(Vote::create_compound_threshold_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            tree.clone(),
+            None
+        ));
+        assert_eq!(Vote::vote_states(1).unwrap().threshold_tree(), Some(tree));
+        // the cheaper branch sets the signal needed
+        assert_eq!(
+            Vote::vote_states(1).unwrap().effective_threshold_signal(),
+            4
+        );
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Voting);
+        assert_eq!(
+            Vote::vote_states(1).unwrap().remaining_signal_needed(),
+            Some(1)
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(4),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert_eq!(
+            Vote::vote_states(1).unwrap().remaining_signal_needed(),
+            None
+        );
+    });
+}
+
+#[test]
+fn compound_thresholds_check_turnout_and_supermajority() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1)].into();
+        for unreachable in vec![
+            MultipleThreshold::Turnout(7),
+            MultipleThreshold::Supermajority(3, 2),
+            MultipleThreshold::Supermajority(1, 0),
+        ] {
+            assert_noop!(
+                Vote::create_compound_threshold_vote(
+                    Origin::signed(1),
+                    None,
+                    vote_set.clone(),
+                    unreachable,
+                    None
+                ),
+                Error::<Test>::InputThresholdExceedsBounds
+            );
+        }
+        // a quorum of 4 and two thirds of the signal in favor or against
+        assert_ok!(Vote::create_compound_threshold_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            MultipleThreshold::and(
+                MultipleThreshold::Turnout(4),
+                MultipleThreshold::Supermajority(2, 3),
+            ),
+            None
+        ));
+        let views = [
+            VoterView::InFavor,
+            VoterView::Against,
+            VoterView::InFavor,
+            VoterView::Against,
+            VoterView::InFavor,
+        ];
+        for (i, view) in views.iter().enumerate() {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i as u64 + 1),
+                1,
+                *view,
+                None
+            ));
+            // the quorum is met from the 4th vote but 3 of 5 is short of 2/3
+            assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Voting);
+        }
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(6),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+    });
+}
+
+#[test]
+fn single_leaves_are_blocked_by_their_rejection_threshold() {
+    let state: VoteState<u64, u64, u32> = VoteState::new_compound(
+        None,
+        6,
+        MultipleThreshold::Turnout(1),
+        1,
+        None,
+    );
+    let leaf = MultipleThreshold::Single(Threshold::new(2, Some(2)));
+    let in_favor = state
+        .apply(2, VoterView::Uninitialized, VoterView::InFavor)
+        .unwrap();
+    assert!(leaf.is_met(&in_favor));
+    let contested = in_favor
+        .apply(2, VoterView::Uninitialized, VoterView::Against)
+        .unwrap();
+    assert!(!leaf.is_met(&contested));
+}
+
+#[test]
+fn compound_threshold_codec_roundtrip() {
+    use parity_scale_codec::{
+        Decode,
+        Encode,
+    };
+    let tree: MultipleThreshold<u64> = MultipleThreshold::and(
+        MultipleThreshold::Single(Threshold::new(3, Some(2))),
+        MultipleThreshold::or(
+            MultipleThreshold::Turnout(4),
+            MultipleThreshold::Supermajority(2, 3),
+        ),
+    );
+    let encoded = tree.encode();
+    assert_eq!(
+        MultipleThreshold::<u64>::decode(&mut &encoded[..]).unwrap(),
+        tree
+    );
+    // nesting beyond the max depth is rejected on decode
+    let mut deep = MultipleThreshold::Single(Threshold::new(1u64, None));
+    for _ in 0..util::vote::MAX_THRESHOLD_DEPTH {
+        deep = MultipleThreshold::and(deep.clone(), deep);
+    }
+    assert!(MultipleThreshold::<u64>::decode(&mut &deep.encode()[..]).is_err());
+}
+
+#[test]
+fn late_registration_respects_snapshot() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set.clone(),
+            Threshold::new(3, None),
+            None
+        ));
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(3, None),
+            None
+        ));
+        assert_eq!(Vote::snapshot_block(1), Some(1));
+        assert_ok!(Vote::set_disallow_late_registration(2, true));
+        System::set_block_number(3);
+        for vote_id in 1u64..3u64 {
+            assert_ok!(Vote::register_voter(vote_id, 7, 5));
+            assert_eq!(Vote::late_registrations(vote_id, 7), Some(3));
+            assert_eq!(Vote::total_signal_issuance(vote_id), Some(8));
+            assert_eq!(
+                Vote::vote_states(vote_id).unwrap().all_possible_turnout(),
+                8
+            );
+        }
+        assert_noop!(
+            Vote::register_voter(1, 7, 5),
+            Error::<Test>::VoterAlreadyRegisteredForVote
+        );
+        // late registrants vote unless the vote disallows it
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(7),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(7), 2, VoterView::InFavor, None),
+            Error::<Test>::VoterRegisteredAfterSnapshot
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            2,
+            VoterView::InFavor,
+            None
+        ));
+    });
+}
+
+#[test]
+fn cached_outcome_matches_fresh_computation() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(1, None),
+            None
+        ));
+        let cache_is_fresh = || {
+            let state = Vote::vote_states(1).unwrap();
+            assert_eq!(state.validate(), Ok(()));
+            assert_eq!(state.outcome(), state.computed_outcome());
+        };
+        cache_is_fresh();
+        assert_eq!(Vote::outcome_computed_at(1), Some(1));
+        System::set_block_number(3);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        cache_is_fresh();
+        assert_eq!(Vote::outcome_computed_at(1), Some(3));
+        // reads return the cached outcome without recomputing it
+        System::set_block_number(4);
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert_eq!(Vote::outcome_computed_at(1), Some(3));
+        // clearing the tally invalidates the cached approval
+        assert_ok!(Vote::update_vote_topic(1, 2, true));
+        cache_is_fresh();
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Voting);
+        assert_ok!(Vote::register_voter(1, 7, 5));
+        cache_is_fresh();
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(7),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        cache_is_fresh();
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        // configuration changes are stamped like tally changes
+        System::set_block_number(5);
+        assert_ok!(Vote::set_disallow_late_registration(1, true));
+        cache_is_fresh();
+        assert_eq!(Vote::outcome_computed_at(1), Some(5));
+        let vote_set: WeightedVector<u64, u64> = vec![(1, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(1, None),
+            Some(2)
+        ));
+        System::set_block_number(6);
+        assert_ok!(Vote::extend_vote_length(2, 10));
+        cache_is_fresh();
+        assert_eq!(Vote::vote_states(2).unwrap().ends(), Some(16));
+        assert_eq!(Vote::outcome_computed_at(2), Some(6));
+    });
+}
+
+#[test]
+fn vote_state_validate_catches_invariant_violations() {
+    type State = VoteState<u64, u64, u32>;
+    let state = State::new(None, 5, Threshold::new(3, Some(3)), 2, Some(4));
+    assert_eq!(state.validate(), Ok(()));
+    let tallied = state
+        .apply(3, VoterView::Uninitialized, VoterView::InFavor)
+        .unwrap()
+        .apply(2, VoterView::Uninitialized, VoterView::Against)
+        .unwrap();
+    assert_eq!(tallied.validate(), Ok(()));
+    // more signal applied than exists
+    let overflowed = tallied
+        .apply(1, VoterView::Uninitialized, VoterView::Against)
+        .unwrap();
+    assert_eq!(
+        overflowed.validate(),
+        Err(VoteStateError::TallyExceedsTotal)
+    );
+    assert_eq!(
+        State::new(None, 5, Threshold::new(6, None), 2, None).validate(),
+        Err(VoteStateError::InFavorThresholdExceedsTotal)
+    );
+    assert_eq!(
+        State::new(None, 5, Threshold::new(5, Some(6)), 2, None).validate(),
+        Err(VoteStateError::AgainstThresholdExceedsTotal)
+    );
+    assert_eq!(
+        State::new(None, 5, Threshold::new(5, None), 2, Some(1)).validate(),
+        Err(VoteStateError::EndsBeforeInitialized)
+    );
+}
+
+#[test]
+fn opaque_hasher_migration_works() {
+    new_test_ext().execute_with(|| {
+        // write a vote with the legacy opaque hashers and layout
+        let old_state: VoteStV1<Test> = VoteStateV1::new(
+            None,
+            0,
+            0,
+            0,
+            2,
+            Threshold::new(1, None),
+            1,
+            None,
+            VoteOutcome::Voting,
+        );
+        let vote_hash = Blake2_256::hash(&1u64.encode());
+        put_storage_value(b"VoteDirect", b"VoteStates", &vote_hash, old_state);
+        put_storage_value(
+            b"VoteDirect",
+            b"TotalSignalIssuance",
+            &vote_hash,
+            2u64,
+        );
+        let mut voter_hash = vote_hash.to_vec();
+        voter_hash.extend_from_slice(&Blake2_256::hash(&1u64.encode()));
+        let entry: VoteVec<Test> =
+            util::vote::Vote::new(2, VoterView::Uninitialized, None);
+        put_storage_value(b"VoteDirect", b"VoteLogger", &voter_hash, entry);
+        put_storage_value(
+            b"VoteDirect",
+            b"LateRegistrations",
+            &voter_hash,
+            1u64,
+        );
+        // account 9 has no system account so its entry can't be matched
+        let mut unknown_hash = vote_hash.to_vec();
+        unknown_hash.extend_from_slice(&Blake2_256::hash(&9u64.encode()));
+        put_storage_value(b"VoteDirect", b"VoteLogger", &unknown_hash, entry);
+        <VoteIdCounter<Test>>::put(1);
+        assert!(Vote::vote_states(1).is_none());
+        assert_eq!(Vote::storage_version(), Releases::V1OpaqueHashers);
+        assert_ok!(LegacyMigration::<Test>::pre_upgrade());
+        assert_eq!(
+            LegacyMigration::<Test>::post_upgrade(),
+            Err("storage version was not bumped")
+        );
+        LegacyMigration::<Test>::on_runtime_upgrade();
+        assert_eq!(Vote::storage_version(), Releases::V2ConcatHashers);
+        assert_ok!(LegacyMigration::<Test>::post_upgrade());
+        assert_eq!(
+            LegacyMigration::<Test>::pre_upgrade(),
+            Err("vote states were already migrated")
+        );
+        // the module runs the remaining steps on top
+        Vote::on_runtime_upgrade();
+        assert_eq!(Vote::storage_version(), Releases::V3DistinctVoters);
+        let state: VoteSt<Test> =
+            VoteState::new(None, 2, Threshold::new(1, None), 1, None);
+        assert_eq!(Vote::vote_states(1), Some(state));
+        assert_eq!(Vote::total_signal_issuance(1), Some(2));
+        // double map entries of known accounts moved with the upgrade
+        assert_eq!(
+            <VoteLogger<Test>>::iter_prefix(1).collect::<Vec<_>>(),
+            vec![(1, entry)]
+        );
+        assert_eq!(
+            <LateRegistrations<Test>>::iter_prefix(1).collect::<Vec<_>>(),
+            vec![(1, 1)]
+        );
+        // others move when first read
+        assert!(Vote::vote_logger(1, 9).is_none());
+        assert_eq!(Vote::vote_logger_entry(1, &9), Some(entry));
+        assert_eq!(Vote::vote_logger(1, 9), Some(entry));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::vote_logger(1, 1).unwrap().magnitude(), 2);
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert_eq!(Vote::vote_states(1).unwrap().distinct_voters(), 1);
+    });
+}
+
+#[test]
+fn sybil_check_gates_voting() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> = vec![(1, 1), (13, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(2, None),
+            None
+        ));
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(13), 1, VoterView::InFavor, None),
+            Error::<Test>::SybilCheckFailed
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+    });
+}
+
+#[test]
+fn bonded_votes_slash_the_losing_side() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 10), (2, 10), (3, 10), (4, 10)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(20, None),
+            Some(5)
+        ));
+        assert_noop!(
+            Vote::set_bonded_voting(2, BondedVotingConfig::new(10, true)),
+            Error::<Test>::CannotBondVoteIfVoteStateDNE
+        );
+        assert_noop!(
+            Vote::require_vote_bonds(
+                Origin::signed(2),
+                1,
+                BondedVotingConfig::new(10, true)
+            ),
+            Error::<Test>::OnlyVoteCreatorCanConfigureVote
+        );
+        assert_ok!(Vote::require_vote_bonds(
+            Origin::signed(1),
+            1,
+            BondedVotingConfig::new(10, true)
+        ));
+        assert_eq!(get_last_event(), RawEvent::VoteBondsRequired(1, 10, true));
+        // voters without the bond can't vote
+        assert!(Vote::submit_vote(
+            Origin::signed(4),
+            1,
+            VoterView::InFavor,
+            None
+        )
+        .is_err());
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::Against,
+            None
+        ));
+        assert_noop!(
+            Vote::set_bonded_voting(1, BondedVotingConfig::new(5, false)),
+            Error::<Test>::CannotBondVoteAfterVotingStarted
+        );
+        assert_noop!(
+            Vote::settle_bonds(Origin::signed(1), 1),
+            Error::<Test>::CannotSettleBondsBeforeVoteConcludes
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        // changing a vote doesn't reserve the bond twice
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Abstain,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(2), 10);
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        // voters may still change their view until the vote is finalized
+        assert_noop!(
+            Vote::settle_bonds(Origin::signed(4), 1),
+            Error::<Test>::CannotSettleBondsBeforeVoteConcludes
+        );
+        run_to_block(7);
+        assert_eq!(Vote::final_outcomes(1), Some(VoteOutcome::Approved));
+        assert_ok!(Vote::settle_bonds(Origin::signed(4), 1));
+        assert_eq!(get_last_event(), RawEvent::BondsSettled(1, 20, 10));
+        for voter in 1u64..4u64 {
+            assert_eq!(Balances::reserved_balance(voter), 0);
+        }
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(3), 90);
+        assert_eq!(Balances::free_balance(100), 10);
+        // settled once
+        assert_noop!(
+            Vote::settle_bonds(Origin::signed(1), 1),
+            Error::<Test>::CannotSettleBondsIfVoteNotBonded
+        );
+    });
+}
+
+#[test]
+fn inconclusive_bonded_votes_return_every_bond() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 10), (2, 10), (3, 10)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(30, Some(30)),
+            Some(5)
+        ));
+        assert_ok!(Vote::set_bonded_voting(
+            1,
+            BondedVotingConfig::new(10, true)
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            None
+        ));
+        System::set_block_number(7);
+        assert_ok!(Vote::settle_bonds(Origin::signed(3), 1));
+        assert_eq!(get_last_event(), RawEvent::BondsSettled(1, 20, 0));
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
+
+#[test]
+fn multiround_vote_runs_off_top_candidates() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1), (4, 1), (5, 1)].into();
+        let rounds = vec![
+            RoundSpec::new(Threshold::new(4, None), Some(5), 2),
+            RoundSpec::new(Threshold::new(3, Some(2)), Some(5), 1),
+        ];
+        assert_noop!(
+            Vote::create_multiround_vote(
+                Origin::signed(1),
+                Vec::new(),
+                vote_set.clone(),
+                rounds.clone()
+            ),
+            Error::<Test>::MultiRoundVoteRequiresCandidates
+        );
+        assert_noop!(
+            Vote::create_multiround_vote(
+                Origin::signed(1),
+                vec![10, 20, 30],
+                vote_set.clone(),
+                Vec::new()
+            ),
+            Error::<Test>::MultiRoundVoteRequiresAtLeastOneRound
+        );
+        assert_ok!(Vote::create_multiround_vote(
+            Origin::signed(1),
+            vec![10, 20, 30],
+            vote_set,
+            rounds
+        ));
+        assert_eq!(get_last_event(), RawEvent::MultiRoundVoteStarted(1, 1));
+        assert_eq!(
+            Vote::multi_round_vote_states(1).unwrap().current_votes(),
+            vec![(10, 1), (20, 2), (30, 3)]
+        );
+        for (voter, vote_id) in
+            vec![(1, 1), (2, 1), (3, 2), (1, 3), (2, 3), (3, 3)]
+        {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                vote_id,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_noop!(
+            Vote::advance_to_next_round(Origin::signed(1), 1, 2),
+            Error::<Test>::CandidateCountWitnessTooLow
+        );
+        assert_noop!(
+            Vote::advance_to_next_round(Origin::signed(1), 1, 3),
+            Error::<Test>::RoundStillInProgress
+        );
+        // no candidate reached the threshold before the round expired
+        System::set_block_number(7);
+        assert_ok!(Vote::advance_to_next_round(Origin::signed(1), 1, 3));
+        assert_eq!(get_last_event(), RawEvent::MultiRoundVoteAdvanced(1, 1));
+        let multi_round = Vote::multi_round_vote_states(1).unwrap();
+        assert_eq!(multi_round.current_round(), 1);
+        assert_eq!(multi_round.current_votes(), vec![(30, 4), (10, 5)]);
+        for voter in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                5,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_noop!(
+            Vote::advance_to_next_round(Origin::signed(1), 1, 3),
+            Error::<Test>::RoundStillInProgress
+        );
+        for voter in 4u64..6u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                4,
+                VoterView::Against,
+                None
+            ));
+        }
+        // decided votes may still change until they are finalized
+        assert_noop!(
+            Vote::advance_to_next_round(Origin::signed(1), 1, 3),
+            Error::<Test>::RoundStillInProgress
+        );
+        run_to_block(13);
+        assert_eq!(Vote::final_outcomes(5), Some(VoteOutcome::Approved));
+        assert_ok!(Vote::advance_to_next_round(Origin::signed(1), 1, 3));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MultiRoundVoteFinalized(1, 5, VoteOutcome::Approved)
+        );
+        assert!(Vote::multi_round_vote_states(1).is_none());
+        assert!(Vote::multi_round_voters(1).is_none());
+    });
+}
+
+#[test]
+fn mint_into_rejects_malformed_voter_sets() {
+    new_test_ext().execute_with(|| {
+        let decode = |total: u64, vec: Vec<(u64, u64)>| {
+            WeightedVector::<u64, u64>::decode(&mut &(total, vec).encode()[..])
+                .unwrap()
+        };
+        let mut minted = Vec::new();
+        let vote_set = decode(30, vec![(1, 10), (2, 20)]);
+        assert_eq!(
+            vote_set.mint_into(|who, signal| minted.push((*who, *signal))),
+            Ok(30)
+        );
+        assert_eq!(minted, vec![(1, 10), (2, 20)]);
+        // the closure never runs for malformed sets
+        let mismatched = decode(31, vec![(1, 10), (2, 20)]);
+        assert_eq!(mismatched.mint_into(|_, _| panic!()), Err(()));
+        let overflowing = decode(u64::MAX, vec![(1, u64::MAX), (2, 1)]);
+        assert_eq!(overflowing.mint_into(|_, _| panic!()), Err(()));
+        MAX_SIGNAL_PER_VOTE.with(|m| *m.borrow_mut() = u64::MAX);
+        assert_eq!(
+            Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                mismatched,
+                Threshold::new(10, None),
+                None
+            ),
+            Err(Error::<Test>::VoterSetTotalMustEqualSumOfSignal.into())
+        );
+        assert_eq!(
+            Vote::create_percent_vote(
+                Origin::signed(1),
+                None,
+                overflowing,
+                Threshold::new(Permill::one(), None),
+                None
+            ),
+            Err(Error::<Test>::VoterSetTotalMustEqualSumOfSignal.into())
+        );
+        assert!(Vote::vote_states(1).is_none());
+        assert!(Vote::vote_logger(1, 1).is_none());
+        MAX_SIGNAL_PER_VOTE.with(|m| *m.borrow_mut() = 100);
+    });
+}
+
+#[test]
+fn proportional_threshold_follows_voter_changes() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set.clone(),
+            Threshold::new(3, None),
+            None
+        ));
+        assert_noop!(
+            Vote::set_proportional_threshold(1, true),
+            Error::<Test>::OnlyPercentVotesHaveProportionalThresholds
+        );
+        // 34% of 6 => 3 in favor
+        for _ in 0..2 {
+            assert_ok!(Vote::create_percent_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                Threshold::new(Permill::from_percent(34), None),
+                None,
+            ));
+        }
+        assert_eq!(
+            Vote::percent_thresholds(2),
+            Some(Threshold::new(Permill::from_percent(34), None))
+        );
+        assert_ok!(Vote::set_proportional_threshold(2, true));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            2,
+            VoterView::InFavor,
+            None
+        ));
+        assert_noop!(
+            Vote::remove_voter(2, 1),
+            Error::<Test>::CannotRemoveVoterAfterTheyVoted
+        );
+        for vote_id in 2u64..4u64 {
+            assert_ok!(Vote::remove_voter(vote_id, 5));
+            assert_ok!(Vote::remove_voter(vote_id, 6));
+            assert!(Vote::vote_logger(vote_id, 6).is_none());
+            assert_eq!(Vote::total_signal_issuance(vote_id), Some(4));
+            assert_eq!(
+                Vote::vote_states(vote_id).unwrap().all_possible_turnout(),
+                4
+            );
+        }
+        // 34% of 4 => 2 in favor, the fixed threshold still needs 3
+        assert_eq!(Vote::vote_states(2).unwrap().threshold().in_favor(), 2);
+        assert_eq!(Vote::vote_states(3).unwrap().threshold().in_favor(), 3);
+        assert_noop!(
+            Vote::remove_voter(3, 4),
+            Error::<Test>::RemovingVoterLeavesThresholdUnreachable
+        );
+        // registering a voter re-derives the threshold as well
+        assert_ok!(Vote::register_voter(2, 7, 2));
+        assert_eq!(Vote::vote_states(2).unwrap().threshold().in_favor(), 3);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            2,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::get_vote_outcome(2).unwrap(), VoteOutcome::Voting);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(7),
+            2,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::get_vote_outcome(2).unwrap(), VoteOutcome::Approved);
+    });
+}
+
+#[test]
+fn scheduler_finalizes_votes_when_they_expire() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        for duration in [None, Some(5), Some(5)].iter() {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                Threshold::new(2, None),
+                *duration
+            ));
+        }
+        assert_eq!(Vote::open_vote_counter(), 3);
+        assert!(Vote::scheduled_finalizations(1).is_none());
+        assert_eq!(
+            TestScheduler::next_dispatch_time(
+                Vote::scheduled_finalizations(2).unwrap()
+            ),
+            Ok(7)
+        );
+        assert_noop!(
+            Vote::finalize_vote(Origin::signed(1), 2),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Vote::finalize_vote(Origin::root(), 2),
+            Error::<Test>::CannotFinalizeVoteBeforeItExpires
+        );
+        assert_ok!(Vote::extend_vote_length(3, 10));
+        assert_eq!(
+            TestScheduler::next_dispatch_time(
+                Vote::scheduled_finalizations(3).unwrap()
+            ),
+            Ok(12)
+        );
+        for voter in 1u64..3u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(6);
+        assert!(Vote::final_outcomes(2).is_none());
+        run_to_block(7);
+        assert_eq!(Vote::final_outcomes(2), Some(VoteOutcome::Approved));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteFinalized(2, VoteOutcome::Approved)
+        );
+        assert!(Vote::scheduled_finalizations(2).is_none());
+        assert_eq!(Vote::open_vote_counter(), 2);
+        assert_noop!(
+            Vote::finalize_vote(Origin::root(), 2),
+            Error::<Test>::VoteAlreadyFinalized
+        );
+        // the extended vote is finalized at its new end
+        assert!(Vote::final_outcomes(3).is_none());
+        run_to_block(12);
+        assert_eq!(
+            Vote::final_outcomes(3),
+            Some(VoteOutcome::ExpiredInconclusive)
+        );
+        assert_eq!(Vote::open_vote_counter(), 1);
+    });
+}
+
+#[test]
+fn undecided_votes_expire_inconclusive() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(2, None),
+            Some(5)
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(6);
+        assert_eq!(Vote::get_vote_outcome(1), Ok(VoteOutcome::Voting));
+        run_to_block(7);
+        assert_eq!(
+            Vote::get_vote_outcome(1),
+            Ok(VoteOutcome::ExpiredInconclusive)
+        );
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteFinalized(1, VoteOutcome::ExpiredInconclusive)
+        );
+        // the tally itself is left as it was when the vote ended
+        assert_eq!(
+            Vote::vote_states(1).unwrap().outcome(),
+            VoteOutcome::Voting
+        );
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(2), 1, VoterView::InFavor, None),
+            Error::<Test>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+    });
+}
+
+#[test]
+fn root_force_finalizes_wedged_votes() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(2, None),
+            Some(5)
+        ));
+        assert_noop!(
+            Vote::force_finalize_vote(
+                Origin::signed(1),
+                1,
+                VoteOutcome::Rejected
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Vote::force_finalize_vote(Origin::root(), 2, VoteOutcome::Rejected),
+            Error::<Test>::CannotFinalizeVoteIfVoteStateDNE
+        );
+        // a finalized vote can't be left open
+        assert_noop!(
+            Vote::force_finalize_vote(Origin::root(), 1, VoteOutcome::Voting),
+            Error::<Test>::CannotForceFinalizeVoteWithNonTerminalOutcome
+        );
+        assert_noop!(
+            Vote::force_finalize_vote(
+                Origin::root(),
+                1,
+                VoteOutcome::NotStarted
+            ),
+            Error::<Test>::CannotForceFinalizeVoteWithNonTerminalOutcome
+        );
+        let address = Vote::scheduled_finalizations(1).unwrap();
+        assert_ok!(Vote::force_finalize_vote(
+            Origin::root(),
+            1,
+            VoteOutcome::Rejected
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteForceFinalized(1, VoteOutcome::Rejected)
+        );
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Rejected);
+        assert_eq!(Vote::open_vote_counter(), 0);
+        // the scheduled finalization is cancelled
+        assert!(Vote::scheduled_finalizations(1).is_none());
+        assert_eq!(TestScheduler::next_dispatch_time(address), Err(()));
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(1), 1, VoterView::InFavor, None),
+            Error::<Test>::VoteAlreadyFinalized
+        );
+        assert_noop!(
+            Vote::force_finalize_vote(Origin::root(), 1, VoteOutcome::Approved),
+            Error::<Test>::VoteAlreadyFinalized
+        );
+    });
+}
+
+#[test]
+fn voter_sets_over_the_bounds_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let too_many: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1)].into();
+        assert_noop!(
+            Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                too_many,
+                Threshold::new(4, None),
+                None
+            ),
+            Error::<Test>::VoterSetTooLarge
+        );
+        let too_much: WeightedVector<u64, u64> = vec![(1, 60), (2, 41)].into();
+        assert_noop!(
+            Vote::create_percent_vote(
+                Origin::signed(1),
+                None,
+                too_much,
+                Threshold::new(Permill::from_percent(51), None),
+                None
+            ),
+            Error::<Test>::SignalExceedsBound
+        );
+        // both bounds are inclusive
+        let at_bounds: WeightedVector<u64, u64> =
+            vec![(1, 50), (2, 10), (3, 10), (4, 10), (5, 10), (6, 10)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            at_bounds,
+            Threshold::new(51, None),
+            None
+        ));
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 100);
+        // late registrations count against the bounds
+        assert_noop!(
+            Vote::register_voter(1, 7, 1),
+            Error::<Test>::VoterSetTooLarge
+        );
+        let five: WeightedVector<u64, u64> =
+            vec![(1, 60), (2, 10), (3, 10), (4, 10), (5, 10)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            five,
+            Threshold::new(51, None),
+            None
+        ));
+        assert_noop!(
+            Vote::register_voter(2, 6, 1),
+            Error::<Test>::SignalExceedsBound
+        );
+    });
+}
+
+#[test]
+fn votes_by_account_tracks_open_votes() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        for duration in [Some(5), None].iter() {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                Threshold::new(2, None),
+                *duration
+            ));
+        }
+        assert_eq!(Vote::votes_of(1), vec![1, 2]);
+        assert_ok!(Vote::register_voter(2, 7, 1));
+        assert_eq!(Vote::votes_of(7), vec![2]);
+        assert_ok!(Vote::remove_voter(2, 7));
+        assert_ok!(Vote::remove_voter(2, 3));
+        assert!(Vote::votes_of(7).is_empty());
+        assert_eq!(Vote::votes_of(3), vec![1]);
+        // finalizing the vote unindexes every voter
+        run_to_block(7);
+        assert!(Vote::final_outcomes(1).is_some());
+        assert_eq!(Vote::votes_of(1), vec![2]);
+        assert!(!VotesByAccount::<Test>::contains_key(3));
+        for _ in 0..4 {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                Threshold::new(2, None),
+                None
+            ));
+        }
+        assert_eq!(Vote::votes_of(1), vec![2, 3, 4, 5, 6]);
+        // a full entry rejects new votes instead of evicting indexed ones
+        assert_noop!(
+            Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                Threshold::new(2, None),
+                None
+            ),
+            Error::<Test>::AccountVoteIndexFull
+        );
+        let other_set: WeightedVector<u64, u64> = vec![(7, 1), (8, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            other_set,
+            Threshold::new(1, None),
+            None
+        ));
+        assert_noop!(
+            Vote::register_voter(7, 1, 1),
+            Error::<Test>::AccountVoteIndexFull
+        );
+        assert_eq!(Vote::votes_of(1), vec![2, 3, 4, 5, 6]);
+    });
+}
+
+#[test]
+fn shutdown_mode_finalizes_open_votes() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        for _ in 0..3 {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                Threshold::new(2, None),
+                None
+            ));
+        }
+        for voter in 1u64..3u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_noop!(
+            Vote::set_shutdown_mode(Origin::signed(1), true),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Vote::set_shutdown_mode(Origin::root(), true));
+        assert_eq!(get_last_event(), RawEvent::ShutdownModeSet(true));
+        assert_noop!(
+            Vote::create_percent_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                Threshold::new(Permill::one(), None),
+                None
+            ),
+            Error::<Test>::PalletIsShuttingDown
+        );
+        // two vote ids are checked per block
+        Vote::on_initialize(2);
+        assert_eq!(Vote::open_vote_counter(), 1);
+        assert_eq!(Vote::shutdown_cursor(), 3);
+        Vote::on_initialize(3);
+        assert_eq!(Vote::open_vote_counter(), 0);
+        assert_eq!(get_last_event(), RawEvent::VotePalletShutdownComplete);
+        assert_eq!(Vote::final_outcomes(1), Some(VoteOutcome::Approved));
+        // undecided votes are inconclusive rather than rejected
+        assert_eq!(
+            Vote::final_outcomes(2),
+            Some(VoteOutcome::ExpiredInconclusive)
+        );
+        assert_eq!(
+            Vote::final_outcomes(3),
+            Some(VoteOutcome::ExpiredInconclusive)
+        );
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(3), 2, VoterView::InFavor, None),
+            Error::<Test>::VoteAlreadyFinalized
+        );
+        assert_ok!(Vote::set_shutdown_mode(Origin::root(), false));
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(2, None),
+            None
+        ));
+        assert_eq!(Vote::open_vote_counter(), 1);
+    });
+}
+
+#[test]
+fn votes_may_be_retracted_during_the_grace_period() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(3, None),
+            Some(5)
+        ));
+        assert_noop!(
+            Vote::configure_grace_period(Origin::signed(2), 1, 3),
+            Error::<Test>::OnlyVoteCreatorCanConfigureVote
+        );
+        assert_ok!(Vote::configure_grace_period(Origin::signed(1), 1, 3));
+        assert_eq!(get_last_event(), RawEvent::VoteConfigured(1, 1));
+        // finalization waits for the grace period after the end at block 6
+        assert_eq!(
+            TestScheduler::next_dispatch_time(
+                Vote::scheduled_finalizations(1).unwrap()
+            ),
+            Ok(10)
+        );
+        for voter in 1u64..3u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(7);
+        assert_noop!(
+            Vote::set_grace_period(1, 5),
+            Error::<Test>::CannotSetGracePeriodAfterVoteExpired
+        );
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(3), 1, VoterView::InFavor, None),
+            Error::<Test>::OnlyRetractionAllowedDuringGracePeriod
+        );
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(1), 1, VoterView::Against, None),
+            Error::<Test>::OnlyRetractionAllowedDuringGracePeriod
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::Abstain,
+            None
+        ));
+        assert_eq!(Vote::vote_states(1).unwrap().in_favor(), 1);
+        run_to_block(9);
+        assert!(Vote::final_outcomes(1).is_none());
+        // sealed once the grace period passes
+        run_to_block(10);
+        assert!(Vote::final_outcomes(1).is_some());
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(2), 1, VoterView::Abstain, None),
+            Error::<Test>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+    });
+}
+
+#[test]
+fn percent_thresholds_never_round_to_zero() {
+    new_test_ext().execute_with(|| {
+        let forty_percent = Threshold::new(Permill::from_percent(40), None);
+        let voters: Vec<(u64, u64)> = vec![(1, 1), (2, 1), (3, 1)];
+        // totals of 1, 2 and 3 need 1, 1 and 2 in favor
+        for (total, needed) in [(1u64, 1u64), (2, 1), (3, 2)].iter() {
+            let vote_set: WeightedVector<u64, u64> =
+                voters[..*total as usize].to_vec().into();
+            assert_ok!(Vote::create_percent_vote(
+                Origin::signed(1),
+                None,
+                vote_set,
+                forty_percent.clone(),
+                None
+            ));
+            let vote_state = Vote::vote_states(*total).unwrap();
+            assert_eq!(vote_state.threshold().in_favor(), *needed);
+            assert_eq!(vote_state.outcome(), VoteOutcome::Voting);
+        }
+        let no_signal: WeightedVector<u64, u64> = vec![(1, 0)].into();
+        assert_noop!(
+            Vote::create_percent_vote(
+                Origin::signed(1),
+                None,
+                no_signal,
+                forty_percent,
+                None
+            ),
+            Error::<Test>::ThresholdRoundsToZero
+        );
+    });
+}
+
+#[test]
+fn vote_metrics_track_peak_and_total_votes() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        let open = || {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                vote_set.clone(),
+                Threshold::new(2, None),
+                None
+            ));
+        };
+        open();
+        open();
+        assert_ok!(Vote::force_finalize_vote(
+            Origin::root(),
+            1,
+            VoteOutcome::Approved
+        ));
+        open();
+        assert_ok!(Vote::force_finalize_vote(
+            Origin::root(),
+            2,
+            VoteOutcome::Rejected
+        ));
+        assert_ok!(Vote::force_finalize_vote(
+            Origin::root(),
+            3,
+            VoteOutcome::Rejected
+        ));
+        let metrics = Vote::get_vote_metrics();
+        assert_eq!(metrics.current_open(), 0);
+        assert_eq!(metrics.peak_open(), 2);
+        assert_eq!(metrics.total_ever(), 3);
+        let mut by_outcome = metrics.by_outcome();
+        by_outcome.sort_by_key(|(_, n)| *n);
+        assert_eq!(
+            by_outcome,
+            vec![(VoteOutcome::Approved, 1), (VoteOutcome::Rejected, 2)]
+        );
+    });
+}
+
+#[test]
+fn joined_voters_lock_their_signal_until_finalization() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> = vec![(1, 10)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(40, None),
+            Some(5)
+        ));
+        assert_noop!(
+            Vote::join_vote(Origin::signed(2), 1, 50),
+            Error::<Test>::VoteNotOpenToJoin
+        );
+        assert_noop!(
+            Vote::configure_joinable(Origin::signed(2), 1, true),
+            Error::<Test>::OnlyVoteCreatorCanConfigureVote
+        );
+        assert_ok!(Vote::configure_joinable(Origin::signed(1), 1, true));
+        assert_noop!(
+            Vote::join_vote(Origin::signed(2), 1, 0),
+            Error::<Test>::CannotJoinVoteWithoutLock
+        );
+        assert_noop!(
+            Vote::join_vote(Origin::signed(4), 1, 10),
+            Error::<Test>::InsufficientBalanceToJoinVote
+        );
+        assert_ok!(Vote::join_vote(Origin::signed(2), 1, 50));
+        assert_eq!(get_last_event(), RawEvent::VoteJoined(1, 2, 50));
+        assert_ok!(Vote::join_vote(Origin::signed(3), 1, 30));
+        assert_noop!(
+            Vote::join_vote(Origin::signed(2), 1, 10),
+            Error::<Test>::VoterAlreadyRegisteredForVote
+        );
+        // signal equals the lock
+        assert_eq!(Balances::reserved_balance(2), 50);
+        assert_eq!(Vote::vote_logger(1, 2).unwrap().magnitude(), 50);
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 90);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::get_vote_outcome(1), Ok(VoteOutcome::Approved));
+        // every lock is released when the vote is finalized
+        run_to_block(7);
+        assert_eq!(Vote::final_outcomes(1), Some(VoteOutcome::Approved));
+        for voter in 2u64..4u64 {
+            assert_eq!(Balances::reserved_balance(voter), 0);
+            assert_eq!(Balances::free_balance(voter), 100);
+            assert!(Vote::vote_locks(1, voter).is_none());
+        }
+        assert!(!Vote::joinable_votes(1));
+    });
+}
+
+#[test]
+fn creator_overrides_signal_before_the_voter_votes() {
+    new_test_ext().execute_with(|| {
+        let vote_set: WeightedVector<u64, u64> =
+            vec![(1, 1), (2, 1), (3, 1)].into();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            vote_set,
+            Threshold::new(3, None),
+            Some(10)
+        ));
+        assert_eq!(Vote::vote_creators(1), Some(1));
+        assert_noop!(
+            Vote::override_voter_signal(Origin::signed(2), 1, 3, 5),
+            Error::<Test>::OnlyVoteCreatorCanOverrideSignal
+        );
+        assert_noop!(
+            Vote::override_voter_signal(Origin::signed(1), 1, 4, 5),
+            Error::<Test>::VoterNotRegisteredForVote
+        );
+        // 1 + 1 + 0 falls below the threshold of 3
+        assert_noop!(
+            Vote::override_voter_signal(Origin::signed(1), 1, 3, 0),
+            Error::<Test>::InputThresholdExceedsBounds
+        );
+        // 1 + 1 + 99 exceeds the bound of 100
+        assert_noop!(
+            Vote::override_voter_signal(Origin::signed(1), 1, 3, 99),
+            Error::<Test>::SignalExceedsBound
+        );
+        // joined voters keep the signal of their lock
+        assert_ok!(Vote::set_joinable(1, true));
+        assert_ok!(Vote::join_vote(Origin::signed(4), 1, 2));
+        assert_noop!(
+            Vote::override_voter_signal(Origin::signed(1), 1, 4, 5),
+            Error::<Test>::CannotOverrideSignalBackedByLock
+        );
+        assert_ok!(Vote::override_voter_signal(Origin::signed(1), 1, 3, 5));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoterSignalOverridden(1, 3, 1, 5)
+        );
+        assert_eq!(Vote::vote_logger(1, 3).unwrap().magnitude(), 5);
+        assert_eq!(Vote::total_signal_issuance(1), Some(9));
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 9);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert_noop!(
+            Vote::override_voter_signal(Origin::signed(1), 1, 3, 1),
+            Error::<Test>::CannotOverrideSignalAfterVoterVoted
+        );
+    });
+}
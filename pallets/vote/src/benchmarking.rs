@@ -0,0 +1,89 @@
+//! Benchmarks for opening a vote from an org's membership, with and without the
+//! membership scan `open_vote_unchecked` skips
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use util::{
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+};
+
+const SEED: u32 = 0;
+/// Members of the largest org the benchmarks open a vote for
+const MAX_MEMBERS: u32 = 1000;
+
+/// Registers an org of `m` members with one share each
+fn org_with_members<T: Trait>(m: u32) -> T::OrgId {
+    let members = (0..m)
+        .map(|i| (account("member", i, SEED), 1u32.into()))
+        .collect::<Vec<(T::AccountId, T::Shares)>>();
+    <org::Module<T>>::register_organization(
+        OrganizationSource::AccountsWeighted(members),
+        None,
+        T::Cid::default(),
+    )
+    .expect("org ids are available in a fresh chain")
+}
+
+/// Asserts the last vote opened was minted one signal per member
+fn assert_vote_minted<T: Trait>(m: u32) {
+    let vote_id = <VoteIdCounter<T>>::get();
+    let state = <VoteStates<T>>::get(vote_id).expect("vote was just opened");
+    assert_eq!(state.all_possible_turnout(), m.into());
+}
+
+benchmarks! {
+    _ { }
+
+    // validated path, reads the org's membership before minting
+    open_vote_with_min_signal {
+        let m in 1 .. MAX_MEMBERS;
+        let org = org_with_members::<T>(m);
+        let threshold = Threshold::new(T::Signal::one(), None);
+    }: {
+        Module::<T>::open_vote_with_min_signal(
+            None,
+            OrgRep::Weighted(org),
+            threshold,
+            None,
+            None,
+        )?;
+    }
+    verify {
+        assert_vote_minted::<T>(m);
+    }
+
+    // trusted path, mints from a genesis the caller already holds
+    open_vote_unchecked {
+        let m in 1 .. MAX_MEMBERS;
+        let org = org_with_members::<T>(m);
+        let genesis = <org::Module<T>>::get_membership_with_shape(org)
+            .expect("org was just registered");
+        let threshold = Threshold::new(T::Signal::one(), None);
+    }: {
+        Module::<T>::open_vote_unchecked(None, org, genesis, threshold, None)?;
+    }
+    verify {
+        assert_vote_minted::<T>(m);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{
+        new_test_ext,
+        Test,
+    };
+    use frame_support::assert_ok;
+
+    #[test]
+    fn benchmarks_run_against_the_mock() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_open_vote_with_min_signal::<Test>());
+            assert_ok!(test_benchmark_open_vote_unchecked::<Test>());
+        });
+    }
+}
@@ -12,38 +12,249 @@
 //! member (`AccountId`) has some quantity of `Signal` in proportion
 //! to their relative `Shares` ownership in the `org` module.
 //!
+//! ## Runtime API
+//!
+//! `VoteApi` exposes how each member voted, either all at once or one page at a
+//! time, and `project_outcome` previews whether a member's vote would change the
+//! outcome before they cast it. `VoteRegistry` lists the votes opened for an org
+//! from the `VotesByOrg` index, filtered by `VoteStatus`, and counts open votes.
+//!
+//! ## Expired Votes
+//!
+//! `get_vote_outcome` reports a vote that ended without being approved or rejected
+//! as `ExpiredInconclusive`, so consumers can tell an undecided vote that ended from
+//! one still counting.
+//!
+//! ## Participation Index
+//!
+//! `VoteParticipationIndex` maps each account to the votes it has cast a vote in, for
+//! voter history and participation rates. Runtimes that only need it for open votes
+//! set `PruneParticipationIndex` to drop a vote's entries once it closes. A vote is
+//! queued in `ParticipationPrunes` for the block after its end and for the block
+//! after an outcome closes it, and `on_initialize` prunes it then if it is still
+//! closed, so casting a vote never pays for pruning.
+//!
+//! ## Vote Pools
+//!
+//! A parent org's supervisor can pool open votes of the org and its immediate sub
+//! orgs, up to `MaxVotesPerPool`, with `create_vote_pool`.
+//! Each member vote contributes its share of possible turnout in favor and against,
+//! scaled by its `Permill` weight, and `compute_pool_outcome` applies the pool's own
+//! threshold to the weighted sums.
+//!
+//! ## Logging
+//!
+//! Opening a vote, applying a vote and a vote closing are logged under the
+//! `runtime::vote` target. Only native execution logs so block execution in wasm is
+//! unaffected.
+//!
+//! ## Vote Genesis Events
+//!
+//! Minting a vote's signal emits no event per member. Once a vote is opened,
+//! `VoteGenesisSet` reports how many members were minted signal and the vote's total
+//! signal, so indexers have the baseline to replay its `Voted` events against. The
+//! event stays the same size however large the org is; each member's signal is read
+//! from `VoteLogger` under the vote id at the block the vote was opened.
+//!
+//! ## Signal Caps
+//!
+//! `open_vote_with_signal_cap` clamps each member's signal to an absolute limit, a
+//! percentage of the org's uncapped signal, or the lower of both. Thresholds are
+//! checked against the capped total so a large holder cannot carry a vote alone. A
+//! zero limit is rejected with `SignalCapMustBeNonZero`.
+//!
+//! ## Multi-Class Votes
+//!
+//! `open_multi_class_vote` opens a vote for members of several orgs, each treated as
+//! a share class with a multiplier. A member's signal is the sum of their shares in
+//! each class times its multiplier. Opening fails rather than saturating if any
+//! weight overflows.
+//!
+//! ## Voting Proxies
+//!
+//! Votes minted from an org's shares credit a member's signal to the voting proxy
+//! they set in the org pallet, if proxy voting is active there. The member keeps a
+//! zero signal entry. Votes with one signal per member ignore proxies.
+//!
+//! Each delegation is recorded per vote in `VoteDelegations` and announced with
+//! `SignalDelegated`. A delegator left without signal is logged with the `Delegated`
+//! view rather than `Uninitialized`, so audits can tell delegation from abstention.
+//! It counts towards no tally and cannot vote until it revokes. A delegator may take
+//! its signal back with `revoke_delegation` while the vote is open. If the proxy
+//! already voted, the returned signal leaves the tally and the delegator holds it
+//! without a view. `MaxDelegatorsPerVote` caps the delegators one proxy represents
+//! in a vote; holders past the cap keep their own signal.
+//!
+//! ## Vote Comments
+//!
+//! Any account, including one without signal in the vote, may attach a comment to
+//! a vote with `submit_vote_comment`. Each account keeps one comment per vote which
+//! it may replace. Comments are stored as content references and never change the
+//! tally. The call carries a non-zero weight so its fee deters comment spam.
+//!
+//! ## Conviction Voting
+//!
+//! `submit_vote` takes a conviction level for a voter's first vote in favor or
+//! against. Each level multiplies the voter's signal and locks their vote for a
+//! number of `ConvictionLockPeriod`s, see `conviction_terms`: 0 is 1x and unlocked,
+//! 1 is 2x for one period and 2 is 4x for two periods. The possible turnout grows
+//! by the signal a conviction adds, so voters who vote later still fit in the
+//! tally. A locked vote cannot be changed, and a voting proxy's locked vote
+//! cannot have delegations revoked from it, until the lock ends. The voter's
+//! shares in the vote's org cannot be burned or transferred until the vote ends
+//! or the lock ends, whichever is later. The multiplier stays with the vote after
+//! the lock ends.
+//!
+//! ## Signal Decay
+//!
+//! `set_vote_decay` gives a vote with an end a `DecayConfig`. Once `decay_start` of
+//! the vote's duration has passed, every `period` blocks `on_initialize` takes
+//! `decay_per_period` of the signal each account that has not voted held before
+//! decay started, and removes it from the vote's possible turnout. Accounts that
+//! voted keep their signal so their share of the possible turnout grows. Decay
+//! never takes the possible turnout below what the vote's thresholds and quorum
+//! require, so it cannot leave a vote unpassable.
+//!
+//! The creator of a vote opened by extrinsic sets its decay with
+//! `configure_vote_decay`, its quorum with `configure_vote_quorum` and its minimum
+//! distinct voters with `configure_min_distinct_voters`. Other accounts fail with
+//! `OnlyVoteCreatorCanConfigureVote`. Quorum and minimum distinct voters can only
+//! be changed while the vote is open and before anyone votes. Both calls take
+//! the vote's voter count, which weighs them, and fail with
+//! `VoterCountWitnessTooLow` if the vote has more voters.
+//!
+//! ## Snapshot Votes
+//!
+//! `open_vote_from_snapshot` mints signal from a membership snapshot stored in the
+//! org pallet instead of the live membership, so a vote opened some time after it
+//! was proposed counts the members at the time of the proposal.
+//!
+//! ## Seniority Weighted Votes
+//!
+//! `open_seniority_weighted_vote` mints each member's shares plus the bonus their
+//! tenure earns under the org's `SeniorityConfig`, so the vote's total is the sum of
+//! the weighted shares rather than the org's issuance. Shares are weighed by the
+//! holder's tenure before they are credited to any voting proxy.
+//!
+//! ## Distinct Voters
+//!
+//! `set_min_distinct_voters` requires a number of voters to have voted before a met
+//! threshold approves the vote, so one large holder cannot decide it alone. Every
+//! voter who casts any view counts once, abstaining included. Votes cannot be
+//! retracted back to no view so the count only grows.
+//!
+//! ## Vote Creation Permissions
+//!
+//! `create_signal_vote` and `create_percent_vote` are open to the org's supervisor.
+//! The supervisor may also let others open votes with `set_open_vote_permission`:
+//! `AnyMember` admits the org's members and `NamedProposers` admits a list of
+//! accounts. `ControllerOnly`, the default, admits no one else. Callers not admitted
+//! fail with `NotAuthorizedToOpenVote`.
+//!
+//! ## Vote Creation Deposits
+//!
+//! `create_signal_vote` and `create_percent_vote` reserve `VoteCreationDeposit` from
+//! the creator. Once the vote closes, by outcome or expiry, anyone may call
+//! `settle_vote_deposit`. It records the vote's turnout as a share of its possible
+//! turnout in `SettledTurnout`. At `FullRefundTurnout` or above the deposit is
+//! refunded in full; below it the creator gets back the same fraction of the deposit
+//! as the fraction of `FullRefundTurnout` reached, and the rest goes to `Treasury`.
+//! Cancelling a vote, which is only possible before anyone votes, refunds it in full.
+//!
+//! ## Vote Creation Fees
+//!
+//! Besides the deposit, the same extrinsics burn `VoteCreationFee` from the creator
+//! and emit `VoteCreationFeeCharged`. Unlike the deposit the fee is never returned to
+//! the creator. If the vote closes `Approved` or `Rejected`, which requires its quorum
+//! to be reached, an equal amount is minted into the `VoteRewardPool` account, the
+//! module's own treasury. Fees of cancelled or inconclusive votes stay burned.
+//!
+//! ## Conflicts Of Interest
+//!
+//! Every vote is checked against `ConflictCheck` before it is applied. A voter it
+//! flags, e.g. the recipient of the bank spend the vote approves, fails with
+//! `VoterHasConflictOfInterest`. The flagged voter's signal stays in the vote's
+//! possible turnout, so excluding them does not lower the threshold.
+//!
+//! ## Migrations
+//!
+//! `StorageVersion` records the layout of stored `VoteState`s. `on_runtime_upgrade`
+//! translates every stored vote from the previous layout when the version is behind;
+//! see the docs on `VoteState` for the steps to follow when adding a field.
+//!
+//! ## Benchmarks
+//!
+//! With the `runtime-benchmarks` feature, `benchmarking.rs` measures opening a vote
+//! for orgs of up to 1000 members through `open_vote_with_min_signal`, which reads the
+//! org's membership, and through `open_vote_unchecked`, which mints from a genesis the
+//! caller already holds.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 
+pub mod runtime_api;
+
 use frame_support::{
+    debug,
     decl_error,
     decl_event,
     decl_module,
     decl_storage,
     ensure,
+    storage::{
+        generator,
+        IterableStorageDoubleMap,
+        IterableStorageMap,
+    },
+    traits::{
+        BalanceStatus,
+        Currency,
+        ExistenceRequirement,
+        Get,
+        Imbalance,
+        ReservableCurrency,
+        WithdrawReason,
+        WithdrawReasons,
+    },
+    weights::Weight,
+    Blake2_128Concat,
     Parameter,
+    ReversibleStorageHasher,
 };
 use frame_system::{
     ensure_signed,
     Trait as System,
 };
 use org::Trait as Org;
-use parity_scale_codec::Codec;
+use parity_scale_codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use sp_runtime::{
     traits::{
+        AccountIdConversion,
         AtLeast32BitUnsigned,
+        CheckedMul,
         CheckedSub,
         MaybeSerializeDeserialize,
         Member,
+        One,
+        Saturating,
         Zero,
     },
     DispatchError,
     DispatchResult,
+    ModuleId,
+    PerThing,
     Permill,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
@@ -51,36 +262,56 @@ use sp_std::{
 };
 use util::{
     organization::OrgRep,
+    share::WeightedVector,
     traits::{
         AccessGenesis,
         Apply,
         ApplyVote,
         CheckVoteStatus,
         ConfigureThreshold,
+        ConflictOfInterestChecker,
         GenerateUniqueID,
         GetGroup,
         GetVoteOutcome,
+        GroupMembership,
         IDIsAvailable,
         MintableSignal,
         OpenVote,
         OrganizationSupervisorPermissions,
         ShareInformation,
         UpdateVote,
+        VerifyShape,
         VoteOnProposal,
         VoteVector,
     },
     vote::{
+        conviction_terms,
+        DecayConfig,
+        OpenVotePermission,
         Threshold,
         ThresholdConfig,
         ThresholdInput,
+        SignalMintingCap,
         Vote,
         VoteOutcome,
+        VotePool,
         VoteState,
+        VoteStateV1,
+        VoteStateV2,
+        VoteStatus,
         VoterView,
         XorThreshold,
     },
 };
 
+/// Target of this module's node-side logs, i.e. `RUST_LOG=runtime::vote=trace`
+/// -> the log macros compile to nothing in wasm builds
+#[allow(dead_code)]
+const LOG_TARGET: &str = "runtime::vote";
+
+type BalanceOf<T> =
+    <<T as Trait>::Currency as Currency<<T as System>::AccountId>>::Balance;
+type Genesis<T> = WeightedVector<<T as System>::AccountId, <T as Org>::Shares>;
 type ThreshInput<T> = ThresholdInput<
     OrgRep<<T as Org>::OrgId>,
     XorThreshold<<T as Trait>::Signal, Permill>,
@@ -95,7 +326,23 @@ type VoteSt<T> = VoteState<
     <T as System>::BlockNumber,
     <T as Org>::Cid,
 >;
+type VoteStV1<T> = VoteStateV1<
+    <T as Trait>::Signal,
+    <T as System>::BlockNumber,
+    <T as Org>::Cid,
+>;
+type VoteStV2<T> = VoteStateV2<
+    <T as Trait>::Signal,
+    <T as System>::BlockNumber,
+    <T as Org>::Cid,
+>;
 type VoteVec<T> = Vote<<T as Trait>::Signal, <T as Org>::Cid>;
+/// Delegator, proxy and the shares credited to the proxy while minting a vote
+type Delegations<T> = Vec<(
+    <T as System>::AccountId,
+    <T as System>::AccountId,
+    <T as Org>::Shares,
+)>;
 
 pub trait Trait: System + Org {
     /// The overarching event type
@@ -140,6 +387,51 @@ pub trait Trait: System + Org {
         + PartialOrd
         + PartialEq
         + Zero;
+
+    /// Whether voters are indexed by the justification they cite
+    type IndexJustifications: Get<bool>;
+
+    /// Max open votes for a single org, zero for no limit
+    type MaxOpenVotesPerOrg: Get<u32>;
+
+    /// Whether a vote's entries in `VoteParticipationIndex` are removed when it closes
+    type PruneParticipationIndex: Get<bool>;
+
+    /// Max delegators a voting proxy represents in a single vote, zero for no limit
+    type MaxDelegatorsPerVote: Get<u32>;
+
+    /// Max votes pooled into one vote pool
+    type MaxVotesPerPool: Get<u32>;
+
+    /// Blocks a vote stays locked for each period its conviction level locks it
+    type ConvictionLockPeriod: Get<Self::BlockNumber>;
+
+    /// Voter entries `on_initialize` reads to decay unvoted signal in one block
+    /// -> queued decays and blocks of the queue walked count too, so it must be
+    /// non-zero for signal to decay
+    type DecayedVotersPerBlock: Get<u32>;
+
+    /// The currency in which vote creation deposits are reserved
+    type Currency: ReservableCurrency<Self::AccountId>;
+
+    /// Reserved from the creator of each vote opened by a create extrinsic
+    type VoteCreationDeposit: Get<BalanceOf<Self>>;
+
+    /// Share of the vote's possible turnout at which its deposit is refunded in full
+    type FullRefundTurnout: Get<Permill>;
+
+    /// Receives the part of a vote creation deposit that is not refunded
+    /// -> must be an existing account, settling a partial refund fails otherwise
+    type Treasury: Get<Self::AccountId>;
+
+    /// Burned from the creator of each vote opened by a create extrinsic
+    type VoteCreationFee: Get<BalanceOf<Self>>;
+
+    /// The module's treasury, credited with the fees of conclusive votes
+    type VoteRewardPool: Get<ModuleId>;
+
+    /// Bars voters who benefit directly from the vote, `()` bars no one
+    type ConflictCheck: ConflictOfInterestChecker<Self::AccountId, Self::VoteId>;
 }
 
 decl_event!(
@@ -147,11 +439,37 @@ decl_event!(
     where
         <T as System>::AccountId,
         <T as Trait>::VoteId,
+        <T as Trait>::Signal,
         <T as Trait>::ThresholdId,
+        <T as Org>::Cid,
+        Balance = BalanceOf<T>,
+        <T as Org>::OrgId,
     {
         ThresholdSet(ThresholdId),
         NewVoteStarted(AccountId, VoteId),
-        Voted(VoteId, AccountId, VoterView),
+        VoteCancelled(AccountId, VoteId),
+        /// Vote id, voter, new view, voter's signal
+        Voted(VoteId, AccountId, VoterView, Signal),
+        /// Creator, pool id
+        VotePoolCreated(AccountId, u32),
+        /// Vote id, delegator, proxy, signal returned to the delegator
+        DelegationRevoked(VoteId, AccountId, AccountId, Signal),
+        /// Vote id, delegator, proxy, signal credited to the proxy
+        SignalDelegated(VoteId, AccountId, AccountId, Signal),
+        /// Vote id, commenter, comment reference
+        VoteCommentSubmitted(VoteId, AccountId, Cid),
+        /// Vote id, creator, deposit refunded, deposit sent to the treasury
+        VoteDepositSettled(VoteId, AccountId, Balance, Balance),
+        /// Org, supervisor who changed who may open its votes
+        OpenVotePermissionSet(OrgId, AccountId),
+        /// Vote id, members minted signal, total signal minted
+        VoteGenesisSet(VoteId, u32, Signal),
+        /// Vote id, creator, fee burned
+        VoteCreationFeeCharged(VoteId, AccountId, Balance),
+        /// Vote id, fee minted into the reward pool
+        VoteRewardPoolCredited(VoteId, Balance),
+        /// Vote id, creator who changed its configuration
+        VoteConfigured(VoteId, AccountId),
     }
 );
 
@@ -159,7 +477,8 @@ decl_error! {
     pub enum Error for Module<T: Trait> {
         VotePastExpirationTimeSoVotesNotAccepted,
         SignalNotMintedForVoter,
-        NotAuthorizedToCreateVoteForOrganization,
+        NotAuthorizedToOpenVote,
+        OnlySupervisorCanSetOpenVotePermission,
         NoVoteStateForOutcomeQuery,
         NoVoteStateForVoteRequest,
         CannotMintSignalBecauseGroupMembershipDNE,
@@ -169,13 +488,81 @@ decl_error! {
         // i.e. changing from any non-NoVote view to NoVote (some vote changes aren't allowed to simplify assumptions)
         VoteChangeNotSupported,
         InputThresholdExceedsBounds,
+        ThresholdRoundsToZero,
         OnlySupervisorCanSetGenericThresholds,
         CannotInvokeThresholdThatDNE,
+        CannotSetQuorumIfVoteStateDNE,
+        QuorumExceedsPossibleTurnout,
+        WeightFractionOnlyAppliesToInFavorOrAgainst,
+        TooManyOpenVotesForOrg,
+        CannotCancelVoteIfVoteStateDNE,
+        OnlyVoteCreatorCanCancelVote,
+        CannotCancelVoteWithCastVotes,
+        VotePoolRequiresMemberVotes,
+        VotePoolWeightsMustMatchVotes,
+        VotePoolWeightsMustSumToOneHundredPercent,
+        VotePoolMemberVoteMustBeOpen,
+        VotePoolDNE,
+        OnlySupervisorCanCreateVotePool,
+        VotePoolMemberVoteMustBelongToOrgOrSubOrg,
+        VotePoolTooLarge,
+        VotePoolIdOverflow,
+        MultiClassSignalOverflow,
+        CannotSetMinDistinctVotersIfVoteStateDNE,
+        MinDistinctVotersExceedsVoters,
+        CannotChangeVoteCountingAfterVoteExpires,
+        CannotChangeVoteCountingAfterVoteCloses,
+        CannotChangeVoteCountingAfterVotesAreCast,
+        CannotOpenSeniorityWeightedVoteWithoutConfig,
+        CannotOpenVoteFromSnapshotThatDNE,
+        NoDelegationToRevokeForVote,
+        CannotRevokeDelegationAfterVoteCloses,
+        CannotRevokeDelegationAfterVotingAsProxy,
+        CannotCommentOnVoteThatDNE,
+        ConvictionExceedsMaximum,
+        ConvictionOnlyAppliesToInFavorOrAgainst,
+        ConvictionOnlyAppliesToFirstVote,
+        VoteLockedByConviction,
+        ConvictionOverflowsPossibleTurnout,
+        CannotRevokeDelegationFromConvictionVote,
+        MustRevokeDelegationBeforeVoting,
+        VoterHasConflictOfInterest,
+        CannotSetDecayIfVoteStateDNE,
+        DecayRequiresVoteWithEnd,
+        DecayPeriodMustBeNonZero,
+        InsufficientBalanceForVoteCreationDeposit,
+        NoVoteCreationDepositToSettle,
+        CannotSettleVoteCreationDepositBeforeVoteCloses,
+        InsufficientBalanceForVoteCreationFee,
+        SignalCapMustBeNonZero,
+        OnlyVoteCreatorCanConfigureVote,
+        VoterCountWitnessTooLow,
+    }
+}
+
+/// Storage layout versions of this module
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// Votes stored with the `VoteStateV1` layout
+    V1,
+    /// Votes stored with the `VoteStateV2` layout
+    V2,
+    /// Votes stored with the current `VoteState` layout
+    V3,
+}
+
+impl Default for Releases {
+    fn default() -> Releases {
+        Releases::V1
     }
 }
 
 decl_storage! {
     trait Store for Module<T: Trait> as Vote {
+        /// The storage layout version, used to gate migrations
+        /// -> new chains start at the latest version so no migration runs on them
+        StorageVersion get(fn storage_version) build(|_: &GenesisConfig| Releases::V3): Releases;
+
         /// The nonce for unique vote id generation
         VoteIdCounter get(fn vote_id_counter): T::VoteId;
 
@@ -201,6 +588,128 @@ decl_storage! {
         pub VoteLogger get(fn vote_logger): double_map
             hasher(blake2_128_concat) T::VoteId,
             hasher(blake2_128_concat) T::AccountId  => Option<VoteVec<T>>;
+
+        /// Index of all votes opened for each org
+        pub VotesByOrg get(fn votes_by_org): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::VoteId => ();
+
+        /// Votes each account has cast a vote in
+        /// -> only open votes are kept if `PruneParticipationIndex` is set
+        pub VoteParticipationIndex get(fn vote_participation_index): double_map
+            hasher(blake2_128_concat) T::AccountId,
+            hasher(blake2_128_concat) T::VoteId => Option<()>;
+
+        /// Votes whose `VoteParticipationIndex` entries are pruned in the block
+        /// -> only queued if `PruneParticipationIndex` is set
+        ParticipationPrunes get(fn participation_prunes): double_map
+            hasher(twox_64_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::VoteId => Option<()>;
+
+        /// Votes whose `OpenVotesPerOrg` slot is released in the block if they
+        /// have expired without an outcome closing them
+        OpenVoteExpiries get(fn open_vote_expiries): double_map
+            hasher(twox_64_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::VoteId => Option<()>;
+
+        /// The account which opened each vote with a create extrinsic
+        /// -> votes opened by other modules have no creator
+        pub VoteCreator get(fn vote_creator): map
+            hasher(blake2_128_concat) T::VoteId => Option<T::AccountId>;
+
+        /// The creator and the deposit reserved for votes opened by a create extrinsic
+        /// -> removed once the deposit is settled or the vote is cancelled
+        pub VoteDeposits get(fn vote_deposits): map
+            hasher(blake2_128_concat) T::VoteId => Option<(T::AccountId, BalanceOf<T>)>;
+
+        /// The fee burned on creation of votes opened by a create extrinsic
+        /// -> taken when the fee is minted into the reward pool, or once the vote
+        /// closes inconclusively and its deposit is settled
+        pub VoteCreationFees get(fn vote_creation_fees): map
+            hasher(blake2_128_concat) T::VoteId => Option<BalanceOf<T>>;
+
+        /// The vote's turnout, as a share of its possible turnout, when its deposit was settled
+        pub SettledTurnout get(fn settled_turnout): map
+            hasher(blake2_128_concat) T::VoteId => Option<Permill>;
+
+        /// The org whose membership minted each vote's signal
+        pub VoteOrg get(fn vote_org): map
+            hasher(blake2_128_concat) T::VoteId => Option<T::OrgId>;
+
+        /// Who besides the supervisor may open votes for each org
+        pub OpenVotePermissions get(fn open_vote_permissions): map
+            hasher(blake2_128_concat) T::OrgId => OpenVotePermission<T::AccountId>;
+
+        /// The number of open votes for each org
+        /// -> released when an outcome closes the vote or in the block after it ends
+        pub OpenVotesPerOrg get(fn open_votes_per_org): map
+            hasher(blake2_128_concat) T::OrgId => u32;
+
+        /// Voters indexed by the justification cited with their current vote
+        /// -> only populated if `IndexJustifications` is set
+        pub JustificationIndex get(fn justification_index): double_map
+            hasher(blake2_128_concat) (T::VoteId, T::Cid),
+            hasher(blake2_128_concat) T::AccountId => ();
+
+        /// Signal committed to the voter's direction when less than their magnitude
+        /// -> the uncommitted remainder is tallied as abstained
+        pub CommittedSignal get(fn committed_signal): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::Signal>;
+
+        /// The proxy credited with each delegator's signal and the signal credited
+        /// -> removed when the delegator revokes the delegation
+        pub VoteDelegations get(fn vote_delegations): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<(T::AccountId, T::Signal)>;
+
+        /// The conviction the voter voted with and the block its vote is locked until
+        /// -> only set for convictions that lock the vote
+        pub VoteConvictions get(fn vote_convictions): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<(u8, T::BlockNumber)>;
+
+        /// Decay of the signal of accounts that have not voted in the vote
+        /// -> removed in `on_initialize` once the vote closes
+        pub VoteDecay get(fn vote_decay): map
+            hasher(blake2_128_concat) T::VoteId => Option<DecayConfig<T::BlockNumber>>;
+
+        /// Votes due to decay in the block, with the block their decay period ends in
+        /// -> a vote is queued for its first period when its decay is set, for the
+        /// next once a period is applied and for the block after its end, when its
+        /// `VoteDecay` is dropped
+        DecayQueue get(fn decay_queue): double_map
+            hasher(twox_64_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::VoteId => Option<T::BlockNumber>;
+
+        /// The next block of `DecayQueue` to drain, behind the current block while
+        /// decays are deferred
+        DecayQueueCursor get(fn decay_queue_cursor): Option<T::BlockNumber>;
+
+        /// The vote whose decay period is partly applied, the block the period ends
+        /// in and the raw `VoteLogger` key of the last voter read
+        DecayCursor get(fn decay_cursor): Option<(T::VoteId, T::BlockNumber, Vec<u8>)>;
+
+        /// Signal an account that has not voted lost to the vote's decay
+        pub DecayedSignal get(fn decayed_signal): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => T::Signal;
+
+        /// Each account's comment on the vote, whether or not it can vote
+        pub VoteComments get(fn vote_comments): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::Cid>;
+
+        /// The number of accounts that commented on the vote
+        pub CommentCount get(fn comment_count): map
+            hasher(blake2_128_concat) T::VoteId => u32;
+
+        /// The nonce for vote pool id generation
+        VotePoolIdCounter get(fn vote_pool_id_counter): u32;
+
+        /// Votes aggregated into one weighted decision
+        pub VotePools get(fn vote_pools): map
+            hasher(blake2_128_concat) u32 => Option<VotePool<T::VoteId>>;
     }
 }
 
@@ -209,6 +718,27 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = 0;
+            // each step leaves the version the next step expects
+            if <StorageVersion>::get() == Releases::V1 {
+                weight += Self::migrate_vote_states_to_v2();
+            }
+            if <StorageVersion>::get() == Releases::V2 {
+                weight += Self::migrate_vote_states_to_v3();
+            }
+            weight
+        }
+
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let mut weight = 0;
+            if T::PruneParticipationIndex::get() {
+                weight += Self::prune_participation_index(n);
+            }
+            weight += Self::release_expired_vote_slots(n);
+            weight + Self::decay_queued_votes(n)
+        }
+
         #[weight = 0]
         pub fn create_signal_vote(
             origin,
@@ -218,9 +748,11 @@ decl_module! {
             duration: Option<T::BlockNumber>,
         ) -> DispatchResult {
             let vote_creator = ensure_signed(origin)?;
-            // default authentication is organization supervisor
-            let authentication: bool = <org::Module<T>>::is_organization_supervisor(organization.org(), &vote_creator);
-            ensure!(authentication, Error::<T>::NotAuthorizedToCreateVoteForOrganization);
+            Self::ensure_can_open_vote(organization.org(), &vote_creator)?;
+            let deposit = T::VoteCreationDeposit::get();
+            ensure!(T::Currency::can_reserve(&vote_creator, deposit), Error::<T>::InsufficientBalanceForVoteCreationDeposit);
+            let fee = T::VoteCreationFee::get();
+            Self::ensure_can_pay_vote_creation_fee(&vote_creator, fee, deposit)?;
             // call helper method
             let new_vote_id = Self::open_vote(
                 topic,
@@ -228,7 +760,10 @@ decl_module! {
                 threshold,
                 duration,
             )?;
+            Self::burn_vote_creation_fee(new_vote_id, &vote_creator, fee)?;
+            Self::reserve_vote_deposit(new_vote_id, &vote_creator, deposit)?;
             // emit event
+            <VoteCreator<T>>::insert(new_vote_id, &vote_creator);
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, new_vote_id));
             Ok(())
         }
@@ -241,9 +776,11 @@ decl_module! {
             duration: Option<T::BlockNumber>,
         ) -> DispatchResult {
             let vote_creator = ensure_signed(origin)?;
-            // default authentication is organization supervisor
-            let authentication: bool = <org::Module<T>>::is_organization_supervisor(organization.org(), &vote_creator);
-            ensure!(authentication, Error::<T>::NotAuthorizedToCreateVoteForOrganization);
+            Self::ensure_can_open_vote(organization.org(), &vote_creator)?;
+            let deposit = T::VoteCreationDeposit::get();
+            ensure!(T::Currency::can_reserve(&vote_creator, deposit), Error::<T>::InsufficientBalanceForVoteCreationDeposit);
+            let fee = T::VoteCreationFee::get();
+            Self::ensure_can_pay_vote_creation_fee(&vote_creator, fee, deposit)?;
             // call helper method
             let new_vote_id = Self::open_percent_vote(
                 topic,
@@ -251,10 +788,154 @@ decl_module! {
                 threshold,
                 duration
             )?;
+            Self::burn_vote_creation_fee(new_vote_id, &vote_creator, fee)?;
+            Self::reserve_vote_deposit(new_vote_id, &vote_creator, deposit)?;
             // emit event
+            <VoteCreator<T>>::insert(new_vote_id, &vote_creator);
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, new_vote_id));
             Ok(())
         }
+        /// Removes a vote opened by mistake and everything indexed under it, only
+        /// while no one has voted
+        /// -> `voter_count` must be at least the number of the vote's voters
+        #[weight = T::DbWeight::get().reads_writes(3 * voter_count as u64 + 10, 3 * voter_count as u64 + 20)]
+        fn cancel_vote(
+            origin,
+            vote_id: T::VoteId,
+            voter_count: u32,
+        ) -> DispatchResult {
+            let canceller = ensure_signed(origin)?;
+            let state = <VoteStates<T>>::get(vote_id).ok_or(Error::<T>::CannotCancelVoteIfVoteStateDNE)?;
+            ensure!(
+                <VoteCreator<T>>::get(vote_id) == Some(canceller.clone()),
+                Error::<T>::OnlyVoteCreatorCanCancelVote
+            );
+            Self::ensure_voter_count_witness(vote_id, voter_count)?;
+            ensure!(
+                <VoteLogger<T>>::iter_prefix_values(vote_id)
+                    .all(|v| !v.direction().is_cast()),
+                Error::<T>::CannotCancelVoteWithCastVotes
+            );
+            // a vote without cast votes can only be closed by expiry
+            if !Self::check_vote_expired(&state) {
+                if let Some(org) = <VoteOrg<T>>::get(vote_id) {
+                    <OpenVotesPerOrg<T>>::mutate(org, |count| *count = count.saturating_sub(1));
+                }
+            }
+            if let Some(org) = <VoteOrg<T>>::take(vote_id) {
+                <VotesByOrg<T>>::remove(org, vote_id);
+            }
+            if let Some(ends) = state.ends() {
+                <ParticipationPrunes<T>>::remove(ends + One::one(), vote_id);
+                <OpenVoteExpiries<T>>::remove(ends + One::one(), vote_id);
+            }
+            for (voter, vote) in <VoteLogger<T>>::drain_prefix(vote_id) {
+                <VoteParticipationIndex<T>>::remove(&voter, vote_id);
+                if let Some(justification) = vote.justification() {
+                    <JustificationIndex<T>>::remove((vote_id, justification), &voter);
+                }
+            }
+            <VoteStates<T>>::remove(vote_id);
+            <CommittedSignal<T>>::remove_prefix(vote_id);
+            <VoteDelegations<T>>::remove_prefix(vote_id);
+            <VoteConvictions<T>>::remove_prefix(vote_id);
+            <SettledTurnout<T>>::remove(vote_id);
+            <VoteComments<T>>::remove_prefix(vote_id);
+            <CommentCount<T>>::remove(vote_id);
+            <VoteDecay<T>>::remove(vote_id);
+            <DecayedSignal<T>>::remove_prefix(vote_id);
+            <TotalSignalIssuance<T>>::remove(vote_id);
+            <VoteCreator<T>>::remove(vote_id);
+            <VoteCreationFees<T>>::remove(vote_id);
+            // nobody voted so there is no turnout to weigh the refund by
+            if let Some((creator, deposit)) = <VoteDeposits<T>>::take(vote_id) {
+                T::Currency::unreserve(&creator, deposit);
+            }
+            <OpenVoteCounter>::mutate(|count| *count = count.saturating_sub(1));
+            Self::deposit_event(RawEvent::VoteCancelled(canceller, vote_id));
+            Ok(())
+        }
+        /// Sets or clears the vote's quorum, only for the vote's creator
+        /// -> `voter_count` must be at least the number of the vote's voters
+        #[weight = T::DbWeight::get().reads_writes(2 * voter_count as u64 + 4, 2)]
+        fn configure_vote_quorum(
+            origin,
+            vote_id: T::VoteId,
+            quorum: Option<T::Signal>,
+            voter_count: u32,
+        ) -> DispatchResult {
+            let caller = Self::ensure_vote_creator(origin, vote_id)?;
+            Self::ensure_voter_count_witness(vote_id, voter_count)?;
+            Self::set_vote_quorum(vote_id, quorum)?;
+            Self::deposit_event(RawEvent::VoteConfigured(vote_id, caller));
+            Ok(())
+        }
+        /// Sets or clears the vote's minimum distinct voters, only for the vote's creator
+        /// -> `voter_count` must be at least the number of the vote's voters
+        #[weight = T::DbWeight::get().reads_writes(3 * voter_count as u64 + 4, 2)]
+        fn configure_min_distinct_voters(
+            origin,
+            vote_id: T::VoteId,
+            min: Option<u32>,
+            voter_count: u32,
+        ) -> DispatchResult {
+            let caller = Self::ensure_vote_creator(origin, vote_id)?;
+            Self::ensure_voter_count_witness(vote_id, voter_count)?;
+            Self::set_min_distinct_voters(vote_id, min)?;
+            Self::deposit_event(RawEvent::VoteConfigured(vote_id, caller));
+            Ok(())
+        }
+        /// Sets or clears the vote's signal decay, only for the vote's creator
+        #[weight = T::DbWeight::get().reads_writes(2, 2)]
+        fn configure_vote_decay(
+            origin,
+            vote_id: T::VoteId,
+            decay: Option<DecayConfig<T::BlockNumber>>,
+        ) -> DispatchResult {
+            let caller = Self::ensure_vote_creator(origin, vote_id)?;
+            Self::set_vote_decay(vote_id, decay)?;
+            Self::deposit_event(RawEvent::VoteConfigured(vote_id, caller));
+            Ok(())
+        }
+        /// Pools open votes of the org or its sub orgs into one decision in which
+        /// each vote counts in proportion to its weight, only for the org's supervisor
+        #[weight = T::DbWeight::get().reads_writes(2 * votes.len() as u64 + 2, 2)]
+        fn create_vote_pool(
+            origin,
+            org: T::OrgId,
+            votes: Vec<T::VoteId>,
+            weights: Vec<Permill>,
+            threshold: Threshold<Permill>,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+            ensure!(
+                <org::Module<T>>::is_organization_supervisor(org, &creator),
+                Error::<T>::OnlySupervisorCanCreateVotePool
+            );
+            ensure!(!votes.is_empty(), Error::<T>::VotePoolRequiresMemberVotes);
+            ensure!(votes.len() <= T::MaxVotesPerPool::get() as usize, Error::<T>::VotePoolTooLarge);
+            ensure!(votes.len() == weights.len(), Error::<T>::VotePoolWeightsMustMatchVotes);
+            let total_weight = weights.iter().fold(0u64, |sum, w| sum + w.deconstruct() as u64);
+            ensure!(
+                total_weight == Permill::one().deconstruct() as u64,
+                Error::<T>::VotePoolWeightsMustSumToOneHundredPercent
+            );
+            ensure!(
+                votes.iter().all(|v| Self::vote_status(*v) == VoteStatus::Open),
+                Error::<T>::VotePoolMemberVoteMustBeOpen
+            );
+            ensure!(
+                votes.iter().all(|v| <VoteOrg<T>>::get(v).map_or(false, |o| {
+                    o == org || <org::Module<T>>::is_immediate_child(org, o)
+                })),
+                Error::<T>::VotePoolMemberVoteMustBelongToOrgOrSubOrg
+            );
+            let pool_id = <VotePoolIdCounter>::get().checked_add(1).ok_or(Error::<T>::VotePoolIdOverflow)?;
+            <VotePools<T>>::insert(pool_id, VotePool::new(votes, weights, threshold));
+            <VotePoolIdCounter>::put(pool_id);
+            Self::deposit_event(RawEvent::VotePoolCreated(creator, pool_id));
+            Ok(())
+        }
         #[weight = 0]
         fn set_threshold_default(
             origin,
@@ -269,22 +950,132 @@ decl_module! {
             Self::deposit_event(RawEvent::ThresholdSet(id));
             Ok(())
         }
+        /// Sets who besides the org's supervisor may open votes for the org
+        #[weight = T::DbWeight::get().reads_writes(1, 1)]
+        fn set_open_vote_permission(
+            origin,
+            org: T::OrgId,
+            permission: OpenVotePermission<T::AccountId>,
+        ) -> DispatchResult {
+            let setter = ensure_signed(origin)?;
+            ensure!(
+                <org::Module<T>>::is_organization_supervisor(org, &setter),
+                Error::<T>::OnlySupervisorCanSetOpenVotePermission
+            );
+            <OpenVotePermissions<T>>::insert(org, permission);
+            Self::deposit_event(RawEvent::OpenVotePermissionSet(org, setter));
+            Ok(())
+        }
         #[weight = 0]
         pub fn submit_vote(
             origin,
             vote_id: T::VoteId,
             direction: VoterView,
             justification: Option<T::Cid>,
+            weight_fraction: Option<Permill>,
+            conviction: u8,
         ) -> DispatchResult {
             let voter = ensure_signed(origin)?;
-            Self::vote_on_proposal(vote_id, voter.clone(), direction, justification)?;
-            Self::deposit_event(RawEvent::Voted(vote_id, voter, direction));
+            Self::cast_vote(vote_id, voter.clone(), direction, justification, weight_fraction, conviction)?;
+            // logger entry exists because the vote was just applied
+            let magnitude = <VoteLogger<T>>::get(vote_id, &voter).map(|v| v.magnitude()).unwrap_or_default();
+            Self::deposit_event(RawEvent::Voted(vote_id, voter, direction, magnitude));
+            Ok(())
+        }
+        /// Takes back the signal the caller's voting proxy received for the vote
+        #[weight = T::DbWeight::get().reads_writes(9, 6)]
+        fn revoke_delegation(
+            origin,
+            vote_id: T::VoteId,
+        ) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            let (proxy, returned) = Self::revoke_vote_delegation(vote_id, &delegator)?;
+            Self::deposit_event(RawEvent::DelegationRevoked(vote_id, delegator, proxy, returned));
+            Ok(())
+        }
+        /// Attaches the caller's comment to the vote, replacing any it submitted before
+        /// -> weighed by its storage access so the fee deters spam from accounts without signal
+        #[weight = T::DbWeight::get().reads_writes(3, 2)]
+        fn submit_vote_comment(
+            origin,
+            vote_id: T::VoteId,
+            comment_cid: T::Cid,
+        ) -> DispatchResult {
+            let commenter = ensure_signed(origin)?;
+            ensure!(<VoteStates<T>>::contains_key(vote_id), Error::<T>::CannotCommentOnVoteThatDNE);
+            if !<VoteComments<T>>::contains_key(vote_id, &commenter) {
+                <CommentCount<T>>::mutate(vote_id, |count| *count += 1);
+            }
+            <VoteComments<T>>::insert(vote_id, &commenter, comment_cid.clone());
+            Self::deposit_event(RawEvent::VoteCommentSubmitted(vote_id, commenter, comment_cid));
+            Ok(())
+        }
+        /// Refunds the creator's deposit once the vote closes, in proportion to its turnout
+        /// -> any account may settle it
+        #[weight = T::DbWeight::get().reads_writes(4, 5)]
+        fn settle_vote_deposit(
+            origin,
+            vote_id: T::VoteId,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let (creator, deposit) = <VoteDeposits<T>>::get(vote_id).ok_or(Error::<T>::NoVoteCreationDepositToSettle)?;
+            ensure!(
+                Self::vote_status(vote_id) == VoteStatus::Closed,
+                Error::<T>::CannotSettleVoteCreationDepositBeforeVoteCloses
+            );
+            // the deposit is removed with the vote when it is cancelled
+            let state = <VoteStates<T>>::get(vote_id).ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
+            let turnout = Permill::from_rational_approximation(state.turnout(), state.all_possible_turnout());
+            let refund = Self::vote_deposit_refund(deposit, turnout);
+            let forfeited = deposit.saturating_sub(refund);
+            if !forfeited.is_zero() {
+                T::Currency::repatriate_reserved(&creator, &T::Treasury::get(), forfeited, BalanceStatus::Free)?;
+            }
+            T::Currency::unreserve(&creator, refund);
+            <VoteDeposits<T>>::remove(vote_id);
+            // a conclusive vote's fee has already been taken into the reward pool
+            <VoteCreationFees<T>>::remove(vote_id);
+            <SettledTurnout<T>>::insert(vote_id, turnout);
+            Self::deposit_event(RawEvent::VoteDepositSettled(vote_id, creator, refund, forfeited));
             Ok(())
         }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Upgrades every stored vote from the `VoteStateV1` layout
+    fn migrate_vote_states_to_v2() -> Weight {
+        // `translate` only takes `Fn` closures
+        let translated = sp_std::cell::Cell::new(0u64);
+        <VoteStates<T>>::translate::<VoteStV1<T>, _>(|_, old| {
+            translated.set(translated.get() + 1);
+            Some(old.upgrade())
+        });
+        <StorageVersion>::put(Releases::V2);
+        let n = translated.get();
+        T::DbWeight::get().reads_writes(n + 1, n + 1)
+    }
+    /// Upgrades every stored vote from the `VoteStateV2` layout and counts the
+    /// voters who already voted in it
+    fn migrate_vote_states_to_v3() -> Weight {
+        let translated = sp_std::cell::Cell::new(0u64);
+        let voters_read = sp_std::cell::Cell::new(0u64);
+        <VoteStates<T>>::translate::<VoteStV2<T>, _>(|vote_id, old| {
+            translated.set(translated.get() + 1);
+            let (mut voters, mut distinct) = (0u64, 0u32);
+            for vote in <VoteLogger<T>>::iter_prefix_values(vote_id) {
+                voters += 1;
+                if vote.direction() != VoterView::Uninitialized {
+                    distinct += 1;
+                }
+            }
+            voters_read.set(voters_read.get() + voters);
+            Some(old.upgrade().set_distinct_voters(distinct))
+        });
+        <StorageVersion>::put(Releases::V3);
+        let n = translated.get();
+        T::DbWeight::get().reads_writes(n + voters_read.get() + 1, n + 1)
+    }
     fn valid_signal_threshold(
         threshold: &Threshold<T::Signal>,
         all_possible_turnout: T::Signal,
@@ -310,6 +1101,27 @@ impl<T: Trait> Module<T> {
         };
         Threshold::new(in_favor_t, against_t)
     }
+    /// Checks that no non-zero percentage converted to a zero signal threshold
+    /// -> `mul_ceil` rounds any positive share up, so this only catches a zero
+    /// total, against which the vote would pass without any signal
+    fn ensure_threshold_not_rounded_to_zero(
+        percent: &Threshold<Permill>,
+        signal: &Threshold<T::Signal>,
+    ) -> DispatchResult {
+        let rounded_to_zero = |p: Permill, s: T::Signal| {
+            p != Permill::from_parts(0) && s.is_zero()
+        };
+        let against_rounded = match (percent.against(), signal.against()) {
+            (Some(p), Some(s)) => rounded_to_zero(p, s),
+            _ => false,
+        };
+        ensure!(
+            !rounded_to_zero(percent.in_favor(), signal.in_favor())
+                && !against_rounded,
+            Error::<T>::ThresholdRoundsToZero
+        );
+        Ok(())
+    }
     fn generate_threshold_uid() -> T::ThresholdId {
         let mut thresh_counter = <ThresholdIdCounter<T>>::get() + 1u32.into();
         while <VoteThresholds<T>>::get(thresh_counter).is_some() {
@@ -318,114 +1130,1603 @@ impl<T: Trait> Module<T> {
         <ThresholdIdCounter<T>>::put(thresh_counter);
         thresh_counter
     }
-}
-
-impl<T: Trait> IDIsAvailable<T::VoteId> for Module<T> {
-    fn id_is_available(id: T::VoteId) -> bool {
-        <VoteStates<T>>::get(id).is_none()
-    }
-}
-
-impl<T: Trait> GenerateUniqueID<T::VoteId> for Module<T> {
-    fn generate_unique_id() -> T::VoteId {
-        let mut id_counter = <VoteIdCounter<T>>::get() + 1u32.into();
-        while <VoteStates<T>>::get(id_counter).is_some() {
-            id_counter += 1u32.into();
+    /// Lifecycle status of the vote, `Cancelled` if its state was removed
+    pub fn vote_status(vote_id: T::VoteId) -> VoteStatus {
+        if let Some(state) = <VoteStates<T>>::get(vote_id) {
+            match state.outcome() {
+                VoteOutcome::Approved | VoteOutcome::Rejected => {
+                    VoteStatus::Closed
+                }
+                _ if Self::check_vote_expired(&state) => VoteStatus::Closed,
+                _ => VoteStatus::Open,
+            }
+        } else {
+            VoteStatus::Cancelled
         }
-        <VoteIdCounter<T>>::put(id_counter);
-        id_counter
     }
-}
-
-impl<T: Trait> GetVoteOutcome<T::VoteId> for Module<T> {
-    type Outcome = VoteOutcome;
-    fn get_vote_outcome(
-        vote_id: T::VoteId,
-    ) -> Result<Self::Outcome, DispatchError> {
-        let vote_state = <VoteStates<T>>::get(vote_id)
-            .ok_or(Error::<T>::NoVoteStateForOutcomeQuery)?;
-        Ok(vote_state.outcome())
+    /// Weighs each member vote's share of its possible turnout in favor and against
+    /// -> cancelled member votes count as no votes
+    pub fn compute_pool_outcome(
+        pool_id: u32,
+    ) -> Result<VoteOutcome, DispatchError> {
+        let pool = <VotePools<T>>::get(pool_id).ok_or(Error::<T>::VotePoolDNE)?;
+        let shares = pool
+            .member_votes()
+            .into_iter()
+            .map(|vote_id| match <VoteStates<T>>::get(vote_id) {
+                Some(state) if !state.all_possible_turnout().is_zero() => {
+                    let total = state.all_possible_turnout();
+                    (
+                        Permill::from_rational_approximation(
+                            state.in_favor(),
+                            total,
+                        ),
+                        Permill::from_rational_approximation(
+                            state.against(),
+                            total,
+                        ),
+                    )
+                }
+                _ => (Permill::from_parts(0), Permill::from_parts(0)),
+            })
+            .collect();
+        Ok(pool.outcome(shares))
     }
-}
-
-impl<T: Trait> ConfigureThreshold<ThreshInput<T>, T::Cid, T::BlockNumber>
-    for Module<T>
-{
-    type ThresholdId = T::ThresholdId;
-    type VoteId = T::VoteId;
-    fn register_threshold(
-        t: ThreshInput<T>,
-    ) -> Result<T::ThresholdId, DispatchError> {
-        let id = Self::generate_threshold_uid();
-        let threshold = Thresh::<T>::new(id, t.org(), t.threshold());
-        <VoteThresholds<T>>::insert(id, threshold);
-        Ok(id)
+    /// All votes opened for the org, filtered by status if one is provided
+    pub fn get_votes_for_org(
+        org: T::OrgId,
+        status: Option<VoteStatus>,
+    ) -> Vec<T::VoteId> {
+        <VotesByOrg<T>>::iter_prefix(org)
+            .map(|(vote_id, _)| vote_id)
+            .filter(|vote_id| {
+                if let Some(s) = status {
+                    Self::vote_status(*vote_id) == s
+                } else {
+                    true
+                }
+            })
+            .collect()
     }
-    fn invoke_threshold(
-        id: T::ThresholdId,
-        topic: Option<T::Cid>,
-        duration: Option<T::BlockNumber>,
-    ) -> Result<T::VoteId, DispatchError> {
-        let config = <VoteThresholds<T>>::get(id)
-            .ok_or(Error::<T>::CannotInvokeThresholdThatDNE)?;
-        match config.threshold() {
-            XorThreshold::Signal(t) => {
-                Self::open_vote(topic, config.org(), t, duration)
-            }
-            XorThreshold::Percent(t) => {
-                Self::open_percent_vote(topic, config.org(), t, duration)
+    /// Every voter's view and the signal committed to it
+    /// -> signal is `None` for voters that have not voted
+    pub fn get_votes_for_vote_id(
+        vote_id: T::VoteId,
+    ) -> Vec<(T::AccountId, VoterView, Option<T::Signal>)> {
+        <VoteLogger<T>>::iter_prefix(vote_id)
+            .map(|(voter, vote)| Self::voter_entry(vote_id, voter, vote))
+            .collect()
+    }
+    /// Up to `limit` entries of `get_votes_for_vote_id` after the `last_key` voter
+    /// -> returns the cursor for the next page, `None` once every voter is returned
+    /// -> voters are in storage order so each page is O(voters before the cursor)
+    pub fn get_votes_page(
+        vote_id: T::VoteId,
+        last_key: Option<T::AccountId>,
+        limit: u32,
+    ) -> (
+        Vec<(T::AccountId, VoterView, Option<T::Signal>)>,
+        Option<T::AccountId>,
+    ) {
+        let mut voters = <VoteLogger<T>>::iter_prefix(vote_id);
+        if let Some(last) = last_key {
+            // unknown cursors yield an empty page
+            if voters.find(|(voter, _)| voter == &last).is_none() {
+                return (Vec::new(), None)
             }
         }
+        let page: Vec<(T::AccountId, VoterView, Option<T::Signal>)> = voters
+            .by_ref()
+            .take(limit as usize)
+            .map(|(voter, vote)| Self::voter_entry(vote_id, voter, vote))
+            .collect();
+        let next = if voters.next().is_some() {
+            page.last().map(|(voter, _, _)| voter.clone())
+        } else {
+            None
+        };
+        (page, next)
     }
-}
-
-impl<T: Trait>
-    OpenVote<
-        OrgRep<T::OrgId>,
-        Threshold<T::Signal>,
-        Threshold<Permill>,
-        T::BlockNumber,
-        T::Cid,
-    > for Module<T>
-{
-    type VoteIdentifier = T::VoteId;
-    fn open_vote(
+    fn voter_entry(
+        vote_id: T::VoteId,
+        voter: T::AccountId,
+        vote: VoteVec<T>,
+    ) -> (T::AccountId, VoterView, Option<T::Signal>) {
+        let signal = match vote.direction() {
+            VoterView::Uninitialized | VoterView::Delegated => None,
+            _ => {
+                Some(
+                    <CommittedSignal<T>>::get(vote_id, &voter)
+                        .unwrap_or_else(|| vote.magnitude()),
+                )
+            }
+        };
+        (voter, vote.direction(), signal)
+    }
+    /// The signer of `origin` if it opened the vote with a create extrinsic
+    fn ensure_vote_creator(
+        origin: T::Origin,
+        vote_id: T::VoteId,
+    ) -> Result<T::AccountId, DispatchError> {
+        let caller = ensure_signed(origin)?;
+        ensure!(
+            <VoteCreator<T>>::get(vote_id) == Some(caller.clone()),
+            Error::<T>::OnlyVoteCreatorCanConfigureVote
+        );
+        Ok(caller)
+    }
+    /// Fails if the vote has more than `voter_count` voters
+    /// -> reads at most one voter entry past the witness
+    fn ensure_voter_count_witness(
+        vote_id: T::VoteId,
+        voter_count: u32,
+    ) -> DispatchResult {
+        ensure!(
+            <VoteLogger<T>>::iter_prefix_values(vote_id)
+                .take(voter_count as usize + 1)
+                .count()
+                <= voter_count as usize,
+            Error::<T>::VoterCountWitnessTooLow
+        );
+        Ok(())
+    }
+    /// Fails unless the vote is open and no one has voted, since changing how
+    /// votes are counted afterwards could reopen or decide the vote
+    fn ensure_vote_counting_configurable(
+        vote_id: T::VoteId,
+        vote_state: &VoteSt<T>,
+    ) -> DispatchResult {
+        ensure!(
+            !Self::check_vote_expired(vote_state),
+            Error::<T>::CannotChangeVoteCountingAfterVoteExpires
+        );
+        ensure!(
+            Self::vote_status(vote_id) == VoteStatus::Open,
+            Error::<T>::CannotChangeVoteCountingAfterVoteCloses
+        );
+        ensure!(
+            <VoteLogger<T>>::iter_prefix_values(vote_id)
+                .all(|v| !v.direction().is_cast()),
+            Error::<T>::CannotChangeVoteCountingAfterVotesAreCast
+        );
+        Ok(())
+    }
+    /// Requires `quorum` turnout before the threshold is evaluated at all
+    /// -> `get_vote_outcome` is `InsufficientParticipation` until it is reached
+    /// -> only while the vote is open and no one has voted
+    pub fn set_vote_quorum(
+        vote_id: T::VoteId,
+        quorum: Option<T::Signal>,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotSetQuorumIfVoteStateDNE)?;
+        Self::ensure_vote_counting_configurable(vote_id, &vote_state)?;
+        if let Some(q) = quorum {
+            ensure!(
+                q <= vote_state.all_possible_turnout(),
+                Error::<T>::QuorumExceedsPossibleTurnout
+            );
+        }
+        let new_state = vote_state.set_quorum(quorum);
+        Self::track_vote_closure(
+            vote_id,
+            vote_state.outcome(),
+            new_state.outcome(),
+        );
+        <VoteStates<T>>::insert(vote_id, new_state);
+        Ok(())
+    }
+    /// Requires `min` voters to have voted for a met threshold to approve the vote
+    /// -> abstaining counts as voting, so the vote stays `Voting` until `min`
+    /// voters have cast any view
+    /// -> only while the vote is open and no one has voted
+    pub fn set_min_distinct_voters(
+        vote_id: T::VoteId,
+        min: Option<u32>,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotSetMinDistinctVotersIfVoteStateDNE)?;
+        Self::ensure_vote_counting_configurable(vote_id, &vote_state)?;
+        if let Some(m) = min {
+            let voters = <VoteLogger<T>>::iter_prefix_values(vote_id).count();
+            ensure!(
+                m as usize <= voters,
+                Error::<T>::MinDistinctVotersExceedsVoters
+            );
+        }
+        let new_state = vote_state.set_min_distinct_voters(min);
+        Self::track_vote_closure(
+            vote_id,
+            vote_state.outcome(),
+            new_state.outcome(),
+        );
+        <VoteStates<T>>::insert(vote_id, new_state);
+        Ok(())
+    }
+    /// Votes with `weight_fraction` of the voter's signal, the rest abstains
+    /// -> `None` commits the voter's full signal
+    pub fn vote_with_fraction(
+        vote_id: T::VoteId,
+        voter: T::AccountId,
+        direction: VoterView,
+        justification: Option<T::Cid>,
+        weight_fraction: Option<Permill>,
+    ) -> DispatchResult {
+        Self::cast_vote(
+            vote_id,
+            voter,
+            direction,
+            justification,
+            weight_fraction,
+            0,
+        )
+    }
+    /// Applies the vote with the signal multiplied by the conviction's multiplier
+    /// -> a conviction above zero only applies to the voter's first vote in favor
+    /// or against and locks it from changing
+    fn cast_vote(
+        vote_id: T::VoteId,
+        voter: T::AccountId,
+        direction: VoterView,
+        justification: Option<T::Cid>,
+        weight_fraction: Option<Permill>,
+        conviction: u8,
+    ) -> DispatchResult {
+        // get the vote state
+        let mut vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
+        // TODO: add permissioned method for adding time to the vote state because of this restriction but this is a legitimate restriction
+        // -> every standard vote has a recognized end to establish when the decision must be made based on collected input
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+        ensure!(
+            !T::ConflictCheck::has_conflict_of_interest(&voter, vote_id),
+            Error::<T>::VoterHasConflictOfInterest
+        );
+        if weight_fraction.is_some() {
+            ensure!(
+                direction == VoterView::InFavor
+                    || direction == VoterView::Against,
+                Error::<T>::WeightFractionOnlyAppliesToInFavorOrAgainst
+            );
+        }
+        let (multiplier, lock_periods) = conviction_terms(conviction)
+            .ok_or(Error::<T>::ConvictionExceedsMaximum)?;
+        let now = <frame_system::Module<T>>::block_number();
+        if let Some((_, locked_until)) =
+            <VoteConvictions<T>>::get(vote_id, &voter)
+        {
+            ensure!(now >= locked_until, Error::<T>::VoteLockedByConviction);
+        }
+        let old_outcome = vote_state.outcome();
+        // get the organization associated with this vote_state
+        let old_vote = <VoteLogger<T>>::get(vote_id, voter.clone())
+            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        ensure!(
+            old_vote.direction() != VoterView::Delegated,
+            Error::<T>::MustRevokeDelegationBeforeVoting
+        );
+        let mut magnitude = old_vote.magnitude();
+        if multiplier > 1 {
+            ensure!(
+                direction == VoterView::InFavor
+                    || direction == VoterView::Against,
+                Error::<T>::ConvictionOnlyAppliesToInFavorOrAgainst
+            );
+            ensure!(
+                old_vote.direction() == VoterView::Uninitialized,
+                Error::<T>::ConvictionOnlyAppliesToFirstVote
+            );
+            // the bonus grows the possible turnout so every other voter's
+            // signal still fits in the tally
+            let boosted = magnitude
+                .checked_mul(&T::Signal::from(multiplier))
+                .ok_or(Error::<T>::ConvictionOverflowsPossibleTurnout)?;
+            vote_state = vote_state
+                .add_possible_turnout(boosted - magnitude)
+                .ok_or(Error::<T>::ConvictionOverflowsPossibleTurnout)?;
+            magnitude = boosted;
+        }
+        let old_committed =
+            <CommittedSignal<T>>::get(vote_id, &voter).unwrap_or(magnitude);
+        let new_committed = weight_fraction
+            .map(|f| f.mul_floor(magnitude))
+            .unwrap_or(magnitude);
+        let new_vote = if multiplier > 1 {
+            Vote::new(magnitude, direction, justification)
+        } else if old_vote.direction() == direction {
+            // same view is only a change if the committed signal changes
+            ensure!(
+                old_committed != new_committed,
+                Error::<T>::OldVoteDirectionEqualsNewVoteDirectionSoNoChange
+            );
+            Vote::new(magnitude, direction, justification)
+        } else {
+            old_vote.set_new_view(direction, justification).ok_or(
+                Error::<T>::OldVoteDirectionEqualsNewVoteDirectionSoNoChange,
+            )?
+        };
+        let new_state =
+            if old_committed == magnitude && new_committed == magnitude {
+                Self::apply_vote(
+                    vote_state,
+                    magnitude,
+                    old_vote.direction(),
+                    direction,
+                )
+            } else {
+                Self::apply_partial_vote(
+                    vote_state,
+                    magnitude,
+                    (old_vote.direction(), old_committed),
+                    (direction, new_committed),
+                )
+            }
+            .ok_or(Error::<T>::VoteChangeNotSupported)?;
+        if T::IndexJustifications::get() {
+            // a revote replaces the cited justification
+            if let Some(old) = old_vote.justification() {
+                <JustificationIndex<T>>::remove((vote_id, old), &voter);
+            }
+            if let Some(new) = new_vote.justification() {
+                <JustificationIndex<T>>::insert((vote_id, new), &voter, ());
+            }
+        }
+        if new_committed == magnitude {
+            <CommittedSignal<T>>::remove(vote_id, &voter);
+        } else {
+            <CommittedSignal<T>>::insert(vote_id, &voter, new_committed);
+        }
+        // set the new vote for the voter's profile
+        <VoteLogger<T>>::insert(vote_id, &voter, new_vote);
+        <VoteParticipationIndex<T>>::insert(&voter, vote_id, ());
+        if lock_periods > 0 {
+            let lock = T::ConvictionLockPeriod::get()
+                .saturating_mul(lock_periods.into());
+            <VoteConvictions<T>>::insert(
+                vote_id,
+                &voter,
+                (conviction, now.saturating_add(lock)),
+            );
+            // votes are accepted until the block after `ends`
+            let vote_ends = new_state
+                .ends()
+                .map(|e| e.saturating_add(One::one()))
+                .unwrap_or(now);
+            Self::lock_voter_shares(
+                vote_id,
+                &voter,
+                vote_ends.max(now.saturating_add(lock)),
+            );
+        }
+        // catch apply_vote bugs in debug builds
+        debug_assert_eq!(new_state.validate(), Ok(()));
+        debug::native::trace!(
+            target: LOG_TARGET,
+            "vote {:?} applied for {:?}: {:?} with {:?} signal",
+            vote_id,
+            voter,
+            direction,
+            new_committed,
+        );
+        Self::track_vote_closure(vote_id, old_outcome, new_state.outcome());
+        // commit new vote state to storage
+        <VoteStates<T>>::insert(vote_id, new_state);
+        Ok(())
+    }
+    /// Keeps the voter from burning or transferring the shares it voted with in
+    /// the vote's org before `until`
+    fn lock_voter_shares(
+        vote_id: T::VoteId,
+        voter: &T::AccountId,
+        until: T::BlockNumber,
+    ) {
+        if let Some(org) = <VoteOrg<T>>::get(vote_id) {
+            if let Some(profile) = <org::Module<T>>::members(org, voter) {
+                <org::Module<T>>::lock_shares_until(
+                    org,
+                    voter,
+                    profile.total(),
+                    until,
+                );
+            }
+        }
+    }
+    /// The outcome the vote would have if `voter` committed their full signal to
+    /// `direction` now
+    /// -> applies the vote to a copy of the state and never writes to storage
+    pub fn project_outcome(
+        vote_id: T::VoteId,
+        voter: &T::AccountId,
+        direction: VoterView,
+    ) -> Result<VoteOutcome, DispatchError> {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+        let old_vote = <VoteLogger<T>>::get(vote_id, voter)
+            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        ensure!(
+            old_vote.direction() != VoterView::Delegated,
+            Error::<T>::MustRevokeDelegationBeforeVoting
+        );
+        let magnitude = old_vote.magnitude();
+        let old_committed =
+            <CommittedSignal<T>>::get(vote_id, voter).unwrap_or(magnitude);
+        if old_vote.direction() == direction {
+            if old_committed == magnitude {
+                // the voter already holds this view
+                return Ok(vote_state.outcome())
+            }
+        } else {
+            // same restrictions on view changes as `vote_with_fraction`
+            old_vote.set_new_view(direction, None).ok_or(
+                Error::<T>::OldVoteDirectionEqualsNewVoteDirectionSoNoChange,
+            )?;
+        }
+        let projected = if old_committed == magnitude {
+            Self::apply_vote(
+                vote_state,
+                magnitude,
+                old_vote.direction(),
+                direction,
+            )
+        } else {
+            Self::apply_partial_vote(
+                vote_state,
+                magnitude,
+                (old_vote.direction(), old_committed),
+                (direction, magnitude),
+            )
+        }
+        .ok_or(Error::<T>::VoteChangeNotSupported)?;
+        Ok(projected.outcome())
+    }
+    /// Moves the old committed signal to abstain before committing the new signal
+    /// -> turnout always counts the voter's full magnitude
+    fn apply_partial_vote(
+        state: VoteSt<T>,
+        magnitude: T::Signal,
+        old: (VoterView, T::Signal),
+        new: (VoterView, T::Signal),
+    ) -> Option<VoteSt<T>> {
+        let abstained = match old.0 {
+            VoterView::Uninitialized => {
+                Self::apply_vote(state, magnitude, old.0, VoterView::Abstain)?
+            }
+            VoterView::Abstain => state,
+            _ => Self::apply_vote(state, old.1, old.0, VoterView::Abstain)?,
+        };
+        match new.0 {
+            VoterView::Abstain => Some(abstained),
+            VoterView::InFavor | VoterView::Against => {
+                Self::apply_vote(abstained, new.1, VoterView::Abstain, new.0)
+            }
+            _ => None,
+        }
+    }
+    /// Moves the delegated signal from the proxy back to the delegator, who has
+    /// not voted with it
+    /// - if the proxy voted, the signal leaves the tally and the signal the proxy
+    /// abstained with is returned before any it committed
+    /// - a delegator that voted as a proxy itself cannot revoke, its view would
+    /// otherwise apply to the returned signal
+    /// -> returns the proxy and the signal returned
+    fn revoke_vote_delegation(
+        vote_id: T::VoteId,
+        delegator: &T::AccountId,
+    ) -> Result<(T::AccountId, T::Signal), DispatchError> {
+        let (proxy, delegated) = <VoteDelegations<T>>::get(vote_id, delegator)
+            .ok_or(Error::<T>::NoDelegationToRevokeForVote)?;
+        ensure!(
+            Self::vote_status(vote_id) == VoteStatus::Open,
+            Error::<T>::CannotRevokeDelegationAfterVoteCloses
+        );
+        // the proxy's vote cannot change while its conviction locks it
+        if let Some((_, locked_until)) =
+            <VoteConvictions<T>>::get(vote_id, &proxy)
+        {
+            ensure!(
+                <frame_system::Module<T>>::block_number() >= locked_until,
+                Error::<T>::CannotRevokeDelegationFromConvictionVote
+            );
+        }
+        let own_vote = <VoteLogger<T>>::get(vote_id, delegator);
+        let own_signal = match &own_vote {
+            Some(v) if v.direction().is_cast() => {
+                return Err(
+                    Error::<T>::CannotRevokeDelegationAfterVotingAsProxy.into()
+                )
+            }
+            Some(v) => v.magnitude(),
+            None => T::Signal::zero(),
+        };
+        let proxy_vote = <VoteLogger<T>>::get(vote_id, &proxy)
+            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        let magnitude = proxy_vote.magnitude();
+        // a signal cap may have left the proxy with less than it was credited
+        let returned = delegated.min(magnitude);
+        let remaining = magnitude - returned;
+        let committed =
+            <CommittedSignal<T>>::get(vote_id, &proxy).unwrap_or(magnitude);
+        let new_committed = committed.min(remaining);
+        if proxy_vote.direction().is_cast() {
+            let state = <VoteStates<T>>::get(vote_id)
+                .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
+            let new_state = state
+                .withdraw_signal(
+                    proxy_vote.direction(),
+                    returned,
+                    committed - new_committed,
+                )
+                .ok_or(Error::<T>::VoteChangeNotSupported)?;
+            debug_assert_eq!(new_state.validate(), Ok(()));
+            Self::track_vote_closure(
+                vote_id,
+                state.outcome(),
+                new_state.outcome(),
+            );
+            <VoteStates<T>>::insert(vote_id, new_state);
+        }
+        if new_committed == remaining {
+            <CommittedSignal<T>>::remove(vote_id, &proxy);
+        } else {
+            <CommittedSignal<T>>::insert(vote_id, &proxy, new_committed);
+        }
+        <VoteLogger<T>>::insert(
+            vote_id,
+            &proxy,
+            Vote::new(
+                remaining,
+                proxy_vote.direction(),
+                proxy_vote.justification(),
+            ),
+        );
+        <VoteLogger<T>>::insert(
+            vote_id,
+            delegator,
+            Vote::new(own_signal + returned, VoterView::Uninitialized, None),
+        );
+        <VoteDelegations<T>>::remove(vote_id, delegator);
+        Ok((proxy, returned))
+    }
+    /// True iff the account has signal minted for the vote and the vote is open
+    pub fn can_vote(vote_id: T::VoteId, account: &T::AccountId) -> bool {
+        <VoteLogger<T>>::contains_key(vote_id, account)
+            && Self::vote_status(vote_id) == VoteStatus::Open
+    }
+    pub fn count_open_votes() -> u32 {
+        <OpenVoteCounter>::get()
+    }
+    /// Checks the caller against the org's `OpenVotePermission`
+    /// -> the org's supervisor may always open votes
+    fn ensure_can_open_vote(
+        org: T::OrgId,
+        who: &T::AccountId,
+    ) -> DispatchResult {
+        let permitted = <org::Module<T>>::is_organization_supervisor(org, who)
+            || match <OpenVotePermissions<T>>::get(org) {
+                OpenVotePermission::AnyMember => {
+                    <org::Module<T>>::is_member_of_group(org, who)
+                }
+                OpenVotePermission::ControllerOnly => false,
+                OpenVotePermission::NamedProposers(proposers) => {
+                    proposers.contains(who)
+                }
+            };
+        ensure!(permitted, Error::<T>::NotAuthorizedToOpenVote);
+        Ok(())
+    }
+    /// Fails if the org already has `MaxOpenVotesPerOrg` open votes
+    fn ensure_open_vote_slot(org: T::OrgId) -> DispatchResult {
+        let max = T::MaxOpenVotesPerOrg::get();
+        if max == 0 {
+            return Ok(())
+        }
+        ensure!(
+            <OpenVotesPerOrg<T>>::get(org) < max,
+            Error::<T>::TooManyOpenVotesForOrg
+        );
+        Ok(())
+    }
+    /// Stores the state of a vote whose signal is already minted and indexes it
+    /// -> every path that opens a vote ends here once its threshold is checked
+    fn build_open_vote(
+        vote_id: T::VoteId,
+        org: T::OrgId,
         topic: Option<T::Cid>,
-        organization: OrgRep<T::OrgId>,
+        total_possible_turnout: T::Signal,
         threshold: Threshold<T::Signal>,
         duration: Option<T::BlockNumber>,
-    ) -> Result<Self::VoteIdentifier, DispatchError> {
+    ) -> T::VoteId {
         // calculate `initialized` and `expires` fields for vote state
         let now = frame_system::Module::<T>::block_number();
-        let ends: Option<T::BlockNumber> = if let Some(time_to_add) = duration {
-            Some(now + time_to_add)
+        let ends = duration.map(|d| now + d);
+        let new_vote_state =
+            VoteState::new(topic, total_possible_turnout, threshold, now, ends);
+        <VoteStates<T>>::insert(vote_id, new_vote_state);
+        Self::index_open_vote(org, vote_id, ends);
+        Self::deposit_genesis_event(vote_id, total_possible_turnout);
+        vote_id
+    }
+    /// Indexes a newly opened vote by the org whose membership minted its signal
+    /// -> a vote with an end is queued for participation pruning after it
+    fn index_open_vote(
+        org: T::OrgId,
+        vote_id: T::VoteId,
+        ends: Option<T::BlockNumber>,
+    ) {
+        <VotesByOrg<T>>::insert(org, vote_id, ());
+        if let Some(ends) = ends.filter(|_| T::PruneParticipationIndex::get()) {
+            <ParticipationPrunes<T>>::insert(ends + One::one(), vote_id, ());
+        }
+        if let Some(ends) = ends {
+            <OpenVoteExpiries<T>>::insert(ends + One::one(), vote_id, ());
+        }
+        <VoteOrg<T>>::insert(vote_id, org);
+        <OpenVotesPerOrg<T>>::mutate(org, |count| *count += 1);
+        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
+        <OpenVoteCounter>::put(new_vote_count);
+        debug::native::debug!(
+            target: LOG_TARGET,
+            "vote {:?} opened for org {:?}",
+            vote_id,
+            org,
+        );
+    }
+    /// Summarizes the signal minted for a vote that was just opened
+    /// -> members who delegated all of their signal are not counted
+    fn deposit_genesis_event(vote_id: T::VoteId, total_signal: T::Signal) {
+        let participants = <VoteLogger<T>>::iter_prefix(vote_id)
+            .filter(|(_, vote)| !vote.magnitude().is_zero())
+            .count() as u32;
+        Self::deposit_event(RawEvent::VoteGenesisSet(
+            vote_id,
+            participants,
+            total_signal,
+        ));
+    }
+    /// Sets or clears the decay of the signal of accounts that have not voted
+    pub fn set_vote_decay(
+        vote_id: T::VoteId,
+        decay: Option<DecayConfig<T::BlockNumber>>,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::CannotSetDecayIfVoteStateDNE)?;
+        if let Some(d) = decay {
+            let ends = vote_state
+                .ends()
+                .ok_or(Error::<T>::DecayRequiresVoteWithEnd)?;
+            ensure!(
+                !d.period().is_zero(),
+                Error::<T>::DecayPeriodMustBeNonZero
+            );
+            let now = <frame_system::Module<T>>::block_number();
+            Self::queue_next_decay(
+                vote_id,
+                &d,
+                vote_state.initialized(),
+                ends,
+                now,
+                now,
+            );
+            <VoteDecay<T>>::insert(vote_id, d);
         } else {
-            None
+            <VoteDecay<T>>::remove(vote_id);
+        }
+        Ok(())
+    }
+    /// Applies queued decay periods, reading at most `DecayedVotersPerBlock`
+    /// voter entries, queued decays and queue blocks
+    /// -> finishes the period in `DecayCursor` first, then drains `DecayQueue`
+    /// from `DecayQueueCursor` up to `n` and leaves the rest for the next block
+    fn decay_queued_votes(n: T::BlockNumber) -> Weight {
+        let db = T::DbWeight::get();
+        let mut remaining = T::DecayedVotersPerBlock::get();
+        let mut weight = db.reads_writes(2, 2);
+        let mut finished = true;
+        if let Some((vote_id, period_end, last)) = <DecayCursor<T>>::take() {
+            let (w, done) = Self::decay_vote(
+                n,
+                vote_id,
+                period_end,
+                Some(last),
+                &mut remaining,
+            );
+            weight += w;
+            finished = done;
+        }
+        let mut block = <DecayQueueCursor<T>>::get().unwrap_or(n);
+        while finished && block <= n && remaining > 0 {
+            remaining -= 1;
+            weight += db.reads(1);
+            match <DecayQueue<T>>::drain_prefix(block).next() {
+                Some((vote_id, period_end)) => {
+                    let (w, done) = Self::decay_vote(
+                        n,
+                        vote_id,
+                        period_end,
+                        None,
+                        &mut remaining,
+                    );
+                    weight += w + db.writes(1);
+                    finished = done;
+                }
+                None => block += One::one(),
+            }
+        }
+        <DecayQueueCursor<T>>::put(block);
+        weight
+    }
+    /// Applies the vote's decay period ending in `period_end` from the voter
+    /// after the raw `VoteLogger` key `last`, or from its first voter, then
+    /// queues the vote for its next period
+    /// -> returns false if voters are left for the next block in `DecayCursor`
+    fn decay_vote(
+        n: T::BlockNumber,
+        vote_id: T::VoteId,
+        period_end: T::BlockNumber,
+        last: Option<Vec<u8>>,
+        remaining: &mut u32,
+    ) -> (Weight, bool) {
+        let db = T::DbWeight::get();
+        let mut weight = db.reads(2);
+        let state = <VoteStates<T>>::get(vote_id);
+        let open = state.as_ref().and_then(|s| {
+            let closed = s.outcome() == VoteOutcome::Approved
+                || s.outcome() == VoteOutcome::Rejected;
+            s.ends().filter(|ends| !closed && n <= *ends)
+        });
+        let (state, ends, decay) =
+            match (state, open, <VoteDecay<T>>::get(vote_id)) {
+                (Some(state), Some(ends), Some(decay)) => (state, ends, decay),
+                _ => {
+                    <VoteDecay<T>>::remove(vote_id);
+                    return (weight + db.writes(1), true)
+                }
+            };
+        let initialized = state.initialized();
+        if decay.decays_at(initialized, ends, period_end) {
+            let last =
+                last.unwrap_or_else(|| Self::vote_logger_prefix(vote_id));
+            let (w, resume) = Self::decay_unvoted_signal(
+                vote_id,
+                state,
+                decay.decay_per_period(),
+                last,
+                remaining,
+            );
+            weight += w;
+            if let Some(last) = resume {
+                <DecayCursor<T>>::put((vote_id, period_end, last));
+                return (weight + db.writes(1), false)
+            }
+        }
+        // a vote extended since it was queued decays at its new periods
+        Self::queue_next_decay(
+            vote_id,
+            &decay,
+            initialized,
+            ends,
+            period_end,
+            n,
+        );
+        (weight + db.writes(1), true)
+    }
+    /// Queues the vote for its first decay period ending after `after`, or for
+    /// the block after it ends, no earlier than the block after `now`
+    fn queue_next_decay(
+        vote_id: T::VoteId,
+        decay: &DecayConfig<T::BlockNumber>,
+        initialized: T::BlockNumber,
+        ends: T::BlockNumber,
+        after: T::BlockNumber,
+        now: T::BlockNumber,
+    ) {
+        let next = decay
+            .next_decay(initialized, ends, after)
+            .unwrap_or_else(|| ends + One::one());
+        <DecayQueue<T>>::insert(next.max(now + One::one()), vote_id, next);
+    }
+    /// The raw key every `VoteLogger` entry of the vote starts with
+    fn vote_logger_prefix(vote_id: T::VoteId) -> Vec<u8> {
+        <VoteLogger<T> as generator::StorageDoubleMap<
+            T::VoteId,
+            T::AccountId,
+            VoteVec<T>,
+        >>::storage_double_map_final_key1(vote_id)
+    }
+    /// Applies one period of decay to the accounts that have not voted, from
+    /// the voter after the raw `VoteLogger` key `last` and reading at most
+    /// `remaining` voter entries
+    /// -> stops early once the possible turnout reaches
+    /// `VoteState::min_possible_turnout`, otherwise returns the key to resume
+    /// from if `remaining` ran out before the voters
+    fn decay_unvoted_signal(
+        vote_id: T::VoteId,
+        state: VoteSt<T>,
+        rate: Permill,
+        mut last: Vec<u8>,
+        remaining: &mut u32,
+    ) -> (Weight, Option<Vec<u8>>) {
+        let prefix = Self::vote_logger_prefix(vote_id);
+        let mut budget = state
+            .all_possible_turnout()
+            .saturating_sub(state.min_possible_turnout());
+        let (mut reads, mut writes) = (0u64, 0u64);
+        let mut total = T::Signal::zero();
+        let resume = loop {
+            if budget.is_zero() {
+                break None
+            }
+            if *remaining == 0 {
+                break Some(last)
+            }
+            let key = match sp_io::storage::next_key(&last)
+                .filter(|key| key.starts_with(&prefix))
+            {
+                Some(key) => key,
+                None => break None,
+            };
+            *remaining -= 1;
+            reads += 1;
+            let who = T::AccountId::decode(&mut Blake2_128Concat::reverse(
+                &key[prefix.len()..],
+            ))
+            .ok();
+            last = key;
+            let (who, vote) = match who
+                .and_then(|w| <VoteLogger<T>>::get(vote_id, &w).map(|v| (w, v)))
+            {
+                Some((who, vote))
+                    if vote.direction() == VoterView::Uninitialized
+                        && !vote.magnitude().is_zero() =>
+                {
+                    (who, vote)
+                }
+                _ => continue,
+            };
+            let decayed = <DecayedSignal<T>>::get(vote_id, &who);
+            let undecayed = vote.magnitude().saturating_add(decayed);
+            let amount = rate
+                .mul_ceil(undecayed)
+                .min(vote.magnitude())
+                .min(budget);
+            budget -= amount;
+            total += amount;
+            <VoteLogger<T>>::insert(
+                vote_id,
+                &who,
+                Vote::new(
+                    vote.magnitude() - amount,
+                    VoterView::Uninitialized,
+                    None,
+                ),
+            );
+            <DecayedSignal<T>>::insert(vote_id, &who, decayed + amount);
+            reads += 1;
+            writes += 2;
         };
+        if !total.is_zero() {
+            // decayed signal was never tallied so it stays within the turnout
+            if let Some(new_state) = state.subtract_possible_turnout(total) {
+                debug_assert_eq!(new_state.validate(), Ok(()));
+                Self::track_vote_closure(
+                    vote_id,
+                    state.outcome(),
+                    new_state.outcome(),
+                );
+                <VoteStates<T>>::insert(vote_id, new_state);
+                writes += 1;
+            }
+        }
+        (T::DbWeight::get().reads_writes(reads + 1, writes), resume)
+    }
+    /// Checks the creator can pay `fee` and still reserve `deposit` afterwards
+    /// -> runs before the vote opens since a later failure would not undo it
+    fn ensure_can_pay_vote_creation_fee(
+        creator: &T::AccountId,
+        fee: BalanceOf<T>,
+        deposit: BalanceOf<T>,
+    ) -> DispatchResult {
+        if fee.is_zero() {
+            return Ok(())
+        }
+        let after_fee = T::Currency::free_balance(creator)
+            .checked_sub(&fee)
+            .ok_or(Error::<T>::InsufficientBalanceForVoteCreationFee)?;
+        // the fee is withdrawn with `KeepAlive`, before the deposit is reserved
+        ensure!(
+            after_fee >= T::Currency::minimum_balance() && after_fee >= deposit,
+            Error::<T>::InsufficientBalanceForVoteCreationFee
+        );
+        T::Currency::ensure_can_withdraw(
+            creator,
+            fee,
+            WithdrawReasons::from(WithdrawReason::Fee),
+            after_fee,
+        )
+    }
+    /// Burns the creation fee and records it for the reward pool
+    fn burn_vote_creation_fee(
+        vote_id: T::VoteId,
+        creator: &T::AccountId,
+        fee: BalanceOf<T>,
+    ) -> DispatchResult {
+        if fee.is_zero() {
+            return Ok(())
+        }
+        // dropping the imbalance reduces the total issuance
+        let _ = T::Currency::withdraw(
+            creator,
+            fee,
+            WithdrawReasons::from(WithdrawReason::Fee),
+            ExistenceRequirement::KeepAlive,
+        )?;
+        <VoteCreationFees<T>>::insert(vote_id, fee);
+        Self::deposit_event(RawEvent::VoteCreationFeeCharged(
+            vote_id,
+            creator.clone(),
+            fee,
+        ));
+        Ok(())
+    }
+    /// Mints the fee burned for a vote that just closed into the reward pool
+    /// -> taking the fee credits it at most once if the vote is reopened
+    fn credit_vote_reward_pool(vote_id: T::VoteId) {
+        if let Some(fee) = <VoteCreationFees<T>>::take(vote_id) {
+            // nothing is minted while the pool doesn't exist and the fee is
+            // below the existential deposit
+            let credited = T::Currency::deposit_creating(
+                &Self::vote_reward_pool_account(),
+                fee,
+            )
+            .peek();
+            if !credited.is_zero() {
+                Self::deposit_event(RawEvent::VoteRewardPoolCredited(
+                    vote_id, credited,
+                ));
+            }
+        }
+    }
+    pub fn vote_reward_pool_account() -> T::AccountId {
+        T::VoteRewardPool::get().into_account()
+    }
+    /// Reserves the creation deposit and records it for settlement
+    fn reserve_vote_deposit(
+        vote_id: T::VoteId,
+        creator: &T::AccountId,
+        deposit: BalanceOf<T>,
+    ) -> DispatchResult {
+        if deposit.is_zero() {
+            return Ok(())
+        }
+        T::Currency::reserve(creator, deposit)?;
+        <VoteDeposits<T>>::insert(vote_id, (creator.clone(), deposit));
+        Ok(())
+    }
+    /// The part of the deposit refunded to the creator of a vote with `turnout`
+    /// -> in full at `FullRefundTurnout`, otherwise in proportion to it
+    pub fn vote_deposit_refund(
+        deposit: BalanceOf<T>,
+        turnout: Permill,
+    ) -> BalanceOf<T> {
+        let full_refund = T::FullRefundTurnout::get();
+        if turnout >= full_refund {
+            deposit
+        } else {
+            Permill::from_rational_approximation(
+                turnout.deconstruct(),
+                full_refund.deconstruct(),
+            ) * deposit
+        }
+    }
+    /// Keeps `OpenVotesPerOrg` in sync when an outcome closes or reopens a vote
+    fn track_vote_closure(
+        vote_id: T::VoteId,
+        old: VoteOutcome,
+        new: VoteOutcome,
+    ) {
+        let closes =
+            |o: VoteOutcome| o == VoteOutcome::Approved || o == VoteOutcome::Rejected;
+        if closes(old) == closes(new) {
+            return
+        }
+        debug::native::debug!(
+            target: LOG_TARGET,
+            "vote {:?} outcome {:?} -> {:?}",
+            vote_id,
+            old,
+            new,
+        );
+        if let Some(org) = <VoteOrg<T>>::get(vote_id) {
+            <OpenVotesPerOrg<T>>::mutate(org, |count| {
+                if closes(new) {
+                    *count = count.saturating_sub(1)
+                } else {
+                    *count += 1
+                }
+            });
+        }
+        if closes(new) {
+            Self::credit_vote_reward_pool(vote_id);
+        }
+        if closes(new) && T::PruneParticipationIndex::get() {
+            let next = <frame_system::Module<T>>::block_number() + One::one();
+            <ParticipationPrunes<T>>::insert(next, vote_id, ());
+        }
+    }
+    /// Removes the `VoteParticipationIndex` entries of the votes queued for the
+    /// block which are closed
+    /// -> a vote reopened since it was queued is queued again for the block
+    /// after its end, or when an outcome closes it again
+    fn prune_participation_index(n: T::BlockNumber) -> Weight {
+        let db = T::DbWeight::get();
+        let mut weight = 0;
+        for (vote_id, _) in <ParticipationPrunes<T>>::drain_prefix(n) {
+            weight += db.reads_writes(2, 1);
+            let state = match <VoteStates<T>>::get(vote_id) {
+                Some(state) => state,
+                None => continue,
+            };
+            let closed = state.outcome() == VoteOutcome::Approved
+                || state.outcome() == VoteOutcome::Rejected
+                || Self::check_vote_expired(&state);
+            if !closed {
+                if let Some(ends) = state.ends() {
+                    let after = ends + One::one();
+                    <ParticipationPrunes<T>>::insert(after, vote_id, ());
+                    weight += db.writes(1);
+                }
+                continue
+            }
+            <VoteLogger<T>>::iter_prefix(vote_id).for_each(|(who, _)| {
+                <VoteParticipationIndex<T>>::remove(&who, vote_id);
+                weight += db.reads_writes(1, 1);
+            });
+        }
+        weight
+    }
+    /// Releases the `OpenVotesPerOrg` slot of each vote queued for the block
+    /// which has expired without an outcome closing it
+    /// -> a vote extended since it was queued is queued again for the block
+    /// after its new end
+    fn release_expired_vote_slots(n: T::BlockNumber) -> Weight {
+        let db = T::DbWeight::get();
+        let mut weight = 0;
+        for (vote_id, _) in <OpenVoteExpiries<T>>::drain_prefix(n) {
+            weight += db.reads_writes(2, 1);
+            let state = match <VoteStates<T>>::get(vote_id) {
+                Some(state) => state,
+                None => continue,
+            };
+            if !Self::check_vote_expired(&state) {
+                if let Some(ends) = state.ends() {
+                    let after = ends + One::one();
+                    <OpenVoteExpiries<T>>::insert(after, vote_id, ());
+                    weight += db.writes(1);
+                }
+                continue
+            }
+            // votes closed by an outcome released their slot then
+            let closed = state.outcome() == VoteOutcome::Approved
+                || state.outcome() == VoteOutcome::Rejected;
+            if let Some(org) = <VoteOrg<T>>::get(vote_id).filter(|_| !closed) {
+                <OpenVotesPerOrg<T>>::mutate(org, |count| {
+                    *count = count.saturating_sub(1)
+                });
+                weight += db.reads_writes(1, 1);
+            }
+        }
+        weight
+    }
+    /// Every vote the account has cast a vote in, by `VoteParticipationIndex`
+    pub fn get_votes_participated_in(account: T::AccountId) -> Vec<T::VoteId> {
+        <VoteParticipationIndex<T>>::iter_prefix(account)
+            .map(|(vote_id, _)| vote_id)
+            .collect()
+    }
+    /// Every account's comment on the vote, in storage order
+    pub fn get_vote_comments(vote_id: T::VoteId) -> Vec<(T::AccountId, T::Cid)> {
+        <VoteComments<T>>::iter_prefix(vote_id).collect()
+    }
+    /// Voters whose current vote cites the justification
+    /// -> always empty if `IndexJustifications` is not set
+    pub fn get_voters_by_justification(
+        vote_id: T::VoteId,
+        justification: T::Cid,
+    ) -> Vec<T::AccountId> {
+        <JustificationIndex<T>>::iter_prefix((vote_id, justification))
+            .map(|(voter, _)| voter)
+            .collect()
+    }
+    /// Opens a signal vote for the org, excluding members with less signal than
+    /// `min_signal` from the vote genesis
+    /// -> excluded signal is dropped, not redistributed, so the vote's total turnout
+    /// only counts included members
+    pub fn open_vote_with_min_signal(
+        topic: Option<T::Cid>,
+        organization: OrgRep<T::OrgId>,
+        threshold: Threshold<T::Signal>,
+        duration: Option<T::BlockNumber>,
+        min_signal: Option<T::Signal>,
+    ) -> Result<T::VoteId, DispatchError> {
+        Self::ensure_open_vote_slot(organization.org())?;
         // generate new vote_id
         let new_vote_id = Self::generate_unique_id();
         // by default, this call mints signal based on weighted ownership in group
-        let total_possible_turnout = match organization {
+        let total_possible_turnout = if let Some(floor) = min_signal {
+            Self::batch_mint_signal_above_floor(
+                new_vote_id,
+                organization,
+                floor,
+            )?
+        } else {
+            match organization {
+                OrgRep::Weighted(org_id) => {
+                    Self::batch_mint_signal(new_vote_id, org_id)?
+                }
+                OrgRep::Equal(org_id) => {
+                    Self::batch_mint_equal_signal(new_vote_id, org_id)?
+                }
+            }
+        };
+        ensure!(
+            Self::valid_signal_threshold(&threshold, total_possible_turnout),
+            Error::<T>::InputThresholdExceedsBounds
+        );
+        Ok(Self::build_open_vote(
+            new_vote_id,
+            organization.org(),
+            topic,
+            total_possible_turnout,
+            threshold,
+            duration,
+        ))
+    }
+    /// Opens a weighted signal vote from a share genesis the caller already holds,
+    /// skipping the membership scan done by `open_vote`
+    ///
+    /// Safety contract: for trusted in-runtime callers only, never pass input taken
+    /// from an extrinsic. `genesis` must be the org's current membership as returned
+    /// by `get_membership_with_shape`, with unique accounts and a total equal to the
+    /// sum of its entries. None of this is checked in release builds, so a forged
+    /// genesis mints signal the org never issued.
+    pub fn open_vote_unchecked(
+        topic: Option<T::Cid>,
+        org: T::OrgId,
+        genesis: Genesis<T>,
+        threshold: Threshold<T::Signal>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<T::VoteId, DispatchError> {
+        debug_assert!(genesis.verify_shape());
+        let mut delegations = Vec::new();
+        let genesis =
+            Self::delegate_genesis(org, genesis, One::one(), &mut delegations);
+        Self::open_vote_from_genesis(
+            topic,
+            org,
+            genesis,
+            delegations,
+            threshold,
+            duration,
+        )
+    }
+    /// Opens a weighted signal vote whose voting body spans several share classes,
+    /// i.e. a founders org and a contributors org, indexed under `org`
+    /// -> each member's signal is the sum of their shares in every class times the
+    /// class multiplier, see `WeightedVector::merge_classes`
+    pub fn open_multi_class_vote(
+        topic: Option<T::Cid>,
+        org: T::OrgId,
+        classes: Vec<(T::OrgId, T::Shares)>,
+        threshold: Threshold<T::Signal>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let mut geneses = Vec::new();
+        let mut delegations = Vec::new();
+        for (class, multiplier) in classes {
+            let genesis = <org::Module<T>>::get_membership_with_shape(class)
+                .ok_or(Error::<T>::CannotMintSignalBecauseMembershipShapeDNE)?;
+            let genesis = Self::delegate_genesis(
+                class,
+                genesis,
+                multiplier,
+                &mut delegations,
+            );
+            geneses.push((genesis, multiplier));
+        }
+        let genesis = WeightedVector::merge_classes(geneses)
+            .ok_or(Error::<T>::MultiClassSignalOverflow)?;
+        Self::open_vote_from_genesis(
+            topic,
+            org,
+            genesis,
+            delegations,
+            threshold,
+            duration,
+        )
+    }
+    /// Opens a weighted signal vote in which each member's shares are raised by the
+    /// bonus their tenure in the org earns, see `SeniorityConfig::weigh`
+    pub fn open_seniority_weighted_vote(
+        topic: Option<T::Cid>,
+        org: T::OrgId,
+        threshold: Threshold<T::Signal>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let config = <org::Module<T>>::seniority_configs(org)
+            .ok_or(Error::<T>::CannotOpenSeniorityWeightedVoteWithoutConfig)?;
+        let genesis = <org::Module<T>>::get_membership_with_shape(org)
+            .ok_or(Error::<T>::CannotMintSignalBecauseMembershipShapeDNE)?;
+        // collecting into a new genesis recomputes the total
+        let genesis: Genesis<T> = genesis
+            .vec()
+            .into_iter()
+            .map(|(who, shares)| {
+                let tenure = <org::Module<T>>::tenure(org, &who);
+                (who, config.weigh(shares, tenure))
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let mut delegations = Vec::new();
+        let genesis =
+            Self::delegate_genesis(org, genesis, One::one(), &mut delegations);
+        Self::open_vote_from_genesis(
+            topic,
+            org,
+            genesis,
+            delegations,
+            threshold,
+            duration,
+        )
+    }
+    /// Opens a weighted signal vote with signal minted from a membership snapshot
+    /// taken with `org::Module::take_org_snapshot`
+    /// -> shares issued, burned or transferred since the snapshot do not count,
+    /// while voting proxies are read when the vote opens
+    pub fn open_vote_from_snapshot(
+        topic: Option<T::Cid>,
+        org: T::OrgId,
+        snapshot_id: u32,
+        threshold: Threshold<T::Signal>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let snapshot = <org::Module<T>>::org_snapshots(org, snapshot_id)
+            .ok_or(Error::<T>::CannotOpenVoteFromSnapshotThatDNE)?;
+        let mut delegations = Vec::new();
+        let genesis = Self::delegate_genesis(
+            org,
+            snapshot.genesis(),
+            One::one(),
+            &mut delegations,
+        );
+        Self::open_vote_from_genesis(
+            topic,
+            org,
+            genesis,
+            delegations,
+            threshold,
+            duration,
+        )
+    }
+    /// Opens a weighted signal vote with signal minted from `genesis`
+    fn open_vote_from_genesis(
+        topic: Option<T::Cid>,
+        org: T::OrgId,
+        genesis: Genesis<T>,
+        delegations: Delegations<T>,
+        threshold: Threshold<T::Signal>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<T::VoteId, DispatchError> {
+        Self::ensure_open_vote_slot(org)?;
+        let total_possible_turnout: T::Signal = genesis.total().into();
+        ensure!(
+            Self::valid_signal_threshold(&threshold, total_possible_turnout),
+            Error::<T>::InputThresholdExceedsBounds
+        );
+        let new_vote_id = Self::generate_unique_id();
+        Self::mint_signal_from_genesis(new_vote_id, genesis);
+        Self::record_delegations(new_vote_id, delegations);
+        Ok(Self::build_open_vote(
+            new_vote_id,
+            org,
+            topic,
+            total_possible_turnout,
+            threshold,
+            duration,
+        ))
+    }
+    /// Credits the shares of members with a voting proxy in `org` to the proxy
+    /// - each delegation is appended to `delegations` with the shares credited
+    /// times `multiplier`
+    /// - a holder keeps its shares if its proxy already represents
+    /// `MaxDelegatorsPerVote` others, or if it delegated to another proxy in an
+    /// earlier share class of the vote
+    fn delegate_genesis(
+        org: T::OrgId,
+        genesis: Genesis<T>,
+        multiplier: T::Shares,
+        delegations: &mut Delegations<T>,
+    ) -> Genesis<T> {
+        let max = T::MaxDelegatorsPerVote::get() as usize;
+        genesis.delegate_to(|who, shares| {
+            let proxy = <org::Module<T>>::voting_proxy_of(org, who)?;
+            let credited = shares.saturating_mul(multiplier);
+            if let Some(entry) =
+                delegations.iter_mut().find(|(d, _, _)| d == who)
+            {
+                if entry.1 != proxy {
+                    return None
+                }
+                entry.2 = entry.2.saturating_add(credited);
+                return Some(proxy)
+            }
+            let represented =
+                delegations.iter().filter(|(_, p, _)| *p == proxy).count();
+            if max != 0 && represented >= max {
+                return None
+            }
+            delegations.push((who.clone(), proxy.clone(), credited));
+            Some(proxy)
+        })
+    }
+    /// Records the delegations made while minting the vote's signal
+    /// - skips proxies minted no signal, i.e. below a minimum signal floor
+    /// - marks delegators left without signal as `Delegated`, one holding
+    /// signal delegated to it keeps its view to vote as a proxy
+    fn record_delegations(vote_id: T::VoteId, delegations: Delegations<T>) {
+        delegations.into_iter().for_each(|(delegator, proxy, shares)| {
+            if <VoteLogger<T>>::contains_key(vote_id, &proxy) {
+                let signal: T::Signal = shares.into();
+                let own = <VoteLogger<T>>::get(vote_id, &delegator);
+                if own.map(|v| v.magnitude().is_zero()).unwrap_or(false) {
+                    <VoteLogger<T>>::insert(
+                        vote_id,
+                        &delegator,
+                        Vote::new(Zero::zero(), VoterView::Delegated, None),
+                    );
+                }
+                <VoteDelegations<T>>::insert(
+                    vote_id,
+                    &delegator,
+                    (proxy.clone(), signal),
+                );
+                Self::deposit_event(RawEvent::SignalDelegated(
+                    vote_id, delegator, proxy, signal,
+                ));
+            }
+        });
+    }
+    /// Mints each member's shares as signal and records the genesis total
+    fn mint_signal_from_genesis(
+        vote_id: T::VoteId,
+        genesis: Genesis<T>,
+    ) -> T::Signal {
+        let total_minted: T::Signal = genesis.total().into();
+        genesis.vec().into_iter().for_each(|(who, shares)| {
+            let minted_signal: T::Signal = shares.into();
+            let new_vote =
+                Vote::new(minted_signal, VoterView::Uninitialized, None);
+            <VoteLogger<T>>::insert(vote_id, who, new_vote);
+        });
+        <TotalSignalIssuance<T>>::insert(vote_id, total_minted);
+        total_minted
+    }
+    /// Mints signal for members of the group with at least `floor` signal
+    fn batch_mint_signal_above_floor(
+        vote_id: T::VoteId,
+        organization: OrgRep<T::OrgId>,
+        floor: T::Signal,
+    ) -> Result<T::Signal, DispatchError> {
+        let (genesis, delegations) = Self::signal_genesis(organization)?;
+        let mut total_minted = T::Signal::zero();
+        genesis
+            .into_iter()
+            .filter(|(_, signal)| *signal >= floor)
+            .for_each(|(who, signal)| {
+                total_minted += signal;
+                let new_vote =
+                    Vote::new(signal, VoterView::Uninitialized, None);
+                <VoteLogger<T>>::insert(vote_id, who, new_vote);
+            });
+        Self::record_delegations(vote_id, delegations);
+        <TotalSignalIssuance<T>>::insert(vote_id, total_minted);
+        Ok(total_minted)
+    }
+    /// The signal each member would receive, by shares or one each, and the
+    /// delegations to voting proxies it includes
+    fn signal_genesis(
+        organization: OrgRep<T::OrgId>,
+    ) -> Result<(Vec<(T::AccountId, T::Signal)>, Delegations<T>), DispatchError>
+    {
+        let mut delegations = Vec::new();
+        let genesis = match organization {
             OrgRep::Weighted(org_id) => {
-                Self::batch_mint_signal(new_vote_id, org_id)?
+                let genesis =
+                    <org::Module<T>>::get_membership_with_shape(org_id).ok_or(
+                        Error::<T>::CannotMintSignalBecauseMembershipShapeDNE,
+                    )?;
+                Self::delegate_genesis(
+                    org_id,
+                    genesis,
+                    One::one(),
+                    &mut delegations,
+                )
+                .vec()
+                    .into_iter()
+                    .map(|(who, shares)| (who, shares.into()))
+                    .collect()
             }
             OrgRep::Equal(org_id) => {
-                Self::batch_mint_equal_signal(new_vote_id, org_id)?
+                <org::Module<T>>::get_group(org_id)
+                    .ok_or(
+                        Error::<T>::CannotMintSignalBecauseGroupMembershipDNE,
+                    )?
+                    .0
+                    .into_iter()
+                    .map(|who| (who, 1u32.into()))
+                    .collect()
+            }
+        };
+        Ok((genesis, delegations))
+    }
+    /// Opens a vote in which no member receives more signal than `cap` allows
+    /// - the percent limit is taken of the signal the org would mint without a cap
+    /// - the vote's possible turnout is the sum of the capped signal, so signal and
+    /// percent thresholds are both checked against it
+    /// - a zero limit is rejected since no member could vote
+    pub fn open_vote_with_signal_cap(
+        topic: Option<T::Cid>,
+        organization: OrgRep<T::OrgId>,
+        threshold: XorThreshold<T::Signal, Permill>,
+        duration: Option<T::BlockNumber>,
+        cap: SignalMintingCap<T::Signal>,
+    ) -> Result<T::VoteId, DispatchError> {
+        ensure!(!cap.is_zero(), Error::<T>::SignalCapMustBeNonZero);
+        Self::ensure_open_vote_slot(organization.org())?;
+        let (genesis, delegations) = Self::signal_genesis(organization)?;
+        let uncapped_total = genesis
+            .iter()
+            .fold(T::Signal::zero(), |total, (_, signal)| total + *signal);
+        let genesis: Vec<(T::AccountId, T::Signal)> =
+            if let Some(max) = cap.cap(uncapped_total) {
+                genesis
+                    .into_iter()
+                    .map(|(who, signal)| (who, signal.min(max)))
+                    .collect()
+            } else {
+                genesis
+            };
+        let total_possible_turnout = genesis
+            .iter()
+            .fold(T::Signal::zero(), |total, (_, signal)| total + *signal);
+        let threshold = match threshold {
+            XorThreshold::Signal(t) => t,
+            XorThreshold::Percent(t) => {
+                let signal =
+                    Self::from_permill_to_signal(&t, total_possible_turnout);
+                Self::ensure_threshold_not_rounded_to_zero(&t, &signal)?;
+                signal
             }
         };
         ensure!(
             Self::valid_signal_threshold(&threshold, total_possible_turnout),
             Error::<T>::InputThresholdExceedsBounds
         );
-        // instantiate new VoteState with threshold and temporal metadata
-        let new_vote_state =
-            VoteState::new(topic, total_possible_turnout, threshold, now, ends);
-        // insert the VoteState
-        <VoteStates<T>>::insert(new_vote_id, new_vote_state);
-        // increment open vote count
-        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
-        <OpenVoteCounter>::put(new_vote_count);
-        Ok(new_vote_id)
+        let new_vote_id = Self::generate_unique_id();
+        genesis.into_iter().for_each(|(who, signal)| {
+            let new_vote = Vote::new(signal, VoterView::Uninitialized, None);
+            <VoteLogger<T>>::insert(new_vote_id, who, new_vote);
+        });
+        Self::record_delegations(new_vote_id, delegations);
+        <TotalSignalIssuance<T>>::insert(new_vote_id, total_possible_turnout);
+        Ok(Self::build_open_vote(
+            new_vote_id,
+            organization.org(),
+            topic,
+            total_possible_turnout,
+            threshold,
+            duration,
+        ))
+    }
+}
+
+impl<T: Trait> IDIsAvailable<T::VoteId> for Module<T> {
+    fn id_is_available(id: T::VoteId) -> bool {
+        <VoteStates<T>>::get(id).is_none()
+    }
+}
+
+impl<T: Trait> GenerateUniqueID<T::VoteId> for Module<T> {
+    fn generate_unique_id() -> T::VoteId {
+        let mut id_counter = <VoteIdCounter<T>>::get() + 1u32.into();
+        while <VoteStates<T>>::get(id_counter).is_some() {
+            id_counter += 1u32.into();
+        }
+        <VoteIdCounter<T>>::put(id_counter);
+        id_counter
+    }
+}
+
+impl<T: Trait> GetVoteOutcome<T::VoteId> for Module<T> {
+    type Outcome = VoteOutcome;
+    fn get_vote_outcome(
+        vote_id: T::VoteId,
+    ) -> Result<Self::Outcome, DispatchError> {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForOutcomeQuery)?;
+        // an expired vote can no longer be decided
+        let outcome = match vote_state.outcome() {
+            VoteOutcome::NotStarted | VoteOutcome::Voting
+                if Self::check_vote_expired(&vote_state) =>
+            {
+                VoteOutcome::ExpiredInconclusive
+            }
+            outcome => outcome,
+        };
+        Ok(outcome)
+    }
+}
+
+impl<T: Trait> ConfigureThreshold<ThreshInput<T>, T::Cid, T::BlockNumber>
+    for Module<T>
+{
+    type ThresholdId = T::ThresholdId;
+    type VoteId = T::VoteId;
+    fn register_threshold(
+        t: ThreshInput<T>,
+    ) -> Result<T::ThresholdId, DispatchError> {
+        let id = Self::generate_threshold_uid();
+        let threshold = Thresh::<T>::new(id, t.org(), t.threshold());
+        <VoteThresholds<T>>::insert(id, threshold);
+        Ok(id)
+    }
+    fn invoke_threshold(
+        id: T::ThresholdId,
+        topic: Option<T::Cid>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let config = <VoteThresholds<T>>::get(id)
+            .ok_or(Error::<T>::CannotInvokeThresholdThatDNE)?;
+        match config.threshold() {
+            XorThreshold::Signal(t) => {
+                Self::open_vote(topic, config.org(), t, duration)
+            }
+            XorThreshold::Percent(t) => {
+                Self::open_percent_vote(topic, config.org(), t, duration)
+            }
+        }
+    }
+}
+
+impl<T: Trait>
+    OpenVote<
+        OrgRep<T::OrgId>,
+        Threshold<T::Signal>,
+        Threshold<Permill>,
+        T::BlockNumber,
+        T::Cid,
+    > for Module<T>
+{
+    type VoteIdentifier = T::VoteId;
+    fn open_vote(
+        topic: Option<T::Cid>,
+        organization: OrgRep<T::OrgId>,
+        threshold: Threshold<T::Signal>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<Self::VoteIdentifier, DispatchError> {
+        Self::open_vote_with_min_signal(
+            topic,
+            organization,
+            threshold,
+            duration,
+            None,
+        )
     }
     fn open_percent_vote(
         topic: Option<T::Cid>,
@@ -433,13 +2734,7 @@ impl<T: Trait>
         threshold: Threshold<Permill>,
         duration: Option<T::BlockNumber>,
     ) -> Result<Self::VoteIdentifier, DispatchError> {
-        // calculate `initialized` and `expires` fields for vote state
-        let now = frame_system::Module::<T>::block_number();
-        let ends: Option<T::BlockNumber> = if let Some(time_to_add) = duration {
-            Some(now + time_to_add)
-        } else {
-            None
-        };
+        Self::ensure_open_vote_slot(organization.org())?;
         // generate new vote_id
         let new_vote_id = Self::generate_unique_id();
         // by default, this call mints signal based on weighted ownership in group
@@ -453,6 +2748,10 @@ impl<T: Trait>
         };
         let signal_threshold =
             Self::from_permill_to_signal(&threshold, total_possible_turnout);
+        Self::ensure_threshold_not_rounded_to_zero(
+            &threshold,
+            &signal_threshold,
+        )?;
         ensure!(
             Self::valid_signal_threshold(
                 &signal_threshold,
@@ -460,20 +2759,14 @@ impl<T: Trait>
             ),
             Error::<T>::InputThresholdExceedsBounds
         );
-        // instantiate new VoteState with threshold and temporal metadata
-        let new_vote_state = VoteState::new(
+        Ok(Self::build_open_vote(
+            new_vote_id,
+            organization.org(),
             topic,
             total_possible_turnout,
             signal_threshold,
-            now,
-            ends,
-        );
-        // insert the VoteState
-        <VoteStates<T>>::insert(new_vote_id, new_vote_state);
-        // increment open vote count
-        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
-        <OpenVoteCounter>::put(new_vote_count);
-        Ok(new_vote_id)
+            duration,
+        ))
     }
 }
 
@@ -490,6 +2783,11 @@ impl<T: Trait> UpdateVote<T::VoteId, T::Cid, T::BlockNumber> for Module<T> {
         } else {
             old_vote_state.update_topic_without_clearing_state(new_topic)
         };
+        Self::track_vote_closure(
+            vote_id,
+            old_vote_state.outcome(),
+            new_vote_state.outcome(),
+        );
         <VoteStates<T>>::insert(vote_id, new_vote_state);
         Ok(())
     }
@@ -541,15 +2839,16 @@ impl<T: Trait> MintableSignal<T::AccountId, T::OrgId, T::VoteId, T::Signal>
         let new_vote_group =
             <org::Module<T>>::get_membership_with_shape(organization)
                 .ok_or(Error::<T>::CannotMintSignalBecauseMembershipShapeDNE)?;
-        // total issuance
-        let total_minted: T::Signal = new_vote_group.total().into();
-        new_vote_group.vec().into_iter().for_each(|(who, shares)| {
-            let minted_signal: T::Signal = shares.into();
-            let new_vote =
-                Vote::new(minted_signal, VoterView::Uninitialized, None);
-            <VoteLogger<T>>::insert(vote_id, who, new_vote);
-        });
-        <TotalSignalIssuance<T>>::insert(vote_id, total_minted);
+        let mut delegations = Vec::new();
+        let new_vote_group = Self::delegate_genesis(
+            organization,
+            new_vote_group,
+            One::one(),
+            &mut delegations,
+        );
+        let total_minted =
+            Self::mint_signal_from_genesis(vote_id, new_vote_group);
+        Self::record_delegations(vote_id, delegations);
         Ok(total_minted)
     }
 }
@@ -587,32 +2886,6 @@ impl<T: Trait> VoteOnProposal<T::AccountId, T::VoteId, T::Cid> for Module<T> {
         direction: Self::Direction,
         justification: Option<T::Cid>,
     ) -> DispatchResult {
-        // get the vote state
-        let vote_state = <VoteStates<T>>::get(vote_id)
-            .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
-        // TODO: add permissioned method for adding time to the vote state because of this restriction but this is a legitimate restriction
-        // -> every standard vote has a recognized end to establish when the decision must be made based on collected input
-        ensure!(
-            !Self::check_vote_expired(&vote_state),
-            Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
-        );
-        // get the organization associated with this vote_state
-        let old_vote = <VoteLogger<T>>::get(vote_id, voter.clone())
-            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
-        let new_vote = old_vote.set_new_view(direction, justification).ok_or(
-            Error::<T>::OldVoteDirectionEqualsNewVoteDirectionSoNoChange,
-        )?;
-        let new_state = Self::apply_vote(
-            vote_state,
-            old_vote.magnitude(),
-            old_vote.direction(),
-            direction,
-        )
-        .ok_or(Error::<T>::VoteChangeNotSupported)?;
-        // set the new vote for the voter's profile
-        <VoteLogger<T>>::insert(vote_id, voter, new_vote);
-        // commit new vote state to storage
-        <VoteStates<T>>::insert(vote_id, new_state);
-        Ok(())
+        Self::vote_with_fraction(vote_id, voter, direction, justification, None)
     }
 }
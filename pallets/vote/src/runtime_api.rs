@@ -0,0 +1,67 @@
+//! Runtime APIs for inspecting individual votes and listing an org's votes
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+use util::vote::{
+    VoteOutcome,
+    VoteStatus,
+    VoterView,
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait VoteApi<AccountId, VoteId, Signal, Cid> where
+        AccountId: Codec,
+        VoteId: Codec,
+        Signal: Codec,
+        Cid: Codec,
+    {
+        /// Every voter's view and the signal committed to it
+        fn get_votes_for_vote_id(
+            vote_id: VoteId,
+        ) -> Vec<(AccountId, VoterView, Option<Signal>)>;
+        /// Up to `limit` voters after `last_key` and the cursor for the next page
+        fn get_votes_page(
+            vote_id: VoteId,
+            last_key: Option<AccountId>,
+            limit: u32,
+        ) -> (Vec<(AccountId, VoterView, Option<Signal>)>, Option<AccountId>);
+        /// The account which created the vote, if created by extrinsic
+        fn get_vote_creator(vote_id: VoteId) -> Option<AccountId>;
+        /// The weighted outcome of the vote pool, `None` if it does not exist
+        fn compute_pool_outcome(pool_id: u32) -> Option<VoteOutcome>;
+        /// The outcome if `account` cast all of its signal for `direction` now,
+        /// `None` if it cannot vote
+        fn project_outcome(
+            vote_id: VoteId,
+            account: AccountId,
+            direction: VoterView,
+        ) -> Option<VoteOutcome>;
+        /// Every vote the account has cast a vote in
+        fn get_votes_participated_in(account: AccountId) -> Vec<VoteId>;
+        /// Every account's comment on the vote
+        fn get_vote_comments(vote_id: VoteId) -> Vec<(AccountId, Cid)>;
+        /// True iff the account has signal minted for the vote and the vote
+        /// is neither expired nor closed
+        fn can_vote(vote_id: VoteId, account: AccountId) -> bool;
+        /// Voters whose current vote cites the justification, always empty if
+        /// the runtime does not set `IndexJustifications`
+        fn get_voters_by_justification(
+            vote_id: VoteId,
+            justification: Cid,
+        ) -> Vec<AccountId>;
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait VoteRegistry<OrgId, VoteId> where
+        OrgId: Codec,
+        VoteId: Codec,
+    {
+        /// Every vote opened for the org, filtered by status if one is provided
+        fn get_votes_for_org(
+            org_id: OrgId,
+            status: Option<VoteStatus>,
+        ) -> Vec<VoteId>;
+        /// The number of open votes
+        fn count_open_votes() -> u32;
+    }
+}
@@ -5,7 +5,14 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    storage::migration::put_storage_value,
+    traits::{
+        OnInitialize,
+        OnRuntimeUpgrade,
+    },
     weights::Weight,
+    Blake2_128Concat,
+    StorageHasher,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -13,6 +20,15 @@ use sp_runtime::{
     traits::IdentityLookup,
     Perbill,
 };
+use std::cell::RefCell;
+use util::{
+    organization::SeniorityConfig,
+    traits::{
+        AccessGenesis,
+        ShareInformation,
+        ShareIssuance,
+    },
+};
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
@@ -28,6 +44,47 @@ parameter_types! {
     pub const MaximumBlockWeight: Weight = 1024;
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
+    pub const IndexJustifications: bool = true;
+    pub const MaxOpenVotesPerOrg: u32 = 3;
+    pub const PruneParticipationIndex: bool = true;
+    pub const MaxDelegatorsPerVote: u32 = 2;
+    pub const MaxVotesPerPool: u32 = 2;
+    pub const ConvictionLockPeriod: u64 = 5;
+    pub const ProxyVotingActive: bool = true;
+    pub const SnapshotRetentionPeriod: u64 = 10;
+    pub const ExistentialDeposit: u64 = 1;
+    pub const MaxLocks: u32 = 50;
+    pub const VoteCreationDeposit: u64 = 10;
+    pub const FullRefundTurnout: Permill = Permill::from_percent(50);
+    pub const VoteTreasury: u64 = 7;
+    pub const VoteRewardPool: ModuleId = ModuleId(*b"vote/rwd");
+}
+thread_local! {
+    static VOTE_CREATION_FEE: RefCell<u64> = RefCell::new(0);
+    static CONFLICTED_VOTER: RefCell<Option<(u64, u64)>> = RefCell::new(None);
+    static DECAYED_VOTERS_PER_BLOCK: RefCell<u32> = RefCell::new(100);
+}
+/// Zero unless a test charges for opening votes
+pub struct VoteCreationFee;
+impl Get<u64> for VoteCreationFee {
+    fn get() -> u64 {
+        VOTE_CREATION_FEE.with(|f| *f.borrow())
+    }
+}
+/// Enough to decay every member of a test org in one block unless a test
+/// lowers it
+pub struct DecayedVotersPerBlock;
+impl Get<u32> for DecayedVotersPerBlock {
+    fn get() -> u32 {
+        DECAYED_VOTERS_PER_BLOCK.with(|d| *d.borrow())
+    }
+}
+/// No one unless a test flags a voter in a vote
+pub struct ConflictedVoter;
+impl ConflictOfInterestChecker<u64, u64> for ConflictedVoter {
+    fn has_conflict_of_interest(voter: &u64, vote_id: u64) -> bool {
+        CONFLICTED_VOTER.with(|c| *c.borrow() == Some((*voter, vote_id)))
+    }
 }
 impl frame_system::Trait for Test {
     type Origin = Origin;
@@ -56,17 +113,44 @@ impl frame_system::Trait for Test {
     type BaseCallFilter = ();
     type SystemWeightInfo = ();
 }
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type MaxLocks = MaxLocks;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type ShareTransferVote = ();
+    type ShareTransferRequiresVote = ();
+    type ProxyVotingActive = ProxyVotingActive;
+    type SnapshotRetentionPeriod = SnapshotRetentionPeriod;
 }
 impl Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type IndexJustifications = IndexJustifications;
+    type MaxOpenVotesPerOrg = MaxOpenVotesPerOrg;
+    type PruneParticipationIndex = PruneParticipationIndex;
+    type MaxDelegatorsPerVote = MaxDelegatorsPerVote;
+    type MaxVotesPerPool = MaxVotesPerPool;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type DecayedVotersPerBlock = DecayedVotersPerBlock;
+    type Currency = Balances;
+    type VoteCreationDeposit = VoteCreationDeposit;
+    type FullRefundTurnout = FullRefundTurnout;
+    type Treasury = VoteTreasury;
+    type VoteCreationFee = VoteCreationFee;
+    type VoteRewardPool = VoteRewardPool;
+    type ConflictCheck = ConflictedVoter;
 }
 
 mod vote {
@@ -76,14 +160,16 @@ mod vote {
 impl_outer_event! {
     pub enum TestEvent for Test {
         frame_system<T>,
+        pallet_balances<T>,
         org<T>,
         vote<T>,
     }
 }
 pub type System = frame_system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
 pub type Vote = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u64, u32, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -98,10 +184,27 @@ fn get_last_event() -> RawEvent<u64, u64, u64> {
         .unwrap()
 }
 
-fn new_test_ext() -> sp_io::TestExternalities {
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    // tests on the same thread share these, so each test starts from the defaults
+    VOTE_CREATION_FEE.with(|f| *f.borrow_mut() = 0);
+    CONFLICTED_VOTER.with(|c| *c.borrow_mut() = None);
+    DECAYED_VOTERS_PER_BLOCK.with(|d| *d.borrow_mut() = 100);
     let mut t = frame_system::GenesisConfig::default()
         .build_storage::<Test>()
         .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (1, 1000),
+            (2, 100),
+            (3, 100),
+            (4, 100),
+            (5, 100),
+            (6, 100),
+            (7, 1),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     org::GenesisConfig::<Test> {
         sudo: 1,
         doc: 1738,
@@ -127,7 +230,7 @@ fn vote_creation_works() {
                 Threshold::new(4, None),
                 None
             ),
-            Error::<Test>::NotAuthorizedToCreateVoteForOrganization
+            Error::<Test>::NotAuthorizedToOpenVote
         );
         assert_ok!(Vote::create_signal_vote(
             one,
@@ -137,6 +240,16 @@ fn vote_creation_works() {
             None
         ));
         assert_eq!(get_last_event(), RawEvent::NewVoteStarted(1, 1));
+        assert_eq!(Vote::vote_creator(1), Some(1));
+        // votes opened by other modules have no creator
+        let vote_id = Vote::open_vote(
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, None),
+            None,
+        )
+        .unwrap();
+        assert_eq!(Vote::vote_creator(vote_id), None);
     });
 }
 
@@ -158,14 +271,23 @@ fn vote_signal_threshold_works() {
                 i_origin,
                 1,
                 VoterView::InFavor,
-                None
+                None,
+                None,
+                0
             ));
         }
         // check that the vote has not passed
         let outcome_almost_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_almost_passed, VoteOutcome::Voting);
         let six = Origin::signed(6);
-        assert_ok!(Vote::submit_vote(six, 1, VoterView::InFavor, None));
+        assert_ok!(Vote::submit_vote(
+            six,
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
         // check that the vote has passed
         let outcome_has_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_has_passed, VoteOutcome::Approved);
@@ -187,18 +309,47 @@ fn vote_pct_threshold_works() {
         // check that the vote has not passed
         let outcome_almost_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_almost_passed, VoteOutcome::Voting);
-        assert_ok!(Vote::submit_vote(one, 1, VoterView::InFavor, None));
+        assert_ok!(Vote::submit_vote(
+            one,
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
         let outcome_almost_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_almost_passed, VoteOutcome::Voting);
+        // 50% of the 6 members was fixed in signal when the vote opened
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.effective_threshold_signal(), 3);
+        assert_eq!(state.remaining_signal_needed(), Some(2));
         let two = Origin::signed(2);
-        assert_ok!(Vote::submit_vote(two, 1, VoterView::InFavor, None));
+        assert_ok!(Vote::submit_vote(
+            two,
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
         let outcome_almost_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_almost_passed, VoteOutcome::Voting);
         let three = Origin::signed(3);
-        assert_ok!(Vote::submit_vote(three, 1, VoterView::InFavor, None));
+        assert_ok!(Vote::submit_vote(
+            three,
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
         // check that the vote has passed
         let outcome_has_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_has_passed, VoteOutcome::Approved);
+        assert_eq!(
+            Vote::vote_states(1).unwrap().remaining_signal_needed(),
+            None
+        );
     });
 }
 
@@ -207,7 +358,14 @@ fn changing_votes_upholds_invariants() {
     new_test_ext().execute_with(|| {
         let one = Origin::signed(1);
         assert_noop!(
-            Vote::submit_vote(one.clone(), 1, VoterView::Against, None),
+            Vote::submit_vote(
+                one.clone(),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ),
             Error::<Test>::NoVoteStateForVoteRequest
         );
         // unanimous consent
@@ -224,7 +382,9 @@ fn changing_votes_upholds_invariants() {
                 i_origin,
                 1,
                 VoterView::InFavor,
-                None
+                None,
+                None,
+                0
             ));
         }
         // change the vote of voter 5
@@ -233,25 +393,2431 @@ fn changing_votes_upholds_invariants() {
             five.clone(),
             1,
             VoterView::Against,
-            None
+            None,
+            None,
+            0
         ));
         // check that the vote has not passed
         let outcome_almost_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_almost_passed, VoteOutcome::Voting);
         let six = Origin::signed(6);
-        assert_ok!(Vote::submit_vote(six.clone(), 1, VoterView::InFavor, None));
+        assert_ok!(Vote::submit_vote(
+            six.clone(),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
         // cannot change vote to NoVote from an existing vote
         assert_noop!(
-            Vote::submit_vote(six, 1, VoterView::Uninitialized, None),
+            Vote::submit_vote(six, 1, VoterView::Uninitialized, None, None, 0),
             Error::<Test>::VoteChangeNotSupported
         );
         // check that the vote has still not passed
         let outcome_has_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_has_passed, VoteOutcome::Voting);
         // change the vote of voter 5
-        assert_ok!(Vote::submit_vote(five, 1, VoterView::InFavor, None));
+        assert_ok!(Vote::submit_vote(
+            five,
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
         // check that the vote has not passed
         let outcome_almost_passed = Vote::get_vote_outcome(1).unwrap();
         assert_eq!(outcome_almost_passed, VoteOutcome::Approved);
     });
 }
+
+#[test]
+fn min_signal_floor_excludes_dust_accounts() {
+    new_test_ext().execute_with(|| {
+        // 1 and 2 hold 10 shares each, everyone else holds 1
+        assert_ok!(<org::Module<Test>>::issue(1, 1, 9, false));
+        assert_ok!(<org::Module<Test>>::issue(1, 2, 9, false));
+        let new_vote_id = Vote::open_vote_with_min_signal(
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(15, None),
+            None,
+            Some(2),
+        )
+        .unwrap();
+        assert_eq!(Vote::vote_logger(new_vote_id, 1).unwrap().magnitude(), 10);
+        assert_eq!(Vote::vote_logger(new_vote_id, 2).unwrap().magnitude(), 10);
+        for i in 3u64..7u64 {
+            assert!(Vote::vote_logger(new_vote_id, i).is_none());
+        }
+        // excluded signal is dropped rather than redistributed
+        assert_eq!(Vote::total_signal_issuance(new_vote_id), Some(20));
+        assert_eq!(
+            Vote::vote_states(new_vote_id)
+                .unwrap()
+                .all_possible_turnout(),
+            20
+        );
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(3),
+                new_vote_id,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ),
+            Error::<Test>::SignalNotMintedForVoter
+        );
+    });
+}
+
+#[test]
+fn votes_are_indexed_by_org_and_status() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert!(Vote::get_votes_for_org(1, None).is_empty());
+        assert_ok!(Vote::create_signal_vote(
+            one.clone(),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None
+        ));
+        assert_ok!(Vote::create_percent_vote(
+            one.clone(),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(Permill::from_percent(50), None),
+            Some(5)
+        ));
+        assert!(<VotesByOrg<Test>>::contains_key(1, 1));
+        assert!(<VotesByOrg<Test>>::contains_key(1, 2));
+        assert_eq!(Vote::count_open_votes(), 2);
+        let mut all = Vote::get_votes_for_org(1, None);
+        all.sort();
+        assert_eq!(all, vec![1, 2]);
+        // approving the first vote closes it
+        assert_ok!(Vote::submit_vote(
+            one,
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(
+            Vote::get_votes_for_org(1, Some(VoteStatus::Closed)),
+            vec![1]
+        );
+        assert_eq!(Vote::get_votes_for_org(1, Some(VoteStatus::Open)), vec![2]);
+        // expired votes are closed
+        System::set_block_number(7);
+        assert_eq!(Vote::vote_status(2), VoteStatus::Closed);
+        // removed vote state reads as cancelled
+        <VoteStates<Test>>::remove(2);
+        assert_eq!(
+            Vote::get_votes_for_org(1, Some(VoteStatus::Cancelled)),
+            vec![2]
+        );
+        assert!(Vote::get_votes_for_org(2, None).is_empty());
+    });
+}
+
+#[test]
+fn can_vote_predicate_works() {
+    new_test_ext().execute_with(|| {
+        assert!(!Vote::can_vote(1, &1));
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(2, None),
+            Some(5)
+        ));
+        assert!(Vote::can_vote(1, &1));
+        // no signal minted for non-members
+        assert!(!Vote::can_vote(1, &7));
+        System::set_block_number(7);
+        assert!(!Vote::can_vote(1, &1));
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            2,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        // closed after approval
+        assert!(!Vote::can_vote(2, &1));
+    });
+}
+
+#[test]
+fn voters_are_indexed_by_justification() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(6, None),
+            None
+        ));
+        for voter in 1u64..3u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                1,
+                VoterView::InFavor,
+                Some(7),
+                None,
+                0
+            ));
+        }
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::Against,
+            Some(8),
+            None,
+            0
+        ));
+        let mut cited = Vote::get_voters_by_justification(1, 7);
+        cited.sort();
+        assert_eq!(cited, vec![1, 2]);
+        assert_eq!(Vote::get_voters_by_justification(1, 8), vec![3]);
+        // revoting without a justification removes the voter from the index
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_voters_by_justification(1, 7), vec![2]);
+        assert!(Vote::get_voters_by_justification(2, 7).is_empty());
+    });
+}
+
+#[test]
+fn quorum_gates_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None
+        ));
+        assert_noop!(
+            Vote::set_vote_quorum(2, Some(3)),
+            Error::<Test>::CannotSetQuorumIfVoteStateDNE
+        );
+        assert_noop!(
+            Vote::set_vote_quorum(1, Some(7)),
+            Error::<Test>::QuorumExceedsPossibleTurnout
+        );
+        // only the creator configures the vote
+        assert_noop!(
+            Vote::configure_vote_quorum(Origin::signed(2), 1, Some(3), 6),
+            Error::<Test>::OnlyVoteCreatorCanConfigureVote
+        );
+        let decay = DecayConfig::new(
+            Permill::from_percent(50),
+            Permill::from_percent(20),
+            1,
+        );
+        assert_noop!(
+            Vote::configure_vote_decay(Origin::signed(2), 1, Some(decay)),
+            Error::<Test>::OnlyVoteCreatorCanConfigureVote
+        );
+        assert_noop!(
+            Vote::configure_vote_decay(Origin::signed(1), 1, Some(decay)),
+            Error::<Test>::DecayRequiresVoteWithEnd
+        );
+        assert_noop!(
+            Vote::configure_vote_quorum(Origin::signed(1), 1, Some(3), 5),
+            Error::<Test>::VoterCountWitnessTooLow
+        );
+        assert_ok!(Vote::configure_vote_quorum(
+            Origin::signed(1),
+            1,
+            Some(3),
+            6
+        ));
+        assert_eq!(get_last_event(), RawEvent::VoteConfigured(1, 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(
+            Vote::get_vote_outcome(1).unwrap(),
+            VoteOutcome::InsufficientParticipation
+        );
+        // still open so turnout may reach quorum
+        assert_eq!(Vote::vote_status(1), VoteStatus::Open);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::Abstain,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+    });
+}
+
+#[test]
+fn quorum_is_checked_before_threshold() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, Some(2)),
+            None
+        ));
+        assert_ok!(Vote::set_vote_quorum(1, Some(3)));
+        assert_eq!(
+            Vote::get_vote_outcome(1).unwrap(),
+            VoteOutcome::InsufficientParticipation
+        );
+        for voter in 1..3 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        // enough against to reject but not enough turnout to count
+        assert_eq!(
+            Vote::get_vote_outcome(1).unwrap(),
+            VoteOutcome::InsufficientParticipation
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Rejected);
+        // reaching quorum without deciding the vote leaves it voting
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, Some(4)),
+            None
+        ));
+        assert_ok!(Vote::set_vote_quorum(2, Some(2)));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            2,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(
+            Vote::get_vote_outcome(2).unwrap(),
+            VoteOutcome::InsufficientParticipation
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            2,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(2).unwrap(), VoteOutcome::Voting);
+    });
+}
+
+#[test]
+fn min_distinct_voters_gate_approval() {
+    new_test_ext().execute_with(|| {
+        // 1 holds 10 shares, everyone else holds 1
+        assert_ok!(<org::Module<Test>>::issue(1, 1, 9, false));
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(8, None),
+            None
+        ));
+        assert_noop!(
+            Vote::set_min_distinct_voters(2, Some(2)),
+            Error::<Test>::CannotSetMinDistinctVotersIfVoteStateDNE
+        );
+        // only 6 members hold signal for the vote
+        assert_noop!(
+            Vote::set_min_distinct_voters(1, Some(7)),
+            Error::<Test>::MinDistinctVotersExceedsVoters
+        );
+        assert_noop!(
+            Vote::configure_min_distinct_voters(
+                Origin::signed(2),
+                1,
+                Some(3),
+                6
+            ),
+            Error::<Test>::OnlyVoteCreatorCanConfigureVote
+        );
+        assert_noop!(
+            Vote::configure_min_distinct_voters(
+                Origin::signed(1),
+                1,
+                Some(3),
+                5
+            ),
+            Error::<Test>::VoterCountWitnessTooLow
+        );
+        assert_ok!(Vote::configure_min_distinct_voters(
+            Origin::signed(1),
+            1,
+            Some(3),
+            6
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        // the whale meets the signal threshold alone but is one voter
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.remaining_signal_needed(), None);
+        assert_eq!(state.distinct_voters(), 1);
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Voting);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        // changing view does not count the voter twice
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Abstain,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::vote_states(1).unwrap().distinct_voters(), 2);
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Voting);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::Abstain,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+    });
+}
+
+#[test]
+fn vote_counting_is_fixed_once_voting_starts() {
+    new_test_ext().execute_with(|| {
+        // vote 1 is approved by one vote, vote 2 needs four
+        for (vote_id, threshold) in vec![(1, 1), (2, 4)] {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                OrgRep::Equal(1),
+                Threshold::new(threshold, None),
+                Some(5)
+            ));
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(1),
+                vote_id,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ));
+        }
+        // an approved vote can't be reopened
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert_noop!(
+            Vote::set_vote_quorum(1, Some(6)),
+            Error::<Test>::CannotChangeVoteCountingAfterVoteCloses
+        );
+        assert_noop!(
+            Vote::set_min_distinct_voters(1, Some(6)),
+            Error::<Test>::CannotChangeVoteCountingAfterVoteCloses
+        );
+        // a vote short of its threshold can't be decided by changing the rules
+        assert_noop!(
+            Vote::set_vote_quorum(2, Some(2)),
+            Error::<Test>::CannotChangeVoteCountingAfterVotesAreCast
+        );
+        assert_noop!(
+            Vote::set_min_distinct_voters(2, None),
+            Error::<Test>::CannotChangeVoteCountingAfterVotesAreCast
+        );
+        // nor can an expired one
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, None),
+            Some(5)
+        ));
+        System::set_block_number(7);
+        assert_noop!(
+            Vote::configure_vote_quorum(Origin::signed(1), 3, None, 6),
+            Error::<Test>::CannotChangeVoteCountingAfterVoteExpires
+        );
+        assert_noop!(
+            Vote::configure_min_distinct_voters(Origin::signed(1), 3, None, 6),
+            Error::<Test>::CannotChangeVoteCountingAfterVoteExpires
+        );
+    });
+}
+
+#[test]
+fn voting_proxies_receive_delegated_signal() {
+    new_test_ext().execute_with(|| {
+        // 2 delegates to 3 who delegates to 4, 5 delegates outside the org
+        org::VotingProxies::<Test>::insert(1, 2, 3);
+        org::VotingProxies::<Test>::insert(1, 3, 4);
+        org::VotingProxies::<Test>::insert(1, 5, 7);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(3, None),
+            None
+        ));
+        let signal = |who: u64| Vote::vote_logger(1, who).unwrap().magnitude();
+        // delegation is one hop so 3 keeps what 2 delegated
+        assert_eq!(signal(2), 0);
+        assert_eq!(signal(3), 1);
+        assert_eq!(signal(4), 2);
+        assert_eq!(signal(5), 0);
+        assert_eq!(signal(7), 1);
+        assert_eq!(Vote::total_signal_issuance(1), Some(6));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(4),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(7),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        // one signal per member votes ignore proxies
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(3, None),
+            None
+        ));
+        assert_eq!(Vote::vote_logger(2, 2).unwrap().magnitude(), 1);
+        assert!(Vote::vote_logger(2, 7).is_none());
+    });
+}
+
+#[test]
+fn one_hop_delegation_does_not_depend_on_member_order() {
+    new_test_ext().execute_with(|| {
+        // 2 delegates to 3 who delegates to 4
+        org::VotingProxies::<Test>::insert(1, 2, 3);
+        org::VotingProxies::<Test>::insert(1, 3, 4);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(3, None),
+            None
+        ));
+        // 3 delegates to 2 who delegates to 4, so the genesis visits the
+        // proxy in the middle on the other side of its delegator
+        org::VotingProxies::<Test>::insert(1, 3, 2);
+        org::VotingProxies::<Test>::insert(1, 2, 4);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(3, None),
+            None
+        ));
+        let signal = |vote: u64, who: u64| {
+            Vote::vote_logger(vote, who).unwrap().magnitude()
+        };
+        assert_eq!((signal(1, 2), signal(1, 3), signal(1, 4)), (0, 1, 2));
+        assert_eq!((signal(2, 3), signal(2, 2), signal(2, 4)), (0, 1, 2));
+        assert_eq!(Vote::total_signal_issuance(1), Some(6));
+        assert_eq!(Vote::total_signal_issuance(2), Some(6));
+    });
+}
+
+#[test]
+fn revoking_delegation_after_proxy_voted() {
+    new_test_ext().execute_with(|| {
+        org::VotingProxies::<Test>::insert(1, 2, 4);
+        org::VotingProxies::<Test>::insert(1, 3, 4);
+        org::VotingProxies::<Test>::insert(1, 6, 2);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(4, None),
+            None
+        ));
+        assert_eq!(Vote::vote_delegations(1, 2), Some((4, 1)));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(4),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::vote_states(1).unwrap().in_favor(), 3);
+        assert_ok!(Vote::revoke_delegation(Origin::signed(2), 1));
+        assert_eq!(get_last_event(), RawEvent::DelegationRevoked(1, 2, 4, 1));
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 2);
+        assert_eq!(state.turnout(), 2);
+        // the proxy still counts as a voter
+        assert_eq!(state.distinct_voters(), 1);
+        assert_eq!(Vote::vote_logger(1, 4).unwrap().magnitude(), 2);
+        // 2 holds its own signal and the signal 6 delegated, without a view
+        let own = Vote::vote_logger(1, 2).unwrap();
+        assert_eq!(own.magnitude(), 2);
+        assert_eq!(own.direction(), VoterView::Uninitialized);
+        assert_noop!(
+            Vote::revoke_delegation(Origin::signed(2), 1),
+            Error::<Test>::NoDelegationToRevokeForVote
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert_noop!(
+            Vote::revoke_delegation(Origin::signed(3), 1),
+            Error::<Test>::CannotRevokeDelegationAfterVoteCloses
+        );
+        // the signal a proxy abstained with is returned first
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(4, None),
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(4),
+            2,
+            VoterView::InFavor,
+            None,
+            Some(Permill::from_percent(50)),
+            0
+        ));
+        assert_eq!(Vote::vote_states(2).unwrap().in_favor(), 1);
+        assert_ok!(Vote::revoke_delegation(Origin::signed(3), 2));
+        let state = Vote::vote_states(2).unwrap();
+        assert_eq!(state.in_favor(), 1);
+        assert_eq!(state.turnout(), 2);
+        assert_eq!(Vote::committed_signal(2, 4), Some(1));
+        // a delegator that voted with signal delegated to it cannot revoke
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            2,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        assert_noop!(
+            Vote::revoke_delegation(Origin::signed(2), 2),
+            Error::<Test>::CannotRevokeDelegationAfterVotingAsProxy
+        );
+    });
+}
+
+#[test]
+fn delegators_are_logged_as_delegated() {
+    new_test_ext().execute_with(|| {
+        // 2 delegates to 4 who delegates to 3, so 4 votes as a proxy
+        org::VotingProxies::<Test>::insert(1, 2, 4);
+        org::VotingProxies::<Test>::insert(1, 4, 3);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(4, None),
+            None
+        ));
+        let view = |who: u64| Vote::vote_logger(1, who).unwrap().direction();
+        assert_eq!(view(2), VoterView::Delegated);
+        assert_eq!(view(4), VoterView::Uninitialized);
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::vote(RawEvent::SignalDelegated(1, 2, 4, 1))
+        }));
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(2),
+                1,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ),
+            Error::<Test>::MustRevokeDelegationBeforeVoting
+        );
+        assert_noop!(
+            Vote::project_outcome(1, &2, VoterView::InFavor),
+            Error::<Test>::MustRevokeDelegationBeforeVoting
+        );
+        // a delegated view is not a cast vote
+        assert_eq!(Vote::vote_states(1).unwrap().turnout(), 0);
+        assert_ok!(Vote::revoke_delegation(Origin::signed(2), 1));
+        assert_eq!(view(2), VoterView::Uninitialized);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::vote_states(1).unwrap().in_favor(), 1);
+        // votes with only delegated views may still be cancelled
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(4, None),
+            None
+        ));
+        assert_eq!(
+            Vote::vote_logger(2, 2).unwrap().direction(),
+            VoterView::Delegated
+        );
+        assert_ok!(Vote::cancel_vote(Origin::signed(1), 2, 6));
+    });
+}
+
+#[test]
+fn delegation_cap_limits_delegators_per_proxy() {
+    new_test_ext().execute_with(|| {
+        for delegator in &[2u64, 3, 5] {
+            org::VotingProxies::<Test>::insert(1, delegator, 4);
+        }
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(4, None),
+            None
+        ));
+        // delegators are credited in membership order until the cap of 2
+        let delegated: Vec<u64> = vec![2, 3, 5]
+            .into_iter()
+            .filter(|d| Vote::vote_delegations(1, d).is_some())
+            .collect();
+        assert_eq!(delegated.len(), 2);
+        assert_eq!(Vote::vote_logger(1, 4).unwrap().magnitude(), 3);
+        let kept = [2u64, 3, 5]
+            .iter()
+            .copied()
+            .find(|d| !delegated.contains(d))
+            .unwrap();
+        assert_eq!(Vote::vote_logger(1, kept).unwrap().magnitude(), 1);
+        assert_noop!(
+            Vote::revoke_delegation(Origin::signed(kept), 1),
+            Error::<Test>::NoDelegationToRevokeForVote
+        );
+        assert_eq!(Vote::total_signal_issuance(1), Some(6));
+    });
+}
+
+#[test]
+fn snapshot_votes_ignore_later_membership_changes() {
+    new_test_ext().execute_with(|| {
+        let snapshot_id = org::Module::<Test>::take_org_snapshot(1).unwrap();
+        assert_ok!(<org::Module<Test>>::issue(1, 7, 10, false));
+        assert!(<org::Module<Test>>::burn(1, 2, None, false).is_ok());
+        assert_noop!(
+            Vote::open_vote_from_snapshot(
+                None,
+                1,
+                snapshot_id + 1,
+                Threshold::new(1, None),
+                None
+            ),
+            Error::<Test>::CannotOpenVoteFromSnapshotThatDNE
+        );
+        let vote_id = Vote::open_vote_from_snapshot(
+            None,
+            1,
+            snapshot_id,
+            Threshold::new(4, None),
+            None,
+        )
+        .unwrap();
+        assert_eq!(Vote::total_signal_issuance(vote_id), Some(6));
+        assert_eq!(Vote::vote_logger(vote_id, 2).unwrap().magnitude(), 1);
+        assert!(Vote::vote_logger(vote_id, 7).is_none());
+        // votes on the live membership count both changes
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(4, None),
+            None
+        ));
+        assert_eq!(Vote::total_signal_issuance(2), Some(15));
+        assert!(Vote::vote_logger(2, 2).is_none());
+        assert_eq!(Vote::vote_logger(2, 7).unwrap().magnitude(), 10);
+    });
+}
+
+#[test]
+fn seniority_weighted_votes_scale_signal_by_tenure() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Vote::open_seniority_weighted_vote(
+                None,
+                1,
+                Threshold::new(1, None),
+                None
+            ),
+            Error::<Test>::CannotOpenSeniorityWeightedVoteWithoutConfig
+        );
+        // genesis members joined in block 0, 1 holds 10 shares
+        assert_ok!(<org::Module<Test>>::issue(1, 1, 9, false));
+        System::set_block_number(6);
+        assert_ok!(<org::Module<Test>>::issue(1, 7, 10, false));
+        assert_eq!(org::Module::<Test>::member_since(1, 7), Some(6));
+        // up to double the shares after 10 blocks
+        org::SeniorityConfigs::<Test>::insert(
+            1,
+            SeniorityConfig::new(Permill::one(), 10),
+        );
+        System::set_block_number(11);
+        let vote_id = Vote::open_seniority_weighted_vote(
+            None,
+            1,
+            Threshold::new(25, None),
+            None,
+        )
+        .unwrap();
+        let signal =
+            |who: u64| Vote::vote_logger(vote_id, who).unwrap().magnitude();
+        assert_eq!(signal(1), 20);
+        assert_eq!(signal(2), 2);
+        // halfway to the full bonus
+        assert_eq!(signal(7), 15);
+        // the total counts the bonus, not the org's 20 shares
+        assert_eq!(Vote::total_signal_issuance(vote_id), Some(45));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            vote_id,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(
+            Vote::get_vote_outcome(vote_id).unwrap(),
+            VoteOutcome::Voting
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(7),
+            vote_id,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(
+            Vote::get_vote_outcome(vote_id).unwrap(),
+            VoteOutcome::Approved
+        );
+    });
+}
+
+#[test]
+fn partial_weight_votes_are_recomputed() {
+    new_test_ext().execute_with(|| {
+        // 1 holds 10 shares, everyone else holds 1
+        assert_ok!(<org::Module<Test>>::issue(1, 1, 9, false));
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(8, None),
+            None
+        ));
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(1),
+                1,
+                VoterView::Abstain,
+                None,
+                Some(Permill::from_percent(50)),
+                0
+            ),
+            Error::<Test>::WeightFractionOnlyAppliesToInFavorOrAgainst
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            Some(Permill::from_percent(50)),
+            0
+        ));
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 5);
+        // the uncommitted half counts as abstained
+        assert_eq!(state.turnout(), 10);
+        assert_eq!(Vote::committed_signal(1, 1), Some(5));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Voting);
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(1),
+                1,
+                VoterView::InFavor,
+                None,
+                Some(Permill::from_percent(50)),
+                0
+            ),
+            Error::<Test>::OldVoteDirectionEqualsNewVoteDirectionSoNoChange
+        );
+        // raising to 100% commits the full magnitude
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            Some(Permill::from_percent(100)),
+            0
+        ));
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 10);
+        assert_eq!(state.turnout(), 10);
+        assert_eq!(Vote::committed_signal(1, 1), None);
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        // switching sides with a fraction moves only the committed portion
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::Against,
+            None,
+            Some(Permill::from_percent(30)),
+            0
+        ));
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 0);
+        assert_eq!(state.against(), 3);
+        assert_eq!(state.turnout(), 10);
+    });
+}
+
+#[test]
+fn votes_are_listed_and_paged_by_voter() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(6, None),
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        let mut votes = Vote::get_votes_for_vote_id(1);
+        votes.sort_by_key(|(voter, _, _)| *voter);
+        assert_eq!(votes.len(), 6);
+        assert_eq!(votes[0], (1, VoterView::InFavor, Some(1)));
+        assert_eq!(votes[1], (2, VoterView::Against, Some(1)));
+        assert_eq!(votes[2], (3, VoterView::Uninitialized, None));
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) = Vote::get_votes_page(1, cursor, 4);
+            assert!(page.len() <= 4);
+            paged.extend(page);
+            if next.is_none() {
+                break
+            }
+            cursor = next;
+        }
+        paged.sort_by_key(|(voter, _, _)| *voter);
+        assert_eq!(paged, votes);
+        assert!(Vote::get_votes_for_vote_id(2).is_empty());
+        assert_eq!(Vote::get_votes_page(1, Some(7), 4), (Vec::new(), None));
+    });
+}
+
+#[test]
+fn unchecked_open_vote_matches_checked_path() {
+    new_test_ext().execute_with(|| {
+        // a 1000 member org
+        for who in 7u64..1001u64 {
+            assert_ok!(<org::Module<Test>>::issue(1, who, who % 3 + 1, true));
+        }
+        let genesis =
+            <org::Module<Test>>::get_membership_with_shape(1).unwrap();
+        let checked = Vote::open_vote(
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(10, None),
+            None,
+        )
+        .unwrap();
+        let unchecked = Vote::open_vote_unchecked(
+            None,
+            1,
+            genesis.clone(),
+            Threshold::new(10, None),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            Vote::total_signal_issuance(checked),
+            Vote::total_signal_issuance(unchecked)
+        );
+        assert_eq!(
+            Vote::vote_states(checked).unwrap().all_possible_turnout(),
+            Vote::vote_states(unchecked).unwrap().all_possible_turnout()
+        );
+        for (who, _) in genesis.vec() {
+            assert_eq!(
+                Vote::vote_logger(checked, who),
+                Vote::vote_logger(unchecked, who)
+            );
+        }
+        assert_eq!(Vote::get_votes_for_org(1, None).len(), 2);
+        // thresholds are still bounded by the genesis total
+        assert_noop!(
+            Vote::open_vote_unchecked(
+                None,
+                1,
+                genesis,
+                Threshold::new(10_000, None),
+                None,
+            ),
+            Error::<Test>::InputThresholdExceedsBounds
+        );
+    });
+}
+
+#[test]
+fn vote_state_migration_decodes_old_layout() {
+    new_test_ext().execute_with(|| {
+        let old_state: VoteStV1<Test> = VoteStateV1::new(
+            None,
+            2,
+            0,
+            2,
+            6,
+            Threshold::new(2, None),
+            1,
+            Some(10),
+            VoteOutcome::Approved,
+        );
+        // seed the bytes an older runtime would have written
+        put_storage_value(
+            b"Vote",
+            b"VoteStates",
+            &Blake2_128Concat::hash(&1u64.encode()),
+            old_state.clone(),
+        );
+        assert_eq!(Vote::storage_version(), Releases::V1);
+        Vote::on_runtime_upgrade();
+        assert_eq!(Vote::storage_version(), Releases::V3);
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state, old_state.upgrade().upgrade());
+        assert_eq!(state.in_favor(), 2);
+        assert_eq!(state.all_possible_turnout(), 6);
+        assert_eq!(state.ends(), Some(10));
+        assert_eq!(state.threshold_tree(), None);
+        assert!(!state.disallow_late_registration());
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        // already migrated so upgrading again is a no-op
+        assert_eq!(Vote::on_runtime_upgrade(), 0);
+        assert_eq!(Vote::vote_states(1), Some(state));
+    });
+}
+
+#[test]
+fn vote_state_v2_migration_counts_distinct_voters() {
+    new_test_ext().execute_with(|| {
+        let old_state: VoteStV2<Test> = VoteStateV2::new(
+            None,
+            1,
+            0,
+            2,
+            6,
+            Threshold::new(3, None),
+            None,
+            None,
+            1,
+            None,
+            false,
+            VoteOutcome::Voting,
+        );
+        put_storage_value(
+            b"Vote",
+            b"VoteStates",
+            &Blake2_128Concat::hash(&1u64.encode()),
+            old_state.clone(),
+        );
+        <VoteLogger<Test>>::insert(
+            1,
+            1,
+            VoteVec::<Test>::new(1, VoterView::InFavor, None),
+        );
+        <VoteLogger<Test>>::insert(
+            1,
+            2,
+            VoteVec::<Test>::new(1, VoterView::Abstain, None),
+        );
+        <VoteLogger<Test>>::insert(
+            1,
+            3,
+            VoteVec::<Test>::new(1, VoterView::Uninitialized, None),
+        );
+        <StorageVersion>::put(Releases::V2);
+        Vote::on_runtime_upgrade();
+        assert_eq!(Vote::storage_version(), Releases::V3);
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state, old_state.upgrade().set_distinct_voters(2));
+        assert_eq!(state.min_distinct_voters(), None);
+    });
+}
+
+#[test]
+fn approval_only_vote_is_never_rejected() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Vote::create_signal_vote(
+            one.clone(),
+            None,
+            OrgRep::Equal(1),
+            Threshold::approval_only(6),
+            Some(5)
+        ));
+        // a zero against threshold is also approval only
+        assert_ok!(Vote::create_signal_vote(
+            one,
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, Some(0)),
+            None
+        ));
+        assert!(Threshold::new(4, Some(0)).is_approval_only());
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_eq!(Vote::get_vote_outcome(1), Ok(VoteOutcome::Voting));
+        assert_eq!(Vote::get_vote_outcome(2), Ok(VoteOutcome::Voting));
+        // expires inconclusive rather than rejected
+        System::set_block_number(7);
+        assert_eq!(Vote::vote_status(1), VoteStatus::Closed);
+        assert_eq!(
+            Vote::get_vote_outcome(1),
+            Ok(VoteOutcome::ExpiredInconclusive)
+        );
+        // votes without an end keep counting
+        assert_eq!(Vote::get_vote_outcome(2), Ok(VoteOutcome::Voting));
+    });
+}
+
+#[test]
+fn open_votes_are_capped_per_org() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(<org::Module<Test>>::new_flat_org(
+            Origin::signed(1),
+            Some(1),
+            None,
+            1739,
+            vec![1, 2, 3]
+        ));
+        for _ in 0..3 {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                OrgRep::Equal(1),
+                Threshold::new(1, None),
+                Some(5)
+            ));
+        }
+        assert_eq!(Vote::open_votes_per_org(1), 3);
+        assert_eq!(Vote::vote_org(3), Some(1));
+        assert_noop!(
+            Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                OrgRep::Equal(1),
+                Threshold::new(1, None),
+                None
+            ),
+            Error::<Test>::TooManyOpenVotesForOrg
+        );
+        // other orgs are unaffected
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(2),
+            Threshold::new(1, None),
+            None
+        ));
+        assert_eq!(Vote::open_votes_per_org(2), 1);
+        // approval releases the slot
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::open_votes_per_org(1), 2);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None
+        ));
+        // expired votes are released in the block after they end
+        System::set_block_number(7);
+        Vote::on_initialize(7);
+        assert_eq!(Vote::open_votes_per_org(1), 1);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None
+        ));
+        assert_eq!(Vote::open_votes_per_org(1), 2);
+    });
+}
+
+#[test]
+fn creator_cancels_vote_before_votes_are_cast() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                OrgRep::Equal(1),
+                Threshold::new(4, None),
+                None
+            ));
+        }
+        assert_noop!(
+            Vote::cancel_vote(Origin::signed(2), 1, 6),
+            Error::<Test>::OnlyVoteCreatorCanCancelVote
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            2,
+            VoterView::Abstain,
+            None,
+            None,
+            0
+        ));
+        assert_noop!(
+            Vote::cancel_vote(Origin::signed(1), 2, 6),
+            Error::<Test>::CannotCancelVoteWithCastVotes
+        );
+        assert_noop!(
+            Vote::cancel_vote(Origin::signed(1), 1, 5),
+            Error::<Test>::VoterCountWitnessTooLow
+        );
+        assert_ok!(Vote::cancel_vote(Origin::signed(1), 1, 6));
+        assert_eq!(get_last_event(), RawEvent::VoteCancelled(1, 1));
+        assert_eq!(Vote::vote_status(1), VoteStatus::Cancelled);
+        assert!(Vote::vote_logger(1, 1).is_none());
+        assert!(Vote::vote_org(1).is_none());
+        assert!(!<VotesByOrg<Test>>::contains_key(1, 1));
+        assert_eq!(Vote::get_votes_for_org(1, None), vec![2]);
+        assert_eq!(Vote::count_open_votes(), 1);
+        assert_eq!(Vote::open_votes_per_org(1), 1);
+        // the cancelled vote's deposit is refunded in full
+        assert!(Vote::vote_deposits(1).is_none());
+        assert_eq!(Balances::reserved_balance(1), 10);
+        assert_noop!(
+            Vote::cancel_vote(Origin::signed(1), 1, 6),
+            Error::<Test>::CannotCancelVoteIfVoteStateDNE
+        );
+    });
+}
+
+#[test]
+fn vote_pool_weighs_sub_org_votes() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        // sub orgs 2 and 3 of org 1
+        assert_ok!(<org::Module<Test>>::new_flat_org(
+            one.clone(),
+            Some(1),
+            Some(1),
+            1738,
+            vec![1, 2],
+        ));
+        assert_ok!(<org::Module<Test>>::new_flat_org(
+            one.clone(),
+            Some(1),
+            Some(1),
+            1738,
+            vec![3, 4, 5, 6],
+        ));
+        for org in 2u64..4u64 {
+            assert_ok!(Vote::create_signal_vote(
+                one.clone(),
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(2, None),
+                None
+            ));
+        }
+        let threshold = Threshold::new(
+            Permill::from_percent(60),
+            Some(Permill::from_percent(50)),
+        );
+        let weights =
+            vec![Permill::from_percent(70), Permill::from_percent(30)];
+        assert_noop!(
+            Vote::create_vote_pool(
+                Origin::signed(2),
+                1,
+                vec![1, 2],
+                weights.clone(),
+                threshold.clone()
+            ),
+            Error::<Test>::OnlySupervisorCanCreateVotePool
+        );
+        assert_noop!(
+            Vote::create_vote_pool(
+                one.clone(),
+                1,
+                vec![1, 2, 2],
+                vec![Permill::from_percent(70); 3],
+                threshold.clone()
+            ),
+            Error::<Test>::VotePoolTooLarge
+        );
+        assert_noop!(
+            Vote::create_vote_pool(
+                one.clone(),
+                1,
+                vec![1, 2],
+                vec![Permill::from_percent(70)],
+                threshold.clone()
+            ),
+            Error::<Test>::VotePoolWeightsMustMatchVotes
+        );
+        assert_noop!(
+            Vote::create_vote_pool(
+                one.clone(),
+                1,
+                vec![1, 2],
+                vec![Permill::from_percent(70), Permill::from_percent(20)],
+                threshold.clone()
+            ),
+            Error::<Test>::VotePoolWeightsMustSumToOneHundredPercent
+        );
+        // the vote of org 3 is not a vote of org 2 or its sub orgs
+        assert_noop!(
+            Vote::create_vote_pool(
+                one.clone(),
+                2,
+                vec![1, 2],
+                weights.clone(),
+                threshold.clone()
+            ),
+            Error::<Test>::VotePoolMemberVoteMustBelongToOrgOrSubOrg
+        );
+        assert_ok!(Vote::create_vote_pool(
+            one.clone(),
+            1,
+            vec![1, 2],
+            vec![Permill::from_percent(70), Permill::from_percent(30)],
+            threshold.clone()
+        ));
+        assert_eq!(get_last_event(), RawEvent::VotePoolCreated(1, 1));
+        assert_eq!(Vote::compute_pool_outcome(1), Ok(VoteOutcome::Voting));
+        // 50% of the first vote => 35% of the pool
+        assert_ok!(Vote::submit_vote(
+            one.clone(),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        // 50% of the second vote => another 15%
+        for voter in 3u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                2,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_eq!(Vote::compute_pool_outcome(1), Ok(VoteOutcome::Voting));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::compute_pool_outcome(1), Ok(VoteOutcome::Approved));
+        // the first vote closed once it was approved
+        assert_noop!(
+            Vote::create_vote_pool(
+                one,
+                1,
+                vec![1, 2],
+                vec![Permill::from_percent(70), Permill::from_percent(30)],
+                threshold
+            ),
+            Error::<Test>::VotePoolMemberVoteMustBeOpen
+        );
+        assert_eq!(
+            Vote::compute_pool_outcome(2),
+            Err(Error::<Test>::VotePoolDNE.into())
+        );
+    });
+}
+
+#[test]
+fn multi_class_vote_sums_weighted_shares() {
+    new_test_ext().execute_with(|| {
+        // founders org 2 alongside the contributors in org 1
+        assert_ok!(<org::Module<Test>>::new_flat_org(
+            Origin::signed(1),
+            Some(1),
+            Some(1),
+            1738,
+            vec![1, 2],
+        ));
+        assert_ok!(<org::Module<Test>>::issue(2, 1, 4, false));
+        let vote_id = Vote::open_multi_class_vote(
+            None,
+            1,
+            vec![(1, 1), (2, 3)],
+            Threshold::new(13, None),
+            None,
+        )
+        .unwrap();
+        // 1 * 1 + 5 * 3 for the first founder, 1 * 1 + 1 * 3 for the second
+        assert_eq!(Vote::vote_logger(vote_id, 1).unwrap().magnitude(), 16);
+        assert_eq!(Vote::vote_logger(vote_id, 2).unwrap().magnitude(), 4);
+        assert_eq!(Vote::vote_logger(vote_id, 3).unwrap().magnitude(), 1);
+        assert_eq!(Vote::total_signal_issuance(vote_id), Some(24));
+        assert_eq!(
+            Vote::vote_states(vote_id).unwrap().all_possible_turnout(),
+            24
+        );
+        assert_eq!(Vote::get_votes_for_org(1, None), vec![vote_id]);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            vote_id,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(vote_id), Ok(VoteOutcome::Approved));
+        // a zero multiplier drops the class
+        let founders_only = Vote::open_multi_class_vote(
+            None,
+            1,
+            vec![(1, 0), (2, 1)],
+            Threshold::new(1, None),
+            None,
+        )
+        .unwrap();
+        assert_eq!(Vote::total_signal_issuance(founders_only), Some(6));
+        assert!(Vote::vote_logger(founders_only, 3).is_none());
+        assert_noop!(
+            Vote::open_multi_class_vote(
+                None,
+                1,
+                vec![(1, 1), (2, u64::MAX)],
+                Threshold::new(1, None),
+                None,
+            ),
+            Error::<Test>::MultiClassSignalOverflow
+        );
+        assert_noop!(
+            Vote::open_multi_class_vote(
+                None,
+                1,
+                vec![(9, 1)],
+                Threshold::new(1, None),
+                None,
+            ),
+            Error::<Test>::CannotMintSignalBecauseMembershipShapeDNE
+        );
+    });
+}
+
+#[test]
+fn project_outcome_does_not_write_storage() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(2, Some(2)),
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        let root = sp_io::storage::root();
+        assert_eq!(
+            Vote::project_outcome(1, &2, VoterView::InFavor),
+            Ok(VoteOutcome::Approved)
+        );
+        assert_eq!(
+            Vote::project_outcome(1, &2, VoterView::Against),
+            Ok(VoteOutcome::Voting)
+        );
+        // the voter already holds this view
+        assert_eq!(
+            Vote::project_outcome(1, &1, VoterView::InFavor),
+            Ok(VoteOutcome::Voting)
+        );
+        assert_eq!(
+            Vote::project_outcome(1, &22, VoterView::InFavor),
+            Err(Error::<Test>::SignalNotMintedForVoter.into())
+        );
+        assert_eq!(sp_io::storage::root(), root);
+        assert_eq!(Vote::get_vote_outcome(1), Ok(VoteOutcome::Voting));
+        assert_eq!(
+            Vote::vote_logger(1, 2).unwrap().direction(),
+            VoterView::Uninitialized
+        );
+    });
+}
+
+#[test]
+fn signal_cap_clamps_large_holders() {
+    new_test_ext().execute_with(|| {
+        // 10 of the org's 15 shares
+        assert_ok!(<org::Module<Test>>::issue(1, 1, 9, false));
+        // 20% of 15 is 3
+        let relative =
+            SignalMintingCap::new(None, Some(Permill::from_percent(20)));
+        assert_noop!(
+            Vote::open_vote_with_signal_cap(
+                None,
+                OrgRep::Weighted(1),
+                XorThreshold::Signal(Threshold::new(9, None)),
+                None,
+                relative,
+            ),
+            Error::<Test>::InputThresholdExceedsBounds
+        );
+        let vote_id = Vote::open_vote_with_signal_cap(
+            None,
+            OrgRep::Weighted(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+            None,
+            relative,
+        )
+        .unwrap();
+        assert_eq!(Vote::vote_logger(vote_id, 1).unwrap().magnitude(), 3);
+        assert_eq!(Vote::vote_logger(vote_id, 2).unwrap().magnitude(), 1);
+        assert_eq!(Vote::total_signal_issuance(vote_id), Some(8));
+        let state = Vote::vote_states(vote_id).unwrap();
+        assert_eq!(state.all_possible_turnout(), 8);
+        // 50% of the capped total
+        assert_eq!(state.threshold().in_favor(), 4);
+        // the lower of both limits applies
+        let both =
+            SignalMintingCap::new(Some(2), Some(Permill::from_percent(20)));
+        let vote_id = Vote::open_vote_with_signal_cap(
+            None,
+            OrgRep::Weighted(1),
+            XorThreshold::Signal(Threshold::new(4, None)),
+            None,
+            both,
+        )
+        .unwrap();
+        assert_eq!(Vote::vote_logger(vote_id, 1).unwrap().magnitude(), 2);
+        assert_eq!(Vote::total_signal_issuance(vote_id), Some(7));
+        // without limits every share counts
+        let vote_id = Vote::open_vote_with_signal_cap(
+            None,
+            OrgRep::Weighted(1),
+            XorThreshold::Signal(Threshold::new(9, None)),
+            None,
+            SignalMintingCap::new(None, None),
+        )
+        .unwrap();
+        assert_eq!(Vote::vote_logger(vote_id, 1).unwrap().magnitude(), 10);
+        assert_eq!(Vote::total_signal_issuance(vote_id), Some(15));
+        // a zero limit would leave no member with signal
+        for zero in [
+            SignalMintingCap::new(None, Some(Permill::zero())),
+            SignalMintingCap::new(Some(0), Some(Permill::from_percent(20))),
+        ]
+        .iter()
+        {
+            assert_noop!(
+                Vote::open_vote_with_signal_cap(
+                    None,
+                    OrgRep::Weighted(1),
+                    XorThreshold::Signal(Threshold::new(1, None)),
+                    None,
+                    *zero,
+                ),
+                Error::<Test>::SignalCapMustBeNonZero
+            );
+        }
+    });
+}
+
+#[test]
+fn participation_index_tracks_voters_until_close() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                OrgRep::Equal(1),
+                Threshold::new(2, None),
+                None
+            ));
+        }
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            2,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        let mut votes = Vote::get_votes_participated_in(1);
+        votes.sort();
+        assert_eq!(votes, vec![1, 2]);
+        assert!(Vote::get_votes_participated_in(2).is_empty());
+        // closing the first vote queues its entries for the next block
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(1), Ok(VoteOutcome::Approved));
+        assert!(Vote::participation_prunes(2, 1).is_some());
+        assert!(Vote::vote_participation_index(1, 1).is_some());
+        System::set_block_number(2);
+        Vote::on_initialize(2);
+        assert!(Vote::participation_prunes(2, 1).is_none());
+        assert_eq!(Vote::get_votes_participated_in(1), vec![2]);
+        assert!(Vote::get_votes_participated_in(2).is_empty());
+        assert!(Vote::vote_participation_index(1, 1).is_none());
+    });
+}
+
+#[test]
+fn anyone_can_comment_on_a_vote() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Vote::submit_vote_comment(Origin::signed(7), 1, 10),
+            Error::<Test>::CannotCommentOnVoteThatDNE
+        );
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(2, None),
+            None
+        ));
+        // account 7 holds no signal in the vote
+        assert!(!Vote::can_vote(1, &7));
+        assert_ok!(Vote::submit_vote_comment(Origin::signed(7), 1, 10));
+        assert_eq!(get_last_event(), RawEvent::VoteCommentSubmitted(1, 7, 10));
+        assert_ok!(Vote::submit_vote_comment(Origin::signed(2), 1, 11));
+        assert_eq!(Vote::comment_count(1), 2);
+        // replacing a comment does not count the account twice
+        assert_ok!(Vote::submit_vote_comment(Origin::signed(7), 1, 12));
+        assert_eq!(Vote::comment_count(1), 2);
+        let mut comments = Vote::get_vote_comments(1);
+        comments.sort();
+        assert_eq!(comments, vec![(2, 11), (7, 12)]);
+        // the tally is untouched
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.turnout(), 0);
+        assert_eq!(state.in_favor(), 0);
+        // cancelling the vote drops its comments
+        assert_ok!(Vote::cancel_vote(Origin::signed(1), 1, 6));
+        assert!(Vote::get_vote_comments(1).is_empty());
+        assert_eq!(Vote::comment_count(1), 0);
+    });
+}
+
+#[test]
+fn conviction_multiplies_signal_and_locks_vote() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(6, None),
+            None
+        ));
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(1),
+                1,
+                VoterView::InFavor,
+                None,
+                None,
+                3
+            ),
+            Error::<Test>::ConvictionExceedsMaximum
+        );
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(1),
+                1,
+                VoterView::Abstain,
+                None,
+                None,
+                1
+            ),
+            Error::<Test>::ConvictionOnlyAppliesToInFavorOrAgainst
+        );
+        // 1x, not locked
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::Voted(1, 1, VoterView::InFavor, 1)
+        );
+        assert!(Vote::vote_convictions(1, 1).is_none());
+        // 2x, locked for one period
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            1
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::Voted(1, 2, VoterView::InFavor, 2)
+        );
+        assert_eq!(Vote::vote_convictions(1, 2), Some((1, 6)));
+        assert_eq!(<org::Module<Test>>::share_locks(1, 2), Some((1, 6)));
+        // 4x, locked for two periods
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::Against,
+            None,
+            None,
+            2
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::Voted(1, 3, VoterView::Against, 4)
+        );
+        assert_eq!(Vote::vote_convictions(1, 3), Some((2, 11)));
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 3);
+        assert_eq!(state.against(), 4);
+        assert_eq!(state.turnout(), 7);
+        // grown by each conviction bonus
+        assert_eq!(state.all_possible_turnout(), 10);
+        // conviction only applies to the first vote
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(1),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                1
+            ),
+            Error::<Test>::ConvictionOnlyAppliesToFirstVote
+        );
+        System::set_block_number(5);
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(2),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ),
+            Error::<Test>::VoteLockedByConviction
+        );
+        assert_noop!(
+            <org::Module<Test>>::burn(1, 2, None, false),
+            org::Error::<Test>::SharesLockedUntilVoteEnds
+        );
+        System::set_block_number(6);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        // the multiplier outlasts the lock
+        assert_eq!(
+            get_last_event(),
+            RawEvent::Voted(1, 2, VoterView::Against, 2)
+        );
+        System::set_block_number(10);
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(3),
+                1,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ),
+            Error::<Test>::VoteLockedByConviction
+        );
+        System::set_block_number(11);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 5);
+        assert_eq!(state.against(), 2);
+        assert_eq!(state.turnout(), 7);
+        // the lock ended so the shares can leave the org
+        assert_ok!(<org::Module<Test>>::burn(1, 2, None, false));
+        assert!(<org::Module<Test>>::share_locks(1, 2).is_none());
+    });
+}
+
+#[test]
+fn tally_stays_valid_when_everyone_votes_after_a_max_conviction() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(6, None),
+            None
+        ));
+        // 4x, the highest conviction
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            2
+        ));
+        for voter in 2..7 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(voter),
+                1,
+                VoterView::Against,
+                None,
+                None,
+                0
+            ));
+        }
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 4);
+        assert_eq!(state.against(), 5);
+        assert_eq!(state.all_possible_turnout(), 9);
+        assert_eq!(state.validate(), Ok(()));
+    });
+}
+
+#[test]
+fn conviction_locks_shares_until_the_vote_ends() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(6, None),
+            Some(20)
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            1
+        ));
+        // the conviction lock ends at block 6 but votes are accepted until 22
+        assert_eq!(Vote::vote_convictions(1, 2), Some((1, 6)));
+        assert_eq!(<org::Module<Test>>::share_locks(1, 2), Some((1, 22)));
+        System::set_block_number(21);
+        assert_noop!(
+            <org::Module<Test>>::burn(1, 2, Some(1), false),
+            org::Error::<Test>::SharesLockedUntilVoteEnds
+        );
+        System::set_block_number(22);
+        assert_ok!(<org::Module<Test>>::burn(1, 2, Some(1), false));
+    });
+}
+
+#[test]
+fn unvoted_signal_decays_to_zero_by_the_deadline() {
+    new_test_ext().execute_with(|| {
+        // member 6 holds 5 of the org's 10 shares
+        assert_ok!(<org::Module<Test>>::issue(1, 6, 4, false));
+        let decay = DecayConfig::new(
+            Permill::from_percent(50),
+            Permill::from_percent(20),
+            1,
+        );
+        assert_noop!(
+            Vote::set_vote_decay(1, Some(decay)),
+            Error::<Test>::CannotSetDecayIfVoteStateDNE
+        );
+        // ends at block 11, decay starts at block 6
+        assert_ok!(Vote::open_vote(
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(2, None),
+            Some(10)
+        ));
+        assert_ok!(Vote::open_vote(
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(2, None),
+            None
+        ));
+        assert_noop!(
+            Vote::set_vote_decay(2, Some(decay)),
+            Error::<Test>::DecayRequiresVoteWithEnd
+        );
+        assert_noop!(
+            Vote::set_vote_decay(
+                1,
+                Some(DecayConfig::new(
+                    Permill::from_percent(50),
+                    Permill::from_percent(20),
+                    0
+                ))
+            ),
+            Error::<Test>::DecayPeriodMustBeNonZero
+        );
+        assert_ok!(Vote::set_vote_decay(1, Some(decay)));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            None,
+            None,
+            0
+        ));
+        for n in 2..=6 {
+            System::set_block_number(n);
+            Vote::on_initialize(n);
+        }
+        assert_eq!(Vote::vote_logger(1, 6).unwrap().magnitude(), 5);
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 10);
+        // each period takes a fifth of the signal held before decay started
+        System::set_block_number(7);
+        Vote::on_initialize(7);
+        assert_eq!(Vote::vote_logger(1, 6).unwrap().magnitude(), 4);
+        assert_eq!(Vote::vote_logger(1, 3).unwrap().magnitude(), 0);
+        assert_eq!(Vote::decayed_signal(1, 6), 1);
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 6);
+        for n in 8..=11 {
+            System::set_block_number(n);
+            Vote::on_initialize(n);
+        }
+        assert_eq!(Vote::vote_logger(1, 6).unwrap().magnitude(), 0);
+        assert_eq!(Vote::decayed_signal(1, 6), 5);
+        // the voters keep their signal and now make up all possible turnout
+        assert_eq!(Vote::vote_logger(1, 1).unwrap().magnitude(), 1);
+        assert_eq!(Vote::vote_logger(1, 2).unwrap().magnitude(), 1);
+        let state = Vote::vote_states(1).unwrap();
+        assert_eq!(state.all_possible_turnout(), 2);
+        assert_eq!(state.turnout(), 2);
+        // the other vote never decays
+        assert_eq!(Vote::vote_logger(2, 6).unwrap().magnitude(), 5);
+        // dropped once the vote ends
+        System::set_block_number(12);
+        Vote::on_initialize(12);
+        assert!(Vote::vote_decay(1).is_none());
+    });
+}
+
+#[test]
+fn decay_reads_a_bounded_number_of_voters_per_block() {
+    new_test_ext().execute_with(|| {
+        DECAYED_VOTERS_PER_BLOCK.with(|d| *d.borrow_mut() = 3);
+        // ends at block 21, decay periods end in blocks 16 and 21
+        assert_ok!(Vote::open_vote(
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(1, None),
+            Some(20)
+        ));
+        assert_ok!(Vote::set_vote_decay(
+            1,
+            Some(DecayConfig::new(
+                Permill::from_percent(50),
+                Permill::from_percent(100),
+                5,
+            ))
+        ));
+        assert_eq!(Vote::decay_queue(16, 1), Some(16));
+        for n in 2..=16 {
+            System::set_block_number(n);
+            Vote::on_initialize(n);
+        }
+        // the queued decay and two of the six members
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 4);
+        assert_eq!(
+            Vote::decay_cursor()
+                .map(|(vote_id, period_end, _)| (vote_id, period_end)),
+            Some((1, 16))
+        );
+        System::set_block_number(17);
+        Vote::on_initialize(17);
+        // stops once the threshold is barely reachable
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 1);
+        assert!(Vote::decay_cursor().is_none());
+        assert_eq!(Vote::decay_queue(21, 1), Some(21));
+    });
+}
+
+#[test]
+fn defaults_match_newly_opened_votes() {
+    new_test_ext().execute_with(|| {
+        // no vote is open under an unreserved id
+        assert!(Vote::vote_states(1).is_none());
+        assert_eq!(VoteOutcome::default(), VoteOutcome::NotStarted);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, None),
+            None
+        ));
+        assert_eq!(
+            Vote::vote_states(1).unwrap().outcome(),
+            VoteOutcome::Voting
+        );
+        for i in 1..7 {
+            assert_eq!(
+                Vote::vote_logger(1, i).unwrap().direction(),
+                VoterView::default()
+            );
+        }
+    });
+}
+
+#[test]
+fn vote_deposit_is_refunded_in_full_at_high_turnout() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, None),
+            None
+        ));
+        assert_eq!(Vote::vote_deposits(1), Some((1, 10)));
+        assert_eq!(Balances::reserved_balance(1), 10);
+        assert_noop!(
+            Vote::settle_vote_deposit(Origin::signed(2), 1),
+            Error::<Test>::CannotSettleVoteCreationDepositBeforeVoteCloses
+        );
+        for i in 1..5 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ));
+        }
+        assert_eq!(Vote::vote_status(1), VoteStatus::Closed);
+        // any account may settle
+        assert_ok!(Vote::settle_vote_deposit(Origin::signed(2), 1));
+        assert_eq!(get_last_event(), RawEvent::VoteDepositSettled(1, 1, 10, 0));
+        assert_eq!(
+            Vote::settled_turnout(1),
+            Some(Permill::from_rational_approximation(4u64, 6))
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1000);
+        assert_noop!(
+            Vote::settle_vote_deposit(Origin::signed(2), 1),
+            Error::<Test>::NoVoteCreationDepositToSettle
+        );
+    });
+}
+
+#[test]
+fn vote_deposit_is_partly_refunded_at_low_turnout() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, None),
+            Some(2)
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        System::set_block_number(4);
+        assert_eq!(Vote::vote_status(1), VoteStatus::Closed);
+        assert_ok!(Vote::settle_vote_deposit(Origin::signed(1), 1));
+        // 1/6 turnout is a third of the 50% that earns a full refund
+        assert_eq!(get_last_event(), RawEvent::VoteDepositSettled(1, 1, 3, 7));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 993);
+        assert_eq!(Balances::free_balance(7), 8);
+        // votes opened by other modules take no deposit
+        let vote_id = Vote::open_vote(
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, None),
+            None,
+        )
+        .unwrap();
+        assert_noop!(
+            Vote::settle_vote_deposit(Origin::signed(1), vote_id),
+            Error::<Test>::NoVoteCreationDepositToSettle
+        );
+    });
+}
+
+#[test]
+fn vote_creation_fee_is_burned_and_credited_to_the_reward_pool() {
+    new_test_ext().execute_with(|| {
+        VOTE_CREATION_FEE.with(|f| *f.borrow_mut() = 5);
+        let issuance = Balances::total_issuance();
+        let pool = Vote::vote_reward_pool_account();
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None
+        ));
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::vote(RawEvent::VoteCreationFeeCharged(1, 1, 5))
+        }));
+        assert_eq!(Vote::vote_creation_fees(1), Some(5));
+        assert_eq!(Balances::free_balance(1), 985);
+        assert_eq!(Balances::total_issuance(), issuance - 5);
+        // a conclusive outcome mints the fee into the pool
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::vote(RawEvent::VoteRewardPoolCredited(1, 5))
+        }));
+        assert_eq!(Vote::vote_creation_fees(1), None);
+        assert_eq!(Balances::free_balance(pool), 5);
+        assert_eq!(Balances::total_issuance(), issuance);
+        // a cancelled vote's fee stays burned
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None
+        ));
+        assert_ok!(Vote::cancel_vote(Origin::signed(1), 2, 6));
+        assert_eq!(Vote::vote_creation_fees(2), None);
+        assert_eq!(Balances::free_balance(1), 980);
+        assert_eq!(Balances::free_balance(pool), 5);
+        // the fee is checked before the vote is opened
+        VOTE_CREATION_FEE.with(|f| *f.borrow_mut() = 980);
+        assert_noop!(
+            Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                OrgRep::Equal(1),
+                Threshold::new(1, None),
+                None
+            ),
+            Error::<Test>::InsufficientBalanceForVoteCreationFee
+        );
+    });
+}
+
+#[test]
+fn open_vote_permission_admits_configured_callers() {
+    new_test_ext().execute_with(|| {
+        let open = |who: u64| {
+            Vote::create_signal_vote(
+                Origin::signed(who),
+                None,
+                OrgRep::Equal(1),
+                Threshold::new(4, None),
+                None,
+            )
+        };
+        // only the supervisor by default
+        assert_eq!(
+            Vote::open_vote_permissions(1),
+            OpenVotePermission::ControllerOnly
+        );
+        assert_noop!(open(2), Error::<Test>::NotAuthorizedToOpenVote);
+        assert_ok!(open(1));
+        assert_noop!(
+            Vote::set_open_vote_permission(
+                Origin::signed(2),
+                1,
+                OpenVotePermission::AnyMember
+            ),
+            Error::<Test>::OnlySupervisorCanSetOpenVotePermission
+        );
+        assert_ok!(Vote::set_open_vote_permission(
+            Origin::signed(1),
+            1,
+            OpenVotePermission::AnyMember
+        ));
+        assert_eq!(get_last_event(), RawEvent::OpenVotePermissionSet(1, 1));
+        assert_noop!(open(7), Error::<Test>::NotAuthorizedToOpenVote);
+        assert_ok!(open(2));
+        assert_ok!(Vote::set_open_vote_permission(
+            Origin::signed(1),
+            1,
+            OpenVotePermission::NamedProposers(vec![3])
+        ));
+        assert_noop!(open(2), Error::<Test>::NotAuthorizedToOpenVote);
+        assert_ok!(open(3));
+        assert_eq!(Vote::vote_creator(3), Some(3));
+    });
+}
+
+#[test]
+fn opening_a_vote_emits_its_genesis() {
+    new_test_ext().execute_with(|| {
+        let genesis_events = || {
+            System::events()
+                .into_iter()
+                .filter_map(|r| {
+                    match r.event {
+                        TestEvent::vote(e @ RawEvent::VoteGenesisSet(..)) => {
+                            Some(e)
+                        }
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, None),
+            None
+        ));
+        assert_eq!(genesis_events(), vec![RawEvent::VoteGenesisSet(1, 6, 6)]);
+        // a member who delegated all of its signal holds none of its own
+        org::VotingProxies::<Test>::insert(1, 2, 4);
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Weighted(1),
+            Threshold::new(4, None),
+            None
+        ));
+        assert_eq!(
+            genesis_events().last(),
+            Some(&RawEvent::VoteGenesisSet(2, 5, 6))
+        );
+    });
+}
+
+#[test]
+fn conflicted_voters_cannot_vote() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            assert_ok!(Vote::create_signal_vote(
+                Origin::signed(1),
+                None,
+                OrgRep::Equal(1),
+                Threshold::new(6, None),
+                None
+            ));
+        }
+        CONFLICTED_VOTER.with(|c| *c.borrow_mut() = Some((5, 1)));
+        assert_noop!(
+            Vote::submit_vote(
+                Origin::signed(5),
+                1,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ),
+            Error::<Test>::VoterHasConflictOfInterest
+        );
+        // the conflict is with vote 1 only
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(5),
+            2,
+            VoterView::InFavor,
+            None,
+            None,
+            0
+        ));
+        for i in [1u64, 2, 3, 4, 6].iter() {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(*i),
+                1,
+                VoterView::InFavor,
+                None,
+                None,
+                0
+            ));
+        }
+        // the conflicted signal still counts toward the possible turnout
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Voting);
+    });
+}
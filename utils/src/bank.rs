@@ -3,7 +3,10 @@ use parity_scale_codec::{
     Decode,
     Encode,
 };
-use sp_runtime::traits::Zero;
+use sp_runtime::traits::{
+    Saturating,
+    Zero,
+};
 use sp_std::prelude::*;
 
 #[derive(
@@ -63,6 +66,8 @@ pub enum SpendState<VoteId> {
     Voting(VoteId),
     ApprovedButNotExecuted,
     ApprovedAndExecuted,
+    /// Rejected by an org vote, the spend can no longer be approved
+    Rejected,
 }
 
 #[derive(
@@ -118,3 +123,191 @@ impl<
         }
     }
 }
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// State changing operations recorded in a bank's audit log
+pub enum BankAction {
+    /// Funds moved into the bank account
+    Deposit,
+    /// Funds earmarked by a spend proposal
+    Reserve,
+    /// Earmarked funds committed to a payout, by a spend vote or a closed
+    /// dividend pool
+    Commit,
+    /// Earmarked funds released without being spent
+    Unreserve,
+    /// Funds paid out by an approved spend
+    Transfer,
+    /// Funds returned to the org when the bank is closed
+    Withdraw,
+}
+
+#[derive(
+    new, Clone, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub struct AuditEntry<BlockNumber, AccountId, Currency, IpfsRef> {
+    block: BlockNumber,
+    actor: AccountId,
+    action: BankAction,
+    amount: Currency,
+    reason: Option<IpfsRef>,
+}
+
+impl<BlockNumber: Copy, AccountId: Clone, Currency: Copy, IpfsRef: Clone>
+    AuditEntry<BlockNumber, AccountId, Currency, IpfsRef>
+{
+    pub fn block(&self) -> BlockNumber {
+        self.block
+    }
+    pub fn actor(&self) -> AccountId {
+        self.actor.clone()
+    }
+    pub fn action(&self) -> BankAction {
+        self.action
+    }
+    pub fn amount(&self) -> Currency {
+        self.amount
+    }
+    pub fn reason(&self) -> Option<IpfsRef> {
+        self.reason.clone()
+    }
+}
+
+#[derive(
+    new, Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Surplus set aside for one dividend epoch, claimed pro rata by shareholders
+pub struct DividendPool<Currency, Signal> {
+    accumulated: Currency,
+    /// Zero until the pool is closed
+    snapshot_total_shares: Signal,
+    /// True once the pool is closed to additions, claims are only accepted after
+    claims_closed: bool,
+}
+
+impl<Currency: Copy + sp_std::ops::Add<Output = Currency>, Signal: Copy>
+    DividendPool<Currency, Signal>
+{
+    pub fn accumulated(&self) -> Currency {
+        self.accumulated
+    }
+    pub fn snapshot_total_shares(&self) -> Signal {
+        self.snapshot_total_shares
+    }
+    pub fn claims_closed(&self) -> bool {
+        self.claims_closed
+    }
+    pub fn add(&self, amount: Currency) -> Self {
+        Self {
+            accumulated: self.accumulated + amount,
+            ..*self
+        }
+    }
+    pub fn close(&self, snapshot_total_shares: Signal) -> Self {
+        Self {
+            snapshot_total_shares,
+            claims_closed: true,
+            ..*self
+        }
+    }
+}
+
+#[derive(
+    new, PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Spend approved by members individually, each committing their
+/// share-weighted portion of `required` until the total is covered
+pub struct ProportionalSpend<BankId, AccountId, Currency, IpfsRef> {
+    bank_id: BankId,
+    recipient: AccountId,
+    reason: IpfsRef,
+    approvals_received: Currency,
+    required: Currency,
+}
+
+impl<
+        BankId: Copy,
+        AccountId: Clone,
+        Currency: Copy + PartialOrd + sp_std::ops::Add<Output = Currency>,
+        IpfsRef: Clone,
+    > ProportionalSpend<BankId, AccountId, Currency, IpfsRef>
+{
+    pub fn bank_id(&self) -> BankId {
+        self.bank_id
+    }
+    pub fn recipient(&self) -> AccountId {
+        self.recipient.clone()
+    }
+    pub fn reason(&self) -> IpfsRef {
+        self.reason.clone()
+    }
+    pub fn approvals_received(&self) -> Currency {
+        self.approvals_received
+    }
+    pub fn required(&self) -> Currency {
+        self.required
+    }
+    pub fn approved(&self) -> bool {
+        self.approvals_received >= self.required
+    }
+    pub fn add_approval(&self, amount: Currency) -> Self {
+        Self {
+            approvals_received: self.approvals_received + amount,
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(
+    new, PartialEq, Eq, Clone, Copy, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Balances of a bank account, its reserved funds split by whether a pending
+/// spend committed them
+pub struct BankBalanceSummary<Currency> {
+    /// Spendable under the bank's spend rules
+    free: Currency,
+    /// Held by the account but not spendable
+    reserved: Currency,
+    /// Reserved by approvals of pending proportional spends
+    committed: Currency,
+    /// Reserved for other reasons, i.e. dispute locks
+    uncommitted: Currency,
+    /// Free and reserved
+    total: Currency,
+}
+
+impl<Currency: Copy + Saturating> BankBalanceSummary<Currency> {
+    /// Derives the remaining fields from the account's balances and the
+    /// funds committed to spends
+    /// -> saturates so rounding cannot make any field wrap
+    pub fn from_balances(
+        free: Currency,
+        reserved: Currency,
+        committed: Currency,
+    ) -> Self {
+        Self {
+            free,
+            reserved,
+            committed,
+            uncommitted: reserved.saturating_sub(committed),
+            total: free.saturating_add(reserved),
+        }
+    }
+    pub fn free(&self) -> Currency {
+        self.free
+    }
+    pub fn reserved(&self) -> Currency {
+        self.reserved
+    }
+    pub fn committed(&self) -> Currency {
+        self.committed
+    }
+    pub fn uncommitted(&self) -> Currency {
+        self.uncommitted
+    }
+    pub fn total(&self) -> Currency {
+        self.total
+    }
+}
@@ -0,0 +1,69 @@
+use parity_scale_codec::{
+    Decode,
+    Encode,
+};
+use sp_runtime::{
+    DispatchResult,
+    RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// Changes that a group authorizes by vote before they are executed
+pub enum GovernanceAction<AccountId, Balance, IpfsRef> {
+    /// Pay out of capital governed by the group
+    Transfer {
+        to: AccountId,
+        amount: Balance,
+    },
+    /// Replace the group's configuration document
+    UpdateConfig(IpfsRef),
+    AdmitMember(AccountId),
+    RemoveMember(AccountId),
+}
+
+#[derive(new, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// Links an action to the vote that authorizes it
+pub struct GovernanceProposal<Action, VoteId> {
+    action: Action,
+    vote_id: VoteId,
+}
+
+impl<Action: Clone, VoteId: Copy> GovernanceProposal<Action, VoteId> {
+    pub fn action(&self) -> Action {
+        self.action.clone()
+    }
+    pub fn vote_id(&self) -> VoteId {
+        self.vote_id
+    }
+}
+
+#[derive(new, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// An org's open votes, active disputes and membership size in one response
+/// -> each list is truncated by the runtime that builds it
+pub struct GovernanceSummary<VoteId, DisputeId> {
+    open_votes: Vec<VoteId>,
+    active_disputes: Vec<DisputeId>,
+    member_count: u32,
+}
+
+impl<VoteId: Clone, DisputeId: Clone> GovernanceSummary<VoteId, DisputeId> {
+    pub fn open_votes(&self) -> Vec<VoteId> {
+        self.open_votes.clone()
+    }
+    pub fn active_disputes(&self) -> Vec<DisputeId> {
+        self.active_disputes.clone()
+    }
+    pub fn member_count(&self) -> u32 {
+        self.member_count
+    }
+}
+
+/// Executes approved actions within `Scope` (i.e. a bank or an org)
+/// -> returns an error for actions the implementer does not govern
+pub trait GovernanceExecutor<Scope, AccountId, Balance, IpfsRef> {
+    fn execute(
+        scope: Scope,
+        action: GovernanceAction<AccountId, Balance, IpfsRef>,
+    ) -> DispatchResult;
+}
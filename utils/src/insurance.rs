@@ -1,3 +1,4 @@
+use crate::vote::VoteOutcome;
 use parity_scale_codec::{
     Decode,
     Encode,
@@ -13,12 +14,14 @@ pub struct Dispute<AccountId, Currency, BlockNumber, VoteMetadata, State> {
     resolution_metadata: VoteMetadata,
     state: State,
     expiry: Option<BlockNumber>,
+    /// Inclusive (start, end) blocks within which the dispute may be raised
+    raise_window: Option<(BlockNumber, BlockNumber)>,
 }
 
 impl<
         AccountId: Clone + PartialEq,
         Currency: Clone,
-        BlockNumber: Copy,
+        BlockNumber: Copy + PartialOrd,
         VoteMetadata: Clone,
         State: Copy,
     > Dispute<AccountId, Currency, BlockNumber, VoteMetadata, State>
@@ -44,6 +47,22 @@ impl<
     pub fn expiry(&self) -> Option<BlockNumber> {
         self.expiry
     }
+    pub fn raise_window(&self) -> Option<(BlockNumber, BlockNumber)> {
+        self.raise_window
+    }
+    pub fn within_raise_window(&self, now: BlockNumber) -> bool {
+        if let Some((start, end)) = self.raise_window {
+            start <= now && now <= end
+        } else {
+            true
+        }
+    }
+    pub fn set_locked_funds(&self, locked_funds: Currency) -> Self {
+        Self {
+            locked_funds,
+            ..self.clone()
+        }
+    }
     pub fn set_state(&self, state: State) -> Self {
         Self {
             state,
@@ -52,6 +71,53 @@ impl<
     }
 }
 
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// How a raised dispute is resolved
+pub enum ResolutionPath<VoteMetadata, AccountId> {
+    /// The outcome of a vote dispatched when the dispute is raised
+    Vote(VoteMetadata),
+    /// An attestation submitted by this account
+    Oracle(AccountId),
+}
+
+impl<VoteMetadata: Clone, AccountId: PartialEq>
+    ResolutionPath<VoteMetadata, AccountId>
+{
+    pub fn vote(&self) -> Option<VoteMetadata> {
+        match self {
+            ResolutionPath::Vote(v) => Some(v.clone()),
+            ResolutionPath::Oracle(_) => None,
+        }
+    }
+    pub fn is_oracle(&self, who: &AccountId) -> bool {
+        matches!(self, ResolutionPath::Oracle(o) if o == who)
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// Dispute configuration an org reuses across registrations
+pub struct DisputeTemplate<VoteMetadata, Currency, BlockNumber> {
+    resolution_metadata: VoteMetadata,
+    /// Registrations from the template must lock at least this amount
+    minimum_amount: Currency,
+    /// Default number of blocks until registered disputes expire
+    expiry_duration: Option<BlockNumber>,
+}
+
+impl<VoteMetadata: Clone, Currency: Copy, BlockNumber: Copy>
+    DisputeTemplate<VoteMetadata, Currency, BlockNumber>
+{
+    pub fn resolution_metadata(&self) -> VoteMetadata {
+        self.resolution_metadata.clone()
+    }
+    pub fn minimum_amount(&self) -> Currency {
+        self.minimum_amount
+    }
+    pub fn expiry_duration(&self) -> Option<BlockNumber> {
+        self.expiry_duration
+    }
+}
+
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
 pub enum DisputeState<VoteId> {
     DisputeNotRaised,
@@ -61,10 +127,157 @@ pub enum DisputeState<VoteId> {
     DisputeRaisedAndAccepted(VoteId),
     /// Outcome and time last checked and outcome updated
     DisputeRaisedAndRejected(VoteId),
+    /// Locked funds transferred to the court treasury
+    DisputeRaisedAndAwardedToTreasury(VoteId),
+    /// Cancelled by both parties before the dispute was raised
+    DissolvedByMutualConsent,
+    /// Reached its expiry without being raised so the funds were unlocked
+    ExpiredWithoutBeingRaised,
+    /// Dispute raised and waiting on its oracle's attestation
+    DisputeRaisedAwaitingOracle,
+    /// The oracle approved the dispute so the locked funds went to the raiser
+    DisputeAcceptedByOracle,
+    /// The oracle rejected the dispute so the locked funds were unlocked
+    DisputeRejectedByOracle,
 }
 
+/// Matches the state of every newly registered dispute
 impl<VoteId> Default for DisputeState<VoteId> {
     fn default() -> DisputeState<VoteId> {
         DisputeState::DisputeNotRaised
     }
 }
+
+impl<VoteId> DisputeState<VoteId> {
+    /// No further transition is expected
+    /// -> a rejected dispute may still be re-run until it is removed
+    pub fn is_terminal(&self) -> bool {
+        !matches!(
+            self,
+            DisputeState::DisputeNotRaised
+                | DisputeState::DisputeRaisedAndVoteDispatched(_)
+                | DisputeState::DisputeRaisedAwaitingOracle
+        )
+    }
+}
+
+impl<VoteId: Copy> DisputeState<VoteId> {
+    /// The vote that resolved the dispute or that it awaits, if any
+    pub fn vote_id(&self) -> Option<VoteId> {
+        match self {
+            DisputeState::DisputeRaisedAndVoteDispatched(id)
+            | DisputeState::DisputeRaisedAndAccepted(id)
+            | DisputeState::DisputeRaisedAndRejected(id)
+            | DisputeState::DisputeRaisedAndAwardedToTreasury(id) => Some(*id),
+            _ => None,
+        }
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// What remains of a terminal dispute once it is removed from storage
+pub struct DisputeSummary<AccountId, Currency, BlockNumber, State> {
+    locker: AccountId,
+    dispute_raiser: AccountId,
+    locked_funds: Currency,
+    final_state: State,
+    /// The block in which the dispute reached its final state
+    closed: BlockNumber,
+}
+
+impl<AccountId: Clone, Currency: Copy, BlockNumber: Copy, State: Copy>
+    DisputeSummary<AccountId, Currency, BlockNumber, State>
+{
+    pub fn locker(&self) -> AccountId {
+        self.locker.clone()
+    }
+    pub fn dispute_raiser(&self) -> AccountId {
+        self.dispute_raiser.clone()
+    }
+    pub fn locked_funds(&self) -> Currency {
+        self.locked_funds
+    }
+    pub fn final_state(&self) -> State {
+        self.final_state
+    }
+    pub fn closed(&self) -> BlockNumber {
+        self.closed
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// A stored dispute flattened into one response for clients
+pub struct DisputeDetails<
+    DisputeId,
+    AccountId,
+    Currency,
+    BlockNumber,
+    OrgId,
+    VoteId,
+> {
+    dispute_id: DisputeId,
+    locker: AccountId,
+    dispute_raiser: AccountId,
+    locked_amount: Currency,
+    state: DisputeState<VoteId>,
+    /// The org whose vote resolves the dispute, `None` if an oracle resolves it
+    resolution_org: Option<OrgId>,
+    /// The vote the dispute's state refers to
+    vote_id: Option<VoteId>,
+    /// The outcome of `vote_id` so far, only set while that vote is live
+    vote_outcome: Option<VoteOutcome>,
+    expiry: Option<BlockNumber>,
+    /// `None` if the registration is no longer in the dispute's history
+    registered_at: Option<BlockNumber>,
+}
+
+impl<
+        DisputeId: Copy,
+        AccountId: Clone,
+        Currency: Copy,
+        BlockNumber: Copy,
+        OrgId: Copy,
+        VoteId: Copy,
+    >
+    DisputeDetails<DisputeId, AccountId, Currency, BlockNumber, OrgId, VoteId>
+{
+    pub fn dispute_id(&self) -> DisputeId {
+        self.dispute_id
+    }
+    pub fn locker(&self) -> AccountId {
+        self.locker.clone()
+    }
+    pub fn dispute_raiser(&self) -> AccountId {
+        self.dispute_raiser.clone()
+    }
+    pub fn locked_amount(&self) -> Currency {
+        self.locked_amount
+    }
+    pub fn state(&self) -> DisputeState<VoteId> {
+        self.state
+    }
+    pub fn resolution_org(&self) -> Option<OrgId> {
+        self.resolution_org
+    }
+    pub fn vote_id(&self) -> Option<VoteId> {
+        self.vote_id
+    }
+    pub fn vote_outcome(&self) -> Option<VoteOutcome> {
+        self.vote_outcome
+    }
+    pub fn expiry(&self) -> Option<BlockNumber> {
+        self.expiry
+    }
+    pub fn registered_at(&self) -> Option<BlockNumber> {
+        self.registered_at
+    }
+}
+
+/// An account's role in a dispute
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+pub enum DisputeRole {
+    /// Locked the funds at stake
+    Locker,
+    /// Authorized to raise the dispute
+    Raiser,
+}
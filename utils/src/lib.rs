@@ -12,9 +12,11 @@ pub mod bank;
 pub mod bounty;
 pub mod court;
 pub mod drip;
+pub mod governance;
 pub mod grant;
 pub mod insurance;
 pub mod kickback;
+pub mod math;
 pub mod meta;
 pub mod moloch;
 pub mod organization;
@@ -22,5 +24,7 @@ pub mod rank;
 pub mod rfp;
 pub mod share;
 pub mod sss;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod traits;
 pub mod vote;
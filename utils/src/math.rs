@@ -0,0 +1,46 @@
+use sp_runtime::traits::AtLeast32BitUnsigned;
+
+/// Floor of the square root of `n`, exact for perfect squares
+/// -> Newton's method on integers, so no floats are needed in `no_std`
+/// -> cannot overflow because every iterate stays within `n / 2 + 1`
+pub fn integer_sqrt<N: AtLeast32BitUnsigned + Copy>(n: N) -> N {
+    let two: N = 2u32.into();
+    if n < two {
+        return n
+    }
+    // n / 2 + 1 >= sqrt(n) so the iterates decrease towards the root
+    let mut root = n / two + 1u32.into();
+    let mut next = (root + n / root) / two;
+    while next < root {
+        root = next;
+        next = (root + n / root) / two;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_sqrt_floors_the_root() {
+        for root in 0u64..1_000u64 {
+            let square = root * root;
+            // exact for perfect squares
+            assert_eq!(integer_sqrt(square), root);
+            // floor for everything up to the next square
+            for n in square + 1..(root + 1) * (root + 1) {
+                assert_eq!(integer_sqrt(n), root);
+            }
+        }
+        for n in (u64::MAX - 1_000)..=u64::MAX {
+            let root = integer_sqrt(n);
+            assert!(root * root <= n);
+            assert!(root
+                .checked_add(1)
+                .and_then(|r| r.checked_mul(r))
+                .map_or(true, |s| s > n));
+        }
+        assert_eq!(integer_sqrt(u32::MAX), 65_535u32);
+    }
+}
@@ -2,7 +2,15 @@ use parity_scale_codec::{
     Decode,
     Encode,
 };
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{
+    traits::{
+        AtLeast32BitUnsigned,
+        Saturating,
+    },
+    PerThing,
+    Permill,
+    RuntimeDebug,
+};
 use sp_std::prelude::*;
 
 #[derive(new, PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
@@ -60,6 +68,12 @@ impl<
     pub fn total_shares(&self) -> Shares {
         self.shares
     }
+    pub fn set_constitution(&self, constitution: IpfsRef) -> Self {
+        Self {
+            constitution,
+            ..self.clone()
+        }
+    }
     pub fn set_shares(&self, a: Shares) -> Self {
         Self {
             shares: a,
@@ -99,6 +113,65 @@ impl<
     }
 }
 
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// An org's share weighted membership as of the block it was taken in
+pub struct OrgSnapshot<Genesis, BlockNumber> {
+    genesis: Genesis,
+    taken: BlockNumber,
+}
+
+impl<Genesis: Clone, BlockNumber: Copy> OrgSnapshot<Genesis, BlockNumber> {
+    pub fn genesis(&self) -> Genesis {
+        self.genesis.clone()
+    }
+    pub fn taken(&self) -> BlockNumber {
+        self.taken
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+/// How membership duration scales share signal in seniority weighted votes
+/// -> the bonus grows linearly with tenure and is capped once a member has held
+/// shares for `full_multiplier_after` blocks
+pub struct SeniorityConfig<BlockNumber> {
+    /// The largest bonus, as a portion of the member's shares
+    /// -> `Permill::one()` doubles the signal of the most senior members
+    max_multiplier: Permill,
+    /// Tenure after which members receive the whole bonus
+    full_multiplier_after: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> SeniorityConfig<BlockNumber> {
+    pub fn max_multiplier(&self) -> Permill {
+        self.max_multiplier
+    }
+    pub fn full_multiplier_after(&self) -> BlockNumber {
+        self.full_multiplier_after
+    }
+    /// The bonus earned after `tenure` blocks of membership
+    pub fn bonus(&self, tenure: BlockNumber) -> Permill {
+        if tenure >= self.full_multiplier_after {
+            self.max_multiplier
+        } else {
+            let ramp = Permill::from_rational_approximation(
+                tenure,
+                self.full_multiplier_after,
+            );
+            Permill::from_parts(
+                ramp.mul_floor(self.max_multiplier.deconstruct()),
+            )
+        }
+    }
+    /// `shares` plus the bonus earned after `tenure` blocks of membership
+    pub fn weigh<Shares: AtLeast32BitUnsigned + Copy>(
+        &self,
+        shares: Shares,
+        tenure: BlockNumber,
+    ) -> Shares {
+        shares.saturating_add(self.bonus(tenure).mul_floor(shares))
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 /// The pieces of information used to register an organization in `org`
 pub enum OrganizationSource<AccountId, Shares> {
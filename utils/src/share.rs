@@ -9,7 +9,11 @@ use parity_scale_codec::{
     Encode,
 };
 use sp_runtime::{
-    traits::Zero,
+    traits::{
+        CheckedAdd,
+        CheckedMul,
+        Zero,
+    },
     RuntimeDebug,
 };
 use sp_std::prelude::*;
@@ -159,6 +163,78 @@ impl<
     }
 }
 
+impl<
+        AccountId: PartialEq,
+        Shares: Copy + CheckedAdd + CheckedMul + Zero + PartialEq,
+    > WeightedVector<AccountId, Shares>
+{
+    /// Combines the genesis of several share classes into one voting body
+    /// - each class is paired with a multiplier, so an account's weight is the sum of
+    /// `shares * multiplier` over every class it holds shares in
+    /// - a multiplier of zero excludes the class, and accounts left with zero weight
+    /// are dropped
+    /// - accounts keep the position of their first appearance
+    /// -> returns `None` if any product or sum overflows `Shares`, rather than
+    /// saturating, because a saturated weight would misstate voting power
+    pub fn merge_classes(
+        classes: Vec<(WeightedVector<AccountId, Shares>, Shares)>,
+    ) -> Option<WeightedVector<AccountId, Shares>> {
+        let mut total = Shares::zero();
+        let mut vec: Vec<(AccountId, Shares)> = Vec::new();
+        for (genesis, multiplier) in classes {
+            for (who, shares) in genesis.vec {
+                let weight = shares.checked_mul(&multiplier)?;
+                if weight.is_zero() {
+                    continue
+                }
+                total = total.checked_add(&weight)?;
+                if let Some(entry) = vec.iter_mut().find(|(a, _)| *a == who) {
+                    entry.1 = entry.1.checked_add(&weight)?;
+                } else {
+                    vec.push((who, weight));
+                }
+            }
+        }
+        Some(WeightedVector { total, vec })
+    }
+}
+
+impl<
+        AccountId: Clone + PartialEq,
+        Shares: Copy + sp_std::ops::AddAssign + sp_std::ops::SubAssign,
+    > WeightedVector<AccountId, Shares>
+{
+    /// Credits each account's shares to its proxy, if `proxy_of` returns one
+    /// - `proxy_of` is called once per account, in order, with the account's shares
+    /// - the delegating account keeps its entry, less its own shares
+    /// - a proxy outside the genesis is appended, so the total is unchanged
+    /// - only an account's own shares move, shares credited to a proxy never
+    /// move on to the proxy's own proxy
+    pub fn delegate_to<F: FnMut(&AccountId, Shares) -> Option<AccountId>>(
+        self,
+        mut proxy_of: F,
+    ) -> WeightedVector<AccountId, Shares> {
+        let mut vec = self.vec.clone();
+        for (i, (who, shares)) in self.vec.into_iter().enumerate() {
+            let proxy = match proxy_of(&who, shares) {
+                Some(p) if p != who => p,
+                _ => continue,
+            };
+            // shares credited to the account earlier in the loop stay with it
+            vec[i].1 -= shares;
+            if let Some(entry) = vec.iter_mut().find(|(a, _)| *a == proxy) {
+                entry.1 += shares;
+            } else {
+                vec.push((proxy, shares));
+            }
+        }
+        WeightedVector {
+            total: self.total,
+            vec,
+        }
+    }
+}
+
 impl<
         AccountId: Parameter,
         Shares: Copy + sp_std::ops::AddAssign + Zero + PartialEq,
@@ -172,3 +248,29 @@ impl<
         sum == self.total
     }
 }
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// Share transfer between members, pending a vote among the org's shareholders
+pub struct ShareTransferProposal<OrgId, AccountId, Shares> {
+    org: OrgId,
+    from: AccountId,
+    to: AccountId,
+    amount: Shares,
+}
+
+impl<OrgId: Copy, AccountId: Clone, Shares: Copy>
+    ShareTransferProposal<OrgId, AccountId, Shares>
+{
+    pub fn org(&self) -> OrgId {
+        self.org
+    }
+    pub fn from(&self) -> AccountId {
+        self.from.clone()
+    }
+    pub fn to(&self) -> AccountId {
+        self.to.clone()
+    }
+    pub fn amount(&self) -> Shares {
+        self.amount
+    }
+}
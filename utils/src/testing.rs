@@ -0,0 +1,152 @@
+//! Shared setup for pallet tests
+//! - `util` cannot depend on the pallets so each mock runtime implements `TestRuntime`
+//! and `TestEnvironmentBuilder` drives it the same way for every pallet
+use sp_std::{
+    fmt::Debug,
+    marker::PhantomData,
+    prelude::*,
+};
+
+/// The hooks a mock runtime provides to seed and drive test scenarios
+pub trait TestRuntime {
+    type AccountId: Clone;
+    type Balance: Copy;
+    type OrgId: Copy + PartialEq + Debug;
+    type BankId: Copy + PartialEq + Debug;
+    type VoteId: Copy;
+    /// The accounts eligible to vote (i.e. `OrgRep<OrgId>`)
+    type Voters;
+    type Threshold;
+    type Direction;
+    type Outcome: PartialEq + Debug;
+
+    /// Genesis storage in which every account holds its balance
+    fn genesis_storage(
+        accounts: Vec<(Self::AccountId, Self::Balance)>,
+    ) -> sp_runtime::Storage;
+    /// Registers a flat org
+    /// -> returns the org identifier assigned by the runtime
+    fn register_org(members: Vec<Self::AccountId>) -> Self::OrgId;
+    /// Opens a bank for the org, funded by one of its members
+    /// -> returns the bank identifier assigned by the runtime
+    fn open_bank(org_id: Self::OrgId, balance: Self::Balance) -> Self::BankId;
+    fn open_signal_vote(
+        voters: Self::Voters,
+        threshold: Self::Threshold,
+    ) -> Self::VoteId;
+    fn cast_vote(
+        vote_id: Self::VoteId,
+        account: Self::AccountId,
+        direction: Self::Direction,
+    );
+    fn vote_outcome(vote_id: Self::VoteId) -> Self::Outcome;
+    fn advance_blocks(n: u32);
+}
+
+/// Describes the accounts, orgs and banks present before a test runs
+pub struct TestEnvironmentBuilder<R: TestRuntime> {
+    accounts: Vec<(R::AccountId, R::Balance)>,
+    orgs: Vec<(R::OrgId, Vec<R::AccountId>)>,
+    banks: Vec<(R::BankId, R::OrgId, R::Balance)>,
+}
+
+impl<R: TestRuntime> Default for TestEnvironmentBuilder<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: TestRuntime> TestEnvironmentBuilder<R> {
+    pub fn new() -> Self {
+        Self {
+            accounts: Vec::new(),
+            orgs: Vec::new(),
+            banks: Vec::new(),
+        }
+    }
+    pub fn with_accounts(
+        mut self,
+        accounts: Vec<(R::AccountId, R::Balance)>,
+    ) -> Self {
+        self.accounts.extend(accounts);
+        self
+    }
+    /// Orgs are registered in the order they are added so `org_id` must be
+    /// the identifier the runtime assigns next
+    pub fn with_org(
+        mut self,
+        org_id: R::OrgId,
+        members: Vec<R::AccountId>,
+    ) -> Self {
+        self.orgs.push((org_id, members));
+        self
+    }
+    /// Banks are opened after every org is registered, in the order they are added
+    pub fn with_bank(
+        mut self,
+        bank_id: R::BankId,
+        org_id: R::OrgId,
+        balance: R::Balance,
+    ) -> Self {
+        self.banks.push((bank_id, org_id, balance));
+        self
+    }
+    /// Panics if the runtime assigns identifiers other than the ones requested
+    pub fn build(self) -> TestState<R> {
+        let mut ext =
+            sp_io::TestExternalities::new(R::genesis_storage(self.accounts));
+        let (orgs, banks) = (self.orgs, self.banks);
+        ext.execute_with(|| {
+            // events are not stored in the genesis block
+            R::advance_blocks(1);
+            for (org_id, members) in orgs {
+                assert_eq!(R::register_org(members), org_id);
+            }
+            for (bank_id, org_id, balance) in banks {
+                assert_eq!(R::open_bank(org_id, balance), bank_id);
+            }
+        });
+        TestState {
+            ext,
+            _runtime: PhantomData,
+        }
+    }
+}
+
+/// Test externalities seeded by `TestEnvironmentBuilder`
+pub struct TestState<R: TestRuntime> {
+    ext: sp_io::TestExternalities,
+    _runtime: PhantomData<R>,
+}
+
+impl<R: TestRuntime> TestState<R> {
+    pub fn execute_with<T>(&mut self, execute: impl FnOnce() -> T) -> T {
+        self.ext.execute_with(execute)
+    }
+    pub fn add_signal_vote(
+        &mut self,
+        voters: R::Voters,
+        threshold: R::Threshold,
+    ) -> R::VoteId {
+        self.execute_with(|| R::open_signal_vote(voters, threshold))
+    }
+    pub fn cast_vote(
+        &mut self,
+        vote_id: R::VoteId,
+        account: R::AccountId,
+        direction: R::Direction,
+    ) {
+        self.execute_with(|| R::cast_vote(vote_id, account, direction))
+    }
+    pub fn advance_blocks(&mut self, n: u32) {
+        self.execute_with(|| R::advance_blocks(n))
+    }
+    pub fn assert_vote_outcome(
+        &mut self,
+        vote_id: R::VoteId,
+        expected_outcome: R::Outcome,
+    ) {
+        let outcome = self.execute_with(|| R::vote_outcome(vote_id));
+        assert_eq!(outcome, expected_outcome);
+    }
+}
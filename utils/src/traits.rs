@@ -1,4 +1,16 @@
+use crate::{
+    share::WeightedVector,
+    vote::{
+        Threshold as VoteThreshold,
+        VoteOutcome,
+    },
+};
+use frame_support::Parameter;
 use sp_runtime::{
+    traits::{
+        CheckedAdd,
+        Zero,
+    },
     DispatchError,
     DispatchResult,
 };
@@ -51,6 +63,26 @@ pub trait VerifyShape {
 pub trait AccessGenesis<AccountId, Shares> {
     fn total(&self) -> Shares;
     fn vec(&self) -> Vec<(AccountId, Shares)>;
+    /// Calls `f` for every entry once the entries are verified to sum to `total`
+    /// -> returns the total, `Err` if the sum overflows or doesn't match `total`
+    fn mint_into<F: FnMut(&AccountId, &Shares)>(
+        &self,
+        mut f: F,
+    ) -> sp_std::result::Result<Shares, ()>
+    where
+        Shares: CheckedAdd + Zero + PartialEq,
+    {
+        let entries = self.vec();
+        let mut sum = Shares::zero();
+        for (_, shares) in entries.iter() {
+            sum = sum.checked_add(shares).ok_or(())?;
+        }
+        if sum != self.total() {
+            return Err(())
+        }
+        entries.iter().for_each(|(who, shares)| f(who, shares));
+        Ok(sum)
+    }
 }
 pub trait AccessProfile<Shares> {
     fn total(&self) -> Shares;
@@ -155,6 +187,36 @@ pub trait OpenVote<OrgId, Signal, Percent, BlockNumber, Hash> {
     ) -> Result<Self::VoteIdentifier>;
 }
 
+/// Open and poll votes among a set of weighted accounts, without an `OrgId`
+pub trait DirectVote<AccountId, Signal> {
+    type VoteId: Parameter + Copy;
+    fn open_direct_vote(
+        src: WeightedVector<AccountId, Signal>,
+        threshold: VoteThreshold<Signal>,
+    ) -> Result<Self::VoteId>;
+    fn direct_vote_outcome(vote_id: Self::VoteId) -> Result<VoteOutcome>;
+}
+
+/// For runtimes without a direct vote backend, every call fails
+impl<AccountId, Signal> DirectVote<AccountId, Signal> for () {
+    type VoteId = ();
+    fn open_direct_vote(
+        _src: WeightedVector<AccountId, Signal>,
+        _threshold: VoteThreshold<Signal>,
+    ) -> Result<()> {
+        Err(DispatchError::Other("no direct vote backend configured"))
+    }
+    fn direct_vote_outcome(_vote_id: ()) -> Result<VoteOutcome> {
+        Err(DispatchError::Other("no direct vote backend configured"))
+    }
+}
+
+/// Executes the consequences of a vote's outcome for the proposal it gates
+pub trait VoteOutcomeHook<ProposalId> {
+    type Outcome;
+    fn on_vote_outcome(id: ProposalId) -> Result<Self::Outcome>;
+}
+
 pub trait ConfigureThreshold<Threshold, Hash, BlockNumber> {
     type ThresholdId;
     type VoteId; // TODO: make this same as OpenVote type by merging traits someday somehow
@@ -240,6 +302,48 @@ pub trait VoteOnProposal<AccountId, VoteId, Hash>:
     ) -> DispatchResult;
 }
 
+/// Gates voting on an external eligibility check, i.e. identity verification
+pub trait SybilCheck<AccountId> {
+    fn is_eligible(voter: &AccountId) -> bool;
+}
+
+/// Every account is eligible
+impl<AccountId> SybilCheck<AccountId> for () {
+    fn is_eligible(_voter: &AccountId) -> bool {
+        true
+    }
+}
+
+/// Flags voters who stand to benefit directly from a vote's outcome, i.e. the
+/// recipient of the spend it approves
+pub trait ConflictOfInterestChecker<AccountId, VoteId> {
+    fn has_conflict_of_interest(voter: &AccountId, vote_id: VoteId) -> bool;
+}
+
+/// No voter has a conflict of interest
+impl<AccountId, VoteId> ConflictOfInterestChecker<AccountId, VoteId> for () {
+    fn has_conflict_of_interest(_voter: &AccountId, _vote_id: VoteId) -> bool {
+        false
+    }
+}
+
+/// Looks up the name an account displays, i.e. its `pallet_identity` display name
+pub trait IdentityNameOf<AccountId> {
+    fn display_name(who: &AccountId) -> Option<Vec<u8>>;
+}
+
+/// No account has a display name
+impl<AccountId> IdentityNameOf<AccountId> for () {
+    fn display_name(_who: &AccountId) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+/// Maps a voter's signal to the vote power it carries, i.e. quadratic voting
+pub trait VoteWeight<Signal> {
+    fn transform(signal: Signal) -> Signal;
+}
+
 // ====== Court Logic ======
 
 pub trait RegisterDisputeType<AccountId, Currency, VoteMetadata, BlockNumber> {
@@ -250,6 +354,7 @@ pub trait RegisterDisputeType<AccountId, Currency, VoteMetadata, BlockNumber> {
         dispute_raiser: AccountId,
         resolution_path: VoteMetadata,
         expiry: Option<BlockNumber>,
+        raise_window: Option<(BlockNumber, BlockNumber)>,
     ) -> Result<Self::DisputeIdentifier>;
 }
 
@@ -1,15 +1,42 @@
-use crate::traits::{
-    Apply,
-    Approved,
-    Rejected,
-    VoteVector,
+use crate::{
+    math::integer_sqrt,
+    traits::{
+        Apply,
+        Approved,
+        Rejected,
+        VoteVector,
+        VoteWeight,
+    },
+};
+use frame_support::{
+    traits::Get,
+    Parameter,
 };
-use frame_support::Parameter;
 use parity_scale_codec::{
+    Compact,
     Decode,
     Encode,
+    EncodeLike,
+    Error as CodecError,
+    Input,
+    Output,
+};
+use sp_runtime::{
+    traits::{
+        AtLeast32BitUnsigned,
+        CheckedAdd,
+        CheckedSub,
+        Saturating,
+        Zero,
+    },
+    PerThing,
+    Permill,
+};
+use sp_std::{
+    fmt,
+    marker::PhantomData,
+    prelude::*,
 };
-use sp_std::prelude::*;
 
 #[derive(
     Clone, Copy, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug,
@@ -24,14 +51,46 @@ pub enum VoterView {
     Against,
     /// Acknowledged but abstained
     Abstain,
+    /// Signal delegated to a voting proxy, holds no signal of its own
+    /// -> the proxy is recorded with the delegation, not in the view
+    Delegated,
 }
 
+impl VoterView {
+    /// True for the views a voter casts, which count towards turnout
+    pub fn is_cast(&self) -> bool {
+        match self {
+            VoterView::InFavor | VoterView::Against | VoterView::Abstain => {
+                true
+            }
+            VoterView::Uninitialized | VoterView::Delegated => false,
+        }
+    }
+}
+
+/// Matches the view minted for every eligible voter when a vote opens
 impl Default for VoterView {
     fn default() -> VoterView {
         VoterView::Uninitialized
     }
 }
 
+/// Highest conviction a voter may choose
+pub const MAX_CONVICTION: u8 = 2;
+
+/// The signal multiplier and the number of lock periods for a conviction level
+/// - 0: 1x signal, not locked
+/// - 1: 2x signal, locked for one period
+/// - 2: 4x signal, locked for two periods
+/// -> `None` above `MAX_CONVICTION`
+pub fn conviction_terms(conviction: u8) -> Option<(u32, u32)> {
+    if conviction > MAX_CONVICTION {
+        None
+    } else {
+        Some((1u32 << conviction, conviction as u32))
+    }
+}
+
 #[derive(
     new, Clone, Copy, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug,
 )]
@@ -129,10 +188,19 @@ pub enum XorThreshold<S, P> {
 )]
 pub struct Threshold<T> {
     in_favor: T,
+    /// `None` (or zero) makes the vote approval only: it is never rejected,
+    /// it stays inconclusive until it meets `in_favor` or expires
     against: Option<T>,
 }
 
 impl<T: Copy + PartialOrd> Threshold<T> {
+    /// Threshold for a vote which can pass but can never be rejected
+    pub fn approval_only(in_favor: T) -> Self {
+        Threshold {
+            in_favor,
+            against: None,
+        }
+    }
     pub fn in_favor(&self) -> T {
         self.in_favor
     }
@@ -144,8 +212,235 @@ impl<T: Copy + PartialOrd> Threshold<T> {
     }
 }
 
+impl<T: Copy + PartialOrd + From<u32>> Threshold<T> {
+    /// The against threshold which rejects the vote, if rejection is possible
+    pub fn rejection_threshold(&self) -> Option<T> {
+        self.against.filter(|t| *t != 0u32.into())
+    }
+    pub fn is_approval_only(&self) -> bool {
+        self.rejection_threshold().is_none()
+    }
+}
+
+/// Maximum nesting depth accepted when decoding a `MultipleThreshold`
+pub const MAX_THRESHOLD_DEPTH: u32 = 8;
+
+#[derive(PartialEq, Eq, Clone, sp_runtime::RuntimeDebug)]
+/// Composition of threshold conditions, evaluated recursively
+/// -> every leaf is checked against the whole tally, e.g. a quorum and a
+/// supermajority are `And(Turnout(q), Supermajority(2, 3))`
+pub enum MultipleThreshold<T> {
+    /// Met once the in favor signal reaches `in_favor` while the signal
+    /// against is still short of the rejection threshold, if it has one
+    Single(Threshold<T>),
+    And(Box<MultipleThreshold<T>>, Box<MultipleThreshold<T>>),
+    Or(Box<MultipleThreshold<T>>, Box<MultipleThreshold<T>>),
+    /// Met once the turnout, abstentions included, reaches the signal
+    Turnout(T),
+    /// Met once the signal in favor is at least `numerator / denominator` of
+    /// the signal in favor or against, and is not zero
+    Supermajority(u32, u32),
+}
+
+impl<T: Copy + PartialOrd> MultipleThreshold<T> {
+    pub fn and(left: Self, right: Self) -> Self {
+        MultipleThreshold::And(Box::new(left), Box::new(right))
+    }
+    pub fn or(left: Self, right: Self) -> Self {
+        MultipleThreshold::Or(Box::new(left), Box::new(right))
+    }
+    /// The leftmost `Single` leaf of the tree
+    pub fn first(&self) -> Option<Threshold<T>> {
+        match self {
+            MultipleThreshold::Single(t) => Some(t.clone()),
+            MultipleThreshold::And(l, r) | MultipleThreshold::Or(l, r) => {
+                l.first().or_else(|| r.first())
+            }
+            MultipleThreshold::Turnout(_)
+            | MultipleThreshold::Supermajority(_, _) => None,
+        }
+    }
+    /// Every `Single` leaf of the tree, left to right
+    pub fn leaves(&self) -> Vec<Threshold<T>> {
+        match self {
+            MultipleThreshold::Single(t) => [t.clone()].to_vec(),
+            MultipleThreshold::And(l, r) | MultipleThreshold::Or(l, r) => {
+                let mut ret = l.leaves();
+                ret.extend(r.leaves());
+                ret
+            }
+            MultipleThreshold::Turnout(_)
+            | MultipleThreshold::Supermajority(_, _) => Vec::new(),
+        }
+    }
+    pub fn depth(&self) -> u32 {
+        match self {
+            MultipleThreshold::And(l, r) | MultipleThreshold::Or(l, r) => {
+                1 + sp_std::cmp::max(l.depth(), r.depth())
+            }
+            _ => 1,
+        }
+    }
+    /// True if every leaf can be met by a vote with `all_possible_turnout`
+    pub fn is_reachable(&self, all_possible_turnout: T) -> bool {
+        match self {
+            MultipleThreshold::Single(t) => {
+                t.in_favor() <= all_possible_turnout
+                    && t.against()
+                        .map(|a| a <= all_possible_turnout)
+                        .unwrap_or(true)
+            }
+            MultipleThreshold::And(l, r) | MultipleThreshold::Or(l, r) => {
+                l.is_reachable(all_possible_turnout)
+                    && r.is_reachable(all_possible_turnout)
+            }
+            MultipleThreshold::Turnout(q) => *q <= all_possible_turnout,
+            MultipleThreshold::Supermajority(numerator, denominator) => {
+                *denominator != 0 && numerator <= denominator
+            }
+        }
+    }
+    /// A lower bound on the in favor signal which meets the tree
+    /// -> `And` needs the larger of its branches, `Or` only the smaller and
+    /// leaves on turnout or supermajority need none by themselves
+    pub fn min_in_favor(&self) -> T
+    where
+        T: From<u32>,
+    {
+        match self {
+            MultipleThreshold::Single(t) => t.in_favor(),
+            MultipleThreshold::Turnout(_)
+            | MultipleThreshold::Supermajority(_, _) => 0u32.into(),
+            MultipleThreshold::And(l, r) => {
+                let (l, r) = (l.min_in_favor(), r.min_in_favor());
+                if l >= r {
+                    l
+                } else {
+                    r
+                }
+            }
+            MultipleThreshold::Or(l, r) => {
+                let (l, r) = (l.min_in_favor(), r.min_in_favor());
+                if l <= r {
+                    l
+                } else {
+                    r
+                }
+            }
+        }
+    }
+}
+
+impl<
+        T: Copy
+            + PartialOrd
+            + From<u32>
+            + Saturating
+            + sp_std::ops::Add<Output = T>,
+    > MultipleThreshold<T>
+{
+    /// Evaluates the tree against the tally of `state`
+    pub fn is_met<BlockNumber, Hash>(
+        &self,
+        state: &VoteState<T, BlockNumber, Hash>,
+    ) -> bool {
+        match self {
+            MultipleThreshold::Single(t) => {
+                t.geq_in_favor(state.in_favor)
+                    && t.rejection_threshold()
+                        .map(|against| state.against < against)
+                        .unwrap_or(true)
+            }
+            MultipleThreshold::And(l, r) => l.is_met(state) && r.is_met(state),
+            MultipleThreshold::Or(l, r) => l.is_met(state) || r.is_met(state),
+            MultipleThreshold::Turnout(q) => state.turnout >= *q,
+            MultipleThreshold::Supermajority(numerator, denominator) => {
+                let decided = state.in_favor + state.against;
+                state.in_favor > 0u32.into()
+                    && state.in_favor.saturating_mul((*denominator).into())
+                        >= decided.saturating_mul((*numerator).into())
+            }
+        }
+    }
+}
+
+impl<T: Encode> Encode for MultipleThreshold<T> {
+    fn encode_to<W: Output>(&self, dest: &mut W) {
+        match self {
+            MultipleThreshold::Single(t) => {
+                dest.push_byte(0);
+                t.encode_to(dest);
+            }
+            MultipleThreshold::And(l, r) => {
+                dest.push_byte(1);
+                l.encode_to(dest);
+                r.encode_to(dest);
+            }
+            MultipleThreshold::Or(l, r) => {
+                dest.push_byte(2);
+                l.encode_to(dest);
+                r.encode_to(dest);
+            }
+            MultipleThreshold::Turnout(q) => {
+                dest.push_byte(3);
+                q.encode_to(dest);
+            }
+            MultipleThreshold::Supermajority(numerator, denominator) => {
+                dest.push_byte(4);
+                numerator.encode_to(dest);
+                denominator.encode_to(dest);
+            }
+        }
+    }
+}
+
+impl<T: Decode> MultipleThreshold<T> {
+    fn decode_with_depth<I: Input>(
+        input: &mut I,
+        depth: u32,
+    ) -> Result<Self, CodecError> {
+        if depth > MAX_THRESHOLD_DEPTH {
+            return Err("MultipleThreshold exceeds max depth".into())
+        }
+        match input.read_byte()? {
+            0 => Ok(MultipleThreshold::Single(Threshold::decode(input)?)),
+            1 => {
+                let l = Self::decode_with_depth(input, depth + 1)?;
+                let r = Self::decode_with_depth(input, depth + 1)?;
+                Ok(MultipleThreshold::And(Box::new(l), Box::new(r)))
+            }
+            2 => {
+                let l = Self::decode_with_depth(input, depth + 1)?;
+                let r = Self::decode_with_depth(input, depth + 1)?;
+                Ok(MultipleThreshold::Or(Box::new(l), Box::new(r)))
+            }
+            3 => Ok(MultipleThreshold::Turnout(T::decode(input)?)),
+            4 => {
+                Ok(MultipleThreshold::Supermajority(
+                    u32::decode(input)?,
+                    u32::decode(input)?,
+                ))
+            }
+            _ => Err("No such variant in enum MultipleThreshold".into()),
+        }
+    }
+}
+
+impl<T: Decode> Decode for MultipleThreshold<T> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        Self::decode_with_depth(input, 1)
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug)]
 /// The state of an ongoing vote
+///
+/// Values are stored by the vote pallets so any change to this layout breaks
+/// decoding of votes written by older runtimes. To add a field:
+/// 1. copy the current layout into a new `VoteStateV{n}` below and give it an
+///    `upgrade` method that fills the new field with a default
+/// 2. bump `Releases` in every pallet storing `VoteState`
+/// 3. translate stored values with `upgrade` in `on_runtime_upgrade`
 pub struct VoteState<Signal, BlockNumber, Hash> {
     /// Vote state must often be anchored to offchain state, cid
     topic: Option<Hash>,
@@ -159,14 +454,116 @@ pub struct VoteState<Signal, BlockNumber, Hash> {
     all_possible_turnout: Signal,
     /// The threshold requirement for passage
     threshold: Threshold<Signal>,
+    /// Compound threshold requirement, replaces `threshold` for passage if set
+    threshold_tree: Option<MultipleThreshold<Signal>>,
+    /// Minimum turnout for a met threshold to approve the vote
+    quorum: Option<Signal>,
+    /// Minimum number of voters for a met threshold to approve the vote
+    min_distinct_voters: Option<u32>,
+    /// Number of voters who cast any view, abstaining included
+    distinct_voters: u32,
     /// The time at which this vote state is initialized
     initialized: BlockNumber,
     /// The time at which this vote state expires
     ends: Option<BlockNumber>,
-    /// The vote outcome
+    /// If true, voters registered after the vote's snapshot cannot vote
+    disallow_late_registration: bool,
+    /// The vote outcome, cached on every mutation so reads do not recompute it
+    outcome: VoteOutcome,
+}
+
+/// `VoteState` layout before `threshold_tree`, `quorum` and
+/// `disallow_late_registration`
+/// -> only used to decode votes written by older runtimes
+#[derive(
+    new, PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub struct VoteStateV1<Signal, BlockNumber, Hash> {
+    topic: Option<Hash>,
+    in_favor: Signal,
+    against: Signal,
+    turnout: Signal,
+    all_possible_turnout: Signal,
+    threshold: Threshold<Signal>,
+    initialized: BlockNumber,
+    ends: Option<BlockNumber>,
     outcome: VoteOutcome,
 }
 
+impl<Signal, BlockNumber, Hash> VoteStateV1<Signal, BlockNumber, Hash> {
+    pub fn upgrade(self) -> VoteStateV2<Signal, BlockNumber, Hash> {
+        VoteStateV2 {
+            topic: self.topic,
+            in_favor: self.in_favor,
+            against: self.against,
+            turnout: self.turnout,
+            all_possible_turnout: self.all_possible_turnout,
+            threshold: self.threshold,
+            threshold_tree: None,
+            quorum: None,
+            initialized: self.initialized,
+            ends: self.ends,
+            disallow_late_registration: false,
+            outcome: self.outcome,
+        }
+    }
+}
+
+/// `VoteState` layout before `min_distinct_voters` and `distinct_voters`
+/// -> only used to decode votes written by older runtimes
+#[derive(
+    new, PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub struct VoteStateV2<Signal, BlockNumber, Hash> {
+    topic: Option<Hash>,
+    in_favor: Signal,
+    against: Signal,
+    turnout: Signal,
+    all_possible_turnout: Signal,
+    threshold: Threshold<Signal>,
+    threshold_tree: Option<MultipleThreshold<Signal>>,
+    quorum: Option<Signal>,
+    initialized: BlockNumber,
+    ends: Option<BlockNumber>,
+    disallow_late_registration: bool,
+    outcome: VoteOutcome,
+}
+
+impl<Signal, BlockNumber, Hash> VoteStateV2<Signal, BlockNumber, Hash> {
+    /// The distinct voter count starts at zero, callers that can count the
+    /// vote's voters set it with `VoteState::set_distinct_voters`
+    pub fn upgrade(self) -> VoteState<Signal, BlockNumber, Hash> {
+        VoteState {
+            topic: self.topic,
+            in_favor: self.in_favor,
+            against: self.against,
+            turnout: self.turnout,
+            all_possible_turnout: self.all_possible_turnout,
+            threshold: self.threshold,
+            threshold_tree: self.threshold_tree,
+            quorum: self.quorum,
+            min_distinct_voters: None,
+            distinct_voters: 0,
+            initialized: self.initialized,
+            ends: self.ends,
+            disallow_late_registration: self.disallow_late_registration,
+            outcome: self.outcome,
+        }
+    }
+}
+
+/// Internal inconsistency found in a `VoteState`
+/// -> indicates a bug in how votes were applied rather than invalid input
+#[derive(Clone, Copy, PartialEq, Eq, sp_runtime::RuntimeDebug)]
+pub enum VoteStateError {
+    /// In favor and against signal sum exceeds all possible turnout
+    TallyExceedsTotal,
+    InFavorThresholdExceedsTotal,
+    AgainstThresholdExceedsTotal,
+    QuorumExceedsTotal,
+    EndsBeforeInitialized,
+}
+
 impl<
         Signal: Parameter
             + From<u32>
@@ -174,7 +571,8 @@ impl<
             + Default
             + sp_std::ops::Add<Output = Signal>
             + sp_std::ops::Sub<Output = Signal>
-            + PartialOrd,
+            + PartialOrd
+            + Saturating,
         BlockNumber: Parameter + Copy + Default,
         Hash: Clone,
     > VoteState<Signal, BlockNumber, Hash>
@@ -193,8 +591,41 @@ impl<
             turnout: 0u32.into(),
             all_possible_turnout,
             threshold,
+            threshold_tree: None,
+            quorum: None,
+            min_distinct_voters: None,
+            distinct_voters: 0,
             initialized,
             ends,
+            disallow_late_registration: false,
+            outcome: VoteOutcome::Voting,
+        }
+    }
+    /// The single `threshold` mirrors the leftmost `Single` leaf of the tree
+    /// so rejection and existing read paths keep working
+    pub fn new_compound(
+        topic: Option<Hash>,
+        all_possible_turnout: Signal,
+        threshold_tree: MultipleThreshold<Signal>,
+        initialized: BlockNumber,
+        ends: Option<BlockNumber>,
+    ) -> VoteState<Signal, BlockNumber, Hash> {
+        VoteState {
+            topic,
+            in_favor: 0u32.into(),
+            against: 0u32.into(),
+            turnout: 0u32.into(),
+            all_possible_turnout,
+            threshold: threshold_tree
+                .first()
+                .unwrap_or_else(|| Threshold::new(0u32.into(), None)),
+            threshold_tree: Some(threshold_tree),
+            quorum: None,
+            min_distinct_voters: None,
+            distinct_voters: 0,
+            initialized,
+            ends,
+            disallow_late_registration: false,
             outcome: VoteOutcome::Voting,
         }
     }
@@ -211,8 +642,13 @@ impl<
             turnout: 0u32.into(),
             all_possible_turnout,
             threshold: Threshold::new(all_possible_turnout, None),
+            threshold_tree: None,
+            quorum: None,
+            min_distinct_voters: None,
+            distinct_voters: 0,
             initialized,
             ends,
+            disallow_late_registration: false,
             outcome: VoteOutcome::Voting,
         }
     }
@@ -231,6 +667,9 @@ impl<
     pub fn all_possible_turnout(&self) -> Signal {
         self.all_possible_turnout
     }
+    pub fn initialized(&self) -> BlockNumber {
+        self.initialized
+    }
     pub fn ends(&self) -> Option<BlockNumber> {
         self.ends
     }
@@ -240,20 +679,159 @@ impl<
             ..self.clone()
         }
     }
+    pub fn disallow_late_registration(&self) -> bool {
+        self.disallow_late_registration
+    }
+    pub fn set_disallow_late_registration(&self, disallow: bool) -> Self {
+        Self {
+            disallow_late_registration: disallow,
+            ..self.clone()
+        }
+    }
+    /// Adds signal for a voter registered after the vote was opened
+    /// -> `None` if the possible turnout would overflow
+    pub fn add_possible_turnout(&self, amount: Signal) -> Option<Self>
+    where
+        Signal: CheckedAdd,
+    {
+        let all_possible_turnout =
+            self.all_possible_turnout.checked_add(&amount)?;
+        Some(
+            Self {
+                all_possible_turnout,
+                ..self.clone()
+            }
+            .set_outcome(),
+        )
+    }
+    /// Removes the signal of a voter who left before voting
+    /// -> `None` if the possible turnout would fall below the signal already
+    /// tallied in favor and against
+    pub fn subtract_possible_turnout(&self, amount: Signal) -> Option<Self>
+    where
+        Signal: CheckedSub,
+    {
+        let all_possible_turnout = self
+            .all_possible_turnout
+            .checked_sub(&amount)
+            .filter(|total| {
+                *total >= self.in_favor.saturating_add(self.against)
+            })?;
+        Some(
+            Self {
+                all_possible_turnout,
+                ..self.clone()
+            }
+            .set_outcome(),
+        )
+    }
     pub fn threshold(&self) -> Threshold<Signal> {
         self.threshold.clone()
     }
+    /// Replaces the threshold, i.e. after re-deriving it against a new total
+    pub fn set_threshold(&self, threshold: Threshold<Signal>) -> Self {
+        VoteState {
+            threshold,
+            outcome: VoteOutcome::Voting,
+            ..self.clone()
+        }
+        .set_outcome()
+    }
+    pub fn threshold_tree(&self) -> Option<MultipleThreshold<Signal>> {
+        self.threshold_tree.clone()
+    }
+    pub fn quorum(&self) -> Option<Signal> {
+        self.quorum
+    }
+    /// The least possible turnout for which the threshold and quorum can still be met
+    pub fn min_possible_turnout(&self) -> Signal {
+        self.threshold
+            .against()
+            .into_iter()
+            .chain(self.quorum)
+            .fold(
+                self.threshold.in_favor(),
+                |floor, s| {
+                    if s > floor {
+                        s
+                    } else {
+                        floor
+                    }
+                },
+            )
+    }
+    /// Recomputes the outcome because a met threshold may no longer approve
+    pub fn set_quorum(&self, quorum: Option<Signal>) -> Self {
+        VoteState {
+            quorum,
+            outcome: VoteOutcome::Voting,
+            ..self.clone()
+        }
+        .set_outcome()
+    }
+    pub fn min_distinct_voters(&self) -> Option<u32> {
+        self.min_distinct_voters
+    }
+    /// Recomputes the outcome because a met threshold may no longer approve
+    pub fn set_min_distinct_voters(&self, min: Option<u32>) -> Self {
+        VoteState {
+            min_distinct_voters: min,
+            outcome: VoteOutcome::Voting,
+            ..self.clone()
+        }
+        .set_outcome()
+    }
+    pub fn distinct_voters(&self) -> u32 {
+        self.distinct_voters
+    }
+    /// Replaces the count, i.e. after recounting the voters of a migrated vote
+    pub fn set_distinct_voters(&self, distinct_voters: u32) -> Self {
+        VoteState {
+            distinct_voters,
+            ..self.clone()
+        }
+        .set_outcome()
+    }
+    /// True if no minimum is set or enough voters have voted
+    pub fn meets_min_distinct_voters(&self) -> bool {
+        self.min_distinct_voters
+            .map(|min| self.distinct_voters >= min)
+            .unwrap_or(true)
+    }
     pub fn outcome(&self) -> VoteOutcome {
         self.outcome
     }
+    /// The in favor signal at which the vote's threshold is met
+    /// -> percent thresholds are converted against `all_possible_turnout`
+    /// when the vote opens, so this does not move as turnout comes in
+    pub fn effective_threshold_signal(&self) -> Signal {
+        if let Some(tree) = &self.threshold_tree {
+            tree.min_in_favor()
+        } else {
+            self.threshold.in_favor()
+        }
+    }
+    /// In favor signal still missing to meet the threshold, `None` once met
+    pub fn remaining_signal_needed(&self) -> Option<Signal> {
+        let needed = self.effective_threshold_signal();
+        if self.in_favor >= needed {
+            None
+        } else {
+            Some(needed - self.in_favor)
+        }
+    }
     pub fn update_topic_and_clear_state(&self, new_topic: Hash) -> Self {
+        // cleared tally invalidates the cached outcome
         VoteState {
             in_favor: 0u32.into(),
             against: 0u32.into(),
             turnout: 0u32.into(),
+            distinct_voters: 0,
             topic: Some(new_topic),
+            outcome: VoteOutcome::Voting,
             ..self.clone()
         }
+        .set_outcome()
     }
     pub fn update_topic_without_clearing_state(&self, new_topic: Hash) -> Self {
         VoteState {
@@ -261,24 +839,70 @@ impl<
             ..self.clone()
         }
     }
-    fn set_outcome(&self) -> Self {
-        let rejected = if let Some(rejection_outcome) = self.rejected() {
-            rejection_outcome
-        } else {
-            false
+    /// Removes signal from the tally without retracting the voter's view
+    /// - `turnout` leaves the turnout and `committed` leaves the column of `view`
+    /// - the voter still counts as a distinct voter
+    /// -> `None` for `Uninitialized` and `Delegated`, which hold no signal in
+    /// the tally
+    pub fn withdraw_signal(
+        &self,
+        view: VoterView,
+        turnout: Signal,
+        committed: Signal,
+    ) -> Option<Self> {
+        let (in_favor, against) = match view {
+            VoterView::InFavor => (self.in_favor - committed, self.against),
+            VoterView::Against => (self.in_favor, self.against - committed),
+            VoterView::Abstain => (self.in_favor, self.against),
+            VoterView::Uninitialized | VoterView::Delegated => return None,
         };
-        if self.approved() {
+        Some(
             VoteState {
-                outcome: VoteOutcome::Approved,
+                in_favor,
+                against,
+                turnout: self.turnout - turnout,
                 ..self.clone()
             }
-        } else if rejected {
-            VoteState {
-                outcome: VoteOutcome::Rejected,
-                ..self.clone()
+            .set_outcome(),
+        )
+    }
+    /// Computes the outcome from the current tally, ignoring the cached outcome
+    /// -> quorum is checked before the threshold so neither approval nor
+    /// rejection is reported until enough signal has turned out
+    pub fn computed_outcome(&self) -> VoteOutcome {
+        if let Some(q) = self.quorum {
+            if self.turnout < q {
+                return VoteOutcome::InsufficientParticipation
             }
+        }
+        // approval only votes stay `Voting` until approved, even after expiry
+        let rejected = self.rejected().unwrap_or(false);
+        // signal alone cannot approve a vote short of its distinct voters
+        if self.approved() && self.meets_min_distinct_voters() {
+            VoteOutcome::Approved
+        } else if rejected {
+            VoteOutcome::Rejected
         } else {
-            self.clone()
+            VoteOutcome::Voting
+        }
+    }
+    fn set_outcome(&self) -> Self {
+        match (self.computed_outcome(), self.outcome) {
+            // quorum was just reached on a tally that decides nothing yet
+            (VoteOutcome::Voting, VoteOutcome::InsufficientParticipation) => {
+                VoteState {
+                    outcome: VoteOutcome::Voting,
+                    ..self.clone()
+                }
+            }
+            // an inconclusive tally keeps any previously reached outcome
+            (VoteOutcome::Voting, _) => self.clone(),
+            (outcome, _) => {
+                VoteState {
+                    outcome,
+                    ..self.clone()
+                }
+            }
         }
     }
 }
@@ -290,13 +914,18 @@ impl<
             + Default
             + PartialOrd
             + sp_std::ops::Add<Output = Signal>
-            + sp_std::ops::Sub<Output = Signal>,
+            + sp_std::ops::Sub<Output = Signal>
+            + Saturating,
         BlockNumber: Parameter + Copy + Default,
         Hash: Clone,
     > Approved for VoteState<Signal, BlockNumber, Hash>
 {
     fn approved(&self) -> bool {
-        self.in_favor() >= self.threshold().in_favor()
+        if let Some(tree) = &self.threshold_tree {
+            tree.is_met(self)
+        } else {
+            self.in_favor() >= self.threshold().in_favor()
+        }
     }
 }
 
@@ -312,13 +941,46 @@ impl<
         Hash: Clone,
     > Rejected for VoteState<Signal, BlockNumber, Hash>
 {
+    /// `None` for approval only votes, which are never rejected
     fn rejected(&self) -> Option<bool> {
-        if let Some(rejection_threshold_set) = self.threshold().against() {
-            Some(self.against() >= rejection_threshold_set)
-        } else {
-            // rejection threshold not set!
-            None
+        self.threshold()
+            .rejection_threshold()
+            .map(|t| self.against() >= t)
+    }
+}
+
+impl<
+        Signal: Copy + PartialOrd + sp_std::ops::Sub<Output = Signal>,
+        BlockNumber: Copy + PartialOrd,
+        Hash,
+    > VoteState<Signal, BlockNumber, Hash>
+{
+    /// Checks the invariants every vote state must uphold
+    pub fn validate(&self) -> Result<(), VoteStateError> {
+        let total = self.all_possible_turnout;
+        // written to avoid overflowing `in_favor + against`
+        if self.in_favor > total || self.against > total - self.in_favor {
+            return Err(VoteStateError::TallyExceedsTotal)
+        }
+        if self.threshold.in_favor() > total {
+            return Err(VoteStateError::InFavorThresholdExceedsTotal)
+        }
+        if let Some(against) = self.threshold.against() {
+            if against > total {
+                return Err(VoteStateError::AgainstThresholdExceedsTotal)
+            }
         }
+        if let Some(quorum) = self.quorum {
+            if quorum > total {
+                return Err(VoteStateError::QuorumExceedsTotal)
+            }
+        }
+        if let Some(ends) = self.ends {
+            if ends < self.initialized {
+                return Err(VoteStateError::EndsBeforeInitialized)
+            }
+        }
+        Ok(())
     }
 }
 
@@ -329,7 +991,8 @@ impl<
             + Default
             + sp_std::ops::Add<Output = Signal>
             + sp_std::ops::Sub<Output = Signal>
-            + PartialOrd,
+            + PartialOrd
+            + Saturating,
         Hash: Clone,
         BlockNumber: Parameter + Copy + Default,
     > Apply<Signal, VoterView> for VoteState<Signal, BlockNumber, Hash>
@@ -347,6 +1010,7 @@ impl<
                 let new_vote_state = VoteState {
                     in_favor: new_in_favor,
                     turnout: new_turnout,
+                    distinct_voters: self.distinct_voters.saturating_add(1),
                     ..self.clone()
                 };
                 Some(new_vote_state.set_outcome())
@@ -357,6 +1021,7 @@ impl<
                 let new_vote_state = VoteState {
                     against: new_against,
                     turnout: new_turnout,
+                    distinct_voters: self.distinct_voters.saturating_add(1),
                     ..self.clone()
                 };
                 Some(new_vote_state.set_outcome())
@@ -365,6 +1030,7 @@ impl<
                 let new_turnout = self.turnout() + magnitude;
                 let new_vote_state = VoteState {
                     turnout: new_turnout,
+                    distinct_voters: self.distinct_voters.saturating_add(1),
                     ..self.clone()
                 };
                 Some(new_vote_state.set_outcome())
@@ -441,10 +1107,544 @@ pub enum VoteOutcome {
     Approved,
     /// The VoteState is rejected
     Rejected,
+    /// Turnout is below the vote's quorum, whatever the tally
+    /// -> the threshold is not evaluated until quorum is reached
+    InsufficientParticipation,
+    /// The vote ended without approving or rejecting and will not change
+    /// -> only recorded by modules which finalize votes once they expire
+    ExpiredInconclusive,
 }
 
+/// No vote is open under the id yet; opening a vote moves it to `Voting`
 impl Default for VoteOutcome {
     fn default() -> Self {
         VoteOutcome::NotStarted
     }
 }
+
+#[derive(
+    PartialEq, Eq, Copy, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Coarse lifecycle status for filtering indexed votes
+pub enum VoteStatus {
+    /// The vote accepts votes and has no final outcome
+    Open,
+    /// The vote has an outcome or is past its end
+    Closed,
+    /// The vote state was removed before it closed
+    Cancelled,
+}
+
+#[derive(
+    new, PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Aggregates votes, i.e. of sub orgs, into one decision
+/// -> each member vote counts in proportion to its weight
+pub struct VotePool<VoteId> {
+    member_votes: Vec<VoteId>,
+    /// Parallel to `member_votes`, sums to 100%
+    weight: Vec<Permill>,
+    /// Applied to the weighted share of possible turnout in favor and against
+    threshold: Threshold<Permill>,
+}
+
+impl<VoteId: Clone> VotePool<VoteId> {
+    pub fn member_votes(&self) -> Vec<VoteId> {
+        self.member_votes.clone()
+    }
+    pub fn weight(&self) -> Vec<Permill> {
+        self.weight.clone()
+    }
+    pub fn threshold(&self) -> Threshold<Permill> {
+        self.threshold.clone()
+    }
+    /// Sums each member's share in favor and against, scaled by its weight
+    /// -> `shares` are in the order of `member_votes`
+    pub fn tally(&self, shares: Vec<(Permill, Permill)>) -> (Permill, Permill) {
+        self.weight.iter().zip(shares).fold(
+            (Permill::zero(), Permill::zero()),
+            |(in_favor, against), (weight, (f, a))| {
+                (
+                    in_favor.saturating_add(Self::scale(*weight, f)),
+                    against.saturating_add(Self::scale(*weight, a)),
+                )
+            },
+        )
+    }
+    pub fn outcome(&self, shares: Vec<(Permill, Permill)>) -> VoteOutcome {
+        let (in_favor, against) = self.tally(shares);
+        if in_favor >= self.threshold.in_favor() {
+            VoteOutcome::Approved
+        } else if self
+            .threshold
+            .against()
+            .filter(|t| !t.is_zero())
+            .map(|t| against >= t)
+            .unwrap_or(false)
+        {
+            VoteOutcome::Rejected
+        } else {
+            VoteOutcome::Voting
+        }
+    }
+    fn scale(weight: Permill, share: Permill) -> Permill {
+        Permill::from_parts(weight.mul_floor(share.deconstruct()))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, sp_runtime::RuntimeDebug)]
+/// Returned when inserting into a full `BoundedVoteIdSet`
+pub struct CapacityExceeded;
+
+/// Distinct vote identifiers, at most `MaxSize::get()` of them, in insertion order
+///
+/// Encodes exactly like `Vec<VoteId>` so a stored `Vec` can be read as a set
+/// without a migration. Decoding fails if the stored length exceeds the capacity,
+/// before any identifier is read.
+pub struct BoundedVoteIdSet<VoteId, MaxSize> {
+    ids: Vec<VoteId>,
+    _max_size: PhantomData<MaxSize>,
+}
+
+impl<VoteId: PartialEq, MaxSize: Get<u32>> BoundedVoteIdSet<VoteId, MaxSize> {
+    pub fn new() -> Self {
+        Self {
+            ids: Vec::new(),
+            _max_size: PhantomData,
+        }
+    }
+    pub fn capacity() -> usize {
+        MaxSize::get() as usize
+    }
+    /// Inserting an identifier already in the set is a no-op
+    pub fn insert(&mut self, id: VoteId) -> Result<(), CapacityExceeded> {
+        if self.ids.contains(&id) {
+            return Ok(())
+        }
+        if self.ids.len() >= Self::capacity() {
+            return Err(CapacityExceeded)
+        }
+        self.ids.push(id);
+        Ok(())
+    }
+    /// Removing an identifier not in the set is a no-op
+    pub fn remove(&mut self, id: &VoteId) {
+        self.ids.retain(|v| v != id);
+    }
+    pub fn contains(&self, id: &VoteId) -> bool {
+        self.ids.contains(id)
+    }
+    pub fn iter(&self) -> sp_std::slice::Iter<'_, VoteId> {
+        self.ids.iter()
+    }
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+    pub fn last(&self) -> Option<&VoteId> {
+        self.ids.last()
+    }
+}
+
+impl<VoteId: Clone, MaxSize> BoundedVoteIdSet<VoteId, MaxSize> {
+    pub fn to_vec(&self) -> Vec<VoteId> {
+        self.ids.clone()
+    }
+}
+
+// implemented by hand because the derives would bound `MaxSize`, which is only a
+// `Get` marker
+impl<VoteId, MaxSize> Default for BoundedVoteIdSet<VoteId, MaxSize> {
+    fn default() -> Self {
+        Self {
+            ids: Vec::new(),
+            _max_size: PhantomData,
+        }
+    }
+}
+
+impl<VoteId: Clone, MaxSize> Clone for BoundedVoteIdSet<VoteId, MaxSize> {
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+            _max_size: PhantomData,
+        }
+    }
+}
+
+impl<VoteId: PartialEq, MaxSize> PartialEq
+    for BoundedVoteIdSet<VoteId, MaxSize>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ids == other.ids
+    }
+}
+
+impl<VoteId: Eq, MaxSize> Eq for BoundedVoteIdSet<VoteId, MaxSize> {}
+
+impl<VoteId: fmt::Debug, MaxSize> fmt::Debug
+    for BoundedVoteIdSet<VoteId, MaxSize>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.ids.iter()).finish()
+    }
+}
+
+impl<VoteId: Encode, MaxSize> Encode for BoundedVoteIdSet<VoteId, MaxSize> {
+    fn encode_to<W: Output>(&self, dest: &mut W) {
+        self.ids.encode_to(dest)
+    }
+}
+
+impl<VoteId: Encode, MaxSize> EncodeLike for BoundedVoteIdSet<VoteId, MaxSize> {}
+
+impl<VoteId: Decode + PartialEq, MaxSize: Get<u32>> Decode
+    for BoundedVoteIdSet<VoteId, MaxSize>
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        let len = <Compact<u32>>::decode(input)?.0;
+        if len > MaxSize::get() {
+            return Err("BoundedVoteIdSet exceeds its capacity".into())
+        }
+        let mut set = Self::new();
+        for _ in 0..len {
+            let id = VoteId::decode(input)?;
+            // duplicates collapse so the capacity check above still holds
+            if !set.ids.contains(&id) {
+                set.ids.push(id);
+            }
+        }
+        Ok(set)
+    }
+}
+
+#[derive(
+    new, PartialEq, Eq, Copy, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Limits the signal any one voter receives when a vote is opened
+/// -> if both limits are set the lower one applies
+pub struct SignalMintingCap<Signal> {
+    /// Absolute limit on one voter's signal
+    max_signal_per_voter: Option<Signal>,
+    /// Limit relative to the signal the org would mint without any cap
+    max_signal_percent_per_voter: Option<Permill>,
+}
+
+impl<Signal: Copy + AtLeast32BitUnsigned> SignalMintingCap<Signal> {
+    /// The most signal one voter may receive out of `uncapped_total`
+    /// -> `None` if neither limit is set
+    pub fn cap(&self, uncapped_total: Signal) -> Option<Signal> {
+        let relative = self
+            .max_signal_percent_per_voter
+            .map(|p| p.mul_floor(uncapped_total));
+        match (self.max_signal_per_voter, relative) {
+            (Some(a), Some(r)) => Some(a.min(r)),
+            (a, r) => a.or(r),
+        }
+    }
+    /// Either limit is zero, which would leave every voter without signal
+    pub fn is_zero(&self) -> bool {
+        self.max_signal_per_voter == Some(Signal::zero())
+            || self.max_signal_percent_per_voter == Some(Permill::zero())
+    }
+}
+
+#[derive(
+    new, PartialEq, Eq, Copy, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Decay of the signal held by accounts that have not voted as a vote nears its end
+pub struct DecayConfig<BlockNumber> {
+    /// Share of the vote's duration that passes before signal starts to decay
+    decay_start: Permill,
+    /// Share of an account's undecayed signal it loses every period
+    decay_per_period: Permill,
+    /// Blocks in each decay period
+    period: BlockNumber,
+}
+
+impl<BlockNumber: Copy + AtLeast32BitUnsigned> DecayConfig<BlockNumber> {
+    pub fn decay_start(&self) -> Permill {
+        self.decay_start
+    }
+    pub fn decay_per_period(&self) -> Permill {
+        self.decay_per_period
+    }
+    pub fn period(&self) -> BlockNumber {
+        self.period
+    }
+    /// Whether a decay period of a vote open from `initialized` until `ends`
+    /// ends in `now`
+    /// -> periods are counted from the block decay starts in and never end
+    /// after `ends`
+    pub fn decays_at(
+        &self,
+        initialized: BlockNumber,
+        ends: BlockNumber,
+        now: BlockNumber,
+    ) -> bool {
+        let start = self.starts_at(initialized, ends);
+        !self.period.is_zero()
+            && now > start
+            && now <= ends
+            && ((now - start) % self.period).is_zero()
+    }
+    /// The block the first decay period after `after` ends in, if the vote is
+    /// still open then
+    pub fn next_decay(
+        &self,
+        initialized: BlockNumber,
+        ends: BlockNumber,
+        after: BlockNumber,
+    ) -> Option<BlockNumber> {
+        if self.period.is_zero() {
+            return None
+        }
+        let start = self.starts_at(initialized, ends);
+        let next = if after < start {
+            start.saturating_add(self.period)
+        } else {
+            let into_period = (after - start) % self.period;
+            after.saturating_add(self.period - into_period)
+        };
+        Some(next).filter(|n| *n <= ends)
+    }
+    fn starts_at(
+        &self,
+        initialized: BlockNumber,
+        ends: BlockNumber,
+    ) -> BlockNumber {
+        initialized.saturating_add(
+            self.decay_start.mul_floor(ends.saturating_sub(initialized)),
+        )
+    }
+}
+
+#[derive(
+    new, PartialEq, Eq, Copy, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Collateral every voter reserves when casting a vote
+pub struct BondedVotingConfig<Balance> {
+    bond_amount: Balance,
+    /// If false, losing voters get their bond back like everyone else
+    slash_on_losing_side: bool,
+}
+
+impl<Balance: Copy> BondedVotingConfig<Balance> {
+    pub fn bond_amount(&self) -> Balance {
+        self.bond_amount
+    }
+    pub fn slash_on_losing_side(&self) -> bool {
+        self.slash_on_losing_side
+    }
+}
+
+#[derive(
+    new, PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Configuration of one round of a multi round vote
+pub struct RoundSpec<Signal, BlockNumber> {
+    /// Every candidate's vote in the round uses this threshold
+    threshold: Threshold<Signal>,
+    duration: Option<BlockNumber>,
+    /// Number of top candidates carried into the next round if none is approved
+    advancing: u32,
+}
+
+impl<Signal: Clone, BlockNumber: Copy> RoundSpec<Signal, BlockNumber> {
+    pub fn threshold(&self) -> Threshold<Signal> {
+        self.threshold.clone()
+    }
+    pub fn duration(&self) -> Option<BlockNumber> {
+        self.duration
+    }
+    pub fn advancing(&self) -> u32 {
+        self.advancing
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug)]
+/// Who besides the org's supervisor may open votes for the org
+pub enum OpenVotePermission<AccountId> {
+    /// Every account in the org's membership
+    AnyMember,
+    /// No one, only the supervisor opens votes
+    ControllerOnly,
+    /// Only the listed accounts, whether or not they are members
+    NamedProposers(Vec<AccountId>),
+}
+
+/// Votes were only opened by the org's supervisor before this was configurable
+impl<AccountId> Default for OpenVotePermission<AccountId> {
+    fn default() -> Self {
+        OpenVotePermission::ControllerOnly
+    }
+}
+
+#[derive(
+    new, PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Aggregate counts of a module's votes since genesis
+pub struct VoteMetrics {
+    current_open: u32,
+    /// The most votes that were open at once
+    peak_open: u32,
+    total_ever: u64,
+    /// Finalized votes by final outcome, leaving out outcomes never reached
+    by_outcome: Vec<(VoteOutcome, u64)>,
+}
+
+impl VoteMetrics {
+    pub fn current_open(&self) -> u32 {
+        self.current_open
+    }
+    pub fn peak_open(&self) -> u32 {
+        self.peak_open
+    }
+    pub fn total_ever(&self) -> u64 {
+        self.total_ever
+    }
+    pub fn by_outcome(&self) -> Vec<(VoteOutcome, u64)> {
+        self.by_outcome.clone()
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug)]
+/// Sequential runoff rounds, each opening one vote per remaining candidate
+pub struct MultiRoundVote<Cid, VoteId, Signal, BlockNumber> {
+    /// Configuration of every round, including rounds not yet opened
+    specs: Vec<RoundSpec<Signal, BlockNumber>>,
+    /// The vote opened for each candidate, grouped by round
+    rounds: Vec<Vec<(Cid, VoteId)>>,
+    current_round: u8,
+}
+
+impl<Cid: Clone, VoteId: Copy, Signal: Clone, BlockNumber: Copy>
+    MultiRoundVote<Cid, VoteId, Signal, BlockNumber>
+{
+    pub fn new(
+        specs: Vec<RoundSpec<Signal, BlockNumber>>,
+        first_round: Vec<(Cid, VoteId)>,
+    ) -> Self {
+        Self {
+            specs,
+            rounds: vec![first_round],
+            current_round: 0u8,
+        }
+    }
+    pub fn rounds(&self) -> Vec<Vec<(Cid, VoteId)>> {
+        self.rounds.clone()
+    }
+    pub fn current_round(&self) -> u8 {
+        self.current_round
+    }
+    /// The candidates and their votes in the current round
+    pub fn current_votes(&self) -> Vec<(Cid, VoteId)> {
+        self.rounds[self.current_round as usize].clone()
+    }
+    pub fn current_spec(&self) -> RoundSpec<Signal, BlockNumber> {
+        self.specs[self.current_round as usize].clone()
+    }
+    /// `None` if the current round is the last
+    pub fn next_spec(&self) -> Option<RoundSpec<Signal, BlockNumber>> {
+        self.specs.get(self.current_round as usize + 1).cloned()
+    }
+    pub fn advance(&self, next_round: Vec<(Cid, VoteId)>) -> Self {
+        let mut rounds = self.rounds.clone();
+        rounds.push(next_round);
+        Self {
+            specs: self.specs.clone(),
+            rounds,
+            current_round: self.current_round + 1u8,
+        }
+    }
+}
+
+/// Vote power equal to signal
+pub struct LinearWeight;
+
+impl<Signal> VoteWeight<Signal> for LinearWeight {
+    fn transform(signal: Signal) -> Signal {
+        signal
+    }
+}
+
+/// Vote power is the integer square root of signal
+pub struct QuadraticWeight;
+
+impl<Signal: AtLeast32BitUnsigned + Copy> VoteWeight<Signal>
+    for QuadraticWeight
+{
+    fn transform(signal: Signal) -> Signal {
+        integer_sqrt(signal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decay_periods_end_after_decay_starts() {
+        // open from block 1 to 11, decay starts at block 6
+        let decay: DecayConfig<u64> = DecayConfig::new(
+            Permill::from_percent(50),
+            Permill::from_percent(20),
+            2,
+        );
+        assert_eq!(decay.next_decay(1, 11, 1), Some(8));
+        assert_eq!(decay.next_decay(1, 11, 6), Some(8));
+        assert_eq!(decay.next_decay(1, 11, 7), Some(8));
+        assert_eq!(decay.next_decay(1, 11, 8), Some(10));
+        assert_eq!(decay.next_decay(1, 11, 10), None);
+        (1..=11).for_each(|n| {
+            assert_eq!(decay.decays_at(1, 11, n), n == 8 || n == 10);
+        });
+        let never: DecayConfig<u64> = DecayConfig::new(
+            Permill::from_percent(50),
+            Permill::from_percent(20),
+            0,
+        );
+        assert_eq!(never.next_decay(1, 11, 1), None);
+    }
+
+    #[test]
+    fn possible_turnout_changes_are_checked() {
+        let state: VoteState<u64, u64, u64> =
+            VoteState::new(None, 10, Threshold::new(5, None), 1, None);
+        assert_eq!(
+            state
+                .add_possible_turnout(5)
+                .unwrap()
+                .all_possible_turnout(),
+            15
+        );
+        assert!(state.add_possible_turnout(u64::max_value()).is_none());
+        assert_eq!(
+            state
+                .subtract_possible_turnout(4)
+                .unwrap()
+                .all_possible_turnout(),
+            6
+        );
+        assert!(state.subtract_possible_turnout(11).is_none());
+        // the turnout can't fall below the signal already tallied
+        let voted = VoteState {
+            in_favor: 6,
+            against: 2,
+            turnout: 8,
+            ..state
+        };
+        assert!(voted.subtract_possible_turnout(2).is_some());
+        assert!(voted.subtract_possible_turnout(3).is_none());
+    }
+
+    #[test]
+    fn vote_weight_maps_signal_to_vote_power() {
+        assert_eq!(<LinearWeight as VoteWeight<u64>>::transform(17), 17);
+        assert_eq!(<QuadraticWeight as VoteWeight<u64>>::transform(17), 4);
+        assert_eq!(<QuadraticWeight as VoteWeight<u64>>::transform(16), 4);
+        assert_eq!(<QuadraticWeight as VoteWeight<u64>>::transform(0), 0);
+    }
+}